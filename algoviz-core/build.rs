@@ -0,0 +1,29 @@
+//! Embeds every `locales/<lang>.ron` message catalog, so a new language is
+//! one new file: `src/locale.rs` includes the generated list.
+
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("locales");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut catalogs: Vec<(String, String)> = fs::read_dir(&dir)
+        .expect("algoviz-core/locales")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            println!("cargo:rerun-if-changed={}", path.display());
+            let code = path.file_stem()?.to_str()?.to_owned();
+            (path.extension()? == "ron").then(|| (code, path.display().to_string()))
+        })
+        .collect();
+    // English first: it is the fallback and the start of the L cycle
+    catalogs.sort_by_key(|(code, _)| (code != "en", code.clone()));
+
+    let entries: String = catalogs
+        .iter()
+        .map(|(code, path)| format!("    ({code:?}, include_str!({path:?})),\n"))
+        .collect();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("locales.rs");
+    fs::write(out, format!("&[\n{entries}]\n")).unwrap();
+}
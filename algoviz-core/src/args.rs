@@ -0,0 +1,507 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::dense::{DENSE_MAX_N, DENSE_N};
+use crate::headless::{run_flags, viz_args};
+
+/// What an array viz can draw: how many bars fit on screen and the largest
+/// value its bar height scale (and digit labels) can show.
+#[derive(Clone, Copy)]
+pub struct ArraySpec {
+    pub default_n: usize,
+    pub max_n: usize,
+    pub max_value: usize,
+    /// `--n` may also go from [`DENSE_N`] to [`DENSE_MAX_N`], drawn by
+    /// [`VizBuilder::dense_sort`](crate::VizBuilder::dense_sort).
+    pub dense: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    /// `-h` / `--help`: not a mistake, but nothing to run either.
+    Help,
+    Invalid(String),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Help => f.write_str("help requested"),
+            Self::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+/// The shape of a generated array. Each preset orders the ranks `0..n`;
+/// [`ArrayInput::preset`] scales them onto the viz's value range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Distribution {
+    #[default]
+    Random,
+    /// Sorted, then about one in eight neighbours swapped.
+    NearlySorted,
+    Reversed,
+    /// Four values, repeated.
+    FewUnique,
+    /// Ascending runs, each starting a little above the last.
+    Sawtooth,
+    /// Rising to the middle, then falling.
+    OrganPipe,
+    AllEqual,
+}
+
+impl Distribution {
+    pub const ALL: [Self; 7] = [
+        Self::Random,
+        Self::NearlySorted,
+        Self::Reversed,
+        Self::FewUnique,
+        Self::Sawtooth,
+        Self::OrganPipe,
+        Self::AllEqual,
+    ];
+
+    /// The `--dist` name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Random => "random",
+            Self::NearlySorted => "nearly-sorted",
+            Self::Reversed => "reversed",
+            Self::FewUnique => "few-unique",
+            Self::Sawtooth => "sawtooth",
+            Self::OrganPipe => "organ-pipe",
+            Self::AllEqual => "all-equal",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Random => "Random",
+            Self::NearlySorted => "Nearly sorted",
+            Self::Reversed => "Reversed",
+            Self::FewUnique => "Few unique",
+            Self::Sawtooth => "Sawtooth",
+            Self::OrganPipe => "Organ pipe",
+            Self::AllEqual => "All equal",
+        }
+    }
+
+    /// The ranks `0..n` in this shape.
+    pub fn ranks(self, n: usize, rng: &mut StdRng) -> Vec<usize> {
+        let mut ranks: Vec<usize> = (0..n).collect();
+        match self {
+            Self::Random => ranks.shuffle(rng),
+            Self::NearlySorted => {
+                for _ in 0..(n / 8).max(1) {
+                    let i = rng.gen_range(0..n - 1);
+                    ranks.swap(i, i + 1);
+                }
+            }
+            Self::Reversed => ranks.reverse(),
+            Self::FewUnique => ranks = (0..n).map(|_| rng.gen_range(0..4) * (n - 1) / 3).collect(),
+            Self::Sawtooth => {
+                let teeth = if n >= 8 { 4 } else { 2 };
+                let tooth = n.div_ceil(teeth);
+                // rank the slots by (place in tooth, tooth) so the values stay distinct
+                let mut slots: Vec<usize> = (0..n).collect();
+                slots.sort_by_key(|&i| (i % tooth, i / tooth));
+                for (rank, slot) in slots.into_iter().enumerate() {
+                    ranks[slot] = rank;
+                }
+            }
+            Self::OrganPipe => ranks = (0..n).step_by(2).chain((1..n).step_by(2).rev()).collect(),
+            Self::AllEqual => ranks = vec![(n - 1) / 2; n],
+        }
+        ranks
+    }
+}
+
+impl std::str::FromStr for Distribution {
+    type Err = ArgError;
+
+    fn from_str(name: &str) -> Result<Self, ArgError> {
+        Self::ALL
+            .into_iter()
+            .find(|dist| dist.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|dist| dist.name()).collect();
+                ArgError::Invalid(format!(
+                    "--dist: unknown preset `{name}` (one of {})",
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+/// The array a viz sorts, chosen on the command line:
+/// `--input 5,3,9,1` fixes it, otherwise `--n 24` sets the length of a
+/// generated array, `--dist nearly-sorted` its shape (shuffled by default),
+/// and `--seed 42` makes the shuffles repeatable. An array edited on screen
+/// (see [`edit`](Self::edit)) is the next one handed out.
+#[derive(Resource, Clone)]
+pub struct ArrayInput {
+    /// Values given with `--input`; R replays them unchanged.
+    pub values: Option<Vec<usize>>,
+    pub n: usize,
+    /// Picked with `--dist`, D or the control bar's input button.
+    pub distribution: Distribution,
+    pub rng: StdRng,
+    max_value: usize,
+    edited: Option<Vec<usize>>,
+}
+
+impl ArrayInput {
+    /// Parse `std::env::args` minus the run flags (`--headless` / `--trace` / `--export-svg`,
+    /// see [`TraceWriter`](crate::TraceWriter)); the run flag `--seed` seeds the shuffles.
+    /// `--help` prints the usage and exits; bad arguments print the error and usage and exit.
+    pub fn from_args(spec: ArraySpec) -> Self {
+        match Self::parse(viz_args(), spec) {
+            Ok(mut input) => {
+                if let Some(seed) = run_flags().seed {
+                    input.rng = StdRng::seed_from_u64(seed);
+                }
+                input
+            }
+            Err(ArgError::Help) => {
+                println!("{}", usage(spec));
+                std::process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("error: {err}\n\n{}", usage(spec));
+                std::process::exit(2);
+            }
+        }
+    }
+
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        spec: ArraySpec,
+    ) -> Result<Self, ArgError> {
+        let mut values = None;
+        let mut n = None;
+        let mut distribution = None;
+        let mut seed = None;
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| ArgError::Invalid(format!("{flag} needs a value")))
+            };
+            match flag.as_str() {
+                "--input" => values = Some(parse_values(&value()?)?),
+                "--n" => {
+                    n = Some(
+                        value()?
+                            .parse::<usize>()
+                            .map_err(|_| ArgError::Invalid("--n must be a whole number".into()))?,
+                    )
+                }
+                "--dist" => distribution = Some(value()?.parse::<Distribution>()?),
+                "--seed" => {
+                    seed =
+                        Some(value()?.parse::<u64>().map_err(|_| {
+                            ArgError::Invalid("--seed must be a whole number".into())
+                        })?)
+                }
+                "-h" | "--help" => return Err(ArgError::Help),
+                other => return Err(ArgError::Invalid(format!("unknown argument `{other}`"))),
+            }
+        }
+
+        if values.is_some() && n.is_some() {
+            return Err(ArgError::Invalid(
+                "use either --input or --n, not both".into(),
+            ));
+        }
+        if values.is_some() && distribution.is_some() {
+            return Err(ArgError::Invalid(
+                "use either --input or --dist, not both".into(),
+            ));
+        }
+        let distribution = distribution.unwrap_or_default();
+        let n = match &values {
+            Some(v) => {
+                validate(v, spec)?;
+                v.len()
+            }
+            None => n.unwrap_or(spec.default_n),
+        };
+        let dense = spec.dense && values.is_none() && (DENSE_N..=DENSE_MAX_N).contains(&n);
+        if !(2..=spec.max_n).contains(&n) && !dense {
+            let columns = if spec.dense {
+                format!(", or {DENSE_N} to {DENSE_MAX_N} as pixel columns")
+            } else {
+                String::new()
+            };
+            return Err(ArgError::Invalid(format!(
+                "this visualization shows 2 to {} elements{columns}, got {n}",
+                spec.max_n
+            )));
+        }
+        let rng = match seed {
+            Some(s) => StdRng::seed_from_u64(s),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Self {
+            values,
+            n,
+            distribution,
+            rng,
+            max_value: spec.max_value,
+            edited: None,
+        })
+    }
+
+    /// The `--input` array, or the preset over `1..=n` (shuffled by default).
+    pub fn permutation(&mut self) -> Vec<usize> {
+        self.preset(1, self.n)
+    }
+
+    /// The `--input` array, or the preset's ranks spread evenly over
+    /// `lowest..=highest`; a range narrower than `n` repeats values.
+    /// `highest` is capped at the spec's `max_value` unless the array is drawn
+    /// as pixel columns, so generated bars fit the same scale as `--input`.
+    pub fn preset(&mut self, lowest: usize, highest: usize) -> Vec<usize> {
+        let distribution = self.distribution;
+        let highest = if self.dense() {
+            highest
+        } else {
+            highest.min(self.max_value)
+        };
+        self.array_or(|n, rng| {
+            let span = highest.saturating_sub(lowest);
+            distribution
+                .ranks(n, rng)
+                .into_iter()
+                .map(|rank| lowest + rank * span / (n - 1).max(1))
+                .collect()
+        })
+    }
+
+    /// Too long for one bar each: the viz runs as [`VizBuilder::dense_sort`](crate::VizBuilder::dense_sort).
+    pub fn dense(&self) -> bool {
+        self.values.is_none() && self.n >= DENSE_N
+    }
+
+    /// The next preset, for D and the input button.
+    pub fn next_distribution(&self) -> Distribution {
+        let at = Distribution::ALL
+            .iter()
+            .position(|&dist| dist == self.distribution)
+            .unwrap_or(0);
+        Distribution::ALL[(at + 1) % Distribution::ALL.len()]
+    }
+
+    /// Hand out `array` on the next request instead, once: the viz restarts on
+    /// it with [`ResetRequested`](crate::ResetRequested), and R after that
+    /// reshuffles (or replays `--input`) as usual.
+    pub fn edit(&mut self, array: Vec<usize>) {
+        self.edited = Some(array);
+    }
+
+    /// `value` moved by `notches` within `1..=max_value`; `None` past the ends.
+    pub(crate) fn edited_value(&self, value: usize, notches: i32) -> Option<usize> {
+        let moved = value.checked_add_signed(notches as isize)?;
+        (1..=self.max_value).contains(&moved).then_some(moved)
+    }
+
+    /// The edited array, the `--input` array, or whatever `make` builds for `n` elements.
+    pub fn array_or(&mut self, make: impl FnOnce(usize, &mut StdRng) -> Vec<usize>) -> Vec<usize> {
+        if let Some(edited) = self.edited.take() {
+            return edited;
+        }
+        match &self.values {
+            Some(v) => v.clone(),
+            None => make(self.n, &mut self.rng),
+        }
+    }
+}
+
+fn parse_values(list: &str) -> Result<Vec<usize>, ArgError> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<usize>().map_err(|_| {
+                ArgError::Invalid(format!("--input: `{s}` is not a positive whole number"))
+            })
+        })
+        .collect()
+}
+
+fn validate(values: &[usize], spec: ArraySpec) -> Result<(), ArgError> {
+    if values.contains(&0) {
+        return Err(ArgError::Invalid(
+            "--input: 0 would be a bar with no height (values start at 1)".into(),
+        ));
+    }
+    if let Some(&v) = values.iter().find(|&&v| v > spec.max_value) {
+        return Err(ArgError::Invalid(format!(
+            "--input: {v} is too large for the bar height scale (max {})",
+            spec.max_value
+        )));
+    }
+    Ok(())
+}
+
+fn usage(spec: ArraySpec) -> String {
+    format!(
+        "options:\n  --input 5,3,9,1   array to sort (2..={} values, each 1..={})\n  --n N             generated array of N elements instead (default {}{})\n  --dist PRESET     its shape: {}\n  --seed S          repeatable shuffles",
+        spec.max_n,
+        spec.max_value,
+        spec.default_n,
+        if spec.dense { format!("; {DENSE_N}..={DENSE_MAX_N} draws pixel columns") } else { String::new() },
+        presets(),
+    )
+}
+
+fn presets() -> String {
+    Distribution::ALL
+        .into_iter()
+        .map(Distribution::name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: ArraySpec = ArraySpec {
+        default_n: 12,
+        max_n: 150,
+        max_value: 99,
+        dense: false,
+    };
+
+    fn parse(args: &str, spec: ArraySpec) -> Result<ArrayInput, ArgError> {
+        ArrayInput::parse(args.split_whitespace().map(String::from), spec)
+    }
+
+    fn error(args: &str, spec: ArraySpec) -> String {
+        match parse(args, spec) {
+            Err(ArgError::Invalid(message)) => message,
+            Err(ArgError::Help) => panic!("`{args}` asked for help"),
+            Ok(_) => panic!("`{args}` parsed"),
+        }
+    }
+
+    #[test]
+    fn input_fixes_the_array_and_its_length() {
+        let mut input = parse("--input 5,3,9,1 --seed 7", SPEC).unwrap();
+        assert_eq!(input.n, 4);
+        assert_eq!(input.permutation(), [5, 3, 9, 1]);
+        assert_eq!(input.permutation(), [5, 3, 9, 1]);
+    }
+
+    #[test]
+    fn input_excludes_n_and_dist() {
+        assert!(error("--input 5,3,9 --n 3", SPEC).contains("--input or --n"));
+        assert!(error("--dist reversed --input 5,3,9", SPEC).contains("--input or --dist"));
+    }
+
+    #[test]
+    fn n_outside_the_spec_is_an_error() {
+        for args in ["--n 1", "--n 151", "--input 4", "--n 200"] {
+            assert!(error(args, SPEC).contains("2 to 150 elements"), "{args}");
+        }
+        assert!(error("--n many", SPEC).contains("whole number"));
+        assert!(error("--n", SPEC).contains("needs a value"));
+        assert!(error("--dist zigzag", SPEC).contains("unknown preset"));
+        assert!(error("--bars 3", SPEC).contains("unknown argument"));
+    }
+
+    #[test]
+    fn dense_specs_also_take_the_pixel_column_range() {
+        let spec = ArraySpec {
+            max_n: DENSE_N - 1,
+            dense: true,
+            ..SPEC
+        };
+        for n in [DENSE_N, DENSE_MAX_N] {
+            let input = parse(&format!("--n {n}"), spec).unwrap();
+            assert!(input.dense());
+        }
+        assert!(error(&format!("--n {}", DENSE_MAX_N + 1), spec).contains("pixel columns"));
+        // --input never draws as columns
+        let long = vec!["1"; DENSE_N].join(",");
+        assert!(error(&format!("--input {long}"), spec).contains("got 200"));
+    }
+
+    #[test]
+    fn help_is_returned_rather_than_exiting() {
+        assert_eq!(parse("--n 5 -h", SPEC).err(), Some(ArgError::Help));
+        assert_eq!(parse("--help", SPEC).err(), Some(ArgError::Help));
+    }
+
+    #[test]
+    fn validate_rejects_zero_and_values_past_the_scale() {
+        assert_eq!(validate(&[3, 1, 99], SPEC), Ok(()));
+        let Err(ArgError::Invalid(zero)) = validate(&[3, 0, 2], SPEC) else {
+            panic!("0 accepted");
+        };
+        assert!(zero.contains("values start at 1"));
+        let Err(ArgError::Invalid(large)) = validate(&[3, 100], SPEC) else {
+            panic!("100 accepted");
+        };
+        assert!(large.contains("100") && large.contains("max 99"));
+        assert!(error("--input 2,x", SPEC).contains("`x`"));
+    }
+
+    #[test]
+    fn generated_arrays_stay_within_max_value() {
+        for dist in Distribution::ALL {
+            let mut input =
+                parse(&format!("--n 150 --dist {} --seed 3", dist.name()), SPEC).unwrap();
+            let array = input.permutation();
+            assert_eq!(array.len(), 150);
+            assert!(array.iter().all(|v| (1..=99).contains(v)), "{dist:?}");
+        }
+    }
+
+    #[test]
+    fn every_preset_ranks_within_0_to_n() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for n in [2, 7, 8, 30] {
+            for dist in Distribution::ALL {
+                let ranks = dist.ranks(n, &mut rng);
+                assert_eq!(ranks.len(), n, "{dist:?}");
+                assert!(ranks.iter().all(|&rank| rank < n), "{dist:?}");
+                let mut distinct = ranks.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+                match dist {
+                    Distribution::FewUnique => assert!(distinct.len() <= 4),
+                    Distribution::AllEqual => assert_eq!(distinct.len(), 1),
+                    _ => assert_eq!(distinct.len(), n, "{dist:?} repeats a rank"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn presets_have_their_shape() {
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(Distribution::Reversed.ranks(5, &mut rng), [4, 3, 2, 1, 0]);
+        assert_eq!(
+            Distribution::OrganPipe.ranks(6, &mut rng),
+            [0, 2, 4, 5, 3, 1]
+        );
+        assert_eq!(
+            Distribution::Sawtooth.ranks(8, &mut rng),
+            [0, 4, 1, 5, 2, 6, 3, 7]
+        );
+        let nearly = Distribution::NearlySorted.ranks(16, &mut rng);
+        let out_of_place = nearly.iter().enumerate().filter(|&(i, &r)| i != r).count();
+        assert!(out_of_place <= 4, "{nearly:?}");
+        for name in presets().split(", ") {
+            assert_eq!(name.parse::<Distribution>().unwrap().name(), name);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::digits::DigitStyle;
+
+/// The window width the sorting vizzes' `ROW_WIDTH` constants were laid out for.
+const DESIGN_WIDTH: f32 = 900.0;
+const MIN_ROW_WIDTH: f32 = 200.0;
+// gaps shrink once a bar would be narrower than this many gaps
+const CROWDED: f32 = 3.0;
+
+/// Where `n` array bars go: as wide as `max_width` allows, narrowed to fit
+/// the row, with the gaps shrinking too once the bars get crowded.
+///
+/// ```ignore
+/// let row = BarRow::fit(n, row_width(ROW_WIDTH, &windows), BAR_WIDTH, BAR_GAP);
+/// let x = row.x(i);
+/// if row.labels(top) { spawn_value_digits(parent, value, y, color); }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BarRow {
+    x0: f32,
+    pub width: f32,
+    pub gap: f32,
+}
+
+impl BarRow {
+    pub fn fit(n: usize, row_width: f32, max_width: f32, gap: f32) -> Self {
+        let n = n.max(1) as f32;
+        let mut slot = ((row_width + gap) / n).min(max_width + gap);
+        let share = 1.0 / (CROWDED + 1.0);
+        if gap > slot * share {
+            // the gap is a share of the slot now: n slots less one gap fill the row
+            slot = row_width / (n - share);
+        }
+        let gap = gap.min(slot * share);
+        let width = slot - gap;
+        let total = n * width + (n - 1.0) * gap;
+        Self {
+            x0: -total / 2.0 + width / 2.0,
+            width,
+            gap,
+        }
+    }
+
+    /// Center of bar `i`.
+    pub fn x(&self, i: usize) -> f32 {
+        self.x0 + i as f32 * (self.width + self.gap)
+    }
+
+    /// Whether the digits of values up to `top` fit over their bars; below
+    /// that the labels would run into each other, so vizzes leave them out.
+    pub fn labels(&self, top: usize) -> bool {
+        let style = DigitStyle::LARGE;
+        let digits = top.max(1).ilog10() as f32 + 1.0;
+        (digits - 1.0) * style.advance + style.width <= self.width + self.gap
+    }
+}
+
+/// The row width for a viz laid out as `design` pixels in a 900px window,
+/// widened or narrowed with the primary window (unchanged without one).
+pub fn row_width(design: f32, windows: &Query<&Window, With<PrimaryWindow>>) -> f32 {
+    let extra = windows
+        .get_single()
+        .map_or(0.0, |window| window.width() - DESIGN_WIDTH);
+    (design + extra).max(MIN_ROW_WIDTH)
+}
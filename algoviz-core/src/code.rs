@@ -0,0 +1,237 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+
+use crate::controls::{ResetRequested, VizSet};
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Locale;
+use crate::theme::Palette;
+
+const PANEL_WIDTH: f32 = 440.0; // a 55-character line at FONT_SIZE
+const MARGIN: f32 = 10.0;
+// under the cost chart
+const TOP: f32 = 214.0;
+const FONT_SIZE: f32 = 12.0;
+
+/// One line of a [`Listing`]: the ID a step names it by (`""` for lines no
+/// step stops on) and its text.
+pub type CodeLine = (&'static str, &'static str);
+
+/// An algorithm's code as the panel shows it. A viz can offer the same
+/// algorithm twice, say as pseudocode and as Rust, with the same line IDs
+/// on the lines that do the same thing:
+///
+/// ```ignore
+/// const PSEUDOCODE: Listing = Listing::new("code.pseudocode", &[
+///     ("", "procedure bubble_sort(a)"),
+///     ("pass", "  for i = 0 to n - 2"),
+///     ("", "    for j = 0 to n - 2 - i"),
+///     ("compare", "      if a[j] > a[j+1]"),
+///     ("swap", "        swap a[j], a[j+1]"),
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Listing {
+    /// Message ID of the listing's name.
+    pub title: &'static str,
+    pub lines: &'static [CodeLine],
+}
+
+impl Listing {
+    pub const fn new(title: &'static str, lines: &'static [CodeLine]) -> Self {
+        Self { title, lines }
+    }
+}
+
+/// Where the algorithm is in its [`Listing`]: the line of the step just taken
+/// and the local variables to list with their values. The step system sets it:
+///
+/// ```ignore
+/// code.at("compare").var("i", sort.i).var("j", a).var("a[j]", sort.array[a]);
+/// ```
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct CodeCursor {
+    line: Option<&'static str>,
+    vars: Vec<(&'static str, String)>,
+}
+
+impl CodeCursor {
+    /// The step is at line `id`; the previous step's variables are dropped.
+    pub fn at(&mut self, id: &'static str) -> &mut Self {
+        self.line = Some(id);
+        self.vars.clear();
+        self
+    }
+
+    pub fn var(&mut self, name: &'static str, value: impl Display) -> &mut Self {
+        self.vars.push((name, value.to_string()));
+        self
+    }
+
+    /// No line highlighted: before the first step, or after the last when no line stands for it.
+    pub fn clear(&mut self) {
+        self.line = None;
+        self.vars.clear();
+    }
+
+    pub fn line(&self) -> Option<&'static str> {
+        self.line
+    }
+
+    pub fn vars(&self) -> &[(&'static str, String)] {
+        &self.vars
+    }
+}
+
+/// Which listing the panel shows, if any: K steps through a viz's
+/// listings and then hides the panel; `--code` starts on the first.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+struct CodeView {
+    shown: Option<usize>,
+}
+
+#[derive(Component)]
+struct CodePanel(&'static [Listing]);
+
+/// Registers the code panel for the viz in `state`: the [`CodeCursor`],
+/// cleared on entering and on R, and a panel on the right showing the
+/// current listing with the cursor's line highlighted and its variables below.
+pub(crate) fn add_code_panel(app: &mut App, state: Gallery, listings: &'static [Listing]) {
+    let Gallery::Viz(id) = state else { return };
+    if !app.world().contains_resource::<CodeView>() {
+        let shown = crate::headless::run_flags().code.then_some(0);
+        app.insert_resource(CodeView { shown })
+            .init_resource::<CodeCursor>();
+    }
+    let spawn = move |mut commands: Commands| {
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(TOP),
+                    right: Val::Px(MARGIN),
+                    width: Val::Px(PANEL_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                // nearly opaque: the panel sits over the bars
+                background_color: BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.9)),
+                ..default()
+            },
+            CodePanel(listings),
+        ));
+    };
+    app.add_systems(
+        OnEnter(state),
+        (
+            (|mut code: ResMut<CodeCursor>| code.clear()).in_set(Enter::Reset),
+            spawn.in_set(Enter::Controls),
+        ),
+    )
+    .add_systems(
+        Update,
+        (
+            code_input.in_set(VizSet::Controls),
+            draw_code_panel.in_set(VizSet::Highlight),
+        )
+            .in_set(VizScope(id)),
+    );
+}
+
+// K: next listing, then hidden; R clears the cursor along with the viz's state
+fn code_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut view: ResMut<CodeView>,
+    mut code: ResMut<CodeCursor>,
+    panels: Query<&CodePanel>,
+) {
+    if keys.just_pressed(KeyCode::KeyK) {
+        let count = panels.get_single().map_or(0, |panel| panel.0.len());
+        view.shown = match view.shown {
+            None if count > 0 => Some(0),
+            Some(at) if at + 1 < count => Some(at + 1),
+            _ => None,
+        };
+    }
+    if reset.read().count() > 0 {
+        code.clear();
+    }
+}
+
+// Rebuilds the lines when the cursor, the listing shown, the theme or the language changed
+fn draw_code_panel(
+    view: Res<CodeView>,
+    code: Res<CodeCursor>,
+    locale: Res<Locale>,
+    palette: Res<Palette>,
+    mut panel: Query<(Entity, &CodePanel, &mut Visibility, Ref<Node>)>,
+    mut commands: Commands,
+) {
+    let Ok((entity, listings, mut visibility, node)) = panel.get_single_mut() else {
+        return;
+    };
+    let changed =
+        view.is_changed() || code.is_changed() || locale.is_changed() || palette.is_changed();
+    if !changed && !node.is_added() {
+        return;
+    }
+    let listing = view.shown.and_then(|at| listings.0.get(at));
+    *visibility = if listing.is_some() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    commands.entity(entity).despawn_descendants();
+    let Some(listing) = listing else { return };
+    let text = |value: String, color: Color| {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size: FONT_SIZE,
+                color,
+                ..default()
+            },
+        )
+    };
+    commands.entity(entity).with_children(|panel| {
+        let title = crate::tr!(locale, "code.title", listing = locale.text(listing.title));
+        panel.spawn(
+            text(title, palette.ink(Color::srgb(1.0, 1.0, 0.8))).with_style(Style {
+                margin: UiRect::bottom(Val::Px(4.0)),
+                ..default()
+            }),
+        );
+        for (number, &(id, line)) in listing.lines.iter().enumerate() {
+            let current = !id.is_empty() && code.line == Some(id);
+            let (background, color) = if current {
+                (palette.compare.with_alpha(0.35), Color::WHITE)
+            } else {
+                (Color::NONE, Color::srgb(0.75, 0.75, 0.78))
+            };
+            panel
+                .spawn(NodeBundle {
+                    background_color: BackgroundColor(background),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn(text(format!("{:>2}  {line}", number + 1), color));
+                });
+        }
+        if !code.vars.is_empty() {
+            let vars: Vec<String> = code
+                .vars
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect();
+            panel.spawn(
+                text(vars.join("   "), palette.ink(Color::srgb(0.7, 0.9, 1.0))).with_style(Style {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    max_width: Val::Px(PANEL_WIDTH - 16.0),
+                    ..default()
+                }),
+            );
+        }
+    });
+}
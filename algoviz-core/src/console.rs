@@ -0,0 +1,156 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::{ButtonState, InputSystem};
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Locale;
+
+const MAX_LEN: usize = 40;
+const MARGIN: f32 = 10.0;
+const FONT_SIZE: f32 = 15.0;
+const FIELD_IDLE: Color = Color::srgba(0.2, 0.6, 1.0, 0.15);
+const FIELD_FOCUSED: Color = Color::srgba(0.2, 0.6, 1.0, 0.35);
+const LABEL_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+
+/// A line the learner typed into the viz's [console](crate::VizBuilder::console)
+/// and entered, trimmed and never empty. The viz parses it.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct ConsoleCommand(pub String);
+
+/// The line being typed; while focused it has the keyboard to itself.
+#[derive(Resource, Default)]
+struct ConsoleLine {
+    text: String,
+    focused: bool,
+    // message ID of what the console shows while it is not focused
+    hint: &'static str,
+}
+
+#[derive(Component)]
+struct ConsoleField;
+
+#[derive(Component)]
+struct ConsoleText;
+
+/// Registers the console for the viz in `state`: Enter (or a click on the
+/// field) focuses it, typed keys go into the line instead of the viz's hotkeys,
+/// Enter sends the line as a [`ConsoleCommand`] and Escape leaves the console.
+pub(crate) fn add_console(app: &mut App, state: Gallery, hint: &'static str) {
+    let Gallery::Viz(id) = state else { return };
+    let start = move |mut commands: Commands| {
+        commands.insert_resource(ConsoleLine { hint, ..default() });
+        commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(MARGIN),
+                        left: Val::Px(MARGIN),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        min_width: Val::Px(320.0),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(FIELD_IDLE),
+                    ..default()
+                },
+                ConsoleField,
+            ))
+            .with_children(|field| {
+                field.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font_size: FONT_SIZE,
+                            color: LABEL_COLOR,
+                            ..default()
+                        },
+                    ),
+                    ConsoleText,
+                ));
+            });
+    };
+    app.add_event::<ConsoleCommand>()
+        .add_systems(OnEnter(state), start.in_set(Enter::Setup))
+        .add_systems(
+            PreUpdate,
+            // before Update, where the hotkeys are read from the keys this clears
+            type_line
+                .after(InputSystem)
+                .after(UiSystem::Focus)
+                .in_set(VizScope(id)),
+        )
+        .add_systems(Update, draw_line.in_set(VizScope(id)));
+}
+
+fn type_line(
+    mut typed: EventReader<KeyboardInput>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    field: Query<&Interaction, (Changed<Interaction>, With<ConsoleField>)>,
+    mut line: ResMut<ConsoleLine>,
+    mut entered: EventWriter<ConsoleCommand>,
+) {
+    let typed: Vec<Key> = typed
+        .read()
+        .filter(|event| event.state == ButtonState::Pressed)
+        .map(|event| event.logical_key.clone())
+        .collect();
+    if !line.focused {
+        if keys.just_pressed(KeyCode::Enter)
+            || field
+                .iter()
+                .any(|interaction| *interaction == Interaction::Pressed)
+        {
+            line.focused = true;
+            keys.clear_just_pressed(KeyCode::Enter);
+        }
+        return;
+    }
+    for key in typed {
+        match key {
+            Key::Enter => {
+                let text = std::mem::take(&mut line.text);
+                if !text.trim().is_empty() {
+                    entered.send(ConsoleCommand(text.trim().to_string()));
+                }
+            }
+            Key::Escape => line.focused = false,
+            Key::Backspace => {
+                line.text.pop();
+            }
+            Key::Space if line.text.len() < MAX_LEN => line.text.push(' '),
+            Key::Character(chars) if line.text.len() < MAX_LEN => {
+                line.text.extend(chars.chars().filter(|c| !c.is_control()));
+            }
+            _ => {}
+        }
+    }
+    // nothing typed into the line is also a hotkey (R, T, Space, Escape, ...)
+    keys.reset_all();
+}
+
+fn draw_line(
+    line: Res<ConsoleLine>,
+    locale: Res<Locale>,
+    mut texts: Query<&mut Text, With<ConsoleText>>,
+    mut fields: Query<&mut BackgroundColor, With<ConsoleField>>,
+) {
+    if !line.is_changed() && !locale.is_changed() {
+        return;
+    }
+    let shown = if line.focused {
+        format!("> {}_\n{}", line.text, locale.text("console.typing"))
+    } else {
+        locale.text(line.hint)
+    };
+    for mut text in &mut texts {
+        text.sections[0].value = shown.clone();
+    }
+    for mut bg in &mut fields {
+        *bg = BackgroundColor(if line.focused {
+            FIELD_FOCUSED
+        } else {
+            FIELD_IDLE
+        });
+    }
+}
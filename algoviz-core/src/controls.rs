@@ -0,0 +1,529 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::args::ArrayInput;
+
+const TRACK_IDLE: Color = Color::srgba(0.2, 0.6, 1.0, 0.2);
+const TRACK_HOVER: Color = Color::srgba(0.2, 0.6, 1.0, 0.3);
+const KNOB_ON: Color = Color::srgb(0.2, 0.8, 0.4);
+const KNOB_OFF: Color = Color::srgba(0.6, 0.6, 0.6, 1.0);
+const LABEL_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+const SPEED_FILL: Color = Color::srgba(0.2, 0.6, 1.0, 0.7);
+
+pub const MIN_SPEED: f32 = 0.1;
+pub const MAX_SPEED: f32 = 10.0;
+// one +/- press moves the log-scale speed slider a tenth of its length
+const SPEED_KEY_STEP: f32 = 0.1;
+
+/// Playback mode and step gate shared by every visualization.
+#[derive(Resource)]
+pub struct Settings {
+    /// Auto-play: step on every timer tick. When off, Space/click steps once.
+    pub auto: bool,
+    pub timer: Timer,
+    /// Set for exactly one frame by a manual step request.
+    pub manual_step: bool,
+    /// Playback speed, MIN_SPEED..=MAX_SPEED. Applied to virtual time, so the
+    /// step timer and every `time.delta_seconds()` animation scale together.
+    pub speed: f32,
+}
+
+impl Settings {
+    pub fn new(step_interval: f32) -> Self {
+        Self {
+            auto: true,
+            timer: Timer::from_seconds(step_interval, TimerMode::Repeating),
+            manual_step: false,
+            speed: 1.0,
+        }
+    }
+
+    /// True on the frames where one logical algorithm step may run.
+    pub fn step_ready(&self) -> bool {
+        if self.auto {
+            self.timer.just_finished()
+        } else {
+            self.manual_step
+        }
+    }
+}
+
+/// Run condition form of [`Settings::step_ready`].
+pub fn step_ready(settings: Res<Settings>) -> bool {
+    settings.step_ready()
+}
+
+/// Sent when R is pressed; each viz rebuilds its input (reshuffle, new maze, ...).
+#[derive(Event, Default, Clone, Copy)]
+pub struct ResetRequested;
+
+/// Per-frame ordering of a visualization's systems (chained in this order).
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VizSet {
+    /// Viz-specific input. Runs before the standard controls so a viz can
+    /// claim a key press (e.g. Space to restart once finished).
+    Input,
+    /// Standard controls, toggle UI and the step timer (owned by the plugin).
+    Controls,
+    /// One logical algorithm step when [`Settings::step_ready`].
+    Step,
+    /// Move visuals toward their targets.
+    Animate,
+    /// Colors, overlays and text derived from state.
+    Highlight,
+}
+
+/// Root UI node of the top control strip; the Auto Play toggle lives here.
+#[derive(Component)]
+pub struct ControlBar;
+
+#[derive(Component)]
+pub struct AutoBtn;
+
+#[derive(Component)]
+pub struct AutoKnob;
+
+/// Transport bar buttons next to the Auto Play toggle.
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum TransportBtn {
+    PlayPause,
+    Step,
+    Restart,
+}
+
+#[derive(Component)]
+struct PlayPauseLabel;
+
+/// Cycles the [`ArrayInput`] preset; only spawned for generated arrays.
+#[derive(Component)]
+struct DistributionBtn;
+
+#[derive(Component)]
+struct DistributionLabel;
+
+#[derive(Component)]
+struct SpeedTrack;
+
+#[derive(Component)]
+struct SpeedFill;
+
+#[derive(Component)]
+struct SpeedLabel;
+
+/// Standard controls: Settings, Auto Play toggle, transport bar, Space/click/R/D/+/- handling and the step timer.
+/// Added through [`VizBuilder::controls`](crate::VizBuilder::controls), which also spawns the bar on entering the viz.
+pub struct AlgoVizPlugin {
+    pub step_interval: f32,
+    pub auto: bool,
+}
+
+impl AlgoVizPlugin {
+    pub fn new(step_interval: f32) -> Self {
+        Self {
+            step_interval,
+            auto: true,
+        }
+    }
+
+    /// Start paused in manual mode instead of auto-playing.
+    pub fn manual(mut self) -> Self {
+        self.auto = false;
+        self
+    }
+}
+
+impl Plugin for AlgoVizPlugin {
+    fn build(&self, app: &mut App) {
+        crate::headless::reject_headless();
+        let mut settings = Settings::new(self.step_interval);
+        settings.auto = self.auto;
+        app.insert_resource(settings)
+            .add_event::<ResetRequested>()
+            .configure_sets(
+                Update,
+                (
+                    VizSet::Input,
+                    VizSet::Controls,
+                    VizSet::Step,
+                    VizSet::Animate,
+                    VizSet::Highlight,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    standard_input,
+                    ui_toggle,
+                    transport_buttons,
+                    distribution_input,
+                    speed_input,
+                    sync_knob,
+                    sync_transport,
+                    sync_distribution,
+                    apply_speed,
+                    tick_timer,
+                )
+                    .chain()
+                    .in_set(VizSet::Controls),
+            )
+            .add_systems(PostUpdate, consume_manual_step);
+    }
+}
+
+pub(crate) fn spawn_control_bar(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    input: Option<Res<ArrayInput>>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(40.0),
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    left: Val::Px(8.0),
+                    right: Val::Px(8.0),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+                ..default()
+            },
+            ControlBar,
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(80.0),
+                        height: Val::Px(22.0),
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(TRACK_IDLE),
+                    ..default()
+                },
+                AutoBtn,
+            ))
+            .with_children(|btn| {
+                let knob = if settings.auto { KNOB_ON } else { KNOB_OFF };
+                btn.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(18.0),
+                            height: Val::Px(18.0),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(knob),
+                        ..default()
+                    },
+                    AutoKnob,
+                ));
+            });
+            for (action, label) in [
+                (TransportBtn::PlayPause, "Pause"),
+                (TransportBtn::Step, "Step"),
+                (TransportBtn::Restart, "Restart"),
+            ] {
+                bar.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            height: Val::Px(22.0),
+                            margin: UiRect::left(Val::Px(8.0)),
+                            padding: UiRect::horizontal(Val::Px(8.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: BackgroundColor(TRACK_IDLE),
+                        ..default()
+                    },
+                    action,
+                ))
+                .with_children(|btn| {
+                    let mut text = btn.spawn(TextBundle::from_section(
+                        label,
+                        TextStyle {
+                            font_size: 14.0,
+                            color: LABEL_COLOR,
+                            ..default()
+                        },
+                    ));
+                    if action == TransportBtn::PlayPause {
+                        text.insert(PlayPauseLabel);
+                    }
+                });
+            }
+            // a fixed --input array has no preset to switch
+            if let Some(input) = input.filter(|input| input.values.is_none()) {
+                bar.spawn((
+                    ButtonBundle {
+                        style: Style {
+                            height: Val::Px(22.0),
+                            margin: UiRect::left(Val::Px(8.0)),
+                            padding: UiRect::horizontal(Val::Px(8.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: BackgroundColor(TRACK_IDLE),
+                        ..default()
+                    },
+                    DistributionBtn,
+                ))
+                .with_children(|btn| {
+                    let label = format!("Input: {}", input.distribution.label());
+                    btn.spawn((
+                        TextBundle::from_section(
+                            label,
+                            TextStyle {
+                                font_size: 14.0,
+                                color: LABEL_COLOR,
+                                ..default()
+                            },
+                        ),
+                        DistributionLabel,
+                    ));
+                });
+            }
+            bar.spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(120.0),
+                        height: Val::Px(10.0),
+                        margin: UiRect::left(Val::Px(12.0)),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(TRACK_IDLE),
+                    ..default()
+                },
+                RelativeCursorPosition::default(),
+                SpeedTrack,
+            ))
+            .with_children(|track| {
+                track.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(50.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(SPEED_FILL),
+                        ..default()
+                    },
+                    SpeedFill,
+                ));
+            });
+            bar.spawn((
+                TextBundle::from_section(
+                    "1.0x",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: LABEL_COLOR,
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::left(Val::Px(6.0)),
+                    ..default()
+                }),
+                SpeedLabel,
+            ));
+        });
+}
+
+// speed <-> slider position on a log scale, so 1x sits in the middle of 0.1x..10x
+fn speed_to_slider(speed: f32) -> f32 {
+    (speed.log10() - MIN_SPEED.log10()) / (MAX_SPEED.log10() - MIN_SPEED.log10())
+}
+
+fn slider_to_speed(at: f32) -> f32 {
+    10f32.powf(MIN_SPEED.log10() + at.clamp(0.0, 1.0) * (MAX_SPEED.log10() - MIN_SPEED.log10()))
+}
+
+fn standard_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    buttons: Query<&Interaction, With<Button>>,
+    mut settings: ResMut<Settings>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    // a click on a UI button belongs to that button, not to the step control
+    let over_ui = buttons.iter().any(|i| *i != Interaction::None);
+    if keys.just_pressed(KeyCode::Space) || (mouse.just_pressed(MouseButton::Left) && !over_ui) {
+        if settings.auto {
+            settings.auto = false;
+        } else {
+            settings.manual_step = true;
+        }
+    }
+    if keys.just_pressed(KeyCode::KeyR) {
+        reset.send(ResetRequested);
+    }
+}
+
+fn ui_toggle(
+    mut q: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<AutoBtn>)>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg) in q.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                settings.auto = !settings.auto;
+                *bg = BackgroundColor(TRACK_HOVER);
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(TRACK_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(TRACK_IDLE);
+            }
+        }
+    }
+}
+
+// keyboard pauses flip `auto` too, so the knob follows Settings rather than clicks
+fn sync_knob(settings: Res<Settings>, mut knobs: Query<&mut BackgroundColor, With<AutoKnob>>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let color = if settings.auto { KNOB_ON } else { KNOB_OFF };
+    for mut bg in knobs.iter_mut() {
+        bg.0 = color;
+    }
+}
+
+pub(crate) fn tick_timer(time: Res<Time>, mut settings: ResMut<Settings>) {
+    settings.timer.tick(time.delta());
+}
+
+fn transport_buttons(
+    mut q: Query<(&Interaction, &TransportBtn, &mut BackgroundColor), Changed<Interaction>>,
+    mut settings: ResMut<Settings>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    for (interaction, action, mut bg) in q.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                match action {
+                    TransportBtn::PlayPause => settings.auto = !settings.auto,
+                    TransportBtn::Step => {
+                        settings.auto = false;
+                        settings.manual_step = true;
+                    }
+                    TransportBtn::Restart => {
+                        reset.send(ResetRequested);
+                    }
+                }
+                *bg = BackgroundColor(TRACK_HOVER);
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(TRACK_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(TRACK_IDLE);
+            }
+        }
+    }
+}
+
+// D or the input button switches to the next preset and reshuffles
+fn distribution_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut q: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<DistributionBtn>),
+    >,
+    input: Option<ResMut<ArrayInput>>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    let mut pressed = keys.just_pressed(KeyCode::KeyD);
+    for (interaction, mut bg) in q.iter_mut() {
+        pressed |= *interaction == Interaction::Pressed;
+        *bg = BackgroundColor(if *interaction == Interaction::None {
+            TRACK_IDLE
+        } else {
+            TRACK_HOVER
+        });
+    }
+    let Some(mut input) = input.filter(|input| input.values.is_none()) else {
+        return;
+    };
+    if pressed {
+        input.distribution = input.next_distribution();
+        reset.send(ResetRequested);
+    }
+}
+
+fn speed_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    track: Query<(&Interaction, &RelativeCursorPosition), With<SpeedTrack>>,
+    mut settings: ResMut<Settings>,
+) {
+    let at = speed_to_slider(settings.speed);
+    // `+` shares its key with `=`
+    let at = if keys.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        at + SPEED_KEY_STEP
+    } else if keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        at - SPEED_KEY_STEP
+    } else if let Ok((
+        Interaction::Pressed,
+        RelativeCursorPosition {
+            normalized: Some(p),
+            ..
+        },
+    )) = track.get_single()
+    {
+        p.x
+    } else {
+        return;
+    };
+    settings.speed = slider_to_speed(at);
+}
+
+fn sync_transport(
+    settings: Res<Settings>,
+    mut play: Query<&mut Text, (With<PlayPauseLabel>, Without<SpeedLabel>)>,
+    mut speed_label: Query<&mut Text, (With<SpeedLabel>, Without<PlayPauseLabel>)>,
+    mut fill: Query<&mut Style, With<SpeedFill>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in play.iter_mut() {
+        text.sections[0].value = if settings.auto { "Pause" } else { "Play" }.to_string();
+    }
+    for mut text in speed_label.iter_mut() {
+        text.sections[0].value = format!("{:.1}x", settings.speed);
+    }
+    for mut style in fill.iter_mut() {
+        style.width = Val::Percent(speed_to_slider(settings.speed) * 100.0);
+    }
+}
+
+fn sync_distribution(
+    input: Option<Res<ArrayInput>>,
+    mut labels: Query<&mut Text, With<DistributionLabel>>,
+) {
+    let Some(input) = input.filter(|input| input.is_changed()) else {
+        return;
+    };
+    for mut text in labels.iter_mut() {
+        text.sections[0].value = format!("Input: {}", input.distribution.label());
+    }
+}
+
+fn apply_speed(settings: Res<Settings>, mut time: ResMut<Time<Virtual>>) {
+    if settings.is_changed() && time.relative_speed() != settings.speed {
+        time.set_relative_speed(settings.speed);
+    }
+}
+
+pub(crate) fn consume_manual_step(mut settings: ResMut<Settings>) {
+    if settings.manual_step {
+        settings.manual_step = false;
+    }
+}
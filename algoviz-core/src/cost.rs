@@ -0,0 +1,312 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use serde::Serialize;
+
+use crate::controls::VizSet;
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Locale;
+use crate::svg::SceneGizmos;
+use crate::theme::Palette;
+
+/// Panel size and its gap to the window's right edge and the control bar, in pixels.
+const PANEL: Vec2 = Vec2::new(330.0, 150.0);
+const MARGIN: f32 = 8.0;
+const BELOW_BAR: f32 = 56.0;
+const LEGEND_WIDTH: f32 = 140.0;
+const PADDING: f32 = 10.0;
+const FONT_SIZE: f32 = 13.0;
+const Z: f32 = 900.0;
+
+/// The work a sort has done so far, counted as it steps. Keep it in the
+/// sort's state, so resets and history snapshots carry it along, and count
+/// each operation where the algorithm performs it:
+///
+/// ```ignore
+/// sort.counts.compare();
+/// if sort.array[j] > sort.array[j + 1] {
+///     sort.array.swap(j, j + 1);
+///     sort.counts.swap();
+/// }
+/// ```
+///
+/// Reads and writes are accesses to the array being sorted; `aux` is the peak
+/// size of extra storage: elements in buckets or a merge buffer, counters,
+/// or ranges waiting on a quicksort's stack.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+pub struct OpCounts {
+    pub comparisons: usize,
+    pub swaps: usize,
+    pub reads: usize,
+    pub writes: usize,
+    pub aux: usize,
+}
+
+impl OpCounts {
+    /// Two array elements compared.
+    pub fn compare(&mut self) {
+        self.comparisons += 1;
+        self.reads += 2;
+    }
+
+    /// An array element compared with a value already held (a pivot, a key).
+    pub fn compare_held(&mut self) {
+        self.comparisons += 1;
+        self.reads += 1;
+    }
+
+    /// Two array elements exchanged.
+    pub fn swap(&mut self) {
+        self.swaps += 1;
+        self.reads += 2;
+        self.writes += 2;
+    }
+
+    pub fn read(&mut self, elements: usize) {
+        self.reads += elements;
+    }
+
+    pub fn write(&mut self, elements: usize) {
+        self.writes += elements;
+    }
+
+    /// `elements` are in extra storage right now; `aux` keeps the peak.
+    pub fn hold(&mut self, elements: usize) {
+        self.aux = self.aux.max(elements);
+    }
+
+    /// True if no counter is above `later`'s: `later` can follow this.
+    fn precedes(&self, later: &Self) -> bool {
+        self.comparisons <= later.comparisons
+            && self.swaps <= later.swaps
+            && self.reads <= later.reads
+            && self.writes <= later.writes
+            && self.aux <= later.aux
+    }
+
+    fn series(&self) -> [usize; 5] {
+        [
+            self.comparisons,
+            self.swaps,
+            self.reads,
+            self.writes,
+            self.aux,
+        ]
+    }
+}
+
+/// Message ID and palette role of each counter, in [`OpCounts::series`] order.
+const SERIES: [(&str, fn(&Palette) -> Color); 5] = [
+    ("cost.comparisons", |palette| palette.compare),
+    ("cost.swaps", |palette| palette.violation),
+    ("cost.reads", |palette| palette.visited),
+    ("cost.writes", |palette| palette.settled),
+    ("cost.aux", |palette| palette.focus),
+];
+
+/// The counts after each step that changed them, and whether C hid the chart.
+#[derive(Resource)]
+struct CostChart {
+    samples: Vec<OpCounts>,
+    shown: bool,
+}
+
+impl Default for CostChart {
+    fn default() -> Self {
+        Self {
+            samples: Vec::new(),
+            shown: true,
+        }
+    }
+}
+
+#[derive(Component)]
+struct CostPanel;
+
+#[derive(Component)]
+struct CostLegend;
+
+/// Registers the cost chart: a panel under the control bar plotting the
+/// cumulative [`OpCounts`] that `counts` returns, one point per step that
+/// changed them, with the totals beside it. Counts that go back (a reset, a
+/// history step back) drop the points after them. C shows or hides it.
+pub(crate) fn add_cost_chart<M>(
+    app: &mut App,
+    state: Gallery,
+    counts: impl IntoSystem<(), OpCounts, M>,
+) {
+    let Gallery::Viz(id) = state else { return };
+    app.init_resource::<CostChart>()
+        .add_systems(
+            OnEnter(state),
+            (
+                (|mut chart: ResMut<CostChart>| chart.samples.clear()).in_set(Enter::Reset),
+                spawn_cost_chart.in_set(Enter::Setup),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                toggle_cost_chart.in_set(VizSet::Controls),
+                (counts.pipe(sample_costs), draw_cost_chart)
+                    .chain()
+                    .in_set(VizSet::Highlight),
+            )
+                .in_set(VizScope(id)),
+        );
+}
+
+fn spawn_cost_chart(mut commands: Commands) {
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0.0, 0.0, Z),
+                ..default()
+            },
+            CostPanel,
+        ))
+        .with_children(|panel| {
+            let sections = (0..=SERIES.len()).map(|_| {
+                TextSection::new(
+                    "",
+                    TextStyle {
+                        font_size: FONT_SIZE,
+                        ..default()
+                    },
+                )
+            });
+            panel.spawn((
+                Text2dBundle {
+                    text: Text::from_sections(sections),
+                    text_anchor: Anchor::TopLeft,
+                    ..default()
+                },
+                CostLegend,
+            ));
+        });
+}
+
+fn toggle_cost_chart(keys: Res<ButtonInput<KeyCode>>, mut chart: ResMut<CostChart>) {
+    if keys.just_pressed(KeyCode::KeyC) {
+        chart.shown = !chart.shown;
+    }
+}
+
+fn sample_costs(In(counts): In<OpCounts>, mut chart: ResMut<CostChart>) {
+    let kept = chart
+        .samples
+        .iter()
+        .take_while(|sample| sample.precedes(&counts))
+        .count();
+    if kept < chart.samples.len() {
+        chart.samples.truncate(kept);
+    }
+    if chart.samples.last() != Some(&counts) {
+        chart.samples.push(counts);
+    }
+}
+
+// Laid out in pixels from the window's top-right corner and converted to
+// world space, so the panel stays put whatever the 2D camera shows.
+fn draw_cost_chart(
+    chart: Res<CostChart>,
+    palette: Res<Palette>,
+    locale: Res<Locale>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut panel: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<CostPanel>>,
+    mut legend: Query<(&mut Transform, &mut Text), (With<CostLegend>, Without<CostPanel>)>,
+    mut gizmos: SceneGizmos,
+) {
+    let Ok((mut transform, mut sprite, mut visibility)) = panel.get_single_mut() else {
+        return;
+    };
+    let shown = if chart.shown {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    if *visibility != shown {
+        *visibility = shown;
+    }
+    let Some((camera, view)) = cameras.iter().find(|(camera, _)| camera.is_active) else {
+        return;
+    };
+    let Some(size) = camera.logical_viewport_size() else {
+        return;
+    };
+    let top_left = Vec2::new(size.x - MARGIN - PANEL.x, BELOW_BAR);
+    let world = |pixel: Vec2| camera.viewport_to_world_2d(view, top_left + pixel);
+    let (Some(corner), Some(across), Some(down)) =
+        (world(Vec2::ZERO), world(Vec2::X), world(Vec2::Y))
+    else {
+        return;
+    };
+    let (x, y) = (across - corner, down - corner);
+    let at = |pixel: Vec2| corner + x * pixel.x + y * pixel.y;
+
+    let center = at(PANEL / 2.0).extend(Z);
+    if transform.translation != center {
+        transform.translation = center;
+    }
+    let scale = Vec2::new(x.length(), y.length());
+    let (size, color) = (
+        Some(PANEL * scale),
+        palette.ink(Color::srgba(0.9, 0.9, 0.9, 0.1)),
+    );
+    if sprite.custom_size != size || sprite.color != color {
+        sprite.custom_size = size;
+        sprite.color = color;
+    }
+
+    if let Ok((mut transform, mut text)) = legend.get_single_mut() {
+        let origin =
+            (at(Vec2::new(PANEL.x - LEGEND_WIDTH, PADDING)) - center.truncate()).extend(1.0);
+        if transform.translation != origin || transform.scale.x != scale.x {
+            transform.translation = origin;
+            transform.scale = scale.extend(1.0);
+        }
+        if chart.is_changed() || palette.is_changed() || locale.is_changed() {
+            let last = chart.samples.last().copied().unwrap_or_default().series();
+            text.sections[0].value = format!("{}\n", locale.text("cost.title"));
+            text.sections[0].style.color = palette.ink(Color::srgb(0.85, 0.85, 0.85));
+            for (i, ((key, color), count)) in SERIES.iter().zip(last).enumerate() {
+                let section = &mut text.sections[i + 1];
+                section.value = format!("{}\n", crate::tr!(locale, key, count = count));
+                section.style.color = palette.ink(color(&palette));
+            }
+        }
+    }
+
+    if !chart.shown || chart.samples.len() < 2 {
+        return;
+    }
+    let plot = Rect::new(
+        PADDING,
+        PADDING,
+        PANEL.x - LEGEND_WIDTH - PADDING,
+        PANEL.y - PADDING,
+    );
+    let top = chart
+        .samples
+        .iter()
+        .flat_map(|sample| sample.series())
+        .max()
+        .unwrap_or(0)
+        .max(1) as f32;
+    let last = (chart.samples.len() - 1) as f32;
+    gizmos.rect(
+        at(plot.center()).extend(0.0),
+        Quat::IDENTITY,
+        plot.size() * scale,
+        palette.ink(Color::srgba(0.6, 0.6, 0.6, 0.5)),
+    );
+    for (series, (_, color)) in SERIES.iter().enumerate() {
+        let points = chart.samples.iter().enumerate().map(|(step, sample)| {
+            let value = sample.series()[series] as f32 / top;
+            at(Vec2::new(
+                plot.min.x + plot.width() * step as f32 / last,
+                plot.max.y - plot.height() * value,
+            ))
+        });
+        gizmos.linestrip_2d(points, palette.ink(color(&palette)));
+    }
+}
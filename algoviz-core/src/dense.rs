@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::render::texture::ImageSampler;
+use bevy::sprite::Anchor;
+use bevy::window::PrimaryWindow;
+
+use crate::args::ArrayInput;
+use crate::bars::row_width;
+use crate::controls::{ResetRequested, Settings, VizSet};
+use crate::cost::OpCounts;
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Localized;
+use crate::race::Racer;
+use crate::segments::{SegmentStyle, SegmentText, SegmentText2dBundle};
+use crate::theme::Palette;
+
+/// Arrays this long are drawn as pixel columns; see [`VizBuilder::dense_sort`](crate::VizBuilder::dense_sort).
+pub const DENSE_N: usize = 200;
+/// The longest array `--n` accepts for a dense sort.
+pub const DENSE_MAX_N: usize = 2000;
+pub(crate) const DENSE_STEP_INTERVAL: f32 = 1.0 / 30.0;
+// each tick does n / WORK_DIVISOR comparisons and writes, so n log n sorts finish in seconds
+const WORK_DIVISOR: usize = 8;
+const ROW_WIDTH: f32 = 860.0;
+const COLUMN_HEIGHT: f32 = 400.0;
+const BASE_Y: f32 = -250.0;
+const CAPTION_Y: f32 = 190.0;
+// texture rows per column; the sprite scales them to COLUMN_HEIGHT
+const PIXELS: u32 = 256;
+
+/// A sort too long for one entity per bar: its [`Racer`] steps against the
+/// plain array and one image is repainted with a column per element.
+#[derive(Resource)]
+pub(crate) struct DenseSort {
+    racer: Box<dyn Racer>,
+    make: fn(Vec<usize>) -> Box<dyn Racer>,
+    top: usize,
+}
+
+impl DenseSort {
+    fn new(make: fn(Vec<usize>) -> Box<dyn Racer>, array: Vec<usize>) -> Self {
+        let top = array.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            racer: make(array),
+            make,
+            top,
+        }
+    }
+
+    pub(crate) fn counts(&self) -> OpCounts {
+        self.racer.counts()
+    }
+
+    fn work(&self) -> usize {
+        let counts = self.counts();
+        counts.comparisons + counts.writes
+    }
+}
+
+#[derive(Component)]
+struct DenseColumns;
+
+pub(crate) fn add_dense_sort(
+    app: &mut App,
+    state: Gallery,
+    make: fn(Vec<usize>) -> Box<dyn Racer>,
+) {
+    let Gallery::Viz(id) = state else { return };
+    let setup = move |commands: Commands,
+                      images: ResMut<Assets<Image>>,
+                      input: ResMut<ArrayInput>,
+                      windows: Query<&Window, With<PrimaryWindow>>| {
+        setup_dense(commands, images, input, windows, make)
+    };
+    app.add_systems(OnEnter(state), setup.in_set(Enter::Setup))
+        .add_systems(OnExit(state), |mut commands: Commands| {
+            commands.remove_resource::<DenseSort>()
+        })
+        .add_systems(
+            Update,
+            (
+                reset_dense.in_set(VizSet::Input),
+                step_dense.in_set(VizSet::Step),
+                paint_columns.in_set(VizSet::Highlight),
+            )
+                .in_set(VizScope(id)),
+        );
+}
+
+fn setup_dense(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    make: fn(Vec<usize>) -> Box<dyn Racer>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    let sort = DenseSort::new(make, input.permutation());
+    let n = sort.racer.array().len();
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: n as u32,
+            height: PIXELS,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    // keep the columns crisp when the sprite stretches them
+    image.sampler = ImageSampler::nearest();
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(row_width(ROW_WIDTH, &windows), COLUMN_HEIGHT)),
+                anchor: Anchor::BottomCenter,
+                ..default()
+            },
+            texture: images.add(image),
+            transform: Transform::from_xyz(0.0, BASE_Y, 0.0),
+            ..default()
+        },
+        DenseColumns,
+    ));
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "",
+                SegmentStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, CAPTION_Y, 0.0),
+            ..default()
+        },
+        Localized::new("dense.caption").with("n", n),
+    ));
+    commands.insert_resource(sort);
+}
+
+// R reshuffles; Space once sorted starts over
+fn reset_dense(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut sort: ResMut<DenseSort>,
+    mut input: ResMut<ArrayInput>,
+) {
+    let restart = sort.racer.done() && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        *sort = DenseSort::new(sort.make, input.permutation());
+    }
+}
+
+fn step_dense(mut sort: ResMut<DenseSort>, mut settings: ResMut<Settings>) {
+    if sort.racer.done() || !settings.step_ready() {
+        return;
+    }
+    let budget = (sort.racer.array().len() / WORK_DIVISOR).max(1);
+    let target = sort.work() + budget;
+    while !sort.racer.done() && sort.work() < target {
+        sort.racer.step();
+    }
+    if !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+fn paint_columns(
+    sort: Res<DenseSort>,
+    palette: Res<Palette>,
+    columns: Query<&Handle<Image>, With<DenseColumns>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if !sort.is_changed() && !palette.is_changed() {
+        return;
+    }
+    let Some(image) = columns
+        .get_single()
+        .ok()
+        .and_then(|handle| images.get_mut(handle))
+    else {
+        return;
+    };
+    let array = sort.racer.array();
+    let (n, done, focus) = (array.len(), sort.racer.done(), sort.racer.focus());
+    let rgba = |color: Color| color.to_srgba().to_u8_array();
+    let colors: Vec<[u8; 4]> = array
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            if done {
+                rgba(palette.settled)
+            } else if focus.contains(&i) {
+                rgba(palette.compare)
+            } else {
+                rgba(palette.ink(Color::hsl(value as f32 / sort.top as f32 * 300.0, 0.7, 0.5)))
+            }
+        })
+        .collect();
+    // rows run top to bottom; a column fills from the bottom row up to its value
+    for row in 0..PIXELS as usize {
+        let level = (PIXELS as usize - row) * sort.top;
+        for (i, &value) in array.iter().enumerate() {
+            let at = (row * n + i) * 4;
+            let filled = value * PIXELS as usize >= level || row + 1 == PIXELS as usize;
+            image.data[at..at + 4].copy_from_slice(if filled { &colors[i] } else { &[0; 4] });
+        }
+    }
+}
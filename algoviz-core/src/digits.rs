@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+
+/// Default label color for bar-top and overlay digits.
+pub const DIGIT_COLOR: Color = Color::WHITE;
+
+/// Child container holding the block-digit sprites of one label.
+#[derive(Component)]
+pub struct ValueDigits;
+
+/// Seven-segment glyph metrics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DigitStyle {
+    /// Horizontal segment length.
+    pub width: f32,
+    pub height: f32,
+    /// Segment thickness.
+    pub thickness: f32,
+    /// Distance between neighbouring digit centers.
+    pub advance: f32,
+}
+
+impl DigitStyle {
+    /// Bar labels and decision overlays.
+    pub const LARGE: Self = Self {
+        width: 18.0,
+        height: 28.0,
+        thickness: 3.0,
+        advance: 24.0,
+    };
+    /// Labels inside tree/graph nodes.
+    pub const SMALL: Self = Self {
+        width: 14.0,
+        height: 20.0,
+        thickness: 3.0,
+        advance: 24.0,
+    };
+}
+
+impl Default for DigitStyle {
+    fn default() -> Self {
+        Self::LARGE
+    }
+}
+
+/// Spawn a [`ValueDigits`] label `y` above the parent origin (large style, z = 1).
+pub fn spawn_value_digits(parent: &mut ChildBuilder, value: impl Display, y: f32, color: Color) {
+    parent
+        .spawn((
+            SpatialBundle {
+                transform: Transform::from_xyz(0.0, y, 1.0),
+                ..default()
+            },
+            ValueDigits,
+        ))
+        .with_children(|p| spawn_digits(p, value, Vec2::ZERO, DigitStyle::LARGE, color));
+}
+
+/// Spawn the digits of `value` as a row centered on `center`. Non-digit characters are skipped.
+pub fn spawn_digits(
+    parent: &mut ChildBuilder,
+    value: impl Display,
+    center: Vec2,
+    style: DigitStyle,
+    color: Color,
+) {
+    let digits: Vec<u8> = value
+        .to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| d as u8)
+        .collect();
+    let mut x = center.x - (digits.len().max(1) - 1) as f32 * style.advance / 2.0;
+    for d in digits {
+        spawn_digit(parent, d, Vec2::new(x, center.y), style, color);
+        x += style.advance;
+    }
+}
+
+/// Spawn one seven-segment digit centered on `offset`.
+pub fn spawn_digit(
+    parent: &mut ChildBuilder,
+    d: u8,
+    offset: Vec2,
+    style: DigitStyle,
+    color: Color,
+) {
+    let DigitStyle {
+        width: w,
+        height: h,
+        thickness: t,
+        ..
+    } = style;
+    let pos = |x: f32, y: f32| Vec3::new(offset.x + x, offset.y + y, 0.0);
+    let horiz = |p: Vec3| SpriteBundle {
+        sprite: Sprite {
+            color,
+            custom_size: Some(Vec2::new(w, t)),
+            ..default()
+        },
+        transform: Transform::from_translation(p),
+        ..default()
+    };
+    let vert = |p: Vec3| SpriteBundle {
+        sprite: Sprite {
+            color,
+            custom_size: Some(Vec2::new(t, h / 2.0 - t)),
+            ..default()
+        },
+        transform: Transform::from_translation(p),
+        ..default()
+    };
+
+    // a (top), d (bottom), g (middle); f/b top-left/right, e/c bottom-left/right
+    let pos_a = pos(0.0, h / 2.0 - t / 2.0);
+    let pos_d = pos(0.0, -h / 2.0 + t / 2.0);
+    let pos_g = pos(0.0, 0.0);
+    let v = h / 4.0;
+    let pos_f = pos(-w / 2.0 + t / 2.0, v);
+    let pos_b = pos(w / 2.0 - t / 2.0, v);
+    let pos_e = pos(-w / 2.0 + t / 2.0, -v);
+    let pos_c = pos(w / 2.0 - t / 2.0, -v);
+
+    let mask = match d {
+        0 => [true, true, true, true, true, true, false], // a b c d e f
+        1 => [false, true, true, false, false, false, false], // b c
+        2 => [true, true, false, true, true, false, true], // a b d e g
+        3 => [true, true, true, true, false, false, true], // a b c d g
+        4 => [false, true, true, false, false, true, true], // b c f g
+        5 => [true, false, true, true, false, true, true], // a c d f g
+        6 => [true, false, true, true, true, true, true], // a c d e f g
+        7 => [true, true, true, false, false, false, false], // a b c
+        8 => [true, true, true, true, true, true, true],  // all
+        9 => [true, true, true, true, false, true, true], // a b c d f g
+        _ => [false; 7],
+    };
+
+    if mask[0] {
+        parent.spawn(horiz(pos_a));
+    }
+    if mask[1] {
+        parent.spawn(vert(pos_b));
+    }
+    if mask[2] {
+        parent.spawn(vert(pos_c));
+    }
+    if mask[3] {
+        parent.spawn(horiz(pos_d));
+    }
+    if mask[4] {
+        parent.spawn(vert(pos_e));
+    }
+    if mask[5] {
+        parent.spawn(vert(pos_f));
+    }
+    if mask[6] {
+        parent.spawn(horiz(pos_g));
+    }
+}
@@ -0,0 +1,216 @@
+use bevy::input::mouse::MouseWheel;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+
+use crate::args::ArrayInput;
+use crate::controls::{ResetRequested, Settings};
+use crate::gallery::{Gallery, VizScope};
+
+// short bars are hard to hit, so a bar also takes clicks this far above its top (where its label sits)
+const GRAB_ABOVE: f32 = 30.0;
+// lifted over its neighbours while dragged
+const LIFT: f32 = 1.0;
+
+/// A sorting viz's bar component, as the [bar editor](crate::VizBuilder::bar_editor)
+/// reads it: the bar's position in the array and the value it stands for.
+pub trait EditableBar: Component {
+    fn slot(&self) -> usize;
+    fn value(&self) -> usize;
+}
+
+/// The bar being dragged: where it came from and the x of every slot it can drop into.
+#[derive(Resource, Default)]
+struct Drag(Option<Grab>);
+
+struct Grab {
+    bar: Entity,
+    from: usize,
+    home: Vec3,
+    slots: Vec<f32>,
+}
+
+/// Registers the bar editor for the viz in `state`: while auto-play is off,
+/// dragging a bar moves it to another slot and the wheel over a bar raises or
+/// lowers its value. Either restarts the viz on the edited array.
+pub(crate) fn add_bar_editor<B: EditableBar>(app: &mut App, state: Gallery) {
+    let Gallery::Viz(id) = state else { return };
+    app.init_resource::<Drag>()
+        .add_systems(OnExit(state), |mut drag: ResMut<Drag>| drag.0 = None)
+        .add_systems(
+            PreUpdate,
+            // before Update, so the click never reaches the step control and the
+            // viz's reset handler sees the edit on the same frame
+            (pick_up::<B>, drag_bar::<B>, wheel_value::<B>)
+                .chain()
+                .after(InputSystem)
+                .after(UiSystem::Focus)
+                .in_set(VizScope(id)),
+        )
+        // after the viz's own animations, which may be easing every bar toward its slot
+        .add_systems(
+            PostUpdate,
+            follow_cursor::<B>
+                .before(TransformSystem::TransformPropagate)
+                .in_set(VizScope(id)),
+        );
+}
+
+pub(crate) fn cursor(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    cameras: &Query<(&Camera, &GlobalTransform)>,
+) -> Option<Vec2> {
+    let position = windows.get_single().ok()?.cursor_position()?;
+    let (camera, transform) = cameras.iter().find(|(camera, _)| camera.is_active)?;
+    camera.viewport_to_world_2d(transform, position)
+}
+
+// the bar whose column (from its foot up to just over its top) holds `at`
+fn bar_at<'a, B: EditableBar>(
+    at: Vec2,
+    bars: impl IntoIterator<Item = (Entity, &'a B, &'a Transform, &'a Sprite)>,
+) -> Option<(Entity, usize, usize)> {
+    bars.into_iter()
+        .find_map(|(entity, bar, transform, sprite)| {
+            let size = sprite.custom_size?;
+            let center = transform.translation.truncate();
+            let inside = (at.x - center.x).abs() <= size.x / 2.0
+                && at.y >= center.y - size.y / 2.0
+                && at.y <= center.y + size.y / 2.0 + GRAB_ABOVE;
+            inside.then(|| (entity, bar.slot(), bar.value()))
+        })
+}
+
+fn current_array<'a, B: EditableBar>(bars: impl IntoIterator<Item = &'a B>) -> Vec<usize> {
+    let mut slots: Vec<(usize, usize)> = bars
+        .into_iter()
+        .map(|bar| (bar.slot(), bar.value()))
+        .collect();
+    slots.sort_unstable();
+    slots.into_iter().map(|(_, value)| value).collect()
+}
+
+// a press on a bar while paused picks it up instead of stepping
+fn pick_up<B: EditableBar>(
+    settings: Res<Settings>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    buttons: Query<&Interaction, With<Button>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    bars: Query<(Entity, &B, &Transform, &Sprite)>,
+    mut drag: ResMut<Drag>,
+) {
+    if settings.auto || drag.0.is_some() || !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    if buttons
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+    {
+        return;
+    }
+    let Some(at) = cursor(&windows, &cameras) else {
+        return;
+    };
+    let Some((bar, from, _)) = bar_at(at, bars.iter()) else {
+        return;
+    };
+    let mut slots: Vec<(usize, f32)> = bars
+        .iter()
+        .map(|(_, bar, tf, _)| (bar.slot(), tf.translation.x))
+        .collect();
+    slots.sort_unstable_by_key(|&(slot, _)| slot);
+    let home = bars
+        .get(bar)
+        .map_or(Vec3::ZERO, |(_, _, tf, _)| tf.translation);
+    mouse.clear_just_pressed(MouseButton::Left);
+    drag.0 = Some(Grab {
+        bar,
+        from,
+        home,
+        slots: slots.into_iter().map(|(_, x)| x).collect(),
+    });
+}
+
+// on release the bar drops into the nearest slot
+fn drag_bar<B: EditableBar>(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut bars: Query<(&B, &mut Transform)>,
+    mut drag: ResMut<Drag>,
+    mut input: ResMut<ArrayInput>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    if drag.0.is_none() || mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(grab) = drag.0.take() else { return };
+    let at = cursor(&windows, &cameras);
+    if let Ok((_, mut tf)) = bars.get_mut(grab.bar) {
+        tf.translation = grab.home;
+    }
+    let x = at.map_or(grab.home.x, |at| at.x);
+    let to = (0..grab.slots.len()).min_by(|&a, &b| {
+        (grab.slots[a] - x)
+            .abs()
+            .total_cmp(&(grab.slots[b] - x).abs())
+    });
+    let Some(to) = to.filter(|&to| to != grab.from) else {
+        return;
+    };
+    let mut array = current_array(bars.iter().map(|(bar, _)| bar));
+    let value = array.remove(grab.from);
+    array.insert(to, value);
+    input.edit(array);
+    reset.send(ResetRequested);
+}
+
+fn follow_cursor<B: EditableBar>(
+    drag: Res<Drag>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut bars: Query<&mut Transform, With<B>>,
+) {
+    let Some(grab) = &drag.0 else { return };
+    if let (Some(at), Ok(mut tf)) = (cursor(&windows, &cameras), bars.get_mut(grab.bar)) {
+        tf.translation.x = at.x;
+        tf.translation.z = grab.home.z + LIFT;
+    }
+}
+
+// each wheel notch over a bar raises or lowers its value by one
+fn wheel_value<B: EditableBar>(
+    settings: Res<Settings>,
+    mut wheel: EventReader<MouseWheel>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    bars: Query<(Entity, &B, &Transform, &Sprite)>,
+    drag: Res<Drag>,
+    mut input: ResMut<ArrayInput>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    let notches: i32 = wheel
+        .read()
+        .filter(|event| event.y != 0.0)
+        .map(|event| event.y.signum() as i32)
+        .sum();
+    if settings.auto || drag.0.is_some() || notches == 0 {
+        return;
+    }
+    let Some(at) = cursor(&windows, &cameras) else {
+        return;
+    };
+    let Some((_, slot, value)) = bar_at(at, bars.iter()) else {
+        return;
+    };
+    let mut array = current_array(bars.iter().map(|(_, bar, _, _)| bar));
+    let Some(value) = input.edited_value(value, notches) else {
+        return;
+    };
+    array[slot] = value;
+    input.edit(array);
+    reset.send(ResetRequested);
+}
@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+
+use crate::code::{add_code_panel, Listing};
+use crate::console::add_console;
+use crate::controls::{spawn_control_bar, AlgoVizPlugin, Settings};
+use crate::cost::{add_cost_chart, OpCounts};
+use crate::dense::{add_dense_sort, DenseSort, DENSE_STEP_INTERVAL};
+use crate::edit::{add_bar_editor, EditableBar};
+use crate::graph::add_graph_editor;
+use crate::history::add_history_scrubber;
+use crate::locale::{Locale, LocalePlugin};
+use crate::maze::{add_grid_editor, GridBrush, GridTile};
+use crate::quiz::{add_quiz, add_trace_quiz, Question};
+use crate::race::Racer;
+use crate::replay::{ReplayPlugin, VizRng};
+use crate::segments::SegmentTextPlugin;
+use crate::subtitles::SubtitlePlugin;
+use crate::svg::SvgExportPlugin;
+use crate::theme::ThemePlugin;
+use crate::trace::add_trace_player;
+
+/// Which screen is up. The gallery launcher starts in `Menu`; a standalone
+/// viz binary starts in its own `Viz` state and never leaves it.
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gallery {
+    #[default]
+    Menu,
+    /// A viz by its crate's `ID` (the crate name without `bevy-`).
+    Viz(&'static str),
+}
+
+/// A viz's frame systems; they only run while the app is in its `Gallery::Viz` state.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VizScope(pub &'static str);
+
+/// Order of the work done on entering a viz.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Enter {
+    /// Fresh copies of its resources and settings.
+    Reset,
+    /// The control bar, for vizs with [`VizBuilder::controls`].
+    Controls,
+    /// The viz's own setup systems.
+    Setup,
+}
+
+/// Entities alive before the current viz was entered; leaving it despawns the rest.
+#[derive(Resource, Default)]
+struct Outside(HashSet<Entity>);
+
+/// Registers a visualization under [`Gallery::Viz`], so the same plugin runs
+/// standalone and inside the gallery launcher.
+///
+/// ```ignore
+/// pub fn plugin(app: &mut App) {
+///     app.add_viz(ID)
+///         .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+///         .resource(MyState::default())
+///         .setup(setup)
+///         .systems(Update, my_step.in_set(VizSet::Step));
+/// }
+/// ```
+pub trait GalleryApp {
+    fn add_viz(&mut self, id: &'static str) -> VizBuilder<'_>;
+}
+
+impl GalleryApp for App {
+    fn add_viz(&mut self, id: &'static str) -> VizBuilder<'_> {
+        let state = Gallery::Viz(id);
+        if !self.is_plugin_added::<SegmentTextPlugin>() {
+            self.add_plugins(SegmentTextPlugin);
+        }
+        if !self.is_plugin_added::<SvgExportPlugin>() {
+            self.add_plugins(SvgExportPlugin);
+        }
+        if !self.is_plugin_added::<ReplayPlugin>() {
+            self.add_plugins(ReplayPlugin);
+        }
+        if !self.is_plugin_added::<SubtitlePlugin>() {
+            self.add_plugins(SubtitlePlugin);
+        }
+        if !self.is_plugin_added::<ThemePlugin>() {
+            self.add_plugins(ThemePlugin);
+        }
+        if !self.is_plugin_added::<LocalePlugin>() {
+            self.add_plugins(LocalePlugin);
+        }
+        for schedule in [PreUpdate.intern(), Update.intern(), PostUpdate.intern()] {
+            self.configure_sets(schedule, VizScope(id).run_if(in_state(state)));
+        }
+        self.configure_sets(
+            OnEnter(state),
+            (Enter::Reset, Enter::Controls, Enter::Setup).chain(),
+        )
+        .add_systems(OnEnter(state), remember_outside.in_set(Enter::Reset))
+        .add_systems(OnExit(state), despawn_inside);
+        VizBuilder { app: self, id }.resource(VizRng::from_args())
+    }
+}
+
+/// Scoped registration for one viz, returned by [`GalleryApp::add_viz`].
+pub struct VizBuilder<'a> {
+    app: &'a mut App,
+    id: &'static str,
+}
+
+impl VizBuilder<'_> {
+    fn state(&self) -> Gallery {
+        Gallery::Viz(self.id)
+    }
+
+    /// The standard controls configured like `controls`: entering the viz
+    /// resets [`Settings`] to its step interval and mode and spawns the control bar.
+    pub fn controls(self, controls: AlgoVizPlugin) -> Self {
+        let (step_interval, auto) = (controls.step_interval, controls.auto);
+        if !self.app.is_plugin_added::<AlgoVizPlugin>() {
+            self.app.add_plugins(controls);
+        }
+        let state = self.state();
+        self.app.add_systems(
+            OnEnter(state),
+            (
+                (move |mut settings: ResMut<Settings>| {
+                    *settings = Settings::new(step_interval);
+                    settings.auto = auto;
+                })
+                .in_set(Enter::Reset),
+                spawn_control_bar.in_set(Enter::Controls),
+            ),
+        );
+        self
+    }
+
+    /// A resource the viz starts from: a fresh clone is inserted every time
+    /// the viz is entered, and it is removed again on leaving.
+    pub fn resource<R: Resource + Clone>(self, resource: R) -> Self {
+        let state = self.state();
+        self.app
+            .add_systems(
+                OnEnter(state),
+                (move |world: &mut World| world.insert_resource(resource.clone()))
+                    .in_set(Enter::Reset),
+            )
+            .add_systems(OnExit(state), |world: &mut World| {
+                world.remove_resource::<R>();
+            });
+        self
+    }
+
+    /// Systems that build the scene, run on entering the viz (what `Startup` is to a plain app).
+    pub fn setup<M>(self, systems: impl IntoSystemConfigs<M>) -> Self {
+        let state = self.state();
+        self.app
+            .add_systems(OnEnter(state), systems.in_set(Enter::Setup));
+        self
+    }
+
+    /// Frame systems in `PreUpdate`, `Update` or `PostUpdate`, run only while the viz is up.
+    pub fn systems<M>(
+        self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> Self {
+        let scope = VizScope(self.id);
+        self.app.add_systems(schedule, systems.in_set(scope));
+        self
+    }
+
+    /// A [`History<S>`](crate::History) with Left/Right stepping and a timeline in the control bar.
+    pub fn history_scrubber<S: Send + Sync + 'static>(self) -> Self {
+        let state = self.state();
+        add_history_scrubber::<S>(self.app, state);
+        self
+    }
+
+    /// A live chart of the [`OpCounts`](crate::OpCounts) that `counts` reads
+    /// from the viz's state, under the right end of the control bar; C hides it.
+    pub fn cost_chart<M>(self, counts: impl IntoSystem<(), OpCounts, M>) -> Self {
+        let state = self.state();
+        add_cost_chart(self.app, state, counts);
+        self
+    }
+
+    /// The whole viz for an array of [`DENSE_N`](crate::DENSE_N) or more: the
+    /// sort's [`Racer`](crate::Racer) steps against the [`ArrayInput`](crate::ArrayInput)
+    /// array, drawn as one pixel column per element, with the standard controls
+    /// and a cost chart. Use instead of the viz's own setup and systems.
+    pub fn dense_sort(self, racer: fn(Vec<usize>) -> Box<dyn Racer>) -> Self {
+        let builder = self.controls(AlgoVizPlugin::new(DENSE_STEP_INTERVAL));
+        add_dense_sort(builder.app, builder.state(), racer);
+        builder.cost_chart(|sort: Res<DenseSort>| sort.counts())
+    }
+
+    /// Live array editing for a viz whose bars are `B`s: while paused, drag a
+    /// bar to another slot or scroll over it to change its value. The edit goes
+    /// to [`ArrayInput::edit`](crate::ArrayInput::edit) and the viz restarts on
+    /// it through its usual [`ResetRequested`](crate::ResetRequested) handler.
+    pub fn bar_editor<B: EditableBar>(self) -> Self {
+        let state = self.state();
+        add_bar_editor::<B>(self.app, state);
+        self
+    }
+
+    /// Editing of the viz's [`PathGrid`](crate::PathGrid) (added with
+    /// [`resource`](Self::resource)) through its `T` tiles: drag to paint with
+    /// `brush`, right-drag to erase, drag the start or goal to move it; M picks
+    /// a maze, R draws it anew, S and O save and load `<id>-grid.ron`. The viz
+    /// re-runs its search whenever the grid changed.
+    pub fn grid_editor<T: GridTile>(self, brush: GridBrush) -> Self {
+        let state = self.state();
+        add_grid_editor::<T>(self.app, state, brush);
+        self
+    }
+
+    /// Editing of the viz's [`Graph`](crate::Graph) (added with [`resource`](Self::resource)):
+    /// E toggles edit mode, where a click on empty space adds a node, dragging a
+    /// node moves it, right-dragging between nodes joins them, a picked edge takes
+    /// a typed or scrolled weight and Delete removes the pick; F or the Tidy button
+    /// lays the graph out, S and O save and load `<id>-graph.ron`. The viz redraws
+    /// and re-runs whenever the graph changed.
+    pub fn graph_editor(self) -> Self {
+        let state = self.state();
+        add_graph_editor(self.app, state);
+        self
+    }
+
+    /// A one-line console at the bottom left, showing the `hint` message until
+    /// Enter or a click focuses it. While focused it takes the keyboard; each
+    /// entered line arrives as a [`ConsoleCommand`](crate::ConsoleCommand) event.
+    pub fn console(self, hint: &'static str) -> Self {
+        let state = self.state();
+        add_console(self.app, state, hint);
+        self
+    }
+
+    /// Plays the viz's [`Trace<E>`](crate::Trace) (added with [`resource`](Self::resource))
+    /// through `apply`, one event per step.
+    pub fn trace_player<E, M>(self, apply: impl IntoSystem<E, (), M>) -> Self
+    where
+        E: Clone + Send + Sync + 'static,
+    {
+        add_trace_player::<E, M>(self.app, VizScope(self.id), apply);
+        self
+    }
+
+    /// A side panel showing `listings` (K cycles them, then hides the panel;
+    /// `--code` opens it) with the [`CodeCursor`](crate::CodeCursor)'s line
+    /// highlighted and its variables listed. The viz's step moves the cursor.
+    pub fn code_panel(self, listings: &'static [Listing]) -> Self {
+        let state = self.state();
+        add_code_panel(self.app, state, listings);
+        self
+    }
+
+    /// Prediction quiz mode: with `--quiz` or after Q, the viz's step asks
+    /// through [`Quiz::hold`](crate::Quiz::hold) before each decision and a
+    /// panel in the bottom-right corner takes the answer and keeps score.
+    pub fn quiz(self) -> Self {
+        let state = self.state();
+        add_quiz(self.app, state);
+        self
+    }
+
+    /// [`quiz`](Self::quiz) for a [`trace_player`](Self::trace_player): before
+    /// each event `ask` turns into a [`Question`], given the events still to
+    /// come, playback waits for the answer.
+    pub fn trace_quiz<E: Send + Sync + 'static>(
+        self,
+        ask: fn(&[E], &Locale) -> Option<Question>,
+    ) -> Self {
+        let state = self.state();
+        add_trace_quiz(self.app, state, ask);
+        self
+    }
+}
+
+fn remember_outside(world: &mut World) {
+    let outside = world.iter_entities().map(|entity| entity.id()).collect();
+    world.insert_resource(Outside(outside));
+}
+
+fn despawn_inside(
+    mut commands: Commands,
+    outside: Res<Outside>,
+    roots: Query<Entity, Without<Parent>>,
+) {
+    for entity in roots.iter().filter(|entity| !outside.0.contains(entity)) {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<Outside>();
+}
@@ -0,0 +1,936 @@
+use std::path::Path;
+
+use bevy::input::mouse::MouseWheel;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+
+use crate::edit::cursor;
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::{Locale, Localized};
+use crate::scenario::{GraphScenario, Scenario};
+
+// nodes are drawn as 50 px squares
+const NODE_HALF: f32 = 25.0;
+// how far from an edge's line a click still picks it
+const EDGE_REACH: f32 = 8.0;
+const MAX_WEIGHT: i32 = 99;
+// the tidied layout stays inside ±BOUNDS, clear of the captions below
+const BOUNDS: Vec2 = Vec2::new(360.0, 240.0);
+const LAYOUT_STEPS: usize = 300;
+const MARGIN: f32 = 10.0;
+const FONT_SIZE: f32 = 13.0;
+const BUTTON_IDLE: Color = Color::srgba(0.2, 0.6, 1.0, 0.2);
+const BUTTON_ON: Color = Color::srgba(0.2, 0.6, 1.0, 0.5);
+const LABEL_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+const MARK_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
+
+/// Which weights a [`Graph`]'s edges carry, and so which the editor lets the learner type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeWeights {
+    /// Every edge counts as 1 and shows no label.
+    Unweighted,
+    NonNegative,
+    /// Negative weights too (Bellman-Ford).
+    Signed,
+}
+
+/// The graph a graph viz runs on: node positions (ids are indices), `(from, to, weight)`
+/// edges and the source node. With [`VizBuilder::graph_editor`](crate::VizBuilder::graph_editor)
+/// the learner edits it; the viz redraws and re-runs its algorithm whenever it changed.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Graph {
+    nodes: Vec<Vec2>,
+    edges: Vec<(usize, usize, i32)>,
+    directed: bool,
+    weights: EdgeWeights,
+    /// Start node for single-source algorithms.
+    pub source: usize,
+}
+
+impl Graph {
+    /// Nodes at `nodes` joined by `edges`; an edge listed both ways is kept once.
+    pub fn undirected(
+        nodes: Vec<Vec2>,
+        edges: impl IntoIterator<Item = (usize, usize, i32)>,
+        weights: EdgeWeights,
+    ) -> Self {
+        Self::with_edges(
+            Self {
+                nodes,
+                edges: Vec::new(),
+                directed: false,
+                weights,
+                source: 0,
+            },
+            edges,
+        )
+    }
+
+    pub fn directed(
+        nodes: Vec<Vec2>,
+        edges: impl IntoIterator<Item = (usize, usize, i32)>,
+        weights: EdgeWeights,
+    ) -> Self {
+        Self::with_edges(
+            Self {
+                nodes,
+                edges: Vec::new(),
+                directed: true,
+                weights,
+                source: 0,
+            },
+            edges,
+        )
+    }
+
+    /// A `--scenario` graph with its positions, edges and source.
+    pub fn from_scenario(scenario: &GraphScenario, weights: EdgeWeights) -> Self {
+        let edges = scenario.edges.iter().copied();
+        let mut graph = if scenario.directed {
+            Self::directed(scenario.positions(), edges, weights)
+        } else {
+            Self::undirected(scenario.positions(), edges, weights)
+        };
+        graph.source = scenario.source;
+        graph
+    }
+
+    fn with_edges(mut self, edges: impl IntoIterator<Item = (usize, usize, i32)>) -> Self {
+        for (from, to, weight) in edges {
+            self.add_edge(from, to, weight);
+        }
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn weights(&self) -> EdgeWeights {
+        self.weights
+    }
+
+    pub fn positions(&self) -> &[Vec2] {
+        &self.nodes
+    }
+
+    /// `(from, to, weight)`, each undirected edge once.
+    pub fn edges(&self) -> &[(usize, usize, i32)] {
+        &self.edges
+    }
+
+    /// `(to, weight)` lists per node; undirected edges appear both ways.
+    pub fn adjacency(&self) -> Vec<Vec<(usize, i32)>> {
+        let mut adj = vec![Vec::new(); self.nodes.len()];
+        for &(from, to, weight) in &self.edges {
+            adj[from].push((to, weight));
+            if !self.directed {
+                adj[to].push((from, weight));
+            }
+        }
+        adj
+    }
+
+    pub fn add_node(&mut self, at: Vec2) -> usize {
+        self.nodes.push(at);
+        self.nodes.len() - 1
+    }
+
+    pub fn move_node(&mut self, node: usize, to: Vec2) {
+        self.nodes[node] = to;
+    }
+
+    /// Removes `node` and its edges; the nodes after it move down one id. The
+    /// last node stays, so the source is always a node of the graph.
+    pub fn remove_node(&mut self, node: usize) {
+        if self.nodes.len() <= 1 {
+            return;
+        }
+        self.nodes.remove(node);
+        self.edges
+            .retain(|&(from, to, _)| from != node && to != node);
+        let shift = |id: usize| if id > node { id - 1 } else { id };
+        for edge in &mut self.edges {
+            *edge = (shift(edge.0), shift(edge.1), edge.2);
+        }
+        self.source = if self.source == node {
+            0
+        } else {
+            shift(self.source)
+        };
+    }
+
+    /// The new edge's index, or `None` for a self-loop or an edge the graph already has.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i32) -> Option<usize> {
+        let taken = self
+            .edges
+            .iter()
+            .any(|&(a, b, _)| (a, b) == (from, to) || (!self.directed && (b, a) == (from, to)));
+        if from == to || taken {
+            return None;
+        }
+        self.edges.push((from, to, self.allowed(weight)));
+        Some(self.edges.len() - 1)
+    }
+
+    pub fn remove_edge(&mut self, edge: usize) {
+        self.edges.remove(edge);
+    }
+
+    /// Sets the weight, kept within ±99 and to what the graph's [`EdgeWeights`] allow.
+    pub fn set_weight(&mut self, edge: usize, weight: i32) {
+        self.edges[edge].2 = self.allowed(weight);
+    }
+
+    fn allowed(&self, weight: i32) -> i32 {
+        match self.weights {
+            EdgeWeights::Unweighted => 1,
+            EdgeWeights::NonNegative => weight.clamp(0, MAX_WEIGHT),
+            EdgeWeights::Signed => weight.clamp(-MAX_WEIGHT, MAX_WEIGHT),
+        }
+    }
+
+    /// Force-directed layout: nodes push each other apart, edges pull their ends
+    /// together, and the moves cool down step by step. The result is centred.
+    pub fn tidy(&mut self) {
+        let n = self.nodes.len();
+        if n == 0 {
+            return;
+        }
+        // the distance two joined nodes settle at
+        let k = (4.0 * BOUNDS.x * BOUNDS.y / n as f32).sqrt() * 0.5;
+        for step in 0..LAYOUT_STEPS {
+            let heat = k * (1.0 - step as f32 / LAYOUT_STEPS as f32);
+            let mut push: Vec<Vec2> = (0..n)
+                .map(|a| {
+                    (0..n)
+                        .filter(|&b| b != a)
+                        .map(|b| {
+                            let apart = self.nodes[a] - self.nodes[b];
+                            // nodes on top of each other split sideways
+                            let apart = if apart.length_squared() < 1e-4 {
+                                Vec2::new(a as f32 - b as f32, 0.5)
+                            } else {
+                                apart
+                            };
+                            apart.normalize() * k * k / apart.length()
+                        })
+                        .sum()
+                })
+                .collect();
+            for &(from, to, _) in &self.edges {
+                let apart = self.nodes[from] - self.nodes[to];
+                let pull = apart * apart.length() / k;
+                push[from] -= pull;
+                push[to] += pull;
+            }
+            for (node, push) in self.nodes.iter_mut().zip(push) {
+                *node = (*node + push.clamp_length_max(heat)).clamp(-BOUNDS, BOUNDS);
+            }
+        }
+        let centre = self.nodes.iter().sum::<Vec2>() / n as f32;
+        for node in &mut self.nodes {
+            *node = (*node - centre).clamp(-BOUNDS, BOUNDS).round();
+        }
+    }
+
+    /// The graph as a `--scenario` file.
+    pub fn to_ron(&self) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .map(|at| format!("({}, {})", at.x.round(), at.y.round()))
+            .collect();
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|(from, to, weight)| format!("        ({from}, {to}, {weight}),\n"))
+            .collect();
+        format!(
+            "(\n    directed: {},\n    source: {},\n    nodes: [{}],\n    edges: [\n{}    ],\n)\n",
+            self.directed,
+            self.source,
+            nodes.join(", "),
+            edges.concat()
+        )
+    }
+
+    fn node_at(&self, at: Vec2) -> Option<usize> {
+        self.nodes.iter().rposition(|node| {
+            let offset = (at - *node).abs();
+            offset.x <= NODE_HALF && offset.y <= NODE_HALF
+        })
+    }
+
+    // the nearest edge within reach of `at`, away from its end nodes
+    fn edge_at(&self, at: Vec2) -> Option<usize> {
+        if self.node_at(at).is_some() {
+            return None;
+        }
+        let distance = |&(from, to, _): &(usize, usize, i32)| {
+            let (a, b) = (self.nodes[from], self.nodes[to]);
+            let along = ((at - a).dot(b - a) / (b - a).length_squared().max(1e-4)).clamp(0.0, 1.0);
+            at.distance(a + (b - a) * along)
+        };
+        (0..self.edges.len())
+            .map(|edge| (edge, distance(&self.edges[edge])))
+            .filter(|&(_, distance)| distance <= EDGE_REACH)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(edge, _)| edge)
+    }
+}
+
+/// What the editor has picked: Delete removes it, and a picked edge takes a new weight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pick {
+    Node(usize),
+    Edge(usize),
+}
+
+#[derive(Clone, Copy)]
+enum Press {
+    /// Dragging a node, held `grab` away from its centre.
+    Move { node: usize, grab: Vec2 },
+    /// A right-drag from `from` that becomes an edge where it is released.
+    Link { from: usize, to: Vec2 },
+}
+
+enum Note {
+    Saved(String),
+    Loaded(String),
+    Failed(String),
+}
+
+#[derive(Resource)]
+struct GraphEditor {
+    on: bool,
+    pick: Option<Pick>,
+    // digits typed since the edge was picked extend its weight instead of replacing it
+    typing: bool,
+    // a typed - not yet followed by a digit, so the entry reads 0 but the digits go negative
+    negative: bool,
+    press: Option<Press>,
+    note: Option<Note>,
+}
+
+#[derive(Component)]
+struct GraphHint;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum GraphButton {
+    Edit,
+    Tidy,
+}
+
+/// The outline on the picked node or edge, and the line of an edge being drawn.
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Pick,
+    Link,
+}
+
+/// Registers the graph editor for the viz in `state`: E toggles edit mode, in
+/// which clicks and drags add, move, join and remove nodes and set edge weights;
+/// F (or the Tidy button) lays the graph out, S and O save and load it.
+pub(crate) fn add_graph_editor(app: &mut App, state: Gallery) {
+    let Gallery::Viz(id) = state else { return };
+    let file = format!("{id}-graph.ron");
+    app.add_systems(OnEnter(state), spawn_editor.in_set(Enter::Setup))
+        .add_systems(
+            PreUpdate,
+            // before Update, so the viz redraws the edited graph on the same frame
+            (
+                graph_keys.pipe(
+                    move |In(key), editor: ResMut<GraphEditor>, graph: ResMut<Graph>| {
+                        save_or_load(key, &file, editor, graph)
+                    },
+                ),
+                pointer,
+            )
+                .chain()
+                .after(InputSystem)
+                .after(UiSystem::Focus)
+                .in_set(VizScope(id)),
+        )
+        .add_systems(Update, (draw_hint, draw_marks).in_set(VizScope(id)));
+}
+
+fn spawn_editor(mut commands: Commands) {
+    commands.insert_resource(GraphEditor {
+        on: false,
+        pick: None,
+        typing: false,
+        negative: false,
+        press: None,
+        note: None,
+    });
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(MARGIN),
+                left: Val::Px(MARGIN),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|panel| {
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: FONT_SIZE,
+                        color: LABEL_COLOR,
+                        ..default()
+                    },
+                ),
+                GraphHint,
+            ));
+            panel
+                .spawn(NodeBundle {
+                    style: Style {
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (button, label) in [
+                        (GraphButton::Edit, "graph.button.edit"),
+                        (GraphButton::Tidy, "graph.button.tidy"),
+                    ] {
+                        row.spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    height: Val::Px(22.0),
+                                    padding: UiRect::horizontal(Val::Px(8.0)),
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                background_color: BackgroundColor(BUTTON_IDLE),
+                                ..default()
+                            },
+                            button,
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font_size: 14.0,
+                                        color: LABEL_COLOR,
+                                        ..default()
+                                    },
+                                ),
+                                Localized::new(label),
+                            ));
+                        });
+                    }
+                });
+        });
+    for mark in [Mark::Pick, Mark::Link] {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: MARK_COLOR,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            mark,
+        ));
+    }
+}
+
+// E / the Edit button: edit mode, F / Tidy: layout; in edit mode Delete or X removes
+// the pick and 0-9, - and Backspace type a picked edge's weight. S and O are handed on to save_or_load
+fn graph_keys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    buttons: Query<(&Interaction, &GraphButton), Changed<Interaction>>,
+    mut editor: ResMut<GraphEditor>,
+    mut graph: ResMut<Graph>,
+) -> Option<KeyCode> {
+    let pressed = |which: GraphButton| {
+        buttons
+            .iter()
+            .any(|(interaction, &button)| *interaction == Interaction::Pressed && button == which)
+    };
+    if keys.just_pressed(KeyCode::KeyE) || pressed(GraphButton::Edit) {
+        editor.on = !editor.on;
+        editor.press = None;
+        editor.pick = None;
+    }
+    if keys.just_pressed(KeyCode::KeyF) || pressed(GraphButton::Tidy) {
+        graph.tidy();
+    }
+    if !editor.on {
+        return None;
+    }
+    if keys.any_just_pressed([KeyCode::Delete, KeyCode::KeyX]) {
+        match editor.pick.take() {
+            Some(Pick::Node(node)) => {
+                graph.remove_node(node);
+                // a drag or link in progress may name a node that is gone or has a new id
+                editor.press = None;
+            }
+            Some(Pick::Edge(edge)) => graph.remove_edge(edge),
+            None => {}
+        }
+    }
+    if let Some(Pick::Edge(edge)) = editor
+        .pick
+        .filter(|_| graph.weights != EdgeWeights::Unweighted)
+    {
+        let digits = [
+            KeyCode::Digit0,
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+        ];
+        let weight = graph.edges[edge].2;
+        let minus = [KeyCode::Minus, KeyCode::NumpadSubtract];
+        let mut negative = false;
+        let typed = if let Some(digit) = digits.iter().position(|&key| keys.just_pressed(key)) {
+            keys.clear_just_pressed(digits[digit]);
+            let digit = digit as i32;
+            Some(match editor.typing {
+                true if weight < 0 || editor.negative => weight * 10 - digit,
+                true => weight * 10 + digit,
+                false => digit,
+            })
+        } else if let Some(key) = minus
+            .into_iter()
+            .find(|&key| keys.just_pressed(key))
+            .filter(|_| graph.weights == EdgeWeights::Signed)
+        {
+            // - is also the speed control's key
+            keys.clear_just_pressed(key);
+            // a fresh entry starts from the bare sign, one under way flips it
+            negative = !editor.typing || (weight == 0 && !editor.negative);
+            Some(if editor.typing { -weight } else { 0 })
+        } else if keys.just_pressed(KeyCode::Backspace) {
+            negative = weight < 0 || editor.negative;
+            Some(weight / 10)
+        } else {
+            None
+        };
+        if let Some(typed) = typed {
+            graph.set_weight(edge, typed);
+            editor.typing = true;
+            editor.negative = negative;
+        }
+    }
+    [KeyCode::KeyS, KeyCode::KeyO]
+        .into_iter()
+        .find(|&key| keys.just_pressed(key))
+}
+
+// S writes the graph as a scenario file in the current directory, O reads it back
+fn save_or_load(
+    key: Option<KeyCode>,
+    file: &str,
+    mut editor: ResMut<GraphEditor>,
+    mut graph: ResMut<Graph>,
+) {
+    let (directed, weights) = (graph.directed, graph.weights);
+    let fits = |scenario: &GraphScenario| match directed {
+        true => scenario.directed_edges(),
+        false => scenario.undirected(),
+    };
+    let check = |scenario: &GraphScenario| match weights {
+        EdgeWeights::NonNegative => fits(scenario).and_then(|()| scenario.nonnegative_weights()),
+        _ => fits(scenario),
+    };
+    let note = match key {
+        Some(KeyCode::KeyS) => match std::fs::write(file, graph.to_ron()) {
+            Ok(()) => Note::Saved(file.into()),
+            Err(err) => Note::Failed(format!("{file}: {err}")),
+        },
+        Some(KeyCode::KeyO) => match GraphScenario::load(Path::new(file), check) {
+            Ok(scenario) => {
+                *graph = Graph::from_scenario(&scenario, weights);
+                editor.pick = None;
+                editor.press = None;
+                editor.typing = false;
+                Note::Loaded(file.into())
+            }
+            Err(err) => Note::Failed(err.to_string()),
+        },
+        _ => return,
+    };
+    editor.note = Some(note);
+}
+
+// in edit mode: a click on empty space adds a node, on a node or edge picks it;
+// dragging a node moves it, right-dragging from one node to another joins them,
+// and the wheel over an edge steps its weight
+fn pointer(
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut wheel: EventReader<MouseWheel>,
+    buttons: Query<&Interaction, With<Button>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut editor: ResMut<GraphEditor>,
+    mut graph: ResMut<Graph>,
+) {
+    let notches: i32 = wheel
+        .read()
+        .filter(|event| event.y != 0.0)
+        .map(|event| event.y.signum() as i32)
+        .sum();
+    if !editor.on {
+        return;
+    }
+    let Some(at) = cursor(&windows, &cameras) else {
+        return;
+    };
+    if notches != 0 && graph.weights != EdgeWeights::Unweighted {
+        if let Some(edge) = graph.edge_at(at) {
+            let weight = graph.edges[edge].2 + notches;
+            graph.set_weight(edge, weight);
+            editor.pick = Some(Pick::Edge(edge));
+            editor.typing = false;
+        }
+    }
+    let press = editor.press;
+    match press {
+        None => {
+            if buttons
+                .iter()
+                .any(|interaction| *interaction != Interaction::None)
+            {
+                return;
+            }
+            if mouse.just_pressed(MouseButton::Left) {
+                mouse.clear_just_pressed(MouseButton::Left);
+                editor.typing = false;
+                if let Some(node) = graph.node_at(at) {
+                    editor.press = Some(Press::Move {
+                        node,
+                        grab: at - graph.nodes[node],
+                    });
+                    editor.pick = Some(Pick::Node(node));
+                } else if let Some(edge) = graph.edge_at(at) {
+                    editor.pick = Some(Pick::Edge(edge));
+                } else {
+                    editor.pick = Some(Pick::Node(graph.add_node(at.round())));
+                }
+            } else if mouse.just_pressed(MouseButton::Right) {
+                match graph.node_at(at) {
+                    Some(from) => editor.press = Some(Press::Link { from, to: at }),
+                    // a right-click on empty space lets go of the pick
+                    None => editor.pick = None,
+                }
+            }
+        }
+        Some(Press::Move { node, grab }) => {
+            if !mouse.pressed(MouseButton::Left) || node >= graph.len() {
+                editor.press = None;
+            } else if graph.nodes[node] != (at - grab).round() {
+                graph.move_node(node, (at - grab).round());
+            }
+        }
+        Some(Press::Link { from, .. }) => {
+            if mouse.pressed(MouseButton::Right) {
+                // only the line follows the cursor; nothing the hint shows changed
+                editor.bypass_change_detection().press = Some(Press::Link { from, to: at });
+                return;
+            }
+            editor.press = None;
+            let Some(to) = graph.node_at(at) else { return };
+            if let Some(edge) = graph.add_edge(from, to, 1) {
+                editor.pick = Some(Pick::Edge(edge));
+                editor.typing = false;
+            }
+        }
+    }
+}
+
+fn draw_hint(
+    editor: Res<GraphEditor>,
+    graph: Res<Graph>,
+    locale: Res<Locale>,
+    mut hints: Query<&mut Text, With<GraphHint>>,
+    mut buttons: Query<(&GraphButton, &Interaction, &mut BackgroundColor)>,
+) {
+    for (button, interaction, mut bg) in &mut buttons {
+        let lit = (*button == GraphButton::Edit && editor.on) || *interaction != Interaction::None;
+        let color = if lit { BUTTON_ON } else { BUTTON_IDLE };
+        if bg.0 != color {
+            *bg = BackgroundColor(color);
+        }
+    }
+    if !editor.is_changed() && !graph.is_changed() && !locale.is_changed() {
+        return;
+    }
+    let mut hint = locale.text(if editor.on {
+        "graph.hint.edit"
+    } else {
+        "graph.hint.view"
+    });
+    if let Some(Pick::Edge(edge)) = editor.pick.filter(|_| editor.on) {
+        if let Some(&(from, to, weight)) = graph.edges.get(edge) {
+            let key = match graph.weights {
+                EdgeWeights::Unweighted => "graph.hint.edge",
+                EdgeWeights::NonNegative => "graph.hint.weight",
+                EdgeWeights::Signed => "graph.hint.weight.signed",
+            };
+            hint += &format!(
+                "\n{}",
+                crate::tr!(locale, key, from = from, to = to, weight = weight)
+            );
+        }
+    }
+    match &editor.note {
+        Some(Note::Saved(file)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.saved", file = file))
+        }
+        Some(Note::Loaded(file)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.loaded", file = file))
+        }
+        Some(Note::Failed(error)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.failed", error = error))
+        }
+        None => {}
+    }
+    for mut text in &mut hints {
+        text.sections[0].value.clone_from(&hint);
+    }
+}
+
+fn draw_marks(
+    editor: Res<GraphEditor>,
+    graph: Res<Graph>,
+    mut marks: Query<(&Mark, &mut Sprite, &mut Transform, &mut Visibility)>,
+) {
+    for (mark, mut sprite, mut tf, mut visibility) in &mut marks {
+        // (from, to, thickness) of the line to draw, a node being a line of its own width
+        let line = match (mark, &editor.pick, &editor.press) {
+            _ if !editor.on => None,
+            (Mark::Pick, Some(Pick::Node(node)), _) => graph
+                .nodes
+                .get(*node)
+                .map(|&at| (at, at, NODE_HALF * 2.0 + 10.0)),
+            (Mark::Pick, Some(Pick::Edge(edge)), _) => graph
+                .edges
+                .get(*edge)
+                .map(|&(from, to, _)| (graph.nodes[from], graph.nodes[to], 12.0)),
+            (Mark::Link, _, Some(Press::Link { from, to })) => {
+                graph.nodes.get(*from).map(|&at| (at, *to, 4.0))
+            }
+            _ => None,
+        };
+        let Some((from, to, thickness)) = line else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        let along = to - from;
+        *visibility = Visibility::Visible;
+        sprite.custom_size = Some(Vec2::new(along.length().max(thickness), thickness));
+        tf.translation = ((from + to) / 2.0).extend(-0.2);
+        tf.rotation = Quat::from_rotation_z(along.y.atan2(along.x));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::keyboard::{Key, KeyboardInput};
+    use bevy::input::{ButtonState, InputPlugin};
+
+    fn square() -> Graph {
+        let nodes = vec![
+            Vec2::new(-50.0, -50.0),
+            Vec2::new(50.0, -50.0),
+            Vec2::new(50.0, 50.0),
+            Vec2::new(-50.0, 50.0),
+        ];
+        Graph::undirected(
+            nodes,
+            [(0, 1, 4), (1, 2, 5), (2, 3, 6), (3, 0, 7), (1, 3, 8)],
+            EdgeWeights::NonNegative,
+        )
+    }
+
+    #[test]
+    fn remove_node_shifts_later_ids() {
+        let mut graph = square();
+        graph.source = 3;
+        graph.remove_node(1);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.positions()[1], Vec2::new(50.0, 50.0));
+        // (2, 3) and (3, 0) survive as (1, 2) and (2, 0); every edge at node 1 is gone
+        assert_eq!(graph.edges(), &[(1, 2, 6), (2, 0, 7)]);
+        assert_eq!(graph.source, 2);
+    }
+
+    #[test]
+    fn remove_node_keeps_the_source_a_node() {
+        let mut graph = square();
+        graph.source = 2;
+        graph.remove_node(2);
+        assert_eq!(graph.source, 0);
+        for node in (0..graph.len()).rev() {
+            graph.remove_node(node);
+        }
+        assert_eq!(graph.len(), 1);
+        assert_eq!(graph.source, 0);
+    }
+
+    #[test]
+    fn add_edge_rejects_loops_and_duplicates() {
+        let mut graph = square();
+        assert_eq!(graph.add_edge(2, 2, 1), None);
+        assert_eq!(graph.add_edge(0, 1, 9), None);
+        // undirected: the reverse is the same edge
+        assert_eq!(graph.add_edge(1, 0, 9), None);
+        assert_eq!(graph.add_edge(0, 2, 150), Some(5));
+        assert_eq!(graph.edges()[5], (0, 2, MAX_WEIGHT));
+
+        let mut directed =
+            Graph::directed(graph.positions().to_vec(), [(0, 1, 1)], EdgeWeights::Signed);
+        assert_eq!(directed.add_edge(0, 1, 2), None);
+        assert_eq!(directed.add_edge(1, 0, -3), Some(1));
+        assert_eq!(directed.edges()[1], (1, 0, -3));
+    }
+
+    #[test]
+    fn saved_graph_loads_back() {
+        let mut graph = Graph::directed(
+            square().positions().to_vec(),
+            [(0, 1, -4), (1, 2, 5), (3, 1, 0)],
+            EdgeWeights::Signed,
+        );
+        graph.source = 3;
+        let text = graph.to_ron();
+        let scenario = GraphScenario::parse(&text, "saved", false, |_| Ok(())).unwrap();
+        assert_eq!(Graph::from_scenario(&scenario, EdgeWeights::Signed), graph);
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            let logical_key = Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified);
+            app.world_mut().send_event(KeyboardInput {
+                key_code,
+                logical_key,
+                state,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        }
+    }
+
+    fn editor_app(graph: Graph) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin))
+            .insert_resource(graph)
+            .insert_resource(GraphEditor {
+                on: true,
+                pick: None,
+                typing: false,
+                negative: false,
+                press: None,
+                note: None,
+            })
+            .add_systems(
+                PreUpdate,
+                graph_keys
+                    .pipe(
+                        |In(key), editor: ResMut<GraphEditor>, graph: ResMut<Graph>| {
+                            save_or_load(key, "unused.ron", editor, graph)
+                        },
+                    )
+                    .after(InputSystem),
+            )
+            .add_systems(Update, draw_marks);
+        app.world_mut().spawn((SpriteBundle::default(), Mark::Link));
+        app
+    }
+
+    #[test]
+    fn deleting_the_linked_node_ends_the_link() {
+        let mut app = editor_app(square());
+        {
+            let mut editor = app.world_mut().resource_mut::<GraphEditor>();
+            editor.pick = Some(Pick::Node(3));
+            editor.press = Some(Press::Link {
+                from: 3,
+                to: Vec2::ZERO,
+            });
+        }
+        app.update();
+        press(&mut app, KeyCode::Delete);
+        assert_eq!(app.world().resource::<Graph>().len(), 3);
+        assert!(app.world().resource::<GraphEditor>().press.is_none());
+    }
+
+    #[test]
+    fn typed_minus_is_not_a_speed_key() {
+        let mut graph = square();
+        graph.weights = EdgeWeights::Signed;
+        let mut app = editor_app(graph);
+        app.world_mut().resource_mut::<GraphEditor>().pick = Some(Pick::Edge(0));
+        // Update runs after the editor has had the key
+        app.add_systems(Update, |keys: Res<ButtonInput<KeyCode>>| {
+            assert!(!keys.just_pressed(KeyCode::Minus))
+        });
+        press(&mut app, KeyCode::Minus);
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, 0);
+        press(&mut app, KeyCode::Digit5);
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, -5);
+        press(&mut app, KeyCode::Minus);
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, 5);
+    }
+
+    #[test]
+    fn typing_a_minus_starts_a_negative_weight() {
+        let mut graph = square();
+        graph.weights = EdgeWeights::Signed;
+        let mut app = editor_app(graph);
+        app.world_mut().resource_mut::<GraphEditor>().pick = Some(Pick::Edge(0));
+        for key in [KeyCode::Minus, KeyCode::Digit5, KeyCode::Digit2] {
+            press(&mut app, key);
+        }
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, -52);
+        // rubbing out the digits keeps the sign for the next one
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Digit7);
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, -7);
+    }
+
+    #[test]
+    fn minus_is_left_to_the_speed_control_without_signed_weights() {
+        let mut app = editor_app(square());
+        app.world_mut().resource_mut::<GraphEditor>().pick = Some(Pick::Edge(0));
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::Minus,
+            logical_key: Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified),
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_pressed(KeyCode::Minus));
+        assert_eq!(app.world().resource::<Graph>().edges()[0].2, 4);
+    }
+}
@@ -0,0 +1,253 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::cost::OpCounts;
+use crate::race::Racer;
+
+/// `--headless [--trace out.jsonl]`: run the algorithm without a window and
+/// write one JSON record per logical step, to the file or to stdout.
+///
+/// ```ignore
+/// if let Some(mut trace) = TraceWriter::from_args() {
+///     run_headless(array, &mut trace);
+///     return;
+/// }
+/// ```
+pub struct TraceWriter {
+    out: Box<dyn Write>,
+    step: usize,
+}
+
+/// A step of an array algorithm: what it did, to which positions, the array
+/// afterwards and the explanation the viz shows for it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArrayStep {
+    pub op: &'static str,
+    pub indices: Vec<usize>,
+    pub array: Vec<usize>,
+    pub explanation: String,
+}
+
+/// A step of a [`Racer`]: the positions it looked at or moved, the array
+/// afterwards and the running counts.
+#[derive(Serialize)]
+struct RacerStep<'a> {
+    op: &'static str,
+    indices: Vec<usize>,
+    array: &'a [usize],
+    counts: OpCounts,
+}
+
+#[derive(Serialize)]
+struct Numbered<'a, R> {
+    step: usize,
+    #[serde(flatten)]
+    record: &'a R,
+}
+
+impl TraceWriter {
+    /// `Some` when `--headless` was given. Bad flags or an unwritable
+    /// `--trace` file print the error and exit.
+    pub fn from_args() -> Option<Self> {
+        let (flags, _) = split_run_flags(std::env::args().skip(1));
+        let flags = flags.unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            std::process::exit(2);
+        });
+        if !flags.headless {
+            return None;
+        }
+        let out: Box<dyn Write> = match &flags.trace {
+            Some(path) => match File::create(path) {
+                Ok(file) => Box::new(BufWriter::new(file)),
+                Err(err) => {
+                    eprintln!("error: --trace {path}: {err}");
+                    std::process::exit(2);
+                }
+            },
+            None => Box::new(BufWriter::new(io::stdout())),
+        };
+        Some(Self::new(out))
+    }
+
+    pub fn new(out: Box<dyn Write>) -> Self {
+        Self { out, step: 0 }
+    }
+
+    /// Write `record` as the next line, numbered from 0 in a `step` field.
+    pub fn write<R: Serialize>(&mut self, record: &R) {
+        let line = serde_json::to_string(&Numbered {
+            step: self.step,
+            record,
+        })
+        .expect("trace records serialize");
+        if let Err(err) = writeln!(self.out, "{line}") {
+            eprintln!("error: writing trace: {err}");
+            std::process::exit(1);
+        }
+        self.step += 1;
+    }
+
+    /// Number of records written so far.
+    pub fn steps(&self) -> usize {
+        self.step
+    }
+}
+
+/// `--headless` for a sort: steps `racer` to the end, one `step` record per
+/// step and a last `done` one.
+///
+/// ```ignore
+/// if let Some(mut trace) = TraceWriter::from_args() {
+///     run_racer(racer(ArrayInput::from_args(INPUT).permutation()), &mut trace);
+///     return;
+/// }
+/// ```
+pub fn run_racer(mut racer: Box<dyn Racer>, trace: &mut TraceWriter) {
+    while !racer.done() {
+        racer.step();
+        trace.write(&RacerStep {
+            op: "step",
+            indices: racer.focus(),
+            array: racer.array(),
+            counts: racer.counts(),
+        });
+    }
+    trace.write(&RacerStep {
+        op: "done",
+        indices: Vec::new(),
+        array: racer.array(),
+        counts: racer.counts(),
+    });
+}
+
+/// `--headless` for a [`Trace`](crate::Trace) viz: one record per event, in
+/// the order the player would apply them. Events serialize as tagged structs,
+/// e.g. `#[serde(tag = "op", rename_all = "snake_case")]`.
+pub fn run_trace<E: Serialize>(events: &[E], trace: &mut TraceWriter) {
+    for event in events {
+        trace.write(event);
+    }
+}
+
+impl Drop for TraceWriter {
+    fn drop(&mut self) {
+        let _ = self.out.flush();
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct RunFlags {
+    pub headless: bool,
+    pub trace: Option<String>,
+    pub export_svg: Option<String>,
+    pub seed: Option<u64>,
+    pub fixed_step: bool,
+    pub subtitles: Option<String>,
+    pub theme: Option<String>,
+    pub lang: Option<String>,
+    pub quiz: bool,
+    pub code: bool,
+}
+
+/// Take `--headless`, `--trace PATH`, `--export-svg DIR`, `--seed S`,
+/// `--fixed-step`, `--subtitles DIR`, `--theme NAME`, `--lang CODE`, `--quiz` and
+/// `--code` out of `args`, leaving the viz's own arguments for `ArrayInput` / `Scenario` to parse.
+pub(crate) fn split_run_flags(
+    args: impl IntoIterator<Item = String>,
+) -> (Result<RunFlags, String>, Vec<String>) {
+    let mut flags = RunFlags::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--headless" => flags.headless = true,
+            "--trace" => match args.next() {
+                Some(path) => flags.trace = Some(path),
+                None => return (Err("--trace needs a value".into()), rest),
+            },
+            "--export-svg" => match args.next() {
+                Some(dir) => flags.export_svg = Some(dir),
+                None => return (Err("--export-svg needs a value".into()), rest),
+            },
+            "--seed" => match args.next().map(|seed| seed.parse::<u64>()) {
+                Some(Ok(seed)) => flags.seed = Some(seed),
+                Some(Err(_)) => return (Err("--seed must be a whole number".into()), rest),
+                None => return (Err("--seed needs a value".into()), rest),
+            },
+            "--fixed-step" => flags.fixed_step = true,
+            "--subtitles" => match args.next() {
+                Some(dir) => flags.subtitles = Some(dir),
+                None => return (Err("--subtitles needs a value".into()), rest),
+            },
+            "--theme" => match args.next() {
+                Some(name) => flags.theme = Some(name),
+                None => return (Err("--theme needs a value".into()), rest),
+            },
+            "--lang" => match args.next() {
+                Some(code) => flags.lang = Some(code),
+                None => return (Err("--lang needs a value".into()), rest),
+            },
+            "--quiz" => flags.quiz = true,
+            "--code" => flags.code = true,
+            _ => rest.push(arg),
+        }
+    }
+    if flags.trace.is_some() && !flags.headless {
+        return (Err("--trace only applies with --headless".into()), rest);
+    }
+    if flags.export_svg.is_some() && flags.headless {
+        return (
+            Err("--export-svg needs the window, not --headless".into()),
+            rest,
+        );
+    }
+    if flags.subtitles.is_some() && flags.headless {
+        return (
+            Err("--subtitles needs the window, not --headless".into()),
+            rest,
+        );
+    }
+    if flags.quiz && flags.headless {
+        return (Err("--quiz needs the window, not --headless".into()), rest);
+    }
+    if flags.code && flags.headless {
+        return (Err("--code needs the window, not --headless".into()), rest);
+    }
+    (Ok(flags), rest)
+}
+
+/// The command line minus the run flags.
+pub(crate) fn viz_args() -> Vec<String> {
+    split_run_flags(std::env::args().skip(1)).1
+}
+
+/// The run flags on the command line. Bad ones print the error and exit.
+pub(crate) fn run_flags() -> RunFlags {
+    match split_run_flags(std::env::args().skip(1)).0 {
+        Ok(flags) => flags,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// The `--export-svg` directory.
+pub(crate) fn export_svg_dir() -> Option<PathBuf> {
+    run_flags().export_svg.map(PathBuf::from)
+}
+
+/// `--headless` reached a viz that opens its window anyway.
+pub(crate) fn reject_headless() {
+    if split_run_flags(std::env::args().skip(1))
+        .0
+        .is_ok_and(|flags| flags.headless)
+    {
+        eprintln!("error: this visualization has no --headless mode");
+        std::process::exit(2);
+    }
+}
@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use crate::controls::{ControlBar, Settings, VizSet};
+use crate::gallery::{Enter, Gallery, VizScope};
+
+const TRACK_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.12);
+const FILL_COLOR: Color = Color::srgba(0.2, 0.6, 1.0, 0.7);
+const LABEL_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+
+/// Snapshots of a viz's logical state, one per finished step, so a lesson can
+/// go back to a swap and show it again.
+#[derive(Resource)]
+pub struct History<S> {
+    snapshots: Vec<S>,
+    pos: usize,
+}
+
+impl<S> Default for History<S> {
+    fn default() -> Self {
+        Self {
+            snapshots: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+impl<S: PartialEq> History<S> {
+    /// True if `snapshot` is new: neither the one on screen nor just restored.
+    pub fn differs(&self, snapshot: &S) -> bool {
+        self.current() != Some(snapshot)
+    }
+}
+
+impl<S> History<S> {
+    /// Store the state a live step has reached. Stepping on from an earlier
+    /// point drops the snapshots after it.
+    pub fn record(&mut self, snapshot: S) {
+        self.snapshots.truncate(self.pos + 1);
+        self.snapshots.push(snapshot);
+        self.pos = self.snapshots.len() - 1;
+    }
+
+    pub fn current(&self) -> Option<&S> {
+        self.snapshots.get(self.pos)
+    }
+
+    /// Index of the snapshot on screen.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// True when no recorded snapshot lies ahead; moving forward means running the algorithm.
+    pub fn at_latest(&self) -> bool {
+        self.pos + 1 >= self.snapshots.len()
+    }
+
+    /// Move to snapshot `pos` (clamped). Returns whether the position changed.
+    pub fn seek(&mut self, pos: usize) -> bool {
+        let pos = pos.min(self.snapshots.len().saturating_sub(1));
+        if pos == self.pos {
+            return false;
+        }
+        self.pos = pos;
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+        self.pos = 0;
+    }
+}
+
+/// Sent when Left/Right or the timeline moved [`History`]; the viz copies
+/// `history.current()` back into its state and retargets its visuals.
+#[derive(Event, Default, Clone, Copy)]
+pub struct RestoreSnapshot;
+
+#[derive(Component)]
+struct TimelineTrack;
+
+#[derive(Component)]
+struct TimelineFill;
+
+#[derive(Component)]
+struct TimelineLabel;
+
+/// Registers a [`History<S>`] with Left/Right stepping and a timeline slider
+/// in the [`ControlBar`]. Right at the newest snapshot runs the next step.
+/// Entering the viz starts from an empty history.
+pub(crate) fn add_history_scrubber<S: Send + Sync + 'static>(app: &mut App, state: Gallery) {
+    let Gallery::Viz(id) = state else { return };
+    app.init_resource::<History<S>>()
+        .add_event::<RestoreSnapshot>()
+        .add_systems(
+            OnEnter(state),
+            (
+                (|mut history: ResMut<History<S>>| history.clear()).in_set(Enter::Reset),
+                spawn_timeline.in_set(Enter::Setup),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (history_keys::<S>, timeline_drag::<S>).in_set(VizSet::Controls),
+                update_timeline::<S>.in_set(VizSet::Highlight),
+            )
+                .in_set(VizScope(id)),
+        );
+}
+
+fn spawn_timeline(mut commands: Commands, bars: Query<Entity, With<ControlBar>>) {
+    let Ok(bar) = bars.get_single() else { return };
+    commands.entity(bar).with_children(|bar| {
+        bar.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(240.0),
+                    height: Val::Px(10.0),
+                    margin: UiRect::horizontal(Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(TRACK_COLOR),
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            TimelineTrack,
+        ))
+        .with_children(|track| {
+            track.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(FILL_COLOR),
+                    ..default()
+                },
+                TimelineFill,
+            ));
+        });
+        bar.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 14.0,
+                    color: LABEL_COLOR,
+                    ..default()
+                },
+            ),
+            TimelineLabel,
+        ));
+    });
+}
+
+fn history_keys<S: Send + Sync + 'static>(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut history: ResMut<History<S>>,
+    mut settings: ResMut<Settings>,
+    mut restore: EventWriter<RestoreSnapshot>,
+) {
+    let pos = history.pos();
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        settings.auto = false;
+        if pos > 0 && history.seek(pos - 1) {
+            restore.send(RestoreSnapshot);
+        }
+    } else if keys.just_pressed(KeyCode::ArrowRight) {
+        settings.auto = false;
+        if history.at_latest() {
+            settings.manual_step = true;
+        } else if history.seek(pos + 1) {
+            restore.send(RestoreSnapshot);
+        }
+    }
+}
+
+// the track is a Button, so holding the mouse on it keeps Interaction::Pressed and drags
+fn timeline_drag<S: Send + Sync + 'static>(
+    track: Query<(&Interaction, &RelativeCursorPosition), With<TimelineTrack>>,
+    mut history: ResMut<History<S>>,
+    mut settings: ResMut<Settings>,
+    mut restore: EventWriter<RestoreSnapshot>,
+) {
+    let Ok((interaction, cursor)) = track.get_single() else {
+        return;
+    };
+    if *interaction != Interaction::Pressed || history.is_empty() {
+        return;
+    }
+    let Some(at) = cursor.normalized else { return };
+    settings.auto = false;
+    let last = history.len() - 1;
+    let target = (at.x.clamp(0.0, 1.0) * last as f32).round() as usize;
+    if history.seek(target) {
+        restore.send(RestoreSnapshot);
+    }
+}
+
+fn update_timeline<S: Send + Sync + 'static>(
+    history: Res<History<S>>,
+    mut fill: Query<&mut Style, With<TimelineFill>>,
+    mut label: Query<&mut Text, With<TimelineLabel>>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    let last = history.len().saturating_sub(1);
+    let ratio = if last == 0 {
+        0.0
+    } else {
+        history.pos() as f32 / last as f32
+    };
+    for mut style in fill.iter_mut() {
+        style.width = Val::Percent(ratio * 100.0);
+    }
+    for mut text in label.iter_mut() {
+        text.sections[0].value = format!("step {}/{}", history.pos(), last);
+    }
+}
@@ -0,0 +1,186 @@
+//! Shared building blocks for the Bevy algorithm visualizations.
+//!
+//! Every viz used to carry its own copy of `Settings`, the Auto Play toggle,
+//! the step timer and the seven-segment digit spawner. `AlgoVizPlugin` owns
+//! those now; a visualization only registers its algorithm state and the
+//! systems that step and draw it, placed in the [`VizSet`] stages.
+//!
+//! Each viz crate is a library whose `plugin` registers itself with
+//! [`GalleryApp::add_viz`], scoped to its [`Gallery::Viz`] state, so the
+//! gallery launcher can host all of them and the crate's own `main` just
+//! starts in that state:
+//!
+//! ```ignore
+//! pub const ID: &str = "bubble-sort";
+//!
+//! pub fn plugin(app: &mut App) {
+//!     app.add_viz(ID)
+//!         .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+//!         .resource(MyState::default())
+//!         .setup(setup)
+//!         .systems(Update, my_step.in_set(VizSet::Step));
+//! }
+//!
+//! // main.rs
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(bevy_bubble_sort::plugin)
+//!     .insert_state(Gallery::Viz(bevy_bubble_sort::ID))
+//!     .run();
+//! ```
+//!
+//! Algorithms that precompute their run can instead emit events into a
+//! [`Trace`] and let [`VizBuilder::trace_player`] pace them, handing each one
+//! to an `apply` system that updates the scene:
+//!
+//! ```ignore
+//! app.add_viz(ID)
+//!     .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+//!     .resource(Trace::new(build_ops()))
+//!     .trace_player(apply_op);
+//!
+//! fn apply_op(In(op): In<Op>, mut state: ResMut<MyState>) { /* ... */ }
+//! ```
+//!
+//! Graph, grid, tree, puzzle and interval vizs read their input from a
+//! `--scenario` file when one is given (formats in algorithms/SCENARIOS.md):
+//!
+//! ```ignore
+//! let grid = GridScenario::from_args().map_or_else(sample_grid, |scenario| scenario.walls());
+//! ```
+//!
+//! A viz whose step logic runs without the ECS can also offer `--headless`,
+//! writing its steps as JSON Lines through a [`TraceWriter`] instead of
+//! opening a window: sorts through [`run_racer`], [`Trace`] vizs through
+//! [`run_trace`]. `AlgoVizPlugin` refuses the flag everywhere else.
+//!
+//! Labels and captions are [`SegmentText`]: sixteen-segment glyphs drawn
+//! from sprites, used like Bevy's `Text2dBundle` but without a font file.
+//! P saves the scene as an SVG (`--export-svg DIR` saves one per step); 3D
+//! vizs draw with [`SceneGizmos`] so their arrows and circles are included.
+//!
+//! Random input comes from [`VizRng`], seeded by `--seed S`; `--fixed-step`
+//! advances every frame by [`FIXED_STEP`], so a seeded run replays frame for frame.
+//! `--subtitles DIR` turns the changes of a viz's [`Narration`] text into
+//! `.srt` / `.vtt` cues timed to such a run.
+//!
+//! Highlight colors come from the [`Palette`] resource by role (focus,
+//! compare, settled, visited, violation); T cycles its themes at runtime.
+//! Sprites colored once at spawn carry a [`Highlight`] role so T repaints them too.
+//!
+//! Educational text is looked up by message ID in the [`Locale`]'s catalog,
+//! one `algoviz-core/locales/<lang>.ron` file per language: [`tr!`] fills in
+//! placeholders, [`Localized`] keeps a fixed text translated. `--lang CODE`
+//! picks the language and L switches it; missing IDs fall back to English.
+//!
+//! Sorts count their comparisons, swaps, array reads and writes and extra
+//! storage in an [`OpCounts`] kept in their state; [`VizBuilder::cost_chart`]
+//! plots the running totals step by step. A sorting crate's `racer` wraps the
+//! same state and step logic in a [`Racer`], which steps a plain array without
+//! the ECS so bevy-sort-race can run several sorts side by side.
+//!
+//! Bar rows take their size from the window: [`BarRow`] narrows bars and gaps
+//! to fit `n` and tells when digit labels no longer fit. From [`DENSE_N`]
+//! elements a sort runs as [`VizBuilder::dense_sort`] instead, its racer
+//! painting one pixel column per element.
+//!
+//! `--quiz` (or Q) pauses before each decision a viz offers as a [`Question`]
+//! and asks the learner to predict it; [`VizBuilder::quiz`] adds the panel,
+//! the 1-9 answer keys and the running score, and the viz's step asks
+//! through [`Quiz::hold`]. [`VizBuilder::trace_quiz`] does the same for a trace.
+//!
+//! [`VizBuilder::code_panel`] shows the algorithm as one or more [`Listing`]s
+//! (pseudocode, Rust) beside the scene; the step system moves the
+//! [`CodeCursor`] to the line it just ran, by ID, and lists its variables.
+//!
+//! [`VizBuilder::bar_editor`] lets a paused sort's bars be dragged into a new
+//! order or scrolled to new values; the viz restarts on the edited array
+//! through [`ArrayInput::edit`] and its own reset handler.
+//!
+//! Pathfinding vizzes search a [`PathGrid`]; [`VizBuilder::grid_editor`] lets
+//! the learner paint walls and costs on it, drag the start and goal, pick a
+//! [`Maze`] and save or load the grid, and the viz searches it again.
+//!
+//! Graph vizzes run on a [`Graph`]; [`VizBuilder::graph_editor`] adds an edit
+//! mode for adding, moving, joining and removing nodes and typing edge weights
+//! (within its [`EdgeWeights`]), a force-directed tidy-up and save/load.
+//!
+//! [`VizBuilder::console`] adds a text field for commands such as `insert 42`;
+//! while focused it keeps the typed keys from the hotkeys, and each entered line
+//! reaches the viz as a [`ConsoleCommand`].
+//!
+//! Tests drive the same step systems without a window: [`test_app`] builds
+//! an app on `MinimalPlugins` with a manual clock and [`run_until`] updates it
+//! until the algorithm reports it is done.
+
+mod args;
+mod bars;
+mod code;
+mod console;
+mod controls;
+mod cost;
+mod dense;
+mod digits;
+mod edit;
+mod gallery;
+mod graph;
+mod headless;
+mod history;
+mod locale;
+mod maze;
+mod quiz;
+mod race;
+mod replay;
+mod scenario;
+mod segments;
+mod subtitles;
+mod svg;
+mod testing;
+mod theme;
+mod trace;
+
+pub use args::{ArgError, ArrayInput, ArraySpec, Distribution};
+pub use bars::{row_width, BarRow};
+pub use code::{CodeCursor, CodeLine, Listing};
+pub use console::ConsoleCommand;
+pub use controls::{
+    step_ready, AlgoVizPlugin, AutoBtn, AutoKnob, ControlBar, ResetRequested, Settings, VizSet,
+};
+pub use cost::OpCounts;
+pub use dense::{DENSE_MAX_N, DENSE_N};
+pub use digits::{
+    spawn_digit, spawn_digits, spawn_value_digits, DigitStyle, ValueDigits, DIGIT_COLOR,
+};
+pub use edit::EditableBar;
+pub use gallery::{Gallery, GalleryApp, VizBuilder, VizScope};
+pub use graph::{EdgeWeights, Graph};
+pub use headless::{run_racer, run_trace, ArrayStep, TraceWriter};
+pub use history::{History, RestoreSnapshot};
+pub use locale::{Locale, Localized};
+pub use maze::{GridBrush, GridTile, Maze, PathGrid};
+pub use quiz::{Question, Quiz, QuizScore};
+pub use race::Racer;
+pub use replay::{VizRng, FIXED_STEP};
+pub use scenario::{
+    GraphScenario, GridScenario, IntervalScenario, Invalid, PuzzleScenario, Scenario,
+    ScenarioError, TreeNodeSpec, TreeScenario, WordGridScenario,
+};
+pub use segments::{SegmentSection, SegmentStyle, SegmentText, SegmentText2dBundle};
+pub use subtitles::Narration;
+pub use svg::SceneGizmos;
+pub use testing::{run_until, test_app};
+pub use theme::{Highlight, Palette};
+pub use trace::Trace;
+
+pub mod prelude {
+    pub use crate::{
+        row_width, run_racer, run_trace, spawn_digit, spawn_digits, spawn_value_digits, step_ready,
+        tr, AlgoVizPlugin, ArrayInput, ArraySpec, ArrayStep, BarRow, CodeCursor, ConsoleCommand,
+        DigitStyle, Distribution, EdgeWeights, EditableBar, Gallery, GalleryApp, Graph,
+        GraphScenario, GridBrush, GridScenario, GridTile, Highlight, History, IntervalScenario,
+        Invalid, Listing, Locale, Localized, Maze, Narration, OpCounts, Palette, PathGrid,
+        PuzzleScenario, Question, Quiz, Racer, ResetRequested, RestoreSnapshot, Scenario,
+        SceneGizmos, SegmentSection, SegmentStyle, SegmentText, SegmentText2dBundle, Settings,
+        Trace, TraceWriter, TreeScenario, ValueDigits, VizRng, VizSet, WordGridScenario, DENSE_N,
+    };
+}
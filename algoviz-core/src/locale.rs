@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Write as _};
+use std::sync::{Mutex, OnceLock};
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::segments::SegmentText;
+
+/// `(language code, RON)` for every file in algoviz-core/locales, English first.
+const SOURCES: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/locales.rs"));
+
+/// One `locales/<lang>.ron` file.
+#[derive(Deserialize)]
+struct Catalog {
+    #[serde(skip)]
+    code: &'static str,
+    /// What the language calls itself; logged when L switches to it.
+    name: String,
+    /// Font files to try in order for `Text`; the first that exists is used.
+    /// Empty: Bevy's built-in font, which has ASCII only.
+    #[serde(default)]
+    fonts: Vec<String>,
+    /// Whether `SegmentText` can draw the script; otherwise its labels stay English.
+    #[serde(default = "yes")]
+    segment_text: bool,
+    messages: HashMap<String, String>,
+}
+
+fn yes() -> bool {
+    true
+}
+
+fn catalogs() -> &'static [Catalog] {
+    static CATALOGS: OnceLock<Vec<Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        SOURCES
+            .iter()
+            .map(|&(code, source)| {
+                let mut catalog: Catalog =
+                    ron::from_str(source).unwrap_or_else(|err| panic!("locales/{code}.ron: {err}"));
+                catalog.code = code;
+                catalog
+            })
+            .collect()
+    })
+}
+
+/// The language educational text is shown in. Text is looked up by message
+/// ID in the language's catalog, `algoviz-core/locales/<lang>.ron`, with
+/// `{name}` placeholders filled in by [`tr!`](crate::tr); an ID the catalog
+/// lacks falls back to English and logs a warning once.
+///
+/// `--lang CODE` picks the language, L switches to the next one.
+///
+/// ```ignore
+/// fn update_educational_text(locale: Res<Locale>, mut q: Query<&mut Text, With<StepExplanation>>) {
+///     q.single_mut().sections[0].value = tr!(locale, "bubble-sort.step.compare", a = i + 1, b = i + 2);
+/// }
+/// ```
+#[derive(Resource, Clone, Copy)]
+pub struct Locale {
+    catalog: &'static Catalog,
+}
+
+impl Locale {
+    pub fn english() -> Self {
+        Self::named("en").expect("locales/en.ron is embedded")
+    }
+
+    pub fn named(code: &str) -> Option<Self> {
+        catalogs()
+            .iter()
+            .find(|catalog| catalog.code == code)
+            .map(|catalog| Self { catalog })
+    }
+
+    /// Every language with a catalog, in the order L cycles through them.
+    pub fn all() -> impl Iterator<Item = Self> {
+        catalogs().iter().map(|catalog| Self { catalog })
+    }
+
+    /// `--lang CODE`, or English. An unknown code prints the error and exits.
+    pub fn from_args() -> Self {
+        let Some(code) = crate::headless::run_flags().lang else {
+            return Self::english();
+        };
+        Self::named(&code).unwrap_or_else(|| {
+            let codes: Vec<_> = Self::all().map(|locale| locale.code()).collect();
+            eprintln!(
+                "error: --lang: no catalog `{code}` (one of {})",
+                codes.join(", ")
+            );
+            std::process::exit(2);
+        })
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.catalog.code
+    }
+
+    pub fn name(&self) -> &'static str {
+        &self.catalog.name
+    }
+
+    /// This language if [`SegmentText`] can draw it, English otherwise.
+    pub fn for_segment_text(self) -> Self {
+        if self.catalog.segment_text {
+            self
+        } else {
+            Self::english()
+        }
+    }
+
+    /// The message `key`, without placeholders filled in.
+    pub fn text(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    /// The message `key` with each `{name}` in `args` replaced by its value;
+    /// [`tr!`](crate::tr) builds `args` from `name = value` pairs.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let message = self.message(key);
+        let mut out = String::with_capacity(message.len());
+        let mut rest = message;
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            match after.find('}').and_then(|close| {
+                Some((
+                    close,
+                    args.iter().find(|(name, _)| *name == &after[..close])?,
+                ))
+            }) {
+                Some((close, (_, value))) => {
+                    let _ = write!(out, "{value}");
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    fn message<'a>(&self, key: &'a str) -> &'a str {
+        if let Some(message) = self.catalog.messages.get(key) {
+            return message;
+        }
+        let english = Self::english().catalog.messages.get(key);
+        warn_once(self.code(), key, || match english {
+            Some(_) => format!(
+                "locale {}: no message `{key}`, showing English",
+                self.code()
+            ),
+            None => format!("no message `{key}` in any catalog"),
+        });
+        english.map_or(key, String::as_str)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+/// Look `key` up in a [`Locale`] and fill in its `{name}` placeholders.
+///
+/// ```ignore
+/// tr!(locale, "heap-sort.progress", comparisons = state.comparisons, swaps = state.swaps)
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($locale:expr, $key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $locale.format($key, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),*])
+    };
+}
+
+/// Logs `message` the first time `code`/`key` comes up, not every frame.
+fn warn_once(code: &str, key: &str, message: impl FnOnce() -> String) {
+    static WARNED: OnceLock<Mutex<HashSet<(String, String)>>> = OnceLock::new();
+    let mut warned = WARNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if warned.insert((code.to_owned(), key.to_owned())) {
+        warn!("{}", message());
+    }
+}
+
+/// A `Text` or [`SegmentText`] whose whole value is the message with this
+/// ID, such as a title or complexity blurb; it follows language switches.
+/// Placeholders are filled from `args`, fixed when the text is spawned.
+///
+/// ```ignore
+/// commands.spawn((TextBundle::from_section("", style), Localized::new("bubble-sort.blurb")));
+/// commands.spawn((SegmentText2dBundle::default(), Localized::new("radix-lsd.pass").with("pass", 2)));
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct Localized {
+    pub key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl Localized {
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, value: impl Display) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    fn format(&self, locale: &Locale) -> String {
+        let args: Vec<(&str, &dyn Display)> = self
+            .args
+            .iter()
+            .map(|(name, value)| (*name, value as &dyn Display))
+            .collect();
+        locale.format(self.key, &args)
+    }
+}
+
+/// The [`Locale`] resource, `--lang`, L to switch and the language's font.
+/// Added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Locale::from_args()).add_systems(
+            Update,
+            (cycle_language, (translate_localized, apply_font)).chain(),
+        );
+    }
+}
+
+fn cycle_language(keys: Res<ButtonInput<KeyCode>>, mut locale: ResMut<Locale>) {
+    if keys.just_pressed(KeyCode::KeyL) {
+        let all: Vec<_> = Locale::all().collect();
+        let at = all
+            .iter()
+            .position(|other| other.code() == locale.code())
+            .unwrap_or(0);
+        *locale = all[(at + 1) % all.len()];
+        info!("language: {}", locale.name());
+    }
+}
+
+fn translate_localized(
+    locale: Res<Locale>,
+    mut texts: Query<(Ref<Localized>, Option<&mut Text>, Option<&mut SegmentText>)>,
+) {
+    for (localized, text, segment_text) in &mut texts {
+        if !locale.is_changed() && !localized.is_added() {
+            continue;
+        }
+        if let Some(section) = text.and_then(|text| text.into_inner().sections.first_mut()) {
+            section.value = localized.format(&locale);
+        }
+        if let Some(section) = segment_text.and_then(|text| text.into_inner().sections.first_mut())
+        {
+            section.value = localized.format(&locale.for_segment_text());
+        }
+    }
+}
+
+/// Sets every `Text` to the language's font: the first of its catalog's
+/// `fonts` found on disk, loaded once, or the built-in font.
+fn apply_font(
+    locale: Res<Locale>,
+    fonts: Option<ResMut<Assets<Font>>>,
+    mut loaded: Local<HashMap<&'static str, Handle<Font>>>,
+    mut texts: ParamSet<(Query<&mut Text>, Query<&mut Text, Added<Text>>)>,
+) {
+    let Some(mut fonts) = fonts else { return };
+    let code = locale.code();
+    let font = loaded
+        .entry(code)
+        .or_insert_with(|| load_font(&locale, &mut fonts))
+        .clone();
+    let mut set = |mut text: Mut<Text>| {
+        for section in &mut text.sections {
+            if section.style.font != font {
+                section.style.font = font.clone();
+            }
+        }
+    };
+    if locale.is_changed() {
+        texts.p0().iter_mut().for_each(&mut set);
+    } else {
+        texts.p1().iter_mut().for_each(&mut set);
+    }
+}
+
+fn load_font(locale: &Locale, fonts: &mut Assets<Font>) -> Handle<Font> {
+    let candidates = &locale.catalog.fonts;
+    for path in candidates {
+        let Ok(bytes) = std::fs::read(path) else {
+            continue;
+        };
+        match Font::try_from_bytes(bytes) {
+            Ok(font) => return fonts.add(font),
+            Err(err) => warn!("locale {}: font {path}: {err}", locale.code()),
+        }
+    }
+    if !candidates.is_empty() {
+        warn!(
+            "locale {}: none of its fonts found ({}); text may not render",
+            locale.code(),
+            candidates.join(", ")
+        );
+    }
+    Handle::default()
+}
@@ -0,0 +1,560 @@
+use std::path::Path;
+
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use rand::Rng;
+
+use crate::edit::cursor;
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Locale;
+use crate::replay::VizRng;
+use crate::scenario::{GridScenario, Scenario};
+
+// of the cells a random maze walls off
+const WALL_SHARE: f32 = 0.22;
+const WALL: u8 = 0;
+const MAX_COST: u8 = 9;
+const MARGIN: f32 = 10.0;
+const FONT_SIZE: f32 = 13.0;
+// right, down, left, up: the turns a spiral takes
+const TURNS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// What a left drag paints on a [`PathGrid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridBrush {
+    /// Walls, or open cells when the drag starts on a wall.
+    Walls,
+    /// The entry cost 1-9 picked with the digit keys; 0 picks walls.
+    Weights,
+}
+
+/// The mazes M steps through and R regenerates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Maze {
+    /// Scattered walls, or random costs on a weighted grid.
+    #[default]
+    Random,
+    /// Walls split the grid into ever smaller rooms, one opening each.
+    RecursiveDivision,
+    /// One corridor winding from the top-left corner to the middle.
+    Spiral,
+}
+
+impl Maze {
+    pub const ALL: [Maze; 3] = [Maze::Random, Maze::RecursiveDivision, Maze::Spiral];
+
+    /// Message ID of the maze's name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Maze::Random => "grid.maze.random",
+            Maze::RecursiveDivision => "grid.maze.recursive-division",
+            Maze::Spiral => "grid.maze.spiral",
+        }
+    }
+
+    fn next(self) -> Self {
+        let at = Self::ALL.iter().position(|&maze| maze == self).unwrap_or(0);
+        Self::ALL[(at + 1) % Self::ALL.len()]
+    }
+}
+
+/// A pathfinding grid in `(row, col)` cells: walls, the cost of entering each
+/// open cell, and the start and goal. With [`VizBuilder::grid_editor`](crate::VizBuilder::grid_editor)
+/// the learner edits it; the viz re-runs its search whenever it changed.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct PathGrid {
+    rows: usize,
+    cols: usize,
+    // 0 for walls, else the cost of entering
+    cells: Vec<u8>,
+    pub start: (usize, usize),
+    pub goal: (usize, usize),
+    // random mazes draw costs instead of walls
+    weighted: bool,
+}
+
+impl PathGrid {
+    /// `rows` × `cols` open cells, each costing 1.
+    pub fn open(rows: usize, cols: usize, start: (usize, usize), goal: (usize, usize)) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![1; rows * cols],
+            start,
+            goal,
+            weighted: false,
+        }
+    }
+
+    pub fn from_walls(walls: &[Vec<bool>], start: (usize, usize), goal: (usize, usize)) -> Self {
+        let cells = walls
+            .iter()
+            .flatten()
+            .map(|&wall| if wall { WALL } else { 1 })
+            .collect();
+        Self {
+            rows: walls.len(),
+            cols: walls[0].len(),
+            cells,
+            start,
+            goal,
+            weighted: false,
+        }
+    }
+
+    /// Cells costing `costs` to enter; random mazes on this grid pick new costs.
+    pub fn from_costs(costs: &[Vec<usize>], start: (usize, usize), goal: (usize, usize)) -> Self {
+        let cells = costs
+            .iter()
+            .flatten()
+            .map(|&cost| cost.clamp(1, MAX_COST as usize) as u8)
+            .collect();
+        Self {
+            rows: costs.len(),
+            cols: costs[0].len(),
+            cells,
+            start,
+            goal,
+            weighted: true,
+        }
+    }
+
+    /// A `--scenario` grid with its walls, costs and endpoints.
+    pub fn from_scenario(scenario: &GridScenario, weighted: bool) -> Self {
+        let mut grid = Self::from_costs(&scenario.costs(), scenario.start(), scenario.goal());
+        for (cell, wall) in grid
+            .cells
+            .iter_mut()
+            .zip(scenario.walls().into_iter().flatten())
+        {
+            if wall {
+                *cell = WALL;
+            }
+        }
+        grid.weighted = weighted;
+        grid
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn wall(&self, cell: (usize, usize)) -> bool {
+        self.cells[self.index(cell)] == WALL
+    }
+
+    /// What entering `cell` costs; walls can't be entered.
+    pub fn cost(&self, cell: (usize, usize)) -> Option<usize> {
+        let cost = self.cells[self.index(cell)];
+        (cost != WALL).then_some(cost as usize)
+    }
+
+    /// Walls by row, as [`GridScenario::walls`] gives them.
+    pub fn walls(&self) -> Vec<Vec<bool>> {
+        self.cells
+            .chunks(self.cols)
+            .map(|row| row.iter().map(|&cell| cell == WALL).collect())
+            .collect()
+    }
+
+    /// The open neighbors of `cell`, up, down, left and right.
+    pub fn neighbors(
+        &self,
+        (row, col): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        TURNS.iter().filter_map(move |&(dr, dc)| {
+            let next = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+            (next.0 < self.rows && next.1 < self.cols && !self.wall(next)).then_some(next)
+        })
+    }
+
+    /// The grid as a `--scenario` file: `S` and `G`, `#` walls, costs above 1 as digits.
+    pub fn to_ron(&self) -> String {
+        let rows: Vec<String> = (0..self.rows)
+            .map(|row| {
+                let cells: Vec<String> = (0..self.cols)
+                    .map(|col| match self.cells[self.index((row, col))] {
+                        _ if (row, col) == self.start => "S".into(),
+                        _ if (row, col) == self.goal => "G".into(),
+                        WALL => "#".into(),
+                        1 => ".".into(),
+                        cost => cost.to_string(),
+                    })
+                    .collect();
+                format!("        \"{}\",\n", cells.join(" "))
+            })
+            .collect();
+        format!("(\n    rows: [\n{}    ],\n)\n", rows.concat())
+    }
+
+    /// Replaces the cells with a fresh `maze`. Random mazes and recursive division
+    /// keep the start and goal (opening their cells); a spiral runs from the
+    /// top-left corner, where it puts the start, to the goal at its end.
+    pub fn generate(&mut self, maze: Maze, rng: &mut impl Rng) {
+        match maze {
+            Maze::Random if self.weighted => {
+                self.cells
+                    .iter_mut()
+                    .for_each(|cell| *cell = rng.gen_range(1..=MAX_COST));
+            }
+            Maze::Random => {
+                self.cells.iter_mut().for_each(|cell| {
+                    *cell = if rng.gen::<f32>() < WALL_SHARE {
+                        WALL
+                    } else {
+                        1
+                    }
+                });
+            }
+            Maze::RecursiveDivision => {
+                self.cells.fill(1);
+                self.divide((0, 0), (self.rows - 1, self.cols - 1), rng);
+            }
+            Maze::Spiral => {
+                self.spiral();
+                return;
+            }
+        }
+        for end in [self.start, self.goal] {
+            if self.wall(end) {
+                self.set(end, 1);
+            }
+        }
+    }
+
+    fn index(&self, (row, col): (usize, usize)) -> usize {
+        row * self.cols + col
+    }
+
+    fn set(&mut self, cell: (usize, usize), value: u8) {
+        let at = self.index(cell);
+        self.cells[at] = value;
+    }
+
+    // walls go on odd offsets and openings on even ones, so a later wall never closes an earlier opening
+    fn divide(
+        &mut self,
+        (top, left): (usize, usize),
+        (bottom, right): (usize, usize),
+        rng: &mut impl Rng,
+    ) {
+        let (height, width) = (bottom + 1 - top, right + 1 - left);
+        if height < 3 || width < 3 {
+            return;
+        }
+        let across = if height == width {
+            rng.gen()
+        } else {
+            height > width
+        };
+        if across {
+            let row = top + 1 + 2 * rng.gen_range(0..(height - 1) / 2);
+            let opening = left + 2 * rng.gen_range(0..width.div_ceil(2));
+            (left..=right)
+                .filter(|&col| col != opening)
+                .for_each(|col| self.set((row, col), WALL));
+            self.divide((top, left), (row - 1, right), rng);
+            self.divide((row + 1, left), (bottom, right), rng);
+        } else {
+            let col = left + 1 + 2 * rng.gen_range(0..(width - 1) / 2);
+            let opening = top + 2 * rng.gen_range(0..height.div_ceil(2));
+            (top..=bottom)
+                .filter(|&row| row != opening)
+                .for_each(|row| self.set((row, col), WALL));
+            self.divide((top, left), (bottom, col - 1), rng);
+            self.divide((top, col + 1), (bottom, right), rng);
+        }
+    }
+
+    // carves one corridor inward, keeping a wall between it and the turn before
+    fn spiral(&mut self) {
+        self.cells.fill(WALL);
+        let (rows, cols) = (self.rows as isize, self.cols as isize);
+        let inside = |(row, col): (isize, isize)| row >= 0 && col >= 0 && row < rows && col < cols;
+        let (mut at, mut turn, mut failed) = ((0isize, 0isize), 0, 0);
+        self.set((0, 0), 1);
+        while failed < 2 {
+            let (dr, dc) = TURNS[turn];
+            let next = (at.0 + dr, at.1 + dc);
+            let after = (at.0 + 2 * dr, at.1 + 2 * dc);
+            let wall = |cell: (isize, isize)| self.wall((cell.0 as usize, cell.1 as usize));
+            if inside(next) && wall(next) && (!inside(after) || wall(after)) {
+                at = next;
+                self.set((at.0 as usize, at.1 as usize), 1);
+                failed = 0;
+            } else {
+                turn = (turn + 1) % TURNS.len();
+                failed += 1;
+            }
+        }
+        self.start = (0, 0);
+        self.goal = (at.0 as usize, at.1 as usize);
+    }
+}
+
+/// A viz's tile component, as the [grid editor](crate::VizBuilder::grid_editor)
+/// reads it: the `(row, col)` of the [`PathGrid`] cell it draws.
+pub trait GridTile: Component {
+    fn cell(&self) -> (usize, usize);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Stroke {
+    Paint(u8),
+    Start,
+    Goal,
+}
+
+/// What the editor is doing: the brush, the maze M picked and the drag under way.
+#[derive(Resource)]
+struct GridEditor {
+    brush: GridBrush,
+    weight: u8,
+    maze: Maze,
+    stroke: Option<Stroke>,
+    note: Option<Note>,
+}
+
+// the outcome of the last save or load, shown under the hint
+#[derive(Clone, Debug)]
+enum Note {
+    Saved(String),
+    Loaded(String),
+    Failed(String),
+}
+
+#[derive(Component)]
+struct GridHint;
+
+/// Registers the grid editor for the viz in `state`, whose tiles are `T`s:
+/// painting, start and goal handles, mazes and save/load, all on its [`PathGrid`].
+pub(crate) fn add_grid_editor<T: GridTile>(app: &mut App, state: Gallery, brush: GridBrush) {
+    let Gallery::Viz(id) = state else { return };
+    let file = format!("{id}-grid.ron");
+    let start = move |mut commands: Commands| {
+        commands.insert_resource(GridEditor {
+            brush,
+            weight: MAX_COST,
+            maze: Maze::Random,
+            stroke: None,
+            note: None,
+        });
+        commands.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: FONT_SIZE,
+                    color: Color::srgb(0.8, 0.8, 0.85),
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(MARGIN),
+                left: Val::Px(MARGIN),
+                ..default()
+            }),
+            GridHint,
+        ));
+    };
+    app.add_systems(OnEnter(state), start.in_set(Enter::Setup))
+        .add_systems(
+            PreUpdate,
+            // before Update, so a click on the grid never reaches the step control
+            // and the viz sees the edited grid on the same frame
+            (
+                grid_keys.pipe(
+                    move |In(key), editor: ResMut<GridEditor>, grid: ResMut<PathGrid>| {
+                        save_or_load(key, &file, editor, grid)
+                    },
+                ),
+                paint::<T>,
+            )
+                .chain()
+                .after(InputSystem)
+                .after(UiSystem::Focus)
+                .in_set(VizScope(id)),
+        )
+        .add_systems(Update, draw_hint.in_set(VizScope(id)));
+}
+
+// M: next maze, R: the same maze anew, 0-9: brush; S and O are handed on to save_or_load
+fn grid_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut editor: ResMut<GridEditor>,
+    mut grid: ResMut<PathGrid>,
+    mut rng: ResMut<VizRng>,
+) -> Option<KeyCode> {
+    let next = keys.just_pressed(KeyCode::KeyM);
+    if next {
+        editor.maze = editor.maze.next();
+    }
+    if next || keys.just_pressed(KeyCode::KeyR) {
+        let maze = editor.maze;
+        grid.generate(maze, &mut rng.0);
+    }
+    if editor.brush == GridBrush::Weights {
+        let digits = [
+            KeyCode::Digit0,
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+        ];
+        if let Some(weight) = digits.iter().position(|&key| keys.just_pressed(key)) {
+            editor.weight = weight as u8;
+        }
+    }
+    [KeyCode::KeyS, KeyCode::KeyO]
+        .into_iter()
+        .find(|&key| keys.just_pressed(key))
+}
+
+// S writes the grid as a scenario file in the current directory, O reads it back
+fn save_or_load(
+    key: Option<KeyCode>,
+    file: &str,
+    mut editor: ResMut<GridEditor>,
+    mut grid: ResMut<PathGrid>,
+) {
+    let note = match key {
+        Some(KeyCode::KeyS) => match std::fs::write(file, grid.to_ron()) {
+            Ok(()) => Note::Saved(file.into()),
+            Err(err) => Note::Failed(format!("{file}: {err}")),
+        },
+        Some(KeyCode::KeyO) => {
+            match GridScenario::load(Path::new(file), GridScenario::open_endpoints) {
+                Ok(scenario) => {
+                    let loaded = PathGrid::from_scenario(&scenario, grid.weighted);
+                    if (loaded.rows, loaded.cols) == (grid.rows, grid.cols) {
+                        *grid = loaded;
+                        Note::Loaded(file.into())
+                    } else {
+                        Note::Failed(format!(
+                            "{file}: {}x{} cells, this grid is {}x{}",
+                            loaded.cols, loaded.rows, grid.cols, grid.rows
+                        ))
+                    }
+                }
+                Err(err) => Note::Failed(err.to_string()),
+            }
+        }
+        _ => return,
+    };
+    editor.note = Some(note);
+}
+
+// left drag paints (or erases, starting on a wall), right drag erases, and a
+// drag that starts on the start or goal moves it
+fn paint<T: GridTile>(
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    buttons: Query<&Interaction, With<Button>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    tiles: Query<(&T, &Transform, &Sprite)>,
+    mut editor: ResMut<GridEditor>,
+    mut grid: ResMut<PathGrid>,
+) {
+    let held = mouse.pressed(MouseButton::Left) || mouse.pressed(MouseButton::Right);
+    // the drag itself is not something the hint shows
+    let editor = editor.bypass_change_detection();
+    if !held {
+        editor.stroke = None;
+        return;
+    }
+    let hovered = cursor(&windows, &cameras).and_then(|at| {
+        tiles.iter().find_map(|(tile, transform, sprite)| {
+            let half = sprite.custom_size? / 2.0;
+            let offset = (at - transform.translation.truncate()).abs();
+            (offset.x <= half.x && offset.y <= half.y).then(|| tile.cell())
+        })
+    });
+    let Some(cell) = hovered else { return };
+    if editor.stroke.is_none() {
+        let pressed =
+            mouse.just_pressed(MouseButton::Left) || mouse.just_pressed(MouseButton::Right);
+        if !pressed
+            || buttons
+                .iter()
+                .any(|interaction| *interaction != Interaction::None)
+        {
+            return;
+        }
+        let brush = match editor.brush {
+            GridBrush::Walls => WALL,
+            GridBrush::Weights => editor.weight,
+        };
+        let stroke = if mouse.just_pressed(MouseButton::Right) {
+            Stroke::Paint(1)
+        } else if cell == grid.start {
+            Stroke::Start
+        } else if cell == grid.goal {
+            Stroke::Goal
+        } else if brush == WALL && grid.wall(cell) {
+            Stroke::Paint(1)
+        } else {
+            Stroke::Paint(brush)
+        };
+        mouse.clear_just_pressed(MouseButton::Left);
+        editor.stroke = Some(stroke);
+    }
+    let endpoints = [grid.start, grid.goal];
+    match editor.stroke {
+        Some(Stroke::Paint(value))
+            if !endpoints.contains(&cell) && grid.cells[grid.index(cell)] != value =>
+        {
+            grid.set(cell, value);
+        }
+        Some(Stroke::Start) if !grid.wall(cell) && !endpoints.contains(&cell) => grid.start = cell,
+        Some(Stroke::Goal) if !grid.wall(cell) && !endpoints.contains(&cell) => grid.goal = cell,
+        _ => {}
+    }
+}
+
+fn draw_hint(
+    editor: Res<GridEditor>,
+    locale: Res<Locale>,
+    mut hints: Query<&mut Text, With<GridHint>>,
+) {
+    if !editor.is_changed() && !locale.is_changed() {
+        return;
+    }
+    let maze = locale.text(editor.maze.name());
+    let mut hint = match editor.brush {
+        GridBrush::Walls => crate::tr!(locale, "grid.hint.walls", maze = maze),
+        GridBrush::Weights => {
+            let brush = if editor.weight == WALL {
+                locale.text("grid.brush.wall")
+            } else {
+                editor.weight.to_string()
+            };
+            crate::tr!(locale, "grid.hint.weights", maze = maze, brush = brush)
+        }
+    };
+    match &editor.note {
+        Some(Note::Saved(file)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.saved", file = file))
+        }
+        Some(Note::Loaded(file)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.loaded", file = file))
+        }
+        Some(Note::Failed(error)) => {
+            hint += &format!("\n{}", crate::tr!(locale, "grid.failed", error = error))
+        }
+        None => {}
+    }
+    for mut text in &mut hints {
+        text.sections[0].value.clone_from(&hint);
+    }
+}
@@ -0,0 +1,440 @@
+use bevy::prelude::*;
+
+use crate::controls::{ResetRequested, Settings, VizSet};
+use crate::gallery::{Enter, Gallery, VizScope};
+use crate::locale::Locale;
+use crate::theme::Palette;
+use crate::trace::{Trace, TracePlayback};
+
+/// Seconds the verdict stays up before the viz carries on.
+const REVEAL: f32 = 1.5;
+const PANEL_WIDTH: f32 = 300.0;
+const MARGIN: f32 = 10.0;
+const FONT_SIZE: f32 = 15.0;
+const ANSWER_KEYS: [(KeyCode, KeyCode); 9] = [
+    (KeyCode::Digit1, KeyCode::Numpad1),
+    (KeyCode::Digit2, KeyCode::Numpad2),
+    (KeyCode::Digit3, KeyCode::Numpad3),
+    (KeyCode::Digit4, KeyCode::Numpad4),
+    (KeyCode::Digit5, KeyCode::Numpad5),
+    (KeyCode::Digit6, KeyCode::Numpad6),
+    (KeyCode::Digit7, KeyCode::Numpad7),
+    (KeyCode::Digit8, KeyCode::Numpad8),
+    (KeyCode::Digit9, KeyCode::Numpad9),
+];
+
+/// A prediction the learner makes before the viz shows the decision:
+/// the prompt, the choices in button order and which one is right.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Question {
+    pub prompt: String,
+    pub choices: Vec<String>,
+    pub answer: usize,
+}
+
+impl Question {
+    pub fn new(prompt: String, choices: impl IntoIterator<Item = String>, answer: usize) -> Self {
+        let choices: Vec<String> = choices.into_iter().collect();
+        assert!(
+            answer < choices.len(),
+            "answer {answer} out of {} choices",
+            choices.len()
+        );
+        Self {
+            prompt,
+            choices,
+            answer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+enum Phase {
+    #[default]
+    Idle,
+    Asking(Question),
+    Revealed {
+        question: Question,
+        picked: usize,
+        left: f32,
+    },
+    /// Answered and revealed; the next [`Quiz::hold`] lets the decision through.
+    Released,
+}
+
+/// Answers so far in this round.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuizScore {
+    pub asked: usize,
+    pub correct: usize,
+    pub streak: usize,
+    pub best_streak: usize,
+}
+
+/// Prediction quiz mode, on with `--quiz` and toggled with Q. A viz asks
+/// before each decision it makes and waits while the learner answers:
+///
+/// ```ignore
+/// let (a, b) = (sort.array[j], sort.array[j + 1]);
+/// let swap = || Question::new(tr!(locale, "my-sort.quiz.prompt", a = a, b = b), [yes, no], if a > b { 0 } else { 1 });
+/// if quiz.hold(swap) {
+///     return; // asked, or showing the verdict
+/// }
+/// ```
+///
+/// The learner picks a choice with 1-9 or a click, sees whether it was right
+/// for [`REVEAL`] seconds, and the next `hold` returns false so the step
+/// goes ahead. [`finish`](Self::finish) at the end of the run shows the summary.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct Quiz {
+    pub enabled: bool,
+    phase: Phase,
+    score: QuizScore,
+    finished: bool,
+    // bumped on every change the panel shows, so ticking the reveal doesn't redraw it
+    revision: u64,
+}
+
+impl Quiz {
+    /// Quiz mode as `--quiz` asks.
+    pub fn from_args() -> Self {
+        Self {
+            enabled: crate::headless::run_flags().quiz,
+            ..default()
+        }
+    }
+
+    /// True while the viz should hold its next decision: `question` was just
+    /// asked, or is waiting for an answer, or its verdict is still up.
+    pub fn hold(&mut self, question: impl FnOnce() -> Question) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match self.phase {
+            Phase::Idle => {
+                if self.finished {
+                    // a replayed run is a new round
+                    self.score = QuizScore::default();
+                    self.finished = false;
+                }
+                self.phase = Phase::Asking(question());
+                self.revision += 1;
+                true
+            }
+            Phase::Asking(_) | Phase::Revealed { .. } => true,
+            Phase::Released => {
+                self.phase = Phase::Idle;
+                false
+            }
+        }
+    }
+
+    /// Whether a question or its verdict is up.
+    pub fn waiting(&self) -> bool {
+        matches!(self.phase, Phase::Asking(_) | Phase::Revealed { .. })
+    }
+
+    /// The question waiting for an answer.
+    pub fn question(&self) -> Option<&Question> {
+        match &self.phase {
+            Phase::Asking(question) => Some(question),
+            _ => None,
+        }
+    }
+
+    /// Pick choice `choice` of the open question; anything else is ignored.
+    pub fn answer(&mut self, choice: usize) {
+        let Phase::Asking(question) = &self.phase else {
+            return;
+        };
+        if choice >= question.choices.len() {
+            return;
+        }
+        let score = &mut self.score;
+        score.asked += 1;
+        if choice == question.answer {
+            score.correct += 1;
+            score.streak += 1;
+            score.best_streak = score.best_streak.max(score.streak);
+        } else {
+            score.streak = 0;
+        }
+        self.phase = Phase::Revealed {
+            question: question.clone(),
+            picked: choice,
+            left: REVEAL,
+        };
+        self.revision += 1;
+    }
+
+    /// The run is over: show the round's summary.
+    pub fn finish(&mut self) {
+        if self.enabled && !self.finished {
+            self.finished = true;
+            self.phase = Phase::Idle;
+            self.revision += 1;
+        }
+    }
+
+    pub fn score(&self) -> QuizScore {
+        self.score
+    }
+
+    /// Back to an empty round, keeping whether quiz mode is on.
+    pub fn reset(&mut self) {
+        *self = Self {
+            enabled: self.enabled,
+            revision: self.revision + 1,
+            ..default()
+        };
+    }
+
+    fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.phase = Phase::Idle;
+        self.revision += 1;
+    }
+}
+
+/// Counts down the verdict; added by [`test_app`](crate::test_app) too.
+pub(crate) fn tick_quiz(time: Res<Time>, mut quiz: ResMut<Quiz>) {
+    let Phase::Revealed { left, .. } = &mut quiz.bypass_change_detection().phase else {
+        return;
+    };
+    *left -= time.delta_seconds();
+    if *left <= 0.0 {
+        quiz.phase = Phase::Released;
+        quiz.revision += 1;
+    }
+}
+
+#[derive(Component)]
+struct QuizPanel;
+
+#[derive(Component)]
+struct QuizChoice(usize);
+
+/// Registers quiz mode for the viz in `state`: the [`Quiz`] resource, a
+/// fresh round on entering and on R, the answer keys and the panel in the
+/// bottom-right corner.
+pub(crate) fn add_quiz(app: &mut App, state: Gallery) {
+    let Gallery::Viz(id) = state else { return };
+    if !app.world().contains_resource::<Quiz>() {
+        app.insert_resource(Quiz::from_args());
+    }
+    app.add_systems(
+        OnEnter(state),
+        (
+            (|mut quiz: ResMut<Quiz>| quiz.reset()).in_set(Enter::Reset),
+            spawn_quiz_panel.in_set(Enter::Controls),
+        ),
+    )
+    .add_systems(
+        Update,
+        (
+            (quiz_input, tick_quiz).chain().in_set(VizSet::Controls),
+            draw_quiz_panel.in_set(VizSet::Highlight),
+        )
+            .in_set(VizScope(id)),
+    );
+}
+
+/// Holds a [`Trace<E>`]'s playback before each event `ask` has a question
+/// for. `ask` gets the events not yet applied, the next one first.
+pub(crate) fn add_trace_quiz<E: Send + Sync + 'static>(
+    app: &mut App,
+    state: Gallery,
+    ask: fn(&[E], &Locale) -> Option<Question>,
+) {
+    let Gallery::Viz(id) = state else { return };
+    add_quiz(app, state);
+    let gate = move |trace: Option<Res<Trace<E>>>,
+                     settings: Res<Settings>,
+                     locale: Res<Locale>,
+                     mut quiz: ResMut<Quiz>| {
+        let Some(trace) = trace else { return };
+        if trace.is_done() {
+            quiz.finish();
+        } else if settings.step_ready() {
+            if let Some(question) = ask(trace.upcoming(), &locale) {
+                quiz.hold(|| question);
+            }
+        }
+    };
+    // the trace player's run condition checks `Quiz::waiting` right after this
+    app.add_systems(
+        Update,
+        gate.in_set(VizSet::Step)
+            .in_set(VizScope(id))
+            .before(TracePlayback),
+    );
+}
+
+// Q turns quiz mode on or off, 1-9 or a click on a choice answers, R starts a new round
+fn quiz_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    choices: Query<(&Interaction, &QuizChoice), Changed<Interaction>>,
+    mut reset: EventReader<ResetRequested>,
+    mut quiz: ResMut<Quiz>,
+) {
+    if keys.just_pressed(KeyCode::KeyQ) {
+        quiz.toggle();
+    }
+    if reset.read().count() > 0 {
+        quiz.reset();
+    }
+    if quiz.question().is_none() {
+        return;
+    }
+    let key = ANSWER_KEYS
+        .iter()
+        .position(|&(digit, numpad)| keys.just_pressed(digit) || keys.just_pressed(numpad));
+    let click = choices
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, choice)| choice.0);
+    if let Some(choice) = key.or(click) {
+        quiz.answer(choice);
+    }
+}
+
+fn spawn_quiz_panel(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(MARGIN),
+                right: Val::Px(MARGIN),
+                width: Val::Px(PANEL_WIDTH),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        QuizPanel,
+    ));
+}
+
+// Rebuilds the panel's lines when the quiz or the language changed
+fn draw_quiz_panel(
+    quiz: Res<Quiz>,
+    locale: Res<Locale>,
+    palette: Res<Palette>,
+    mut panel: Query<(Entity, &mut Visibility), With<QuizPanel>>,
+    mut drawn: Local<Option<u64>>,
+    mut commands: Commands,
+) {
+    let Ok((entity, mut visibility)) = panel.get_single_mut() else {
+        return;
+    };
+    if *drawn == Some(quiz.revision) && !locale.is_changed() && !palette.is_changed() {
+        return;
+    }
+    *drawn = Some(quiz.revision);
+    *visibility = if quiz.enabled {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    commands.entity(entity).despawn_descendants();
+    if !quiz.enabled {
+        return;
+    }
+    let plain = Color::srgb(0.9, 0.9, 0.9);
+    let line = |value: String, color: Color| {
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size: FONT_SIZE,
+                color,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            max_width: Val::Px(PANEL_WIDTH - 16.0),
+            ..default()
+        })
+    };
+    let score = quiz.score;
+    let percent = (score.correct * 100).checked_div(score.asked).unwrap_or(0);
+    commands.entity(entity).with_children(|panel| {
+        panel.spawn(line(
+            locale.text("quiz.title"),
+            palette.ink(Color::srgb(1.0, 1.0, 0.8)),
+        ));
+        let question = match &quiz.phase {
+            Phase::Asking(question) => Some((question, None)),
+            Phase::Revealed {
+                question, picked, ..
+            } => Some((question, Some(*picked))),
+            Phase::Idle | Phase::Released => None,
+        };
+        match question {
+            Some((question, picked)) => {
+                panel.spawn(line(question.prompt.clone(), plain));
+                for (i, choice) in question.choices.iter().enumerate() {
+                    // once answered, the right choice turns green and a wrong pick red
+                    let color = match picked {
+                        Some(_) if i == question.answer => palette.settled,
+                        Some(picked) if i == picked => palette.violation,
+                        _ => Color::srgba(1.0, 1.0, 1.0, 0.12),
+                    };
+                    panel
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                                    ..default()
+                                },
+                                background_color: BackgroundColor(color),
+                                ..default()
+                            },
+                            QuizChoice(i),
+                        ))
+                        .with_children(|button| {
+                            button.spawn(line(format!("{}  {choice}", i + 1), plain));
+                        });
+                }
+                if let Some(picked) = picked {
+                    let verdict = if picked == question.answer {
+                        locale.text("quiz.correct")
+                    } else {
+                        crate::tr!(
+                            locale,
+                            "quiz.wrong",
+                            answer = question.choices[question.answer]
+                        )
+                    };
+                    panel.spawn(line(verdict, plain));
+                }
+            }
+            None if quiz.finished => {
+                let summary = crate::tr!(
+                    locale,
+                    "quiz.summary",
+                    correct = score.correct,
+                    asked = score.asked,
+                    percent = percent,
+                    best = score.best_streak
+                );
+                panel.spawn(line(summary, palette.settled));
+            }
+            None => {
+                panel.spawn(line(locale.text("quiz.watch"), plain));
+            }
+        }
+        let tally = crate::tr!(
+            locale,
+            "quiz.score",
+            correct = score.correct,
+            asked = score.asked,
+            streak = score.streak,
+            best = score.best_streak
+        );
+        panel.spawn(line(tally, Color::srgb(0.7, 0.9, 1.0)));
+    });
+}
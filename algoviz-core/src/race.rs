@@ -0,0 +1,28 @@
+use crate::cost::OpCounts;
+
+/// A sort stepped against a plain array, outside the ECS, so several can race
+/// side by side in one window. Each sorting crate's `racer(array)` returns one
+/// built on the same state and step logic its own viz runs, so a lane counts
+/// exactly what the single viz counts.
+///
+/// ```ignore
+/// let mut lane = bevy_bubble_sort::racer(vec![3, 1, 2]);
+/// while !lane.done() {
+///     lane.step();
+/// }
+/// assert_eq!(lane.array(), [1, 2, 3]);
+/// ```
+pub trait Racer: Send + Sync {
+    /// One step of the algorithm, with any swap it decides on applied at once.
+    fn step(&mut self);
+
+    /// True once the array is sorted and further steps do nothing.
+    fn done(&self) -> bool;
+
+    fn array(&self) -> &[usize];
+
+    fn counts(&self) -> OpCounts;
+
+    /// Positions the last step looked at or moved, for highlighting.
+    fn focus(&self) -> Vec<usize>;
+}
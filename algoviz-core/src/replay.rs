@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// How far time moves per frame under `--fixed-step`: 60 frames a second.
+pub const FIXED_STEP: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// The viz's random numbers (generated walls, shuffled samples, search
+/// targets). `--seed S` makes them repeatable; entering a viz starts it over.
+///
+/// ```ignore
+/// fn setup(mut commands: Commands, mut rng: ResMut<VizRng>) {
+///     let cells = random_cells(&mut rng.0);
+/// }
+/// ```
+#[derive(Resource, Clone)]
+pub struct VizRng(pub StdRng);
+
+impl VizRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+
+    /// Seeded by `--seed S`, or from entropy without it.
+    pub fn from_args() -> Self {
+        match crate::headless::run_flags().seed {
+            Some(seed) => Self::seeded(seed),
+            None => Self(StdRng::from_entropy()),
+        }
+    }
+}
+
+/// `--fixed-step`: every frame advances time by [`FIXED_STEP`] instead of the
+/// wall-clock delta, so with `--seed` a run is the same frame for frame.
+/// Added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        if crate::headless::run_flags().fixed_step {
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(FIXED_STEP));
+        }
+    }
+}
@@ -0,0 +1,885 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// A scenario that parsed but does not make sense, pointing at the field
+/// (and list item) at fault so the error can name its line.
+#[derive(Debug, Clone)]
+pub struct Invalid {
+    pub field: &'static str,
+    pub index: Option<usize>,
+    pub message: String,
+}
+
+impl Invalid {
+    pub fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            index: None,
+            message: message.into(),
+        }
+    }
+
+    /// Item `index` of the list `field`.
+    pub fn at(field: &'static str, index: usize, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            index: Some(index),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioError(pub String);
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+/// A data shape that can be loaded from a `.ron` or `.json` file given with
+/// `--scenario path`. The formats are described in algorithms/SCENARIOS.md.
+pub trait Scenario: DeserializeOwned {
+    /// Shape name used in `--help`, e.g. "weighted graph".
+    const SHAPE: &'static str;
+
+    fn validate(&self) -> Result<(), Invalid>;
+
+    /// The `--scenario` file, or `None` to keep the viz's built-in sample.
+    /// Bad arguments or files print the error and exit.
+    fn from_args() -> Option<Self> {
+        Self::from_args_checked(|_| Ok(()))
+    }
+
+    /// Like `from_args`, with extra checks for what this particular viz can show.
+    fn from_args_checked(check: impl Fn(&Self) -> Result<(), Invalid>) -> Option<Self> {
+        let mut path = None;
+        let mut args = crate::headless::viz_args().into_iter();
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--scenario" => match args.next() {
+                    Some(p) => path = Some(p),
+                    None => exit_with(&format!("--scenario needs a value\n\n{}", usage::<Self>())),
+                },
+                "-h" | "--help" => {
+                    println!("{}", usage::<Self>());
+                    std::process::exit(0);
+                }
+                other => exit_with(&format!(
+                    "unknown argument `{other}`\n\n{}",
+                    usage::<Self>()
+                )),
+            }
+        }
+        let path = path?;
+        match Self::load(Path::new(&path), check) {
+            Ok(scenario) => Some(scenario),
+            Err(err) => exit_with(&err.to_string()),
+        }
+    }
+
+    fn load(
+        path: &Path,
+        check: impl Fn(&Self) -> Result<(), Invalid>,
+    ) -> Result<Self, ScenarioError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| ScenarioError(format!("{}: {err}", path.display())))?;
+        let json = path.extension().is_some_and(|ext| ext == "json");
+        Self::parse(&text, &path.display().to_string(), json, check)
+    }
+
+    /// Parse `text` as RON (or JSON) and validate it; errors read `name:line: message`.
+    fn parse(
+        text: &str,
+        name: &str,
+        json: bool,
+        check: impl Fn(&Self) -> Result<(), Invalid>,
+    ) -> Result<Self, ScenarioError> {
+        let scenario: Self = if json {
+            serde_json::from_str(text).map_err(|err| {
+                let message = err.to_string();
+                let message = message
+                    .rsplit_once(" at line ")
+                    .map_or(message.as_str(), |(m, _)| m);
+                ScenarioError(format!("{name}:{}: {message}", err.line()))
+            })?
+        } else {
+            ron::Options::default()
+                .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+                .from_str(text)
+                .map_err(|err| {
+                    ScenarioError(format!("{name}:{}: {}", err.position.line, err.code))
+                })?
+        };
+        scenario
+            .validate()
+            .and_then(|()| check(&scenario))
+            .map_err(|invalid| {
+                let message = match invalid.index {
+                    Some(i) => format!("{}[{i}]: {}", invalid.field, invalid.message),
+                    None => format!("{}: {}", invalid.field, invalid.message),
+                };
+                match locate(text, invalid.field, invalid.index) {
+                    Some(line) => ScenarioError(format!("{name}:{line}: {message}")),
+                    None => ScenarioError(format!("{name}: {message}")),
+                }
+            })?;
+        Ok(scenario)
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(2);
+}
+
+fn usage<S: Scenario>() -> String {
+    format!(
+        "options:\n  --scenario FILE   load a {} from a .ron or .json file (see algorithms/SCENARIOS.md)",
+        S::SHAPE
+    )
+}
+
+/// Line of `field` in the source, or of its `index`-th list item.
+fn locate(text: &str, field: &str, index: Option<usize>) -> Option<usize> {
+    let after_colon = find_key(text, field)?;
+    let Some(index) = index else {
+        return Some(line_of(text, after_colon));
+    };
+
+    let mut depth = 0;
+    let mut count = 0;
+    let mut expect_item = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = text[after_colon..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let at = after_colon + i;
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        if c == '/' && chars.peek().is_some_and(|&(_, n)| n == '/') {
+            for (_, n) in chars.by_ref() {
+                if n == '\n' {
+                    break;
+                }
+            }
+            continue;
+        }
+        if c.is_whitespace() {
+            continue;
+        }
+        if depth == 0 {
+            // The value of `field` has to be a list.
+            if c != '[' && c != '(' {
+                return None;
+            }
+            depth = 1;
+            expect_item = true;
+            continue;
+        }
+        if depth == 1 && expect_item && !matches!(c, ']' | ')' | ',') {
+            if count == index {
+                return Some(line_of(text, at));
+            }
+            count += 1;
+            expect_item = false;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return None;
+                }
+            }
+            ',' if depth == 1 => expect_item = true,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte offset just past `field:` (RON) or `"field":` (JSON), skipping comments
+/// and string values that merely mention it.
+fn find_key(text: &str, field: &str) -> Option<usize> {
+    let mut before = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '"' {
+            // a JSON key is a string followed by a colon; any other string is skipped whole
+            let mut escaped = false;
+            let end = chars.by_ref().find_map(|(j, n)| match n {
+                _ if escaped => {
+                    escaped = false;
+                    None
+                }
+                '\\' => {
+                    escaped = true;
+                    None
+                }
+                '"' => Some(j),
+                _ => None,
+            })?;
+            if &text[i + 1..end] == field {
+                if let Some(after) = after_colon(text, end + 1) {
+                    return Some(after);
+                }
+            }
+        } else if c == '/' && chars.peek().is_some_and(|&(_, n)| n == '/') {
+            chars.by_ref().find(|&(_, n)| n == '\n');
+        } else if text[i..].starts_with(field)
+            && !before.is_some_and(|b: char| b.is_alphanumeric() || b == '_')
+        {
+            if let Some(after) = after_colon(text, i + field.len()) {
+                return Some(after);
+            }
+        }
+        before = Some(c);
+    }
+    None
+}
+
+// just past the `:` that follows `from` after optional whitespace
+fn after_colon(text: &str, from: usize) -> Option<usize> {
+    let colon = text[from..].trim_start().strip_prefix(':')?;
+    Some(text.len() - colon.len())
+}
+
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Nodes placed on screen and weighted edges between them. Node ids are
+/// their index in `nodes`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphScenario {
+    #[serde(default)]
+    pub directed: bool,
+    /// Start node for single-source algorithms.
+    #[serde(default)]
+    pub source: usize,
+    /// `(x, y)` screen positions, origin at the window centre.
+    pub nodes: Vec<(f32, f32)>,
+    /// `(from, to, weight)`.
+    pub edges: Vec<(usize, usize, i32)>,
+}
+
+impl GraphScenario {
+    pub fn positions(&self) -> Vec<Vec2> {
+        self.nodes.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    /// `(to, weight)` lists per node; undirected edges appear both ways.
+    pub fn adjacency(&self) -> Vec<Vec<(usize, i32)>> {
+        let mut adj = vec![Vec::new(); self.nodes.len()];
+        for &(from, to, weight) in &self.edges {
+            adj[from].push((to, weight));
+            if !self.directed {
+                adj[to].push((from, weight));
+            }
+        }
+        adj
+    }
+
+    pub fn undirected(&self) -> Result<(), Invalid> {
+        if self.directed {
+            return Err(Invalid::new(
+                "directed",
+                "this visualization needs an undirected graph",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn directed_edges(&self) -> Result<(), Invalid> {
+        if !self.directed {
+            return Err(Invalid::new(
+                "directed",
+                "this visualization needs a directed graph",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn nonnegative_weights(&self) -> Result<(), Invalid> {
+        match self.edges.iter().position(|&(_, _, w)| w < 0) {
+            Some(i) => Err(Invalid::at(
+                "edges",
+                i,
+                "negative weights are not supported here",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Scenario for GraphScenario {
+    const SHAPE: &'static str = "weighted graph";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        let n = self.nodes.len();
+        if n == 0 {
+            return Err(Invalid::new("nodes", "the graph needs at least one node"));
+        }
+        if self.source >= n {
+            return Err(Invalid::new(
+                "source",
+                format!("node {} does not exist ({n} nodes)", self.source),
+            ));
+        }
+        for (i, &(from, to, _)) in self.edges.iter().enumerate() {
+            if let Some(bad) = [from, to].into_iter().find(|&v| v >= n) {
+                return Err(Invalid::at(
+                    "edges",
+                    i,
+                    format!("node {bad} does not exist ({n} nodes)"),
+                ));
+            }
+            if from == to {
+                return Err(Invalid::at("edges", i, format!("self-loop on node {from}")));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A grid drawn as strings, one per row: `.` open, `#` wall, `1`-`9` the
+/// cost of entering a cell, `S` start and `G` goal. Spaces are ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GridScenario {
+    pub rows: Vec<String>,
+}
+
+impl GridScenario {
+    pub fn cells(&self) -> Vec<Vec<char>> {
+        self.rows
+            .iter()
+            .map(|row| row.chars().filter(|c| !c.is_whitespace()).collect())
+            .collect()
+    }
+
+    /// `true` for walls.
+    pub fn walls(&self) -> Vec<Vec<bool>> {
+        self.cells()
+            .iter()
+            .map(|row| row.iter().map(|&c| c == '#').collect())
+            .collect()
+    }
+
+    /// Cost of entering each cell; open, start and goal cells cost 1.
+    pub fn costs(&self) -> Vec<Vec<usize>> {
+        self.cells()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&c| c.to_digit(10).map_or(1, |d| d as usize))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// `S` if present, else the top-left cell.
+    pub fn start(&self) -> (usize, usize) {
+        self.find('S').unwrap_or((0, 0))
+    }
+
+    /// `G` if present, else the bottom-right cell.
+    pub fn goal(&self) -> (usize, usize) {
+        let cells = self.cells();
+        self.find('G')
+            .unwrap_or((cells.len() - 1, cells[0].len() - 1))
+    }
+
+    fn find(&self, target: char) -> Option<(usize, usize)> {
+        self.cells()
+            .iter()
+            .enumerate()
+            .find_map(|(r, row)| row.iter().position(|&c| c == target).map(|c| (r, c)))
+    }
+
+    pub fn no_walls(&self) -> Result<(), Invalid> {
+        match self.cells().iter().position(|row| row.contains(&'#')) {
+            Some(r) => Err(Invalid::at(
+                "rows",
+                r,
+                "this visualization has no walls; use cell costs instead",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Start and goal must not be walls.
+    pub fn open_endpoints(&self) -> Result<(), Invalid> {
+        self.open("start", self.start())?;
+        self.open("goal", self.goal())
+    }
+
+    /// The start must not be a wall; for traversals that have no goal.
+    pub fn open_start(&self) -> Result<(), Invalid> {
+        self.open("start", self.start())
+    }
+
+    fn open(&self, name: &str, (r, c): (usize, usize)) -> Result<(), Invalid> {
+        if self.walls()[r][c] {
+            return Err(Invalid::at(
+                "rows",
+                r,
+                format!("the {name} cell (column {}) is a wall", c + 1),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Scenario for GridScenario {
+    const SHAPE: &'static str = "grid";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        let cells = self.cells();
+        let Some(width) = cells.first().map(Vec::len).filter(|&w| w > 0) else {
+            return Err(Invalid::new("rows", "the grid needs at least one cell"));
+        };
+        let mut seen = HashSet::new();
+        for (r, row) in cells.iter().enumerate() {
+            if row.len() != width {
+                return Err(Invalid::at(
+                    "rows",
+                    r,
+                    format!("row has {} cells, the first row has {width}", row.len()),
+                ));
+            }
+            for (c, &cell) in row.iter().enumerate() {
+                if !matches!(cell, '.' | '#' | 'S' | 'G' | '1'..='9') {
+                    return Err(Invalid::at(
+                        "rows",
+                        r,
+                        format!("unknown cell `{cell}` in column {}", c + 1),
+                    ));
+                }
+                if matches!(cell, 'S' | 'G') && !seen.insert(cell) {
+                    return Err(Invalid::at(
+                        "rows",
+                        r,
+                        format!("second `{cell}` in column {}", c + 1),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A binary tree given as its root and each node's children. Children that
+/// are not listed themselves are leaves.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TreeScenario {
+    pub root: i32,
+    pub nodes: Vec<TreeNodeSpec>,
+    /// Values the viz asks about, e.g. the two nodes whose LCA is shown.
+    #[serde(default)]
+    pub queries: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TreeNodeSpec {
+    pub value: i32,
+    pub left: Option<i32>,
+    pub right: Option<i32>,
+}
+
+impl TreeScenario {
+    /// value -> (left, right), leaves included.
+    pub fn to_map(&self) -> HashMap<i32, (Option<i32>, Option<i32>)> {
+        let mut tree: HashMap<_, _> = self
+            .nodes
+            .iter()
+            .map(|n| (n.value, (n.left, n.right)))
+            .collect();
+        for node in &self.nodes {
+            for child in [node.left, node.right].into_iter().flatten() {
+                tree.entry(child).or_insert((None, None));
+            }
+        }
+        tree
+    }
+
+    /// Exactly `count` queries.
+    pub fn queries(&self, count: usize) -> Result<(), Invalid> {
+        if self.queries.len() != count {
+            return Err(Invalid::new(
+                "queries",
+                format!("expected {count} values, got {}", self.queries.len()),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Every left subtree holds smaller values and every right subtree larger ones.
+    pub fn search_tree(&self) -> Result<(), Invalid> {
+        let tree = self.to_map();
+        let mut stack = vec![(self.root, i64::MIN, i64::MAX)];
+        while let Some((value, low, high)) = stack.pop() {
+            if !(low < value as i64 && (value as i64) < high) {
+                let i = self
+                    .nodes
+                    .iter()
+                    .position(|n| n.left == Some(value) || n.right == Some(value));
+                let message = format!("{value} breaks the search tree order");
+                return Err(match i {
+                    Some(i) => Invalid::at("nodes", i, message),
+                    None => Invalid::new("root", message),
+                });
+            }
+            let (left, right) = tree[&value];
+            stack.extend(left.map(|l| (l, low, value as i64)));
+            stack.extend(right.map(|r| (r, value as i64, high)));
+        }
+        Ok(())
+    }
+}
+
+impl Scenario for TreeScenario {
+    const SHAPE: &'static str = "binary tree";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        if !self.nodes.iter().any(|n| n.value == self.root) {
+            return Err(Invalid::new(
+                "root",
+                format!("{} is not one of the listed nodes", self.root),
+            ));
+        }
+        let mut parent = HashMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            if self.nodes[..i].iter().any(|n| n.value == node.value) {
+                return Err(Invalid::at(
+                    "nodes",
+                    i,
+                    format!("{} is listed twice", node.value),
+                ));
+            }
+            if node.left.is_some() && node.left == node.right {
+                return Err(Invalid::at(
+                    "nodes",
+                    i,
+                    "left and right child are the same node",
+                ));
+            }
+            for child in [node.left, node.right].into_iter().flatten() {
+                if child == self.root {
+                    return Err(Invalid::at(
+                        "nodes",
+                        i,
+                        format!("{child} is the root and cannot be a child"),
+                    ));
+                }
+                if let Some(p) = parent.insert(child, node.value) {
+                    return Err(Invalid::at(
+                        "nodes",
+                        i,
+                        format!("{child} already has parent {p}"),
+                    ));
+                }
+            }
+        }
+        let tree = self.to_map();
+        let mut reached = HashSet::new();
+        let mut stack = vec![self.root];
+        while let Some(value) = stack.pop() {
+            if reached.insert(value) {
+                let (left, right) = tree[&value];
+                stack.extend(left.into_iter().chain(right));
+            }
+        }
+        if let Some(i) = self.nodes.iter().position(|n| !reached.contains(&n.value)) {
+            return Err(Invalid::at(
+                "nodes",
+                i,
+                format!(
+                    "{} is not connected to root {}",
+                    self.nodes[i].value, self.root
+                ),
+            ));
+        }
+        if let Some(i) = self.queries.iter().position(|q| !tree.contains_key(q)) {
+            return Err(Invalid::at(
+                "queries",
+                i,
+                format!("{} is not in the tree", self.queries[i]),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A square board of digits, one string per row: `1`-`9` givens and `.` or
+/// `0` blanks. Spaces are ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PuzzleScenario {
+    pub rows: Vec<String>,
+}
+
+impl PuzzleScenario {
+    /// Row-major digits, 0 for blanks.
+    pub fn cells(&self) -> Vec<u8> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.chars().filter(|c| !c.is_whitespace()))
+            .map(|c| c.to_digit(10).unwrap_or(0) as u8)
+            .collect()
+    }
+
+    pub fn size(&self, n: usize) -> Result<(), Invalid> {
+        if self.rows.len() != n {
+            return Err(Invalid::new(
+                "rows",
+                format!("expected {n} rows, got {}", self.rows.len()),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Scenario for PuzzleScenario {
+    const SHAPE: &'static str = "puzzle";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        let n = self.rows.len();
+        if n == 0 {
+            return Err(Invalid::new("rows", "the board needs at least one row"));
+        }
+        for (r, row) in self.rows.iter().enumerate() {
+            let cells: Vec<char> = row.chars().filter(|c| !c.is_whitespace()).collect();
+            if cells.len() != n {
+                return Err(Invalid::at(
+                    "rows",
+                    r,
+                    format!("row has {} cells, a {n}-row board needs {n}", cells.len()),
+                ));
+            }
+            if let Some(c) = cells
+                .iter()
+                .position(|&c| !(c == '.' || c.is_ascii_digit()))
+            {
+                return Err(Invalid::at(
+                    "rows",
+                    r,
+                    format!("unknown cell `{}` in column {}", cells[c], c + 1),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A board of letters, one string per row, and the words to look for in it.
+/// Spaces and case are ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WordGridScenario {
+    pub rows: Vec<String>,
+    pub words: Vec<String>,
+}
+
+impl WordGridScenario {
+    /// Row-major lowercase letters.
+    pub fn cells(&self) -> Vec<Vec<char>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .filter(|c| !c.is_whitespace())
+                    .map(|c| c.to_ascii_lowercase())
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn lowercase_words(&self) -> Vec<String> {
+        self.words.iter().map(|w| w.to_ascii_lowercase()).collect()
+    }
+}
+
+impl Scenario for WordGridScenario {
+    const SHAPE: &'static str = "word grid";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        let cells = self.cells();
+        let Some(width) = cells.first().map(Vec::len).filter(|&w| w > 0) else {
+            return Err(Invalid::new("rows", "the board needs at least one letter"));
+        };
+        for (r, row) in cells.iter().enumerate() {
+            if row.len() != width {
+                return Err(Invalid::at(
+                    "rows",
+                    r,
+                    format!("row has {} letters, the first row has {width}", row.len()),
+                ));
+            }
+            if let Some(c) = row.iter().position(|c| !c.is_ascii_alphabetic()) {
+                return Err(Invalid::at(
+                    "rows",
+                    r,
+                    format!("`{}` in column {} is not a letter", row[c], c + 1),
+                ));
+            }
+        }
+        if self.words.is_empty() {
+            return Err(Invalid::new("words", "the list needs at least one word"));
+        }
+        if let Some(i) = self
+            .words
+            .iter()
+            .position(|w| w.is_empty() || !w.chars().all(|c| c.is_ascii_alphabetic()))
+        {
+            return Err(Invalid::at("words", i, "words are made of letters only"));
+        }
+        Ok(())
+    }
+}
+
+/// Closed `(start, end)` intervals, plus one to insert for the vizs that
+/// insert.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IntervalScenario {
+    pub intervals: Vec<(i32, i32)>,
+    pub insert: Option<(i32, i32)>,
+}
+
+impl IntervalScenario {
+    pub fn with_insert(&self) -> Result<(), Invalid> {
+        if self.insert.is_none() {
+            return Err(Invalid::new(
+                "intervals",
+                "this visualization needs an `insert` interval",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn nonnegative(&self) -> Result<(), Invalid> {
+        match self.intervals.iter().position(|&(start, _)| start < 0) {
+            Some(i) => Err(Invalid::at("intervals", i, "times start at 0 here")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Scenario for IntervalScenario {
+    const SHAPE: &'static str = "interval list";
+
+    fn validate(&self) -> Result<(), Invalid> {
+        if self.intervals.is_empty() {
+            return Err(Invalid::new(
+                "intervals",
+                "the list needs at least one interval",
+            ));
+        }
+        if let Some(i) = self.intervals.iter().position(|&(start, end)| start > end) {
+            let (start, end) = self.intervals[i];
+            return Err(Invalid::at(
+                "intervals",
+                i,
+                format!("start {start} is after end {end}"),
+            ));
+        }
+        if let Some((start, end)) = self.insert.filter(|&(start, end)| start > end) {
+            return Err(Invalid::new(
+                "insert",
+                format!("start {start} is after end {end}"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str, json: bool) -> String {
+        GraphScenario::parse(text, "s", json, |_| Ok(()))
+            .unwrap_err()
+            .0
+    }
+
+    #[test]
+    fn ron_error_names_the_field_line() {
+        let text = "(\n    source: 5,\n    nodes: [(0, 0), (50, 0)],\n    edges: [],\n)\n";
+        assert_eq!(
+            error(text, false),
+            "s:2: source: node 5 does not exist (2 nodes)"
+        );
+    }
+
+    #[test]
+    fn json_error_names_the_field_line() {
+        let text = "{\n  \"nodes\": [[0, 0], [50, 0]],\n  \"edges\": [],\n  \"source\": 2\n}\n";
+        assert_eq!(
+            error(text, true),
+            "s:4: source: node 2 does not exist (2 nodes)"
+        );
+    }
+
+    #[test]
+    fn bad_list_item_names_its_own_line() {
+        let ron = "(\n    nodes: [(0, 0), (50, 0)],\n    edges: [\n        (0, 1, 3),\n        (1, 1, 2),\n    ],\n)\n";
+        assert_eq!(error(ron, false), "s:5: edges[1]: self-loop on node 1");
+        let json = "{\n  \"nodes\": [[0, 0], [50, 0]],\n  \"edges\": [\n    [0, 1, 3], [1, 0, 1],\n    [0, 7, 1]\n  ]\n}\n";
+        assert_eq!(
+            error(json, true),
+            "s:5: edges[2]: node 7 does not exist (2 nodes)"
+        );
+    }
+
+    #[test]
+    fn comments_and_strings_are_not_keys() {
+        let text = "// edges: listed below\n(\n    nodes: [(0, 0), (50, 0)],\n    edges: [\n        (0, 9, 1),\n    ],\n)\n";
+        assert_eq!(
+            error(text, false),
+            "s:5: edges[0]: node 9 does not exist (2 nodes)"
+        );
+        // past the last colon, the real key's
+        for text in [
+            "(name: \"source: none\", source: 3)",
+            "{\"note\": \"source\", \"source\": 3}",
+        ] {
+            assert_eq!(
+                find_key(text, "source"),
+                Some(text.rfind(':').unwrap() + 1),
+                "{text}"
+            );
+        }
+        assert_eq!(find_key("(resource: 1)", "source"), None);
+    }
+
+    #[test]
+    fn word_grid_takes_letters_only() {
+        let ok = "(rows: [\"oA an\", \"et ae\"], words: [\"Oath\"])";
+        let scenario = WordGridScenario::parse(ok, "s", false, |_| Ok(())).unwrap();
+        assert_eq!(scenario.cells()[0], vec!['o', 'a', 'a', 'n']);
+        assert_eq!(scenario.lowercase_words(), vec!["oath"]);
+
+        let digit = "(\n    rows: [\"oaan\", \"et#e\"],\n    words: [\"eat\"],\n)\n";
+        assert_eq!(
+            WordGridScenario::parse(digit, "s", false, |_| Ok(()))
+                .unwrap_err()
+                .0,
+            "s:2: rows[1]: `#` in column 3 is not a letter"
+        );
+    }
+}
@@ -0,0 +1,486 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::transform::TransformSystem;
+
+use crate::theme::Palette;
+
+/// Text drawn as sixteen-segment glyphs built from sprites, so labels need no
+/// font asset. Shaped like Bevy's `Text`: editing `sections[i].value` or
+/// `sections[i].style.color` redraws the label on the same frame.
+///
+/// Put it in a [`SegmentText2dBundle`], or add it straight to a sprite to
+/// write on top of it.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct SegmentText {
+    pub sections: Vec<SegmentSection>,
+}
+
+impl SegmentText {
+    pub fn from_section(value: impl Into<String>, style: SegmentStyle) -> Self {
+        Self {
+            sections: vec![SegmentSection::new(value, style)],
+        }
+    }
+
+    pub fn from_sections(sections: impl IntoIterator<Item = SegmentSection>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+        }
+    }
+}
+
+/// A run of [`SegmentText`] in one size and color; `\n` starts a new line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SegmentSection {
+    pub value: String,
+    pub style: SegmentStyle,
+}
+
+impl SegmentSection {
+    pub fn new(value: impl Into<String>, style: SegmentStyle) -> Self {
+        Self {
+            value: value.into(),
+            style,
+        }
+    }
+}
+
+/// Size and color of a [`SegmentSection`]. `font_size` matches a Bevy font
+/// size: glyphs are about as tall as its capitals and lines as far apart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SegmentStyle {
+    pub font_size: f32,
+    pub color: Color,
+}
+
+impl Default for SegmentStyle {
+    fn default() -> Self {
+        Self {
+            font_size: 24.0,
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// The `Text2dBundle` of [`SegmentText`]: `text_anchor` says which point of
+/// the text block sits on the transform.
+#[derive(Bundle, Clone, Default)]
+pub struct SegmentText2dBundle {
+    pub text: SegmentText,
+    pub text_anchor: Anchor,
+    pub transform: Transform,
+    pub global_transform: GlobalTransform,
+    pub visibility: Visibility,
+    pub inherited_visibility: InheritedVisibility,
+    pub view_visibility: ViewVisibility,
+}
+
+/// Child holding the segment sprites of a label, with what they show.
+#[derive(Component)]
+struct SegmentGlyphs(SegmentText, Vec2);
+
+/// Redraws [`SegmentText`] labels; added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct SegmentTextPlugin;
+
+impl Plugin for SegmentTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (despawn_orphaned_glyphs, draw_segment_text)
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+// Segments of a 16-segment cell, top row first:
+//
+//    a   b
+//   h k m n c
+//    u   p
+//   g t s r d
+//    f   e
+//
+// plus dots: y above and z below the middle (colons), x on the baseline
+// (periods) and v, a comma tail under it.
+fn segments(ch: char) -> &'static str {
+    match ch.to_ascii_uppercase() {
+        '0' => "abcdefghnt",
+        '1' => "cdn",
+        '2' => "abcpufe",
+        '3' => "abcdefp",
+        '4' => "hupcd",
+        '5' => "abhupdef",
+        '6' => "abhgfedpu",
+        '7' => "abcd",
+        '8' => "abcdefghup",
+        '9' => "abcdefhup",
+        'A' => "ghabcdup",
+        'B' => "abcdefmsp",
+        'C' => "abhgfe",
+        'D' => "abcdefms",
+        'E' => "abhgfeu",
+        'F' => "abhgu",
+        'G' => "abhgfedp",
+        'H' => "hgcdup",
+        'I' => "abmsef",
+        'J' => "cdefg",
+        'K' => "hgunr",
+        'L' => "hgfe",
+        'M' => "ghcdkn",
+        'N' => "ghcdkr",
+        'O' => "abcdefgh",
+        'P' => "abchgup",
+        'Q' => "abcdefghr",
+        'R' => "abchgupr",
+        'S' => "abhupdef",
+        'T' => "abms",
+        'U' => "hgfedc",
+        'V' => "hgtn",
+        'W' => "hgcdtr",
+        'X' => "kntr",
+        'Y' => "kns",
+        'Z' => "abntfe",
+        '-' => "up",
+        '+' => "upms",
+        '*' => "kmnuprst",
+        '/' => "nt",
+        '\\' => "kr",
+        '=' => "upfe",
+        '<' => "nr",
+        '>' => "kt",
+        '(' => "bmse",
+        ')' => "amsf",
+        '[' => "ahgf",
+        ']' => "bcde",
+        '{' => "bmseu",
+        '}' => "amsfp",
+        '_' => "fe",
+        '|' => "ms",
+        '\'' => "m",
+        '"' => "hm",
+        '^' => "tr",
+        '#' => "cdmsupef",
+        '%' => "ahntde",
+        '&' => "akrugfe",
+        '$' => "abhupdefms",
+        '@' => "abcpmhgfe",
+        '!' => "mx",
+        '?' => "abcpx",
+        '.' => "x",
+        ',' => "xv",
+        ':' => "yz",
+        ';' => "yzv",
+        '°' => "ahmu",
+        _ => "",
+    }
+}
+
+/// Characters outside the glyph set that read fine spelled out.
+fn spelled(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        '→' | '➜' => "->",
+        '←' => "<-",
+        '≤' => "<=",
+        '≥' => ">=",
+        '≠' => "!=",
+        '∞' => "INF",
+        '×' => "X",
+        '–' | '—' => "-",
+        '⌀' => "0",
+        '□' => "O",
+        // accented Latin letters drop the accent; inverted marks are left out
+        'á' | 'Á' | 'à' | 'À' | 'â' | 'Â' | 'ä' | 'Ä' => "A",
+        'é' | 'É' | 'è' | 'È' | 'ê' | 'Ê' | 'ë' | 'Ë' => "E",
+        'í' | 'Í' | 'ì' | 'Ì' | 'î' | 'Î' | 'ï' | 'Ï' => "I",
+        'ó' | 'Ó' | 'ò' | 'Ò' | 'ô' | 'Ô' | 'ö' | 'Ö' => "O",
+        'ú' | 'Ú' | 'ù' | 'Ù' | 'û' | 'Û' | 'ü' | 'Ü' => "U",
+        'ñ' | 'Ñ' => "N",
+        'ç' | 'Ç' => "C",
+        '¿' | '¡' => "",
+        _ => return None,
+    })
+}
+
+/// Glyph box for a font size: capital height, width, stroke and advance.
+fn metrics(font_size: f32) -> (Vec2, f32, f32) {
+    let size = Vec2::new(font_size * 0.38, font_size * 0.62);
+    (size, (font_size * 0.075).max(1.2), font_size * 0.52)
+}
+
+/// One drawn character: its center, size, stroke, segments and color.
+struct Glyph {
+    center: Vec2,
+    size: Vec2,
+    stroke: f32,
+    segments: &'static str,
+    color: Color,
+}
+
+/// Lays `text` out as left-justified lines and places the block by `anchor`
+/// (Bevy's `-0.5..0.5` anchor coordinates).
+fn layout(text: &SegmentText, anchor: Vec2) -> Vec<Glyph> {
+    let mut lines: Vec<Vec<(char, SegmentStyle)>> = vec![Vec::new()];
+    for section in &text.sections {
+        for ch in section.value.chars() {
+            match (ch, spelled(ch)) {
+                ('\n', _) => lines.push(Vec::new()),
+                (_, Some(spelling)) => lines
+                    .last_mut()
+                    .unwrap()
+                    .extend(spelling.chars().map(|c| (c, section.style))),
+                _ => lines.last_mut().unwrap().push((ch, section.style)),
+            }
+        }
+    }
+    let fallback = text
+        .sections
+        .last()
+        .map_or(SegmentStyle::default().font_size, |section| {
+            section.style.font_size
+        });
+    let line_height = |line: &[(char, SegmentStyle)]| {
+        line.iter()
+            .map(|(_, style)| style.font_size)
+            .fold(0.0, f32::max)
+            .max(fallback * 0.5)
+            * 1.2
+    };
+    let line_width = |line: &[(char, SegmentStyle)]| {
+        line.iter()
+            .map(|(_, style)| metrics(style.font_size).2)
+            .sum::<f32>()
+    };
+
+    let width = lines
+        .iter()
+        .map(|line| line_width(line))
+        .fold(0.0, f32::max);
+    let height: f32 = lines.iter().map(|line| line_height(line)).sum();
+    let left = -(anchor.x + 0.5) * width;
+    let mut top = (0.5 - anchor.y) * height;
+
+    let mut glyphs = Vec::new();
+    for line in &lines {
+        let line_height = line_height(line);
+        let baseline = top - line_height * 0.5;
+        let mut x = left;
+        for &(ch, style) in line {
+            let (size, stroke, advance) = metrics(style.font_size);
+            let segments = segments(ch);
+            if !segments.is_empty() {
+                glyphs.push(Glyph {
+                    center: Vec2::new(x + advance / 2.0, baseline),
+                    size,
+                    stroke,
+                    segments,
+                    color: style.color,
+                });
+            }
+            x += advance;
+        }
+        top -= line_height;
+    }
+    glyphs
+}
+
+/// Spawns the sprites of one glyph.
+fn spawn_glyph(parent: &mut ChildBuilder, glyph: &Glyph) {
+    let Glyph {
+        center,
+        size,
+        stroke: t,
+        color,
+        ..
+    } = *glyph;
+    let (w, h) = (size.x / 2.0, size.y / 2.0);
+    let bar = |from: Vec2, to: Vec2| {
+        let along = to - from;
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::new(along.length() + t, t)),
+                ..default()
+            },
+            transform: Transform::from_translation((center + (from + to) / 2.0).extend(0.0))
+                .with_rotation(Quat::from_rotation_z(along.y.atan2(along.x))),
+            ..default()
+        }
+    };
+    let dot = |at: Vec2| SpriteBundle {
+        sprite: Sprite {
+            color,
+            custom_size: Some(Vec2::splat(t * 1.5)),
+            ..default()
+        },
+        transform: Transform::from_translation((center + at).extend(0.0)),
+        ..default()
+    };
+
+    // stroke centers of the cell's corners, edge midpoints and middle
+    let (l, r, top, bottom) = (-w + t / 2.0, w - t / 2.0, h - t / 2.0, -h + t / 2.0);
+    for segment in glyph.segments.chars() {
+        let sprite = match segment {
+            'a' => bar(Vec2::new(l, top), Vec2::new(0.0, top)),
+            'b' => bar(Vec2::new(0.0, top), Vec2::new(r, top)),
+            'c' => bar(Vec2::new(r, top), Vec2::new(r, 0.0)),
+            'd' => bar(Vec2::new(r, 0.0), Vec2::new(r, bottom)),
+            'e' => bar(Vec2::new(0.0, bottom), Vec2::new(r, bottom)),
+            'f' => bar(Vec2::new(l, bottom), Vec2::new(0.0, bottom)),
+            'g' => bar(Vec2::new(l, 0.0), Vec2::new(l, bottom)),
+            'h' => bar(Vec2::new(l, top), Vec2::new(l, 0.0)),
+            'k' => bar(Vec2::new(l + t, top - t), Vec2::ZERO),
+            'm' => bar(Vec2::new(0.0, top), Vec2::ZERO),
+            'n' => bar(Vec2::new(r - t, top - t), Vec2::ZERO),
+            'u' => bar(Vec2::new(l, 0.0), Vec2::ZERO),
+            'p' => bar(Vec2::ZERO, Vec2::new(r, 0.0)),
+            'r' => bar(Vec2::ZERO, Vec2::new(r - t, bottom + t)),
+            's' => bar(Vec2::ZERO, Vec2::new(0.0, bottom)),
+            't' => bar(Vec2::ZERO, Vec2::new(l + t, bottom + t)),
+            'x' => dot(Vec2::new(0.0, bottom)),
+            'y' => dot(Vec2::new(0.0, h * 0.4)),
+            'z' => dot(Vec2::new(0.0, -h * 0.4)),
+            'v' => bar(Vec2::new(0.0, bottom), Vec2::new(-t, bottom - 2.0 * t)),
+            _ => continue,
+        };
+        parent.spawn(sprite);
+    }
+}
+
+fn draw_segment_text(
+    mut commands: Commands,
+    labels: Query<(
+        Entity,
+        Ref<SegmentText>,
+        Option<Ref<Anchor>>,
+        Option<&Children>,
+    )>,
+    drawn: Query<&SegmentGlyphs>,
+    palette: Option<Res<Palette>>,
+) {
+    // a new theme re-inks every label
+    let restyled = palette.as_ref().is_some_and(|palette| palette.is_changed());
+    for (entity, text, anchor, children) in &labels {
+        if !restyled
+            && !text.is_changed()
+            && !anchor.as_ref().is_some_and(|anchor| anchor.is_changed())
+        {
+            continue;
+        }
+        let anchor = anchor.map_or(Vec2::ZERO, |anchor| anchor.as_vec());
+        let mut old = children
+            .into_iter()
+            .flatten()
+            .filter(|&&child| drawn.contains(child));
+        if let Some(&child) = old.next() {
+            if !restyled
+                && drawn
+                    .get(child)
+                    .is_ok_and(|glyphs| glyphs.0 == *text && glyphs.1 == anchor)
+            {
+                continue;
+            }
+            commands.entity(child).despawn_recursive();
+        }
+        let mut glyphs = layout(&text, anchor);
+        if let Some(palette) = &palette {
+            glyphs
+                .iter_mut()
+                .for_each(|glyph| glyph.color = palette.ink(glyph.color));
+        }
+        commands.entity(entity).with_children(|parent| {
+            parent
+                .spawn((
+                    SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.0, 0.01)),
+                    SegmentGlyphs((*text).clone(), anchor),
+                ))
+                .with_children(|cell| glyphs.iter().for_each(|glyph| spawn_glyph(cell, glyph)));
+        });
+    }
+}
+
+// a label despawned without its children would leave its glyphs behind
+fn despawn_orphaned_glyphs(
+    mut commands: Commands,
+    glyphs: Query<(Entity, &Parent), With<SegmentGlyphs>>,
+    labels: Query<(), With<SegmentText>>,
+) {
+    for (entity, parent) in &glyphs {
+        if !labels.contains(parent.get()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DRAWN: &str = "abcdefghkmnuprstxyzv";
+
+    #[test]
+    fn letters_ignore_case() {
+        for (lower, upper) in ('a'..='z').zip('A'..='Z') {
+            assert_eq!(segments(lower), segments(upper), "{lower}");
+            assert!(!segments(upper).is_empty(), "{upper}");
+        }
+        assert_eq!(segments('1'), "cdn");
+        assert_eq!(segments(':'), "yz");
+    }
+
+    #[test]
+    fn table_uses_only_drawn_segments() {
+        for ch in (' '..='~').chain(['°']) {
+            let segments = segments(ch);
+            assert!(
+                segments.chars().all(|segment| DRAWN.contains(segment)),
+                "{ch:?}: {segments}"
+            );
+            let mut sorted: Vec<_> = segments.chars().collect();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), segments.len(), "{ch:?} repeats a segment");
+        }
+    }
+
+    #[test]
+    fn printable_ascii_has_glyphs() {
+        let missing: String = ('!'..='~').filter(|&ch| segments(ch).is_empty()).collect();
+        assert_eq!(missing, "`~");
+        assert_eq!(segments(' '), "");
+        assert_eq!(segments('λ'), "");
+    }
+
+    #[test]
+    fn spellings_are_drawable() {
+        for ch in "→➜←≤≥≠∞×–—⌀□áÉüÑç".chars() {
+            let spelling = spelled(ch).unwrap();
+            assert!(
+                !spelling.is_empty() && spelling.chars().all(|c| !segments(c).is_empty()),
+                "{ch}: {spelling}"
+            );
+        }
+        assert_eq!(spelled('¿'), Some(""));
+        assert_eq!(spelled('a'), None);
+    }
+
+    #[test]
+    fn layout_skips_blanks_and_breaks_lines() {
+        let style = SegmentStyle::default();
+        let glyphs = layout(
+            &SegmentText::from_section("a b\n≤", style),
+            Vec2::new(-0.5, 0.5),
+        );
+        let shown: Vec<_> = glyphs.iter().map(|glyph| glyph.segments).collect();
+        assert_eq!(
+            shown,
+            [segments('A'), segments('B'), segments('<'), segments('=')]
+        );
+        let advance = metrics(style.font_size).2;
+        // the space still takes its place; the spelled-out ≤ starts the second line
+        assert_eq!(glyphs[1].center.x - glyphs[0].center.x, 2.0 * advance);
+        assert_eq!(glyphs[2].center.x, glyphs[0].center.x);
+        assert!(glyphs[2].center.y < glyphs[0].center.y);
+        assert_eq!(glyphs[0].center.x, advance / 2.0);
+    }
+}
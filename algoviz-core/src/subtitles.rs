@@ -0,0 +1,300 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+
+use crate::gallery::Gallery;
+use crate::segments::SegmentText;
+
+/// The text that narrates the run, such as a sort's step explanation. Under
+/// `--subtitles DIR` every change to it becomes a subtitle cue.
+///
+/// ```ignore
+/// commands.spawn((TextBundle::from_section(..), StepExplanation, Narration));
+/// ```
+#[derive(Component, Default)]
+pub struct Narration;
+
+/// `--subtitles DIR`: records when the [`Narration`] changes and keeps
+/// `<viz>.srt` and `<viz>.vtt` in `DIR` up to date. Cue times are frame time
+/// since the viz came up, so with `--seed` and `--fixed-step` they match a
+/// recording of the same run. Added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct SubtitlePlugin;
+
+impl Plugin for SubtitlePlugin {
+    fn build(&self, app: &mut App) {
+        let Some(dir) = crate::headless::run_flags().subtitles.map(PathBuf::from) else {
+            return;
+        };
+        if let Err(err) = std::fs::create_dir_all(&dir) {
+            eprintln!("error: --subtitles {}: {err}", dir.display());
+            std::process::exit(2);
+        }
+        app.insert_resource(SubtitleTrack {
+            dir,
+            viz: None,
+            start: Duration::ZERO,
+            cues: Vec::new(),
+        })
+        .add_systems(PostUpdate, record_narration)
+        .add_systems(Last, write_on_exit);
+    }
+}
+
+struct Cue {
+    start: Duration,
+    end: Option<Duration>,
+    text: String,
+}
+
+#[derive(Resource)]
+struct SubtitleTrack {
+    dir: PathBuf,
+    viz: Option<&'static str>,
+    /// Frame time when the viz came up; cues count from here.
+    start: Duration,
+    cues: Vec<Cue>,
+}
+
+impl SubtitleTrack {
+    /// Rewrite both files, ending a still-open cue at `now`.
+    fn write(&self, now: Duration) {
+        let Some(viz) = self.viz else { return };
+        let (srt, vtt) = self.render(now);
+        for (extension, contents) in [("srt", srt), ("vtt", vtt)] {
+            let path = self.dir.join(format!("{viz}.{extension}"));
+            if let Err(err) = std::fs::write(&path, contents) {
+                error!("{}: {err}", path.display());
+            }
+        }
+    }
+
+    /// The SRT and WebVTT files, with a still-open cue ending at `now`.
+    fn render(&self, now: Duration) -> (String, String) {
+        let cues: Vec<_> = self
+            .cues
+            .iter()
+            .map(|cue| (cue.start, cue.end.unwrap_or(now).max(cue.start), &cue.text))
+            .collect();
+
+        let mut srt = String::new();
+        for (i, (start, end, text)) in cues.iter().enumerate() {
+            let _ = write!(
+                srt,
+                "{}\n{} --> {}\n{text}\n\n",
+                i + 1,
+                timestamp(*start, ','),
+                timestamp(*end, ',')
+            );
+        }
+        let mut vtt = String::from("WEBVTT\n\n");
+        for (start, end, text) in &cues {
+            let text = text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            let _ = write!(
+                vtt,
+                "{} --> {}\n{text}\n\n",
+                timestamp(*start, '.'),
+                timestamp(*end, '.')
+            );
+        }
+        (srt, vtt)
+    }
+}
+
+/// `hh:mm:ss,mmm` for SRT, `hh:mm:ss.mmm` for WebVTT.
+fn timestamp(at: Duration, separator: char) -> String {
+    let ms = at.as_millis();
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+/// The narration as one cue: its lines without the blank ones, which would end a cue early.
+fn cue_text(value: &str) -> String {
+    value
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn record_narration(
+    time: Res<Time<Real>>,
+    state: Res<State<Gallery>>,
+    mut track: ResMut<SubtitleTrack>,
+    texts: Query<&Text, With<Narration>>,
+    segment_texts: Query<&SegmentText, With<Narration>>,
+) {
+    let now = time.elapsed();
+    let Gallery::Viz(id) = *state.get() else {
+        return;
+    };
+    if track.viz != Some(id) {
+        *track = SubtitleTrack {
+            dir: track.dir.clone(),
+            viz: Some(id),
+            start: now,
+            cues: Vec::new(),
+        };
+    }
+    let value = match (texts.get_single(), segment_texts.get_single()) {
+        (Ok(text), _) => text
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect::<String>(),
+        (_, Ok(text)) => text
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect(),
+        _ => return,
+    };
+    let text = cue_text(&value);
+    let at = now - track.start;
+    let showing = track
+        .cues
+        .last()
+        .filter(|cue| cue.end.is_none())
+        .map_or("", |cue| cue.text.as_str());
+    if showing == text {
+        return;
+    }
+    if let Some(cue) = track.cues.last_mut().filter(|cue| cue.end.is_none()) {
+        cue.end = Some(at);
+    }
+    if !text.is_empty() {
+        track.cues.push(Cue {
+            start: at,
+            end: None,
+            text,
+        });
+    }
+    track.write(at);
+}
+
+fn write_on_exit(
+    time: Res<Time<Real>>,
+    mut exits: EventReader<AppExit>,
+    track: Res<SubtitleTrack>,
+) {
+    if exits.read().count() > 0 {
+        track.write(time.elapsed() - track.start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(cues: Vec<Cue>) -> SubtitleTrack {
+        SubtitleTrack {
+            dir: PathBuf::new(),
+            viz: Some("test"),
+            start: Duration::ZERO,
+            cues,
+        }
+    }
+
+    fn cue(start: u64, end: Option<u64>, text: &str) -> Cue {
+        Cue {
+            start: Duration::from_millis(start),
+            end: end.map(Duration::from_millis),
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn timestamps_use_the_format_separator() {
+        let at = Duration::from_millis(61_042);
+        assert_eq!(timestamp(at, ','), "00:01:01,042");
+        assert_eq!(timestamp(at, '.'), "00:01:01.042");
+        assert_eq!(timestamp(Duration::ZERO, ','), "00:00:00,000");
+    }
+
+    #[test]
+    fn timestamps_roll_over_into_hours() {
+        assert_eq!(
+            timestamp(Duration::from_millis(3_599_999), ','),
+            "00:59:59,999"
+        );
+        assert_eq!(
+            timestamp(Duration::from_millis(3_600_000), ','),
+            "01:00:00,000"
+        );
+        assert_eq!(
+            timestamp(Duration::from_secs(100 * 3600 + 5), '.'),
+            "100:00:05.000"
+        );
+    }
+
+    #[test]
+    fn cue_text_drops_blank_lines() {
+        assert_eq!(
+            cue_text("  Compare 3 and 5  \n\n   \nswap\n"),
+            "Compare 3 and 5\nswap"
+        );
+        assert_eq!(cue_text("\n  \n"), "");
+    }
+
+    #[test]
+    fn only_vtt_escapes_markup() {
+        let (srt, vtt) = track(vec![cue(0, Some(1000), "a < b && b > c")]).render(Duration::ZERO);
+        assert_eq!(srt, "1\n00:00:00,000 --> 00:00:01,000\na < b && b > c\n\n");
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.000\na &lt; b &amp;&amp; b &gt; c\n\n"
+        );
+    }
+
+    #[test]
+    fn open_cue_ends_when_written() {
+        let track = track(vec![cue(0, Some(1500), "first"), cue(1500, None, "last")]);
+        let (srt, _) = track.render(Duration::from_millis(4000));
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,500\nfirst\n\n2\n00:00:01,500 --> 00:00:04,000\nlast\n\n"
+        );
+        // An exit in the same frame as the cue still gives it a valid range.
+        let (_, vtt) = track.render(Duration::from_millis(1000));
+        assert!(
+            vtt.ends_with("00:00:01.500 --> 00:00:01.500\nlast\n\n"),
+            "{vtt}"
+        );
+    }
+
+    #[test]
+    fn exit_writes_the_last_cue() {
+        let dir = std::env::temp_dir().join(format!("algoviz-subtitles-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<AppExit>()
+            .insert_resource(SubtitleTrack {
+                dir: dir.clone(),
+                ..track(vec![cue(0, None, "done")])
+            })
+            .add_systems(Last, write_on_exit);
+        app.update();
+        assert!(!dir.join("test.srt").exists());
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+        let srt = std::fs::read_to_string(dir.join("test.srt")).unwrap();
+        assert!(
+            srt.starts_with("1\n00:00:00,000 --> ") && srt.ends_with("\ndone\n\n"),
+            "{srt}"
+        );
+        assert!(dir.join("test.vtt").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,716 @@
+use std::cmp::Ordering;
+use std::fmt::Write as _;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+
+use bevy::ecs::system::SystemParam;
+use bevy::gizmos::arrows::ArrowBuilder;
+use bevy::gizmos::circles::EllipseBuilder;
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use bevy::render::mesh::VertexAttributeValues;
+use bevy::sprite::Anchor;
+
+use crate::controls::{Settings, VizSet};
+use crate::gallery::Gallery;
+
+/// P writes the scene as an SVG; `--export-svg DIR` also writes one before
+/// every step. Added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct SvgExportPlugin;
+
+impl Plugin for SvgExportPlugin {
+    fn build(&self, app: &mut App) {
+        let dir = crate::headless::export_svg_dir();
+        if let Some(dir) = &dir {
+            if let Err(err) = std::fs::create_dir_all(dir) {
+                eprintln!("error: --export-svg {}: {err}", dir.display());
+                std::process::exit(2);
+            }
+        }
+        app.insert_resource(SvgExport { dir, ..default() })
+            .init_resource::<GizmoSketch>()
+            .add_systems(First, roll_gizmo_sketch)
+            .add_systems(
+                Update,
+                (
+                    export_on_key,
+                    export_steps.run_if(|export: Res<SvgExport>| export.dir.is_some()),
+                )
+                    .after(VizSet::Controls)
+                    .before(VizSet::Step),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+struct SvgExport {
+    dir: Option<PathBuf>,
+    written: usize,
+    last: String,
+}
+
+impl SvgExport {
+    /// Write `svg` as the next numbered file; `--export-svg` skips repeats of the last one.
+    fn write(&mut self, id: &str, svg: String, again: bool) {
+        if !again && svg == self.last {
+            return;
+        }
+        let path = self
+            .dir
+            .clone()
+            .unwrap_or_default()
+            .join(format!("{id}-{:04}.svg", self.written));
+        match std::fs::write(&path, &svg) {
+            Ok(()) => {
+                info!("wrote {}", path.display());
+                self.written += 1;
+                self.last = svg;
+            }
+            Err(err) => error!("{}: {err}", path.display()),
+        }
+    }
+}
+
+fn export_on_key(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<Gallery>>,
+    scene: SvgScene,
+    mut export: ResMut<SvgExport>,
+) {
+    if let (Gallery::Viz(id), true) = (*state.get(), keys.just_pressed(KeyCode::KeyP)) {
+        export.write(id, scene.render(), true);
+    }
+}
+
+// Runs as a step fires, so the file shows the previous step with its
+// animation finished. Vizs without the step timer are written once.
+fn export_steps(
+    state: Res<State<Gallery>>,
+    settings: Option<Res<Settings>>,
+    scene: SvgScene,
+    mut export: ResMut<SvgExport>,
+    mut entered: Local<Option<(&'static str, u32)>>,
+) {
+    let Gallery::Viz(id) = *state.get() else {
+        return;
+    };
+    let frames = match *entered {
+        Some((shown, frames)) if shown == id => frames + 1,
+        _ => 0,
+    };
+    *entered = Some((id, frames));
+    let due = match settings {
+        Some(settings) => settings.step_ready(),
+        // setup's transforms are propagated by the second frame
+        None => frames == 1,
+    };
+    if due {
+        export.write(id, scene.render(), false);
+    }
+}
+
+/// Gizmo shapes drawn through [`SceneGizmos`], in world space.
+enum Sketched {
+    Line(Vec3, Vec3, Color),
+    Arrow(Vec3, Vec3, Color),
+    Circle(Vec3, Quat, f32, Color),
+}
+
+/// What [`SceneGizmos`] drew this frame and the last one.
+#[derive(Resource, Default)]
+pub struct GizmoSketch {
+    current: Vec<Sketched>,
+    last: Vec<Sketched>,
+}
+
+fn roll_gizmo_sketch(mut sketch: ResMut<GizmoSketch>) {
+    sketch.last = std::mem::take(&mut sketch.current);
+}
+
+/// `Gizmos` that also hand their arrows, circles, rects, cuboids and 2D line strips to the
+/// SVG export; everything else goes straight through to `Gizmos`.
+#[derive(SystemParam)]
+pub struct SceneGizmos<'w, 's> {
+    gizmos: Gizmos<'w, 's>,
+    sketch: ResMut<'w, GizmoSketch>,
+}
+
+impl<'w, 's> SceneGizmos<'w, 's> {
+    pub fn arrow(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        color: impl Into<Color>,
+    ) -> ArrowBuilder<'_, 'w, 's, DefaultGizmoConfigGroup, ()> {
+        let color = color.into();
+        self.sketch.current.push(Sketched::Arrow(start, end, color));
+        self.gizmos.arrow(start, end, color)
+    }
+
+    pub fn circle(
+        &mut self,
+        position: Vec3,
+        normal: Dir3,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> EllipseBuilder<'_, 'w, 's, DefaultGizmoConfigGroup, ()> {
+        let color = color.into();
+        self.sketch.current.push(Sketched::Circle(
+            position,
+            Quat::from_rotation_arc(Vec3::Z, *normal),
+            radius,
+            color,
+        ));
+        self.gizmos.circle(position, normal, radius, color)
+    }
+
+    pub fn rect(&mut self, position: Vec3, rotation: Quat, size: Vec2, color: impl Into<Color>) {
+        let color = color.into();
+        let corner =
+            |x: f32, y: f32| position + rotation * (size * Vec2::new(x, y) / 2.0).extend(0.0);
+        let corners = [
+            corner(-1.0, -1.0),
+            corner(1.0, -1.0),
+            corner(1.0, 1.0),
+            corner(-1.0, 1.0),
+        ];
+        for i in 0..4 {
+            self.sketch
+                .current
+                .push(Sketched::Line(corners[i], corners[(i + 1) % 4], color));
+        }
+        self.gizmos.rect(position, rotation, size, color);
+    }
+
+    pub fn linestrip_2d(
+        &mut self,
+        positions: impl IntoIterator<Item = Vec2>,
+        color: impl Into<Color>,
+    ) {
+        let color = color.into();
+        let points: Vec<Vec2> = positions.into_iter().collect();
+        for pair in points.windows(2) {
+            self.sketch.current.push(Sketched::Line(
+                pair[0].extend(0.0),
+                pair[1].extend(0.0),
+                color,
+            ));
+        }
+        self.gizmos.linestrip_2d(points, color);
+    }
+
+    pub fn cuboid(&mut self, transform: Transform, color: impl Into<Color>) {
+        let color = color.into();
+        let corner = |i: usize| {
+            let sign = |bit: usize| if i & bit == 0 { -0.5 } else { 0.5 };
+            transform.transform_point(Vec3::new(sign(1), sign(2), sign(4)))
+        };
+        // the twelve edges join corners that differ in one bit
+        for i in 0..8 {
+            for bit in [1, 2, 4] {
+                if i & bit == 0 {
+                    self.sketch
+                        .current
+                        .push(Sketched::Line(corner(i), corner(i | bit), color));
+                }
+            }
+        }
+        self.gizmos.cuboid(transform, color);
+    }
+}
+
+impl<'w, 's> Deref for SceneGizmos<'w, 's> {
+    type Target = Gizmos<'w, 's>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.gizmos
+    }
+}
+
+impl DerefMut for SceneGizmos<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.gizmos
+    }
+}
+
+/// Everything the export draws: sprites and 2D text through the 2D camera,
+/// meshes and gizmos through the 3D one when the viz has it.
+#[derive(SystemParam)]
+struct SvgScene<'w, 's> {
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform, Has<Camera3d>)>,
+    clear_color: Option<Res<'w, ClearColor>>,
+    sprites: Query<
+        'w,
+        's,
+        (
+            &'static Sprite,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+        ),
+    >,
+    texts: Query<
+        'w,
+        's,
+        (
+            &'static Text,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+            &'static Anchor,
+        ),
+    >,
+    meshes: Query<
+        'w,
+        's,
+        (
+            &'static Handle<Mesh>,
+            Option<&'static Handle<StandardMaterial>>,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+        ),
+    >,
+    mesh_assets: Option<Res<'w, Assets<Mesh>>>,
+    materials: Option<Res<'w, Assets<StandardMaterial>>>,
+    sketch: Res<'w, GizmoSketch>,
+}
+
+/// An SVG element with its paint order: camera first, then depth.
+struct Shape {
+    order: isize,
+    depth: f32,
+    element: String,
+}
+
+impl SvgScene<'_, '_> {
+    fn render(&self) -> String {
+        let mut cameras: Vec<_> = self
+            .cameras
+            .iter()
+            .filter(|(camera, ..)| camera.is_active)
+            .collect();
+        cameras.sort_by_key(|(camera, ..)| camera.order);
+        let size = cameras
+            .iter()
+            .find_map(|(camera, ..)| camera.logical_viewport_size())
+            .unwrap_or(Vec2::new(900.0, 600.0));
+        let flat = cameras.iter().find(|(_, _, is_3d)| !is_3d).copied();
+        let deep = cameras.iter().find(|(_, _, is_3d)| *is_3d).copied();
+
+        let mut shapes = Vec::new();
+        if let Some((camera, view, _)) = flat {
+            let project = |p: Vec3| camera.world_to_viewport(view, p);
+            for (sprite, transform, visible) in &self.sprites {
+                if !visible.get() {
+                    continue;
+                }
+                let size = sprite.custom_size.unwrap_or(Vec2::ONE);
+                let anchor = sprite.anchor.as_vec();
+                let corner = |x: f32, y: f32| {
+                    project(
+                        transform.transform_point(((Vec2::new(x, y) - anchor) * size).extend(0.0)),
+                    )
+                };
+                let corners = [
+                    corner(-0.5, -0.5),
+                    corner(0.5, -0.5),
+                    corner(0.5, 0.5),
+                    corner(-0.5, 0.5),
+                ];
+                if let [Some(a), Some(b), Some(c), Some(d)] = corners {
+                    let element = quad([a, b, c, d], sprite.color);
+                    shapes.push(Shape {
+                        order: camera.order,
+                        depth: transform.translation().z,
+                        element,
+                    });
+                }
+            }
+            for (text, transform, visible, anchor) in &self.texts {
+                if let (true, Some(at)) = (visible.get(), project(transform.translation())) {
+                    let element = text_lines(text, at, anchor.as_vec());
+                    shapes.push(Shape {
+                        order: camera.order,
+                        depth: transform.translation().z,
+                        element,
+                    });
+                }
+            }
+        }
+        if let Some((camera, view, _)) = deep.or(flat) {
+            let project = |p: Vec3| camera.world_to_viewport(view, p);
+            let distance = |p: Vec3| -view.translation().distance(p);
+            if let (Some(meshes), true) = (&self.mesh_assets, deep.is_some()) {
+                for (mesh, material, transform, visible) in &self.meshes {
+                    let Some(VertexAttributeValues::Float32x3(positions)) = meshes
+                        .get(mesh)
+                        .and_then(|mesh| mesh.attribute(Mesh::ATTRIBUTE_POSITION))
+                    else {
+                        continue;
+                    };
+                    if !visible.get() {
+                        continue;
+                    }
+                    let color = material
+                        .and_then(|material| self.materials.as_ref()?.get(material))
+                        .map_or(Color::WHITE, |material| material.base_color);
+                    let points: Vec<_> = positions
+                        .iter()
+                        .filter_map(|&p| project(transform.transform_point(Vec3::from(p))))
+                        .collect();
+                    if points.len() >= 3 {
+                        let element = outline(&hull(points), color, true);
+                        shapes.push(Shape {
+                            order: camera.order,
+                            depth: distance(transform.translation()),
+                            element,
+                        });
+                    }
+                }
+            }
+            for sketched in &self.sketch.last {
+                let element = match *sketched {
+                    Sketched::Line(a, b, color) => project(a)
+                        .zip(project(b))
+                        .map(|(a, b)| line(a, b, 1.0, color)),
+                    Sketched::Arrow(a, b, color) => {
+                        project(a).zip(project(b)).map(|(a, b)| arrow(a, b, color))
+                    }
+                    Sketched::Circle(center, rotation, radius, color) => {
+                        let ring: Option<Vec<_>> = (0..32)
+                            .map(|i| {
+                                let angle = i as f32 / 32.0 * std::f32::consts::TAU;
+                                project(
+                                    center
+                                        + rotation
+                                            * Vec3::new(angle.cos(), angle.sin(), 0.0)
+                                            * radius,
+                                )
+                            })
+                            .collect();
+                        ring.map(|ring| outline(&ring, color, false))
+                    }
+                };
+                shapes.extend(element.map(|element| Shape {
+                    order: camera.order,
+                    depth: f32::INFINITY,
+                    element,
+                }));
+            }
+        }
+        shapes.sort_by(|a, b| {
+            a.order
+                .cmp(&b.order)
+                .then(a.depth.partial_cmp(&b.depth).unwrap_or(Ordering::Equal))
+        });
+
+        let background = cameras
+            .first()
+            .map_or(ClearColorConfig::Default, |(camera, ..)| camera.clear_color);
+        let background = match background {
+            ClearColorConfig::Custom(color) => Some(color),
+            ClearColorConfig::Default => Some(
+                self.clear_color
+                    .as_deref()
+                    .map_or(ClearColor::default().0, |clear| clear.0),
+            ),
+            ClearColorConfig::None => None,
+        };
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" viewBox=\"0 0 {w:.0} {h:.0}\">\n",
+            w = size.x,
+            h = size.y
+        );
+        if let Some(color) = background {
+            let _ = writeln!(
+                svg,
+                "<rect width=\"100%\" height=\"100%\" {}/>",
+                paint("fill", color)
+            );
+        }
+        for shape in shapes {
+            svg.push_str(&shape.element);
+            svg.push('\n');
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// `fill="#rrggbb"` (or `stroke=`), with an opacity when the color has one.
+fn paint(attribute: &str, color: Color) -> String {
+    let Srgba {
+        red,
+        green,
+        blue,
+        alpha,
+    } = color.to_srgba();
+    let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let mut paint = format!(
+        "{attribute}=\"#{:02x}{:02x}{:02x}\"",
+        byte(red),
+        byte(green),
+        byte(blue)
+    );
+    if alpha < 1.0 {
+        let _ = write!(paint, " {attribute}-opacity=\"{alpha:.2}\"");
+    }
+    paint
+}
+
+/// A sprite's projected corners: an upright `<rect>`, a `<line>` when it is a
+/// thin bar (tree and graph edges), otherwise a `<polygon>`.
+fn quad(corners: [Vec2; 4], color: Color) -> String {
+    let [a, b, c, d] = corners;
+    let (across, up) = (b - a, d - a);
+    if across.y.abs() < 0.01 && up.x.abs() < 0.01 {
+        let (min, max) = (a.min(c), a.max(c));
+        return format!(
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" {}/>",
+            min.x,
+            min.y,
+            max.x - min.x,
+            max.y - min.y,
+            paint("fill", color)
+        );
+    }
+    let (long, short) = if across.length() >= up.length() {
+        (across, up)
+    } else {
+        (up, across)
+    };
+    if long.length() >= 4.0 * short.length() {
+        let from = (a + a + short) / 2.0;
+        return line(from, from + long, short.length(), color);
+    }
+    outline(&corners, color, true)
+}
+
+fn line(a: Vec2, b: Vec2, width: f32, color: Color) -> String {
+    format!(
+        "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke-width=\"{width:.1}\" {}/>",
+        a.x,
+        a.y,
+        b.x,
+        b.y,
+        paint("stroke", color)
+    )
+}
+
+/// A shaft with a head a tenth of its length, like `Gizmos::arrow`.
+fn arrow(a: Vec2, b: Vec2, color: Color) -> String {
+    let back = (a - b) / 10.0;
+    let (left, right) = (
+        b + Vec2::from_angle(0.5).rotate(back),
+        b + Vec2::from_angle(-0.5).rotate(back),
+    );
+    format!(
+        "<path d=\"M{:.1} {:.1}L{:.1} {:.1}M{:.1} {:.1}L{:.1} {:.1}L{:.1} {:.1}\" fill=\"none\" {}/>",
+        a.x,
+        a.y,
+        b.x,
+        b.y,
+        left.x,
+        left.y,
+        b.x,
+        b.y,
+        right.x,
+        right.y,
+        paint("stroke", color)
+    )
+}
+
+/// A closed shape through `points`: a `<circle>` when they sit on one,
+/// otherwise a `<polygon>`. Filled, or stroked like a gizmo.
+fn outline(points: &[Vec2], color: Color, filled: bool) -> String {
+    let paint = if filled {
+        paint("fill", color)
+    } else {
+        format!("fill=\"none\" {}", paint("stroke", color))
+    };
+    let (min, max) = points
+        .iter()
+        .fold((Vec2::MAX, Vec2::MIN), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+    let center = (min + max) / 2.0;
+    let radii: Vec<f32> = points.iter().map(|p| p.distance(center)).collect();
+    let (low, high) = radii.iter().fold((f32::MAX, 0.0f32), |(low, high), &r| {
+        (low.min(r), high.max(r))
+    });
+    if points.len() >= 12 && high - low <= high * 0.03 {
+        return format!(
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" {paint}/>",
+            center.x,
+            center.y,
+            (low + high) / 2.0
+        );
+    }
+    let mut element = String::from("<polygon points=\"");
+    for p in points {
+        let _ = write!(element, "{:.1},{:.1} ", p.x, p.y);
+    }
+    element.pop();
+    let _ = write!(element, "\" {paint}/>");
+    element
+}
+
+/// Convex hull of a projected mesh, counter-clockwise (Andrew's monotone chain).
+fn hull(mut points: Vec<Vec2>) -> Vec<Vec2> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup_by(|a, b| a.distance_squared(*b) < 1e-4);
+    let turn = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+    let mut hull: Vec<Vec2> = Vec::new();
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2
+                && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// A `Text2dBundle` as one `<text>` per line, placed by its anchor.
+fn text_lines(text: &Text, at: Vec2, anchor: Vec2) -> String {
+    let mut lines = vec![(String::new(), 0.0f32, Color::WHITE)];
+    for section in &text.sections {
+        for (i, part) in section.value.split('\n').enumerate() {
+            if i > 0 {
+                lines.push((String::new(), 0.0, section.style.color));
+            }
+            let line = lines.last_mut().unwrap();
+            line.0.push_str(part);
+            line.1 = line.1.max(section.style.font_size);
+            if !part.is_empty() {
+                line.2 = section.style.color;
+            }
+        }
+    }
+    let height: f32 = lines.iter().map(|(_, size, _)| size * 1.2).sum();
+    let text_anchor = match anchor.x {
+        x if x < -0.25 => "start",
+        x if x > 0.25 => "end",
+        _ => "middle",
+    };
+    let mut y = at.y - (0.5 - anchor.y) * height;
+    let mut element = String::new();
+    for (value, size, color) in lines {
+        y += size * 1.2;
+        if value.is_empty() {
+            continue;
+        }
+        let value = value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let _ = write!(
+            element,
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"{size:.0}\" text-anchor=\"{text_anchor}\" {}>{value}</text>",
+            at.x,
+            y - size * 0.3,
+            paint("fill", color)
+        );
+    }
+    element
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::TAU;
+
+    use super::*;
+
+    const RED: Color = Color::srgb(1.0, 0.0, 0.0);
+
+    #[test]
+    fn paint_adds_opacity_only_when_translucent() {
+        assert_eq!(paint("fill", RED), "fill=\"#ff0000\"");
+        assert_eq!(
+            paint("stroke", RED.with_alpha(0.5)),
+            "stroke=\"#ff0000\" stroke-opacity=\"0.50\""
+        );
+    }
+
+    #[test]
+    fn upright_sprite_is_a_rect() {
+        let corners = [
+            Vec2::new(10.0, 40.0),
+            Vec2::new(30.0, 40.0),
+            Vec2::new(30.0, 20.0),
+            Vec2::new(10.0, 20.0),
+        ];
+        assert_eq!(
+            quad(corners, RED),
+            "<rect x=\"10.0\" y=\"20.0\" width=\"20.0\" height=\"20.0\" fill=\"#ff0000\"/>"
+        );
+    }
+
+    #[test]
+    fn thin_rotated_sprite_is_a_line() {
+        // a 2 by 40 bar, tilted: an edge between two nodes
+        let corners = [
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 40.0),
+            Vec2::new(-1.0, 40.0),
+            Vec2::new(-1.0, 0.0),
+        ]
+        .map(|p| Vec2::from_angle(0.3).rotate(p));
+        let element = quad(corners, RED);
+        assert!(
+            element.starts_with("<line ") && element.contains("stroke-width=\"2.0\""),
+            "{element}"
+        );
+        let squat = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(8.0, 6.0),
+            Vec2::new(2.0, 14.0),
+            Vec2::new(-6.0, 8.0),
+        ];
+        assert!(
+            quad(squat, RED).starts_with("<polygon points=\"0.0,0.0 8.0,6.0 2.0,14.0 -6.0,8.0\"")
+        );
+    }
+
+    #[test]
+    fn gizmo_line_is_a_stroked_line() {
+        assert_eq!(
+            line(Vec2::new(1.0, 2.0), Vec2::new(3.5, 4.3), 1.0, RED),
+            "<line x1=\"1.0\" y1=\"2.0\" x2=\"3.5\" y2=\"4.3\" stroke-width=\"1.0\" stroke=\"#ff0000\"/>"
+        );
+    }
+
+    #[test]
+    fn gizmo_circle_is_an_unfilled_circle() {
+        let ring: Vec<_> = (0..32)
+            .map(|i| Vec2::new(50.0, 60.0) + Vec2::from_angle(i as f32 / 32.0 * TAU) * 10.0)
+            .collect();
+        assert_eq!(
+            outline(&ring, RED, false),
+            "<circle cx=\"50.0\" cy=\"60.0\" r=\"10.0\" fill=\"none\" stroke=\"#ff0000\"/>"
+        );
+    }
+
+    #[test]
+    fn hull_drops_inner_points() {
+        let points = vec![
+            Vec2::ZERO,
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(
+            hull(points),
+            [
+                Vec2::ZERO,
+                Vec2::new(2.0, 0.0),
+                Vec2::new(2.0, 2.0),
+                Vec2::new(0.0, 2.0)
+            ]
+        );
+    }
+}
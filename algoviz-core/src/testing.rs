@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::code::CodeCursor;
+use crate::controls::{consume_manual_step, tick_timer, ResetRequested, Settings, VizSet};
+use crate::locale::Locale;
+use crate::quiz::{tick_quiz, Quiz};
+use crate::theme::Palette;
+
+// Time<Virtual> clamps a frame to 250 ms, so slow vizzes still need a few frames per step
+const MAX_FRAME: f32 = 0.25;
+
+/// A windowless app for tests: `MinimalPlugins`, keyboard input, states, [`Settings`]
+/// in auto-play, the default [`Palette`], quiz mode off, an empty [`CodeCursor`], the [`VizSet`] order and the step timer, with every
+/// `update()` advancing time by a fixed slice of `step_interval`.
+///
+/// Add the viz's state and the systems under test, then [`run_until`]:
+///
+/// ```ignore
+/// let mut app = test_app(STEP_INTERVAL);
+/// app.insert_resource(state).add_systems(Update, step.in_set(VizSet::Step));
+/// run_until(&mut app, 10_000, |world| world.resource::<MyState>().done);
+/// ```
+pub fn test_app(step_interval: f32) -> App {
+    let frame = (step_interval / 2.0).min(MAX_FRAME);
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, StatesPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            frame,
+        )))
+        .insert_resource(Settings::new(step_interval))
+        .init_resource::<Palette>()
+        .init_resource::<Locale>()
+        .init_resource::<Quiz>()
+        .init_resource::<CodeCursor>()
+        .add_event::<ResetRequested>()
+        .configure_sets(
+            Update,
+            (
+                VizSet::Input,
+                VizSet::Controls,
+                VizSet::Step,
+                VizSet::Animate,
+                VizSet::Highlight,
+            )
+                .chain(),
+        )
+        .add_systems(Update, (tick_timer, tick_quiz).in_set(VizSet::Controls))
+        .add_systems(PostUpdate, consume_manual_step);
+    app
+}
+
+/// Update `app` until `done` holds and return the number of frames it took.
+/// Panics after `max_frames`, so a stuck step system fails the test instead of hanging it.
+pub fn run_until(app: &mut App, max_frames: usize, mut done: impl FnMut(&World) -> bool) -> usize {
+    for frame in 0..max_frames {
+        if done(app.world()) {
+            return frame;
+        }
+        app.update();
+    }
+    assert!(done(app.world()), "not done after {max_frames} frames");
+    max_frames
+}
@@ -0,0 +1,321 @@
+use bevy::prelude::*;
+
+/// The colors behind the shared color semantics, by role. Highlight systems
+/// read them from this resource instead of spelling out `Color::srgb(..)`,
+/// so T (or `--theme NAME`) can switch every viz between [`Palette::THEMES`].
+///
+/// ```ignore
+/// fn update_highlights(palette: Res<Palette>, mut q: Query<(&Bar, &mut Sprite)>) {
+///     for (bar, mut sprite) in &mut q {
+///         sprite.color = if bar.sorted { palette.settled } else { palette.focus };
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    pub name: &'static str,
+    /// Current focus element or node.
+    pub focus: Color,
+    /// Peer under comparison, or the frontier.
+    pub compare: Color,
+    /// Confirmed: settled, sorted, locked in.
+    pub settled: Color,
+    /// Discovered, visited or queued, not yet settled.
+    pub visited: Color,
+    /// Error, violation, rollback.
+    pub violation: Color,
+    /// Replaces the viz's `ClearColor`; `None` keeps its own.
+    pub background: Option<Color>,
+}
+
+impl Palette {
+    /// White focus, yellow comparison, green settled, blue visited, red violation.
+    pub const DEFAULT: Self = Self {
+        name: "default",
+        focus: Color::WHITE,
+        compare: Color::srgb(1.0, 1.0, 0.0),
+        settled: Color::srgb(0.0, 0.8, 0.0),
+        visited: Color::srgb(0.2, 0.6, 1.0),
+        violation: Color::srgb(1.0, 0.2, 0.2),
+        background: None,
+    };
+
+    /// Okabe–Ito colors: settled is blue and violation vermillion, so the two
+    /// never rely on telling red from green.
+    pub const DEUTERANOPIA: Self = Self {
+        name: "deuteranopia",
+        focus: Color::WHITE,
+        compare: Color::srgb(0.94, 0.89, 0.26),
+        settled: Color::srgb(0.0, 0.45, 0.7),
+        visited: Color::srgb(0.8, 0.47, 0.65),
+        violation: Color::srgb(0.84, 0.37, 0.0),
+        background: None,
+    };
+
+    /// Saturated colors on black.
+    pub const HIGH_CONTRAST: Self = Self {
+        name: "high-contrast",
+        focus: Color::WHITE,
+        compare: Color::srgb(1.0, 1.0, 0.0),
+        settled: Color::srgb(0.0, 1.0, 0.3),
+        visited: Color::srgb(0.0, 0.9, 1.0),
+        violation: Color::srgb(1.0, 0.0, 0.8),
+        background: Some(Color::BLACK),
+    };
+
+    /// Dark ink on white, for printed handouts and SVG exports.
+    pub const LIGHT: Self = Self {
+        name: "light",
+        focus: Color::srgb(0.05, 0.05, 0.08),
+        compare: Color::srgb(0.85, 0.55, 0.0),
+        settled: Color::srgb(0.0, 0.5, 0.2),
+        visited: Color::srgb(0.1, 0.35, 0.8),
+        violation: Color::srgb(0.8, 0.1, 0.1),
+        background: Some(Color::WHITE),
+    };
+
+    /// In the order T cycles through them.
+    pub const THEMES: [Self; 4] = [
+        Self::DEFAULT,
+        Self::DEUTERANOPIA,
+        Self::HIGH_CONTRAST,
+        Self::LIGHT,
+    ];
+
+    pub fn named(name: &str) -> Option<Self> {
+        Self::THEMES.into_iter().find(|theme| theme.name == name)
+    }
+
+    /// `color` as drawn on this theme's background: on a light one, whites and
+    /// grays (labels, captions) turn dark so they stay readable; hues are kept.
+    pub fn ink(&self, color: Color) -> Color {
+        let light = self
+            .background
+            .is_some_and(|background| background.luminance() > 0.5);
+        let Srgba {
+            red, green, blue, ..
+        } = color.to_srgba();
+        if !light || red.max(green).max(blue) - red.min(green).min(blue) > 0.25 {
+            return color;
+        }
+        let mut hsla = Hsla::from(color);
+        if hsla.lightness > 0.5 {
+            hsla.lightness = 1.0 - hsla.lightness;
+        }
+        hsla.into()
+    }
+}
+
+/// A sprite colored by [`Palette`] role once, when it is spawned. The theme
+/// plugin repaints it in its role's color whenever the theme changes, keeping
+/// the sprite's own alpha, so scenes drawn in `setup` follow T too.
+///
+/// ```ignore
+/// commands.spawn((SpriteBundle { sprite: Sprite { color: palette.settled, ..default() }, ..default() }, Highlight::Settled));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Highlight {
+    Focus,
+    Compare,
+    Settled,
+    Visited,
+    Violation,
+}
+
+impl Palette {
+    /// The color of `role` in this theme.
+    pub fn role(&self, role: Highlight) -> Color {
+        match role {
+            Highlight::Focus => self.focus,
+            Highlight::Compare => self.compare,
+            Highlight::Settled => self.settled,
+            Highlight::Visited => self.visited,
+            Highlight::Violation => self.violation,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The [`Palette`] resource, T to cycle themes and the theme's background.
+/// Added by [`GalleryApp::add_viz`](crate::GalleryApp::add_viz).
+pub(crate) struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        let palette = match crate::headless::run_flags().theme {
+            Some(name) => Palette::named(&name).unwrap_or_else(|| {
+                let names: Vec<_> = Palette::THEMES.iter().map(|theme| theme.name).collect();
+                eprintln!(
+                    "error: --theme: no theme `{name}` (one of {})",
+                    names.join(", ")
+                );
+                std::process::exit(2);
+            }),
+            None => Palette::DEFAULT,
+        };
+        app.insert_resource(palette)
+            .add_systems(Update, cycle_theme)
+            .add_systems(PostUpdate, (paint_background, paint_highlights));
+    }
+}
+
+fn cycle_theme(keys: Res<ButtonInput<KeyCode>>, mut palette: ResMut<Palette>) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        let at = Palette::THEMES
+            .iter()
+            .position(|theme| theme.name == palette.name)
+            .unwrap_or(0);
+        *palette = Palette::THEMES[(at + 1) % Palette::THEMES.len()];
+        info!("theme: {}", palette.name);
+    }
+}
+
+fn paint_highlights(palette: Res<Palette>, mut sprites: Query<(Ref<Highlight>, &mut Sprite)>) {
+    for (role, mut sprite) in &mut sprites {
+        if palette.is_changed() || role.is_changed() {
+            let alpha = sprite.color.alpha();
+            sprite.color = palette.role(*role).with_alpha(alpha);
+        }
+    }
+}
+
+// `own` holds the viz's ClearColor while a theme paints over it; any color
+// found there that no theme paints was set by a viz since and becomes its own.
+fn paint_background(
+    palette: Res<Palette>,
+    clear: Option<ResMut<ClearColor>>,
+    mut own: Local<Option<Color>>,
+) {
+    let Some(mut clear) = clear else { return };
+    match palette.background {
+        Some(background) if clear.0 != background => {
+            if !Palette::THEMES
+                .iter()
+                .any(|theme| theme.background == Some(clear.0))
+            {
+                *own = Some(clear.0);
+            }
+            clear.0 = background;
+        }
+        Some(_) => {}
+        None => {
+            if let Some(color) = own.take() {
+                clear.0 = color;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles(palette: &Palette) -> [Color; 5] {
+        [
+            palette.focus,
+            palette.compare,
+            palette.settled,
+            palette.visited,
+            palette.violation,
+        ]
+    }
+
+    #[test]
+    fn every_theme_keeps_its_roles_apart() {
+        for theme in Palette::THEMES {
+            let roles = roles(&theme);
+            for (i, a) in roles.iter().enumerate() {
+                assert!(
+                    roles[i + 1..].iter().all(|b| a != b),
+                    "{}: two roles share {a:?}",
+                    theme.name
+                );
+            }
+            assert_eq!(Palette::named(theme.name), Some(theme));
+        }
+        assert_eq!(Palette::named("sepia"), None);
+    }
+
+    #[test]
+    fn t_cycles_every_theme_and_back() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Palette>()
+            .insert_resource(ClearColor(Color::srgb(0.1, 0.1, 0.1)))
+            .add_systems(Update, (cycle_theme, paint_background).chain());
+        let mut seen = Vec::new();
+        for _ in 0..=Palette::THEMES.len() {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.release(KeyCode::KeyT);
+            keys.clear();
+            keys.press(KeyCode::KeyT);
+            app.update();
+            let palette = *app.world().resource::<Palette>();
+            let clear = app.world().resource::<ClearColor>().0;
+            assert_eq!(
+                clear,
+                palette.background.unwrap_or(Color::srgb(0.1, 0.1, 0.1)),
+                "{}",
+                palette.name
+            );
+            seen.push(palette.name);
+        }
+        assert_eq!(
+            seen,
+            [
+                "deuteranopia",
+                "high-contrast",
+                "light",
+                "default",
+                "deuteranopia"
+            ]
+        );
+    }
+
+    #[test]
+    fn light_theme_darkens_grays_only() {
+        let light = Palette::LIGHT;
+        assert!(light.ink(Color::WHITE).luminance() < 0.01);
+        assert!(light.ink(Color::srgb(0.8, 0.8, 0.8)).luminance() < 0.5);
+        assert_eq!(light.ink(light.violation), light.violation);
+        assert_eq!(Palette::DEFAULT.ink(Color::WHITE), Color::WHITE);
+        assert_eq!(Palette::HIGH_CONTRAST.ink(Color::WHITE), Color::WHITE);
+    }
+
+    #[test]
+    fn highlights_follow_the_theme_and_keep_their_alpha() {
+        let mut app = App::new();
+        app.init_resource::<Palette>()
+            .add_systems(PostUpdate, paint_highlights);
+        let sprite = |color: Color| Sprite { color, ..default() };
+        let settled = app
+            .world_mut()
+            .spawn((sprite(Color::WHITE.with_alpha(0.5)), Highlight::Settled))
+            .id();
+        let plain = app.world_mut().spawn(sprite(Color::WHITE)).id();
+        app.update();
+        let color = |app: &App, entity| app.world().get::<Sprite>(entity).unwrap().color;
+        assert_eq!(
+            color(&app, settled),
+            Palette::DEFAULT.settled.with_alpha(0.5)
+        );
+        *app.world_mut().resource_mut::<Palette>() = Palette::DEUTERANOPIA;
+        app.update();
+        assert_eq!(
+            color(&app, settled),
+            Palette::DEUTERANOPIA.settled.with_alpha(0.5)
+        );
+        assert_eq!(color(&app, plain), Color::WHITE);
+        *app.world_mut().get_mut::<Highlight>(settled).unwrap() = Highlight::Violation;
+        app.update();
+        assert_eq!(
+            color(&app, settled),
+            Palette::DEUTERANOPIA.violation.with_alpha(0.5)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+
+use crate::controls::{ResetRequested, Settings, VizSet};
+use crate::gallery::VizScope;
+use crate::quiz::Quiz;
+
+/// A precomputed run of an algorithm: the typed events it emitted, in order,
+/// and how far playback has got through them.
+#[derive(Resource, Clone)]
+pub struct Trace<E> {
+    events: Vec<E>,
+    cursor: usize,
+}
+
+impl<E> Trace<E> {
+    pub fn new(events: Vec<E>) -> Self {
+        Self { events, cursor: 0 }
+    }
+
+    pub fn events(&self) -> &[E] {
+        &self.events
+    }
+
+    /// Number of events already applied.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.cursor >= self.events.len()
+    }
+
+    /// The events not yet applied, the next one first.
+    pub fn upcoming(&self) -> &[E] {
+        &self.events[self.cursor.min(self.events.len())..]
+    }
+
+    /// The event applied most recently, if any.
+    pub fn last(&self) -> Option<&E> {
+        self.cursor.checked_sub(1).map(|i| &self.events[i])
+    }
+
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+    }
+}
+
+/// The trace player's step, so a quiz can hold it.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct TracePlayback;
+
+/// Registers playback of a [`Trace<E>`] resource for the viz in `scope`.
+///
+/// On every step tick the next event is piped into `apply`, a system taking
+/// `In<E>` plus whatever scene access it needs. R (or Space once the trace
+/// has finished) rewinds the trace; the viz resets its scene on the same
+/// [`ResetRequested`] event.
+pub(crate) fn add_trace_player<E, M>(
+    app: &mut App,
+    scope: VizScope,
+    apply: impl IntoSystem<E, (), M>,
+) where
+    E: Clone + Send + Sync + 'static,
+{
+    // the restart request is sent before Update so the trace and the
+    // viz's own reset handler see it on the same frame
+    app.add_systems(
+        PreUpdate,
+        restart_finished::<E>.after(InputSystem).in_set(scope),
+    )
+    .add_systems(
+        Update,
+        (
+            rewind_on_reset::<E>.in_set(VizSet::Input),
+            next_event::<E>
+                .pipe(apply)
+                .run_if(trace_ready::<E>)
+                .in_set(VizSet::Step)
+                .in_set(TracePlayback),
+        )
+            .in_set(scope),
+    );
+}
+
+// Space on a finished trace replays it instead of toggling auto-play
+fn restart_finished<E: Send + Sync + 'static>(
+    trace: Res<Trace<E>>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventWriter<ResetRequested>,
+) {
+    if trace.is_done() && keys.clear_just_pressed(KeyCode::Space) {
+        reset.send(ResetRequested);
+    }
+}
+
+fn rewind_on_reset<E: Send + Sync + 'static>(
+    mut trace: ResMut<Trace<E>>,
+    mut reset: EventReader<ResetRequested>,
+) {
+    if reset.read().count() > 0 {
+        trace.rewind();
+    }
+}
+
+// Bevy evaluates a system's conditions even when its VizScope is off, and
+// the trace only exists while its viz is up. An open quiz question holds the next event.
+fn trace_ready<E: Send + Sync + 'static>(
+    settings: Res<Settings>,
+    trace: Option<Res<Trace<E>>>,
+    quiz: Option<Res<Quiz>>,
+) -> bool {
+    settings.step_ready()
+        && trace.is_some_and(|trace| !trace.is_done())
+        && !quiz.is_some_and(|quiz| quiz.waiting())
+}
+
+fn next_event<E: Clone + Send + Sync + 'static>(mut trace: ResMut<Trace<E>>) -> E {
+    let event = trace.events[trace.cursor].clone();
+    trace.cursor += 1;
+    event
+}
@@ -0,0 +1,780 @@
+//! Every visualization in one window: a menu of the catalog, each entry
+//! running the viz's `plugin` under its [`Gallery::Viz`] state.
+//! Esc goes back to the menu, which tears the viz down.
+
+use algoviz_core::Gallery;
+use bevy::prelude::*;
+
+const TITLE: &str = "Algorithm Gallery";
+const BACKGROUND: Color = Color::srgb(0.08, 0.08, 0.1);
+const BUTTON: Color = Color::srgb(0.16, 0.17, 0.22);
+const BUTTON_HOVER: Color = Color::srgb(0.26, 0.3, 0.4);
+
+struct Entry {
+    title: &'static str,
+    id: &'static str,
+    plugin: fn(&mut App),
+}
+
+/// The menu: CATALOG.md's sections, each viz under its lesson title.
+const CATALOG: &[(&str, &[Entry])] = &[
+    (
+        "Sorting",
+        &[
+            Entry {
+                title: "Bubble Sort",
+                id: bevy_bubble_sort::ID,
+                plugin: bevy_bubble_sort::plugin,
+            },
+            Entry {
+                title: "Selection Sort",
+                id: bevy_selection_sort::ID,
+                plugin: bevy_selection_sort::plugin,
+            },
+            Entry {
+                title: "Insertion Sort",
+                id: bevy_insertion_sort::ID,
+                plugin: bevy_insertion_sort::plugin,
+            },
+            Entry {
+                title: "Cocktail Shaker Sort",
+                id: bevy_cocktail_sort::ID,
+                plugin: bevy_cocktail_sort::plugin,
+            },
+            Entry {
+                title: "Gnome Sort",
+                id: bevy_gnome_sort::ID,
+                plugin: bevy_gnome_sort::plugin,
+            },
+            Entry {
+                title: "Shell Sort",
+                id: bevy_shell_sort::ID,
+                plugin: bevy_shell_sort::plugin,
+            },
+            Entry {
+                title: "Merge Sort",
+                id: bevy_merge_sort::ID,
+                plugin: bevy_merge_sort::plugin,
+            },
+            Entry {
+                title: "Quick Sort (Lomuto)",
+                id: bevy_quicksort_lomuto::ID,
+                plugin: bevy_quicksort_lomuto::plugin,
+            },
+            Entry {
+                title: "Quick Sort (Hoare)",
+                id: bevy_quicksort_hoare::ID,
+                plugin: bevy_quicksort_hoare::plugin,
+            },
+            Entry {
+                title: "Heap Sort",
+                id: bevy_heap_sort::ID,
+                plugin: bevy_heap_sort::plugin,
+            },
+            Entry {
+                title: "Counting Sort",
+                id: bevy_counting_sort::ID,
+                plugin: bevy_counting_sort::plugin,
+            },
+            Entry {
+                title: "Radix Sort (LSD)",
+                id: bevy_radix_lsd::ID,
+                plugin: bevy_radix_lsd::plugin,
+            },
+            Entry {
+                title: "Bucket Sort",
+                id: bevy_bucket_sort::ID,
+                plugin: bevy_bucket_sort::plugin,
+            },
+            Entry {
+                title: "Dutch National Flag",
+                id: bevy_dnf::ID,
+                plugin: bevy_dnf::plugin,
+            },
+            Entry {
+                title: "Pancake Sort",
+                id: bevy_pancake_sort::ID,
+                plugin: bevy_pancake_sort::plugin,
+            },
+            Entry {
+                title: "Sorting Race",
+                id: bevy_sort_race::ID,
+                plugin: bevy_sort_race::plugin,
+            },
+        ],
+    ),
+    (
+        "Arrays and Two Pointers",
+        &[
+            Entry {
+                title: "Two Sum",
+                id: bevy_two_sum::ID,
+                plugin: bevy_two_sum::plugin,
+            },
+            Entry {
+                title: "Move Zeros",
+                id: bevy_move_zeros::ID,
+                plugin: bevy_move_zeros::plugin,
+            },
+            Entry {
+                title: "Remove Duplicates",
+                id: bevy_remove_dups::ID,
+                plugin: bevy_remove_dups::plugin,
+            },
+            Entry {
+                title: "Sliding Window Max",
+                id: bevy_sliding_max::ID,
+                plugin: bevy_sliding_max::plugin,
+            },
+            Entry {
+                title: "Longest Substring w/o Repeat",
+                id: bevy_lsw::ID,
+                plugin: bevy_lsw::plugin,
+            },
+            Entry {
+                title: "Container With Most Water",
+                id: bevy_most_water::ID,
+                plugin: bevy_most_water::plugin,
+            },
+            Entry {
+                title: "Three Sum",
+                id: bevy_three_sum::ID,
+                plugin: bevy_three_sum::plugin,
+            },
+            Entry {
+                title: "Interval Merge",
+                id: bevy_interval_merge::ID,
+                plugin: bevy_interval_merge::plugin,
+            },
+            Entry {
+                title: "Insert Interval",
+                id: bevy_insert_interval::ID,
+                plugin: bevy_insert_interval::plugin,
+            },
+            Entry {
+                title: "Rotate Array",
+                id: bevy_rotate_array::ID,
+                plugin: bevy_rotate_array::plugin,
+            },
+        ],
+    ),
+    (
+        "Linked Lists",
+        &[
+            Entry {
+                title: "Reverse Linked List",
+                id: bevy_reverse_ll::ID,
+                plugin: bevy_reverse_ll::plugin,
+            },
+            Entry {
+                title: "Detect Cycle (Floyd)",
+                id: bevy_detect_cycle::ID,
+                plugin: bevy_detect_cycle::plugin,
+            },
+            Entry {
+                title: "Merge Two Sorted Lists",
+                id: bevy_merge_two_lists::ID,
+                plugin: bevy_merge_two_lists::plugin,
+            },
+            Entry {
+                title: "Reorder List",
+                id: bevy_reorder_list::ID,
+                plugin: bevy_reorder_list::plugin,
+            },
+            Entry {
+                title: "Remove Nth From End",
+                id: bevy_remove_nth::ID,
+                plugin: bevy_remove_nth::plugin,
+            },
+            Entry {
+                title: "Add Two Numbers",
+                id: bevy_add_two_numbers::ID,
+                plugin: bevy_add_two_numbers::plugin,
+            },
+            Entry {
+                title: "Sort a Linked List",
+                id: bevy_sort_ll::ID,
+                plugin: bevy_sort_ll::plugin,
+            },
+            Entry {
+                title: "LRU Cache",
+                id: bevy_lru_cache::ID,
+                plugin: bevy_lru_cache::plugin,
+            },
+            Entry {
+                title: "Copy List With Random Pointer",
+                id: bevy_copy_random_pointer::ID,
+                plugin: bevy_copy_random_pointer::plugin,
+            },
+            Entry {
+                title: "K Group Reverse",
+                id: bevy_k_group_reverse::ID,
+                plugin: bevy_k_group_reverse::plugin,
+            },
+        ],
+    ),
+    (
+        "Stacks and Queues",
+        &[
+            Entry {
+                title: "Valid Parentheses",
+                id: bevy_valid_parentheses::ID,
+                plugin: bevy_valid_parentheses::plugin,
+            },
+            Entry {
+                title: "Min Stack",
+                id: bevy_min_stack::ID,
+                plugin: bevy_min_stack::plugin,
+            },
+            Entry {
+                title: "Daily Temperatures",
+                id: bevy_daily_temps::ID,
+                plugin: bevy_daily_temps::plugin,
+            },
+            Entry {
+                title: "Evaluate RPN",
+                id: bevy_rpn::ID,
+                plugin: bevy_rpn::plugin,
+            },
+            Entry {
+                title: "Sliding Window Median",
+                id: bevy_sliding_window_median::ID,
+                plugin: bevy_sliding_window_median::plugin,
+            },
+            Entry {
+                title: "Queue Using Two Stacks",
+                id: bevy_two_stack_queue::ID,
+                plugin: bevy_two_stack_queue::plugin,
+            },
+            Entry {
+                title: "Stack Using Two Queues",
+                id: bevy_two_queue_stack::ID,
+                plugin: bevy_two_queue_stack::plugin,
+            },
+            Entry {
+                title: "Largest Rectangle in Histogram",
+                id: bevy_largest_rect_histogram::ID,
+                plugin: bevy_largest_rect_histogram::plugin,
+            },
+            Entry {
+                title: "Car Fleet",
+                id: bevy_car_fleet::ID,
+                plugin: bevy_car_fleet::plugin,
+            },
+        ],
+    ),
+    (
+        "Trees and Tries",
+        &[
+            Entry {
+                title: "BST Search",
+                id: bevy_bst::ID,
+                plugin: bevy_bst::plugin,
+            },
+            Entry {
+                title: "BST Insert/Delete",
+                id: bevy_bst_insert_delete::ID,
+                plugin: bevy_bst_insert_delete::plugin,
+            },
+            Entry {
+                title: "Validate BST",
+                id: bevy_validate_bst::ID,
+                plugin: bevy_validate_bst::plugin,
+            },
+            Entry {
+                title: "BST Validate (ranges)",
+                id: bevy_bst_validate::ID,
+                plugin: bevy_bst_validate::plugin,
+            },
+            Entry {
+                title: "Level Order Traversal",
+                id: bevy_level_order_traversal::ID,
+                plugin: bevy_level_order_traversal::plugin,
+            },
+            Entry {
+                title: "Level Order BFS",
+                id: bevy_level_order_bfs::ID,
+                plugin: bevy_level_order_bfs::plugin,
+            },
+            Entry {
+                title: "Zigzag Level Order",
+                id: bevy_zigzag_level_order::ID,
+                plugin: bevy_zigzag_level_order::plugin,
+            },
+            Entry {
+                title: "Zigzag Level Order (sketch)",
+                id: bevy_zigzag_level::ID,
+                plugin: bevy_zigzag_level::plugin,
+            },
+            Entry {
+                title: "LCA (BST)",
+                id: bevy_lca_bst::ID,
+                plugin: bevy_lca_bst::plugin,
+            },
+            Entry {
+                title: "LCA (Binary Tree)",
+                id: bevy_lca_binary_tree::ID,
+                plugin: bevy_lca_binary_tree::plugin,
+            },
+            Entry {
+                title: "Serialize Binary Tree",
+                id: bevy_serialize_binary_tree::ID,
+                plugin: bevy_serialize_binary_tree::plugin,
+            },
+            Entry {
+                title: "Trie Insert/Search",
+                id: bevy_trie_insert_search::ID,
+                plugin: bevy_trie_insert_search::plugin,
+            },
+            Entry {
+                title: "Word Search II",
+                id: bevy_word_search_ii::ID,
+                plugin: bevy_word_search_ii::plugin,
+            },
+        ],
+    ),
+    (
+        "Heaps",
+        &[
+            Entry {
+                title: "Binary Heap Push/Pop",
+                id: bevy_binary_heap::ID,
+                plugin: bevy_binary_heap::plugin,
+            },
+            Entry {
+                title: "Kth Largest Element",
+                id: bevy_kth_largest_element::ID,
+                plugin: bevy_kth_largest_element::plugin,
+            },
+            Entry {
+                title: "Top K Frequent",
+                id: bevy_top_k_frequent::ID,
+                plugin: bevy_top_k_frequent::plugin,
+            },
+            Entry {
+                title: "Merge K Sorted Lists",
+                id: bevy_merge_k_sorted_lists::ID,
+                plugin: bevy_merge_k_sorted_lists::plugin,
+            },
+            Entry {
+                title: "Task Scheduler",
+                id: bevy_task_scheduler::ID,
+                plugin: bevy_task_scheduler::plugin,
+            },
+        ],
+    ),
+    (
+        "Graphs",
+        &[
+            Entry {
+                title: "BFS (grid)",
+                id: bevy_bfs::ID,
+                plugin: bevy_bfs::plugin,
+            },
+            Entry {
+                title: "DFS (grid)",
+                id: bevy_dfs_grid::ID,
+                plugin: bevy_dfs_grid::plugin,
+            },
+            Entry {
+                title: "Connected Components",
+                id: bevy_connected_components::ID,
+                plugin: bevy_connected_components::plugin,
+            },
+            Entry {
+                title: "Directed Cycle Detection",
+                id: bevy_directed_cycle::ID,
+                plugin: bevy_directed_cycle::plugin,
+            },
+            Entry {
+                title: "Topological Sort (Kahn)",
+                id: bevy_toposort_kahn::ID,
+                plugin: bevy_toposort_kahn::plugin,
+            },
+            Entry {
+                title: "Dijkstra (grid)",
+                id: bevy_dijkstra_grid::ID,
+                plugin: bevy_dijkstra_grid::plugin,
+            },
+            Entry {
+                title: "Bellman-Ford",
+                id: bevy_bellman_ford::ID,
+                plugin: bevy_bellman_ford::plugin,
+            },
+            Entry {
+                title: "Floyd-Warshall",
+                id: bevy_floyd_warshall::ID,
+                plugin: bevy_floyd_warshall::plugin,
+            },
+            Entry {
+                title: "A* Pathfinding",
+                id: bevy_a_star::ID,
+                plugin: bevy_a_star::plugin,
+            },
+            Entry {
+                title: "Kruskal MST",
+                id: bevy_kruskal::ID,
+                plugin: bevy_kruskal::plugin,
+            },
+            Entry {
+                title: "Prim MST",
+                id: bevy_prim::ID,
+                plugin: bevy_prim::plugin,
+            },
+            Entry {
+                title: "Union-Find",
+                id: bevy_union_find::ID,
+                plugin: bevy_union_find::plugin,
+            },
+        ],
+    ),
+    (
+        "Greedy",
+        &[
+            Entry {
+                title: "Activity Selection",
+                id: bevy_activity_selection::ID,
+                plugin: bevy_activity_selection::plugin,
+            },
+            Entry {
+                title: "Interval Scheduling",
+                id: bevy_interval_scheduling::ID,
+                plugin: bevy_interval_scheduling::plugin,
+            },
+            Entry {
+                title: "Gas Station",
+                id: bevy_gas_station::ID,
+                plugin: bevy_gas_station::plugin,
+            },
+            Entry {
+                title: "Jump Game II",
+                id: bevy_jump_game_ii::ID,
+                plugin: bevy_jump_game_ii::plugin,
+            },
+            Entry {
+                title: "Huffman Coding",
+                id: bevy_huffman::ID,
+                plugin: bevy_huffman::plugin,
+            },
+        ],
+    ),
+    (
+        "Dynamic Programming",
+        &[
+            Entry {
+                title: "Fibonacci",
+                id: bevy_fibonacci_dp::ID,
+                plugin: bevy_fibonacci_dp::plugin,
+            },
+            Entry {
+                title: "Climbing Stairs",
+                id: bevy_climbing_stairs::ID,
+                plugin: bevy_climbing_stairs::plugin,
+            },
+            Entry {
+                title: "Coin Change",
+                id: bevy_coin_change::ID,
+                plugin: bevy_coin_change::plugin,
+            },
+            Entry {
+                title: "Longest Increasing Subsequence",
+                id: bevy_longest_increasing_subsequence::ID,
+                plugin: bevy_longest_increasing_subsequence::plugin,
+            },
+            Entry {
+                title: "Edit Distance",
+                id: bevy_edit_distance::ID,
+                plugin: bevy_edit_distance::plugin,
+            },
+            Entry {
+                title: "0/1 Knapsack",
+                id: bevy_knapsack::ID,
+                plugin: bevy_knapsack::plugin,
+            },
+            Entry {
+                title: "Palindromic Substrings",
+                id: bevy_palindromic_substrings::ID,
+                plugin: bevy_palindromic_substrings::plugin,
+            },
+            Entry {
+                title: "Longest Palindromic Subsequence",
+                id: bevy_longest_palindromic_subsequence::ID,
+                plugin: bevy_longest_palindromic_subsequence::plugin,
+            },
+            Entry {
+                title: "Matrix Chain Multiplication",
+                id: bevy_matrix_chain_multiplication::ID,
+                plugin: bevy_matrix_chain_multiplication::plugin,
+            },
+            Entry {
+                title: "Word Break",
+                id: bevy_word_break::ID,
+                plugin: bevy_word_break::plugin,
+            },
+        ],
+    ),
+    (
+        "Backtracking",
+        &[
+            Entry {
+                title: "Subsets",
+                id: bevy_subsets::ID,
+                plugin: bevy_subsets::plugin,
+            },
+            Entry {
+                title: "Permutations",
+                id: bevy_permutations::ID,
+                plugin: bevy_permutations::plugin,
+            },
+            Entry {
+                title: "Combination Sum",
+                id: bevy_combination_sum::ID,
+                plugin: bevy_combination_sum::plugin,
+            },
+            Entry {
+                title: "Generate Parentheses",
+                id: bevy_generate_parentheses::ID,
+                plugin: bevy_generate_parentheses::plugin,
+            },
+            Entry {
+                title: "N-Queens",
+                id: bevy_n_queens::ID,
+                plugin: bevy_n_queens::plugin,
+            },
+            Entry {
+                title: "Sudoku Solver",
+                id: bevy_sudoku_solver::ID,
+                plugin: bevy_sudoku_solver::plugin,
+            },
+        ],
+    ),
+    (
+        "Geometry and Math",
+        &[
+            Entry {
+                title: "Convex Hull",
+                id: bevy_convex_hull::ID,
+                plugin: bevy_convex_hull::plugin,
+            },
+            Entry {
+                title: "Line Sweep Intersections",
+                id: bevy_line_sweep::ID,
+                plugin: bevy_line_sweep::plugin,
+            },
+            Entry {
+                title: "K-D Tree",
+                id: bevy_kd_tree::ID,
+                plugin: bevy_kd_tree::plugin,
+            },
+            Entry {
+                title: "FFT",
+                id: bevy_fft::ID,
+                plugin: bevy_fft::plugin,
+            },
+        ],
+    ),
+    (
+        "Strings and Range Queries",
+        &[
+            Entry {
+                title: "KMP String Search",
+                id: bevy_kmp::ID,
+                plugin: bevy_kmp::plugin,
+            },
+            Entry {
+                title: "Rabin-Karp",
+                id: bevy_rabin_karp::ID,
+                plugin: bevy_rabin_karp::plugin,
+            },
+            Entry {
+                title: "Boyer-Moore Majority Vote",
+                id: bevy_boyer_moore::ID,
+                plugin: bevy_boyer_moore::plugin,
+            },
+            Entry {
+                title: "Segment Tree",
+                id: bevy_segment_tree::ID,
+                plugin: bevy_segment_tree::plugin,
+            },
+        ],
+    ),
+];
+
+/// Which viz a menu button opens.
+#[derive(Component)]
+struct MenuEntry {
+    title: &'static str,
+    id: &'static str,
+}
+
+/// Everything the menu spawns, despawned when a viz is opened.
+#[derive(Component)]
+struct MenuScreen;
+
+fn main() {
+    if std::env::args().len() > 1 {
+        eprintln!("usage: algoviz-gallery\n(the gallery takes no arguments; run a viz's own binary to pass it --input, --scenario or --headless)");
+        std::process::exit(2);
+    }
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            title: TITLE.into(),
+            resolution: (1280.0, 860.0).into(),
+            resizable: true,
+            ..default()
+        }),
+        ..default()
+    }))
+    .init_state::<Gallery>()
+    .add_systems(OnEnter(Gallery::Menu), spawn_menu)
+    .add_systems(OnExit(Gallery::Menu), despawn_menu)
+    .add_systems(
+        Update,
+        (open_viz.run_if(in_state(Gallery::Menu)), back_to_menu),
+    );
+    for (_, entries) in CATALOG {
+        for entry in *entries {
+            (entry.plugin)(&mut app);
+        }
+    }
+    app.run();
+}
+
+fn spawn_menu(mut commands: Commands, mut windows: Query<&mut Window>) {
+    // A viz leaves its clear color and lighting behind; the menu starts clean.
+    commands.insert_resource(ClearColor(BACKGROUND));
+    commands.insert_resource(AmbientLight::default());
+    if let Ok(mut window) = windows.get_single_mut() {
+        window.title = TITLE.into();
+    }
+    commands.spawn((Camera2dBundle::default(), MenuScreen));
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(16.0)),
+                    row_gap: Val::Px(12.0),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+            MenuScreen,
+        ))
+        .with_children(|root| {
+            root.spawn(TextBundle::from_section(
+                TITLE,
+                TextStyle {
+                    font_size: 30.0,
+                    ..default()
+                },
+            ));
+            root.spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    column_gap: Val::Px(18.0),
+                    row_gap: Val::Px(14.0),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|columns| {
+                for (section, entries) in CATALOG {
+                    columns
+                        .spawn(NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(3.0),
+                                width: Val::Px(220.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            column.spawn(TextBundle::from_section(
+                                *section,
+                                TextStyle {
+                                    font_size: 17.0,
+                                    color: Color::srgb(0.95, 0.8, 0.4),
+                                    ..default()
+                                },
+                            ));
+                            for entry in *entries {
+                                column
+                                    .spawn((
+                                        ButtonBundle {
+                                            style: Style {
+                                                padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                                                ..default()
+                                            },
+                                            background_color: BUTTON.into(),
+                                            ..default()
+                                        },
+                                        MenuEntry {
+                                            title: entry.title,
+                                            id: entry.id,
+                                        },
+                                    ))
+                                    .with_children(|button| {
+                                        button.spawn(TextBundle::from_section(
+                                            entry.title,
+                                            TextStyle {
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                        ));
+                                    });
+                            }
+                        });
+                }
+            });
+            root.spawn(TextBundle::from_section(
+                "Click a visualization to open it; Esc returns to this menu.",
+                TextStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.7, 0.7, 0.75),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn despawn_menu(mut commands: Commands, screens: Query<Entity, With<MenuScreen>>) {
+    for entity in &screens {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn open_viz(
+    mut buttons: Query<(&Interaction, &MenuEntry, &mut BackgroundColor), Changed<Interaction>>,
+    mut next: ResMut<NextState<Gallery>>,
+    mut windows: Query<&mut Window>,
+) {
+    for (interaction, entry, mut color) in &mut buttons {
+        match interaction {
+            Interaction::Pressed => {
+                next.set(Gallery::Viz(entry.id));
+                if let Ok(mut window) = windows.get_single_mut() {
+                    window.title = format!("{TITLE}: {}", entry.title);
+                }
+            }
+            Interaction::Hovered => *color = BUTTON_HOVER.into(),
+            Interaction::None => *color = BUTTON.into(),
+        }
+    }
+}
+
+fn back_to_menu(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<Gallery>>,
+    mut next: ResMut<NextState<Gallery>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) && *state.get() != Gallery::Menu {
+        next.set(Gallery::Menu);
+    }
+}
@@ -0,0 +1,185 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub const TITLE: &str = "A* Pathfinding";
+const BG_COLOR: Color = Color::srgb(0.02, 0.04, 0.07);
+
+#[derive(Component)]
+struct GridCell {
+    row: usize,
+    col: usize,
+}
+
+impl GridTile for GridCell {
+    fn cell(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "a-star";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let grid = match GridScenario::from_args_checked(GridScenario::open_endpoints) {
+        Some(scenario) => PathGrid::from_scenario(&scenario, false),
+        None => PathGrid::from_walls(&sample_grid(), (0, 0), (7, 7)),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(grid)
+        .grid_editor::<GridCell>(GridBrush::Walls)
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_grid() -> Vec<Vec<bool>> {
+    vec![
+        vec![false, false, false, true, false, false, false, false],
+        vec![false, true, false, true, false, true, false, false],
+        vec![false, false, false, false, false, true, false, false],
+        vec![true, true, false, true, false, false, false, false],
+        vec![false, false, false, false, false, true, false, false],
+        vec![false, true, false, true, false, false, false, false],
+        vec![false, false, false, false, false, true, false, false],
+        vec![false, false, false, true, false, false, false, false],
+    ]
+}
+
+fn a_star_path(
+    grid: &[Vec<bool>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Reverse((heuristic(start, goal), 0, start))); // (f, g, pos)
+
+    let mut came_from = std::collections::HashMap::new();
+    let mut g_score = std::collections::HashMap::new();
+    g_score.insert(start, 0);
+
+    while let Some(Reverse((_, g, current))) = open_set.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut current = current;
+            while let Some(&prev) = came_from.get(&current) {
+                path.push(prev);
+                current = prev;
+            }
+            path.reverse();
+            return path;
+        }
+
+        let dirs = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        for (dr, dc) in dirs {
+            let nr = current.0 as i32 + dr;
+            let nc = current.1 as i32 + dc;
+            if nr >= 0 && nr < rows as i32 && nc >= 0 && nc < cols as i32 {
+                let neighbor = (nr as usize, nc as usize);
+                if !grid[neighbor.0][neighbor.1] {
+                    let tentative_g = g + 1;
+                    let existing_g = *g_score.get(&neighbor).unwrap_or(&i32::MAX);
+                    if tentative_g < existing_g {
+                        came_from.insert(neighbor, current);
+                        g_score.insert(neighbor, tentative_g);
+                        let f = tentative_g + heuristic(neighbor, goal);
+                        open_set.push(Reverse((f, tentative_g, neighbor)));
+                    }
+                }
+            }
+        }
+    }
+    vec![] // no path
+}
+
+fn heuristic(a: (usize, usize), b: (usize, usize)) -> i32 {
+    (a.0 as i32 - b.0 as i32).abs() + (a.1 as i32 - b.1 as i32).abs()
+}
+
+fn setup(mut commands: Commands, grid: Res<PathGrid>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let rows = grid.rows();
+    let cols = grid.cols();
+    let tile = (640.0 / rows.max(cols) as f32).min(60.0);
+    let origin = Vec2::new(
+        -(cols as f32 - 1.0) * tile / 2.0,
+        (rows as f32 - 1.0) * tile / 2.0,
+    );
+
+    // colors and labels come from redraw
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = origin.x + col as f32 * tile;
+            let y = origin.y - row as f32 * tile;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::new(tile - 6.0, tile - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                GridCell { row, col },
+                SegmentText::from_section(
+                    "",
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+        }
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "A* Pathfinding: Heuristic-guided search with priority queue\nYellow: Start, Red: Goal, Green: Path, Gray: Walls",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+// searches again whenever the grid was edited (or first shown) or the theme changed
+fn redraw(
+    grid: Res<PathGrid>,
+    palette: Res<Palette>,
+    mut cells: Query<(&GridCell, &mut Sprite, &mut SegmentText)>,
+) {
+    if !grid.is_changed() && !palette.is_changed() {
+        return;
+    }
+    let path = a_star_path(&grid.walls(), grid.start, grid.goal);
+    for (cell, mut sprite, mut text) in cells.iter_mut() {
+        let at = (cell.row, cell.col);
+        let is_wall = grid.wall(at);
+        sprite.color = if is_wall {
+            Color::srgb(0.3, 0.3, 0.3)
+        } else if at == grid.start {
+            palette.compare
+        } else if at == grid.goal {
+            palette.violation
+        } else if path.contains(&at) {
+            palette.settled
+        } else {
+            Color::srgb(0.5, 0.5, 0.5)
+        };
+        let label = if is_wall { "W" } else { "" };
+        if text.sections[0].value != label {
+            text.sections[0].value = label.to_string();
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const BAR_HEIGHT: f32 = 40.0;
+const BAR_GAP: f32 = 20.0;
+const TIMELINE_WIDTH: f32 = 800.0;
+const STEP_INTERVAL: f32 = 1.5;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct Activity {
+    id: usize,
+    start: usize,
+    end: usize,
+    selected: bool,
+}
+
+#[derive(Component)]
+struct ActivityBar;
+
+#[derive(Resource, Clone)]
+struct AppState {
+    activities: Vec<(usize, usize)>, // (start, end)
+    selected: Vec<usize>,            // indices of selected activities
+    current: usize,                  // current activity being considered
+    done: bool,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "activity-selection";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL).manual())
+        .resource(ClearColor(Color::srgb(0.03, 0.04, 0.07)))
+        .resource(AppState {
+            activities: Vec::new(),
+            selected: Vec::new(),
+            current: 0,
+            done: false,
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_system.in_set(VizSet::Step),
+                update_visualization.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn sample_activities(rng: &mut impl Rng) -> Vec<(usize, usize)> {
+    let mut activities = vec![
+        (1, 4),
+        (3, 5),
+        (0, 6),
+        (5, 7),
+        (3, 9),
+        (5, 9),
+        (6, 10),
+        (8, 11),
+    ];
+    activities.shuffle(rng);
+    activities
+}
+
+fn activity_selection(activities: &[(usize, usize)]) -> Vec<usize> {
+    let mut sorted = activities.iter().enumerate().collect::<Vec<_>>();
+    sorted.sort_by_key(|&(_, &(_, end))| end);
+
+    let mut selected = Vec::new();
+    let mut last_end = 0;
+
+    for (idx, &(start, end)) in sorted {
+        if start >= last_end {
+            selected.push(idx);
+            last_end = end;
+        }
+    }
+
+    selected
+}
+
+fn setup(mut commands: Commands, mut state: ResMut<AppState>, mut rng: ResMut<VizRng>) {
+    commands.spawn(Camera2dBundle::default());
+    state.activities = sample_activities(&mut rng.0);
+
+    let timeline_y = 0.0;
+    let max_time = 12;
+
+    // Draw timeline
+    commands.spawn(SpriteBundle {
+        sprite: Sprite {
+            color: Color::srgb(0.5, 0.5, 0.5),
+            custom_size: Some(Vec2::new(TIMELINE_WIDTH, 4.0)),
+            ..default()
+        },
+        transform: Transform::from_xyz(0.0, timeline_y, -0.1),
+        ..default()
+    });
+
+    // Timeline labels
+    for i in 0..=max_time {
+        let x = -TIMELINE_WIDTH / 2.0 + (i as f32 / max_time as f32) * TIMELINE_WIDTH;
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("{}", i),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.8, 0.8, 0.8),
+                },
+            ),
+            transform: Transform::from_xyz(x, timeline_y - 30.0, 0.0),
+            ..default()
+        });
+    }
+
+    // Activity bars
+    for (i, &(start, end)) in state.activities.iter().enumerate() {
+        let start_x = -TIMELINE_WIDTH / 2.0 + (start as f32 / max_time as f32) * TIMELINE_WIDTH;
+        let end_x = -TIMELINE_WIDTH / 2.0 + (end as f32 / max_time as f32) * TIMELINE_WIDTH;
+        let width = end_x - start_x;
+        let y = timeline_y + 60.0 + i as f32 * (BAR_HEIGHT + BAR_GAP);
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.25, 0.55, 0.95),
+                    custom_size: Some(Vec2::new(width, BAR_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(start_x + width / 2.0, y, 0.0),
+                ..default()
+            },
+            ActivityBar,
+            Activity {
+                id: i,
+                start,
+                end,
+                selected: false,
+            },
+        ));
+
+        // Activity label
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("A{}: {}-{}", i, start, end),
+                SegmentStyle {
+                    font_size: 18.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            transform: Transform::from_xyz(start_x + width / 2.0, y, 1.0),
+            ..default()
+        });
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Activity Selection: Greedy algorithm selects non-overlapping activities\nPress Space to start, R to reset",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn input_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<AppState>,
+    mut rng: ResMut<VizRng>,
+) {
+    let restart = state.done && keyboard.clear_just_pressed(KeyCode::Space);
+    if restart || reset.read().count() > 0 {
+        state.activities = sample_activities(&mut rng.0);
+        state.selected.clear();
+        state.current = 0;
+        state.done = false;
+    }
+}
+
+fn step_system(settings: Res<Settings>, mut state: ResMut<AppState>) {
+    if state.done || !settings.step_ready() {
+        return;
+    }
+
+    if state.current < state.activities.len() {
+        // Simulate the greedy selection
+        let selected = activity_selection(&state.activities);
+        state.selected = selected;
+        state.done = true;
+    }
+}
+
+fn update_visualization(
+    state: Res<AppState>,
+    mut query: Query<(&Activity, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (activity, mut sprite) in query.iter_mut() {
+        if state.selected.contains(&activity.id) {
+            sprite.color = palette.settled; // Selected
+        } else if activity.id == state.current {
+            sprite.color = palette.compare; // Current
+        } else {
+            sprite.color = palette.visited; // Not selected
+        }
+    }
+}
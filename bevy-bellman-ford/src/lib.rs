@@ -0,0 +1,253 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Bellman-Ford";
+const BG_COLOR: Color = Color::srgb(0.03, 0.03, 0.08);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphNode {
+    id: usize,
+    distance: i32,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    weight: i32,
+    relaxed: bool,
+}
+
+/// Everything redraw spawns, cleared when the graph changes.
+#[derive(Component)]
+struct Drawn;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bellman-ford";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let graph = match GraphScenario::from_args() {
+        Some(scenario) => Graph::from_scenario(&scenario, EdgeWeights::Signed),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(graph)
+        .graph_editor()
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_graph() -> Graph {
+    let edges = [
+        (0, 1, 4),
+        (0, 2, 3),
+        (1, 2, -1),
+        (1, 3, 2),
+        (2, 4, 2),
+        (3, 4, 3),
+        (4, 1, -5), // negative cycle 1->4->1
+    ];
+    Graph::directed(sample_positions(), edges, EdgeWeights::Signed)
+}
+
+fn sample_positions() -> Vec<Vec2> {
+    vec![
+        Vec2::new(-200.0, 100.0),
+        Vec2::new(0.0, 150.0),
+        Vec2::new(200.0, 100.0),
+        Vec2::new(-100.0, -50.0),
+        Vec2::new(100.0, -100.0),
+    ]
+}
+
+/// Distances from `source` (`i32::MAX` if unreachable) and whether a
+/// negative cycle is reachable.
+fn bellman_ford(graph: &[Vec<(usize, i32)>], source: usize) -> (Vec<i32>, bool) {
+    let mut distances = vec![i32::MAX; graph.len()];
+    if source >= graph.len() {
+        return (distances, false);
+    }
+    distances[source] = 0;
+
+    let edges: Vec<(usize, usize, i32)> = graph
+        .iter()
+        .enumerate()
+        .flat_map(|(from, neighbors)| {
+            neighbors
+                .iter()
+                .map(move |&(to, weight)| (from, to, weight))
+        })
+        .collect();
+
+    for _ in 1..graph.len() {
+        // V-1 iterations
+        for &(u, v, w) in &edges {
+            if distances[u] != i32::MAX && distances[u] + w < distances[v] {
+                distances[v] = distances[u] + w;
+            }
+        }
+    }
+
+    // Check for negative cycle
+    let negative_cycle = edges
+        .iter()
+        .any(|&(u, v, w)| distances[u] != i32::MAX && distances[u] + w < distances[v]);
+
+    (distances, negative_cycle)
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+// runs the algorithm again and redraws whenever the graph was edited (or first shown) or the theme changed
+fn redraw(
+    mut commands: Commands,
+    graph: Res<Graph>,
+    palette: Res<Palette>,
+    drawn: Query<Entity, With<Drawn>>,
+) {
+    if !graph.is_changed() && !palette.is_changed() {
+        return;
+    }
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let (distances, negative_cycle) = bellman_ford(&graph.adjacency(), graph.source);
+    let positions = graph.positions();
+
+    // Nodes
+    for (id, &pos) in positions.iter().enumerate() {
+        let distance = if negative_cycle {
+            "Cycle!".to_string()
+        } else if distances[id] == i32::MAX {
+            "inf".to_string()
+        } else {
+            format!("{}", distances[id])
+        };
+        let color = if id == graph.source {
+            palette.compare
+        } else {
+            palette.visited
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            GraphNode {
+                id,
+                distance: distances[id],
+            },
+            SegmentText::from_section(
+                format!("{}\n{}", id, distance),
+                SegmentStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            Drawn,
+        ));
+    }
+
+    // Edges
+    for &(from, to, weight) in graph.edges() {
+        let start = positions[from];
+        let end = positions[to];
+        let dir = (end - start).normalize_or_zero();
+        let length = (end - start).length();
+        let midpoint = start + dir * length / 2.0;
+        let angle = dir.y.atan2(dir.x);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.7, 0.7, 0.7),
+                    custom_size: Some(Vec2::new((length - 60.0).max(0.0), 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            GraphEdge {
+                from,
+                to,
+                weight,
+                relaxed: false,
+            },
+            Drawn,
+        ));
+        // Arrow head
+        if graph.is_directed() {
+            let arrow_pos = end - dir * 30.0;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgb(0.7, 0.7, 0.7),
+                        custom_size: Some(Vec2::new(20.0, 20.0)),
+                        ..default()
+                    },
+                    transform: Transform {
+                        translation: Vec3::new(arrow_pos.x, arrow_pos.y, -0.05),
+                        rotation: Quat::from_rotation_z(angle),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Drawn,
+            ));
+        }
+        // Weight label
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("{}", weight),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                transform: Transform::from_xyz(midpoint.x, midpoint.y + 20.0, 1.0),
+                ..default()
+            },
+            Drawn,
+        ));
+    }
+
+    // Instructions
+    let cycle_text = if negative_cycle {
+        "Negative cycle detected!"
+    } else {
+        "No negative cycle"
+    };
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!(
+                    "Bellman-Ford: Edge relaxation for negative weights\n{}",
+                    cycle_text
+                ),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, -350.0, 0.0),
+            ..default()
+        },
+        Drawn,
+    ));
+}
@@ -0,0 +1,531 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+const GRID_W: usize = 24;
+const GRID_H: usize = 16;
+const CELL: f32 = 28.0;
+const GAP: f32 = 2.0;
+const STEP_INTERVAL: f32 = 0.05;
+const START_COLOR: Color = Color::srgb(1.0, 0.85, 0.0);
+const GOAL_COLOR: Color = Color::srgb(0.95, 0.25, 0.2);
+// the order BFS enqueues neighbors in (y grows upward), and what the quiz calls them
+const DIRS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DIR_NAMES: [&str; 4] = [
+    "bfs.quiz.right",
+    "bfs.quiz.left",
+    "bfs.quiz.up",
+    "bfs.quiz.down",
+];
+// queue entries the code panel lists before summing up the rest
+const QUEUE_SHOWN: usize = 4;
+const PSEUDOCODE: Listing = Listing::new(
+    "code.pseudocode",
+    &[
+        ("", "procedure bfs(start, goal)"),
+        ("", "  queue = [start]; mark start"),
+        ("", "  while queue is not empty"),
+        ("pop", "    cell = queue.pop_front()"),
+        ("goal", "    if cell == goal: return path"),
+        ("", "    for next in neighbors(cell)"),
+        ("", "      if next is open and unmarked"),
+        ("push", "        mark next; queue.push_back(next)"),
+        ("none", "  return no path"),
+    ],
+);
+const RUST: Listing = Listing::new(
+    "code.rust",
+    &[
+        ("", "fn bfs(grid: &Grid, start: P2, goal: P2) -> bool {"),
+        ("", "    let mut queue = VecDeque::from([start]);"),
+        ("", "    visited[start] = true;"),
+        ("pop", "    while let Some(cell) = queue.pop_front() {"),
+        ("goal", "        if cell == goal { return true; }"),
+        ("", "        for next in grid.open_neighbors(cell) {"),
+        ("", "            if !visited[next] {"),
+        ("", "                visited[next] = true;"),
+        ("push", "                queue.push_back(next);"),
+        ("", "            }"),
+        ("", "        }"),
+        ("", "    }"),
+        ("none", "    false"),
+        ("", "}"),
+    ],
+);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+}
+
+#[derive(Resource)]
+struct Grid {
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    fn idx(x: usize, y: usize) -> usize {
+        y * GRID_W + x
+    }
+}
+
+#[derive(Component)]
+struct Tile {
+    x: usize,
+    y: usize,
+}
+
+// rows of the PathGrid run top to bottom, y upward
+impl GridTile for Tile {
+    fn cell(&self) -> (usize, usize) {
+        (GRID_H - 1 - self.y, self.x)
+    }
+}
+
+fn p2((row, col): (usize, usize)) -> P2 {
+    P2 {
+        x: col as i32,
+        y: (GRID_H - 1 - row) as i32,
+    }
+}
+
+/// The search's view of the edited grid.
+fn cells_of(path_grid: &PathGrid) -> Vec<Cell> {
+    let mut cells = vec![Cell::Empty; GRID_W * GRID_H];
+    for y in 0..GRID_H {
+        for x in 0..GRID_W {
+            if path_grid.wall((GRID_H - 1 - y, x)) {
+                cells[Grid::idx(x, y)] = Cell::Wall;
+            }
+        }
+    }
+    cells
+}
+
+#[derive(Default, Clone, Copy)]
+struct P2 {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Resource, Default)]
+struct BfsState {
+    goal: P2,
+    queue: Vec<P2>,
+    visited: Vec<bool>,
+    parent: Vec<i32>, // stores parent index (flat), -1 for none
+    current: Option<P2>,
+    done: bool,
+    reconstruct: bool,
+}
+
+impl BfsState {
+    fn new(start: P2, goal: P2) -> Self {
+        let mut visited = vec![false; GRID_W * GRID_H];
+        visited[Grid::idx(start.x as usize, start.y as usize)] = true;
+        Self {
+            goal,
+            queue: vec![start],
+            visited,
+            parent: vec![-1; GRID_W * GRID_H],
+            current: None,
+            done: false,
+            reconstruct: false,
+        }
+    }
+
+    /// The open, unvisited neighbors of `at` in the order BFS enqueues them, with their `DIRS` index.
+    fn unvisited_neighbors(&self, grid: &Grid, at: P2) -> Vec<(usize, P2)> {
+        DIRS.iter()
+            .enumerate()
+            .filter_map(|(dir, &(dx, dy))| {
+                let nx = at.x + dx;
+                let ny = at.y + dy;
+                if nx < 0 || ny < 0 || nx >= GRID_W as i32 || ny >= GRID_H as i32 {
+                    return None;
+                }
+                let nidx = Grid::idx(nx as usize, ny as usize);
+                (grid.cells[nidx] == Cell::Empty && !self.visited[nidx])
+                    .then_some((dir, P2 { x: nx, y: ny }))
+            })
+            .collect()
+    }
+
+    /// Flat indices from the goal back to the start, once the goal was reached.
+    fn path(&self) -> Vec<usize> {
+        let mut path = Vec::new();
+        if !self.reconstruct {
+            return path;
+        }
+        let mut cur = Grid::idx(self.goal.x as usize, self.goal.y as usize) as i32;
+        while cur >= 0 {
+            path.push(cur as usize);
+            cur = self.parent[cur as usize];
+        }
+        path
+    }
+}
+
+// where the start and goal sit until dragged elsewhere, as PathGrid (row, col) cells
+const START: (usize, usize) = (GRID_H - 2, 1);
+const GOAL: (usize, usize) = (1, GRID_W - 2);
+
+// markers no longer needed; coloring is state-driven
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bfs";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .quiz()
+        .code_panel(&[PSEUDOCODE, RUST])
+        .resource(PathGrid::open(GRID_H, GRID_W, START, GOAL))
+        .grid_editor::<Tile>(GridBrush::Walls)
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                restart.in_set(VizSet::Input),
+                bfs_step.in_set(VizSet::Step),
+                animate_colors.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut path_grid: ResMut<PathGrid>, mut rng: ResMut<VizRng>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // center origin
+    let total_w = GRID_W as f32 * (CELL + GAP) - GAP;
+    let total_h = GRID_H as f32 * (CELL + GAP) - GAP;
+    let origin = Vec2::new(-total_w / 2.0 + CELL / 2.0, -total_h / 2.0 + CELL / 2.0);
+
+    // grid with random walls, about a fifth of the cells
+    path_grid.generate(Maze::Random, &mut rng.0);
+    let cells = cells_of(&path_grid);
+    commands.insert_resource(Grid {
+        cells: cells.clone(),
+    });
+    commands.insert_resource(BfsState::new(p2(path_grid.start), p2(path_grid.goal)));
+
+    // spawn tiles
+    for y in 0..GRID_H {
+        for x in 0..GRID_W {
+            let (r, g, b) = match cells[Grid::idx(x, y)] {
+                Cell::Wall => (0.15, 0.15, 0.15),
+                Cell::Empty => (0.18, 0.18, 0.22),
+            };
+            let color = Color::srgb(r as f32, g as f32, b as f32);
+            let pos = origin + Vec2::new(x as f32 * (CELL + GAP), y as f32 * (CELL + GAP));
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(CELL, CELL)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                    ..default()
+                },
+                Tile { x, y },
+            ));
+        }
+    }
+}
+
+// any edit of the grid (R draws a new maze) starts the search over; so does the reset button
+fn restart(
+    path_grid: Res<PathGrid>,
+    mut reset: EventReader<ResetRequested>,
+    mut bfs: ResMut<BfsState>,
+    mut grid: ResMut<Grid>,
+    mut quiz: ResMut<Quiz>,
+    mut code: ResMut<CodeCursor>,
+) {
+    if reset.read().count() > 0 || path_grid.is_changed() {
+        grid.cells = cells_of(&path_grid);
+        *bfs = BfsState::new(p2(path_grid.start), p2(path_grid.goal));
+        quiz.reset();
+        code.clear();
+    }
+}
+
+fn bfs_step(
+    mut bfs: ResMut<BfsState>,
+    settings: Res<Settings>,
+    grid: Res<Grid>,
+    mut quiz: ResMut<Quiz>,
+    mut code: ResMut<CodeCursor>,
+    locale: Res<Locale>,
+) {
+    if bfs.done {
+        quiz.finish();
+        return;
+    }
+    if !settings.step_ready() {
+        return;
+    }
+
+    if let Some(current) = bfs.queue.first().cloned() {
+        // record current
+        bfs.current = Some(current);
+        let cur_idx = Grid::idx(current.x as usize, current.y as usize) as i32;
+        // goal reached?
+        if current.x == bfs.goal.x && current.y == bfs.goal.y {
+            bfs.done = true;
+            bfs.reconstruct = true;
+            code.at("goal")
+                .var("cell", format!("({}, {})", current.x, current.y))
+                .var("queue", queue_text(&bfs.queue));
+            return;
+        }
+        let next = bfs.unvisited_neighbors(&grid, current);
+        // in quiz mode the learner predicts which neighbor joins the queue first, when there is a choice
+        if next.len() > 1 && quiz.hold(|| enqueue_question(current, next[0].0, &locale)) {
+            return;
+        }
+        // pop front
+        bfs.queue.remove(0);
+        let added = next.len();
+        for (_, p) in next {
+            let nidx = Grid::idx(p.x as usize, p.y as usize);
+            bfs.visited[nidx] = true;
+            bfs.parent[nidx] = cur_idx;
+            bfs.queue.push(p);
+        }
+        code.at(if added > 0 { "push" } else { "pop" })
+            .var("cell", format!("({}, {})", current.x, current.y))
+            .var("added", added)
+            .var("queue", queue_text(&bfs.queue));
+    } else {
+        bfs.done = true; // nothing to explore
+        code.at("none");
+    }
+}
+
+/// The queue's front entries for the code panel, and how many more wait behind them.
+fn queue_text(queue: &[P2]) -> String {
+    let front: Vec<String> = queue
+        .iter()
+        .take(QUEUE_SHOWN)
+        .map(|p| format!("({}, {})", p.x, p.y))
+        .collect();
+    match queue
+        .len()
+        .checked_sub(QUEUE_SHOWN)
+        .filter(|&more| more > 0)
+    {
+        Some(more) => format!("[{} +{more}]", front.join(" ")),
+        None => format!("[{}]", front.join(" ")),
+    }
+}
+
+/// Quiz mode's question on expanding `at`, whose first new neighbor lies in direction `first`.
+fn enqueue_question(at: P2, first: usize, locale: &Locale) -> Question {
+    Question::new(
+        tr!(locale, "bfs.quiz.prompt", x = at.x, y = at.y),
+        DIR_NAMES.map(|name| locale.text(name)),
+        first,
+    )
+}
+
+fn animate_colors(
+    bfs: Res<BfsState>,
+    grid: Res<Grid>,
+    path_grid: Res<PathGrid>,
+    mut tiles: Query<(&Tile, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    let path = bfs.path();
+    for (tile, mut sprite) in tiles.iter_mut() {
+        let idx = Grid::idx(tile.x, tile.y);
+        match grid.cells[idx] {
+            Cell::Wall => {
+                sprite.color = Color::srgb(0.15, 0.15, 0.15);
+            }
+            Cell::Empty => {
+                let mut color = Color::srgb(0.18, 0.18, 0.22);
+                if bfs.visited[idx] {
+                    color = palette.visited;
+                } // visited = blue
+                if bfs
+                    .queue
+                    .iter()
+                    .any(|p| p.x == tile.x as i32 && p.y == tile.y as i32)
+                {
+                    color = palette.settled;
+                } // frontier = green
+                if let Some(cur) = bfs.current {
+                    if cur.x == tile.x as i32 && cur.y == tile.y as i32 {
+                        color = palette.focus;
+                    }
+                } // current = white
+                  // reconstructed path from goal back to start = yellow
+                if path.contains(&idx) {
+                    color = palette.compare;
+                }
+                // the handles the editor drags
+                if tile.cell() == path_grid.start {
+                    color = START_COLOR;
+                }
+                if tile.cell() == path_grid.goal {
+                    color = GOAL_COLOR;
+                }
+                sprite.color = color;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algoviz_core::{run_until, test_app};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::VecDeque;
+
+    fn idx(cell: (usize, usize)) -> usize {
+        let p = p2(cell);
+        Grid::idx(p.x as usize, p.y as usize)
+    }
+
+    /// Steps to the goal by a plain queue BFS, independent of the viz.
+    fn shortest(cells: &[Cell], start: usize, goal: usize) -> Option<usize> {
+        let mut dist = vec![usize::MAX; cells.len()];
+        dist[start] = 0;
+        let mut queue = VecDeque::from([start]);
+        while let Some(i) = queue.pop_front() {
+            let (x, y) = (i % GRID_W, i / GRID_W);
+            let mut next = Vec::new();
+            if x > 0 {
+                next.push(i - 1);
+            }
+            if x + 1 < GRID_W {
+                next.push(i + 1);
+            }
+            if y > 0 {
+                next.push(i - GRID_W);
+            }
+            if y + 1 < GRID_H {
+                next.push(i + GRID_W);
+            }
+            for j in next {
+                if cells[j] == Cell::Empty && dist[j] == usize::MAX {
+                    dist[j] = dist[i] + 1;
+                    queue.push_back(j);
+                }
+            }
+        }
+        (dist[goal] != usize::MAX).then_some(dist[goal])
+    }
+
+    fn run_bfs(path_grid: &PathGrid) -> BfsState {
+        let mut app = test_app(STEP_INTERVAL);
+        app.insert_resource(Grid {
+            cells: cells_of(path_grid),
+        })
+        .insert_resource(BfsState::new(p2(path_grid.start), p2(path_grid.goal)))
+        .add_systems(Update, bfs_step.in_set(VizSet::Step));
+        // one cell per step, two frames per step
+        run_until(&mut app, 4 * GRID_W * GRID_H, |world| {
+            world.resource::<BfsState>().done
+        });
+        app.world_mut().remove_resource::<BfsState>().unwrap()
+    }
+
+    fn assert_shortest(path_grid: &PathGrid, bfs: &BfsState) {
+        let cells = cells_of(path_grid);
+        let (start, goal) = (idx(path_grid.start), idx(path_grid.goal));
+        let path = bfs.path();
+        match shortest(&cells, start, goal) {
+            Some(steps) => {
+                assert_eq!(path.len(), steps + 1);
+                assert_eq!(path[0], goal);
+                assert_eq!(*path.last().unwrap(), start);
+                for w in path.windows(2) {
+                    let (a, b) = (w[0], w[1]);
+                    let adjacent =
+                        a.abs_diff(b) == GRID_W || (a.abs_diff(b) == 1 && a / GRID_W == b / GRID_W);
+                    assert!(adjacent && cells[a] == Cell::Empty, "{a} -> {b}");
+                }
+            }
+            None => assert!(path.is_empty() && !bfs.reconstruct),
+        }
+    }
+
+    #[test]
+    fn open_grid_path_is_manhattan() {
+        let path_grid = PathGrid::open(GRID_H, GRID_W, START, GOAL);
+        let bfs = run_bfs(&path_grid);
+        assert_eq!(bfs.path().len(), START.0 - GOAL.0 + GOAL.1 - START.1 + 1);
+        assert_shortest(&path_grid, &bfs);
+    }
+
+    #[test]
+    fn walled_off_goal_has_no_path() {
+        let mut walls = vec![vec![false; GRID_W]; GRID_H];
+        walls[GRID_H / 2] = vec![true; GRID_W];
+        let path_grid = PathGrid::from_walls(&walls, START, GOAL);
+        let bfs = run_bfs(&path_grid);
+        assert!(bfs.queue.is_empty());
+        assert_shortest(&path_grid, &bfs);
+    }
+
+    #[test]
+    fn every_maze_finds_shortest_paths() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for maze in Maze::ALL {
+            for _ in 0..15 {
+                let mut path_grid = PathGrid::open(GRID_H, GRID_W, START, GOAL);
+                path_grid.generate(maze, &mut rng);
+                let bfs = run_bfs(&path_grid);
+                assert_shortest(&path_grid, &bfs);
+                // recursive division and the spiral leave every open cell reachable
+                if maze != Maze::Random {
+                    assert!(bfs.reconstruct, "{maze:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn an_edit_restarts_the_search() {
+        let mut app = test_app(STEP_INTERVAL);
+        app.insert_resource(PathGrid::open(GRID_H, GRID_W, START, GOAL))
+            .insert_resource(Grid {
+                cells: vec![Cell::Empty; GRID_W * GRID_H],
+            })
+            .insert_resource(BfsState::new(p2(START), p2(GOAL)))
+            .add_systems(
+                Update,
+                (restart.in_set(VizSet::Input), bfs_step.in_set(VizSet::Step)),
+            );
+        run_until(&mut app, 4 * GRID_W * GRID_H, |world| {
+            world.resource::<BfsState>().done
+        });
+        // wall the goal in on three sides and move the start next to its open side
+        let goal = GOAL;
+        let mut walls = vec![vec![false; GRID_W]; GRID_H];
+        for cell in [
+            (goal.0 - 1, goal.1),
+            (goal.0 + 1, goal.1),
+            (goal.0, goal.1 + 1),
+        ] {
+            walls[cell.0][cell.1] = true;
+        }
+        let edited = PathGrid::from_walls(&walls, (goal.0, goal.1 - 3), goal);
+        *app.world_mut().resource_mut::<PathGrid>() = edited.clone();
+        app.update();
+        let bfs = app.world().resource::<BfsState>();
+        assert!(!bfs.done && bfs.path().is_empty());
+        run_until(&mut app, 4 * GRID_W * GRID_H, |world| {
+            world.resource::<BfsState>().done
+        });
+        let bfs = app.world_mut().remove_resource::<BfsState>().unwrap();
+        assert_eq!(bfs.path().len(), 4);
+        assert_shortest(&edited, &bfs);
+    }
+}
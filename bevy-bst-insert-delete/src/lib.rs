@@ -0,0 +1,212 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub const TITLE: &str = "BST Insert/Delete";
+const BG_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const NODE_RADIUS: f32 = 25.0;
+const STEP_INTERVAL: f32 = 1.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct TreeNode {
+    value: i32,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct Edge;
+
+#[derive(Resource, Clone)]
+struct State {
+    operations: Vec<String>,
+    current_idx: usize,
+    tree: HashMap<i32, (Option<i32>, Option<i32>)>, // value -> (left, right)
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bst-insert-delete";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(State {
+            operations: vec![
+                "insert 5".to_string(),
+                "insert 3".to_string(),
+                "insert 7".to_string(),
+                "delete 3".to_string(),
+            ],
+            current_idx: 0,
+            tree: HashMap::new(),
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                update_tree_display.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Operations log
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Operations:\n(insert/delete)",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(-350.0, 200.0, 1.0),
+        ..default()
+    });
+}
+
+fn input(mut reset: EventReader<ResetRequested>, mut state: ResMut<State>) {
+    if reset.read().count() > 0 {
+        state.current_idx = 0;
+        state.tree.clear();
+    }
+}
+
+fn step(mut state: ResMut<State>, settings: Res<Settings>) {
+    if state.current_idx >= state.operations.len() || !settings.step_ready() {
+        return;
+    }
+
+    let op = &state.operations[state.current_idx];
+    if let Some(val_str) = op.strip_prefix("insert ") {
+        if let Ok(val) = val_str.parse::<i32>() {
+            insert(&mut state.tree, val);
+        }
+    } else if let Some(val_str) = op.strip_prefix("delete ") {
+        if let Ok(val) = val_str.parse::<i32>() {
+            delete(&mut state.tree, val);
+        }
+    }
+    state.current_idx += 1;
+}
+
+fn insert(tree: &mut HashMap<i32, (Option<i32>, Option<i32>)>, val: i32) {
+    if tree.is_empty() {
+        tree.insert(val, (None, None));
+        return;
+    }
+    let mut current = *tree.keys().next().unwrap(); // root
+    loop {
+        if val < current {
+            if let Some(left) = tree[&current].0 {
+                current = left;
+            } else {
+                tree.get_mut(&current).unwrap().0 = Some(val);
+                tree.insert(val, (None, None));
+                break;
+            }
+        } else if val > current {
+            if let Some(right) = tree[&current].1 {
+                current = right;
+            } else {
+                tree.get_mut(&current).unwrap().1 = Some(val);
+                tree.insert(val, (None, None));
+                break;
+            }
+        } else {
+            // duplicate, ignore
+            break;
+        }
+    }
+}
+
+fn delete(tree: &mut HashMap<i32, (Option<i32>, Option<i32>)>, val: i32) {
+    // Simple delete, find and remove, no rebalance
+    if tree.contains_key(&val) {
+        tree.remove(&val);
+        // Update parents, but for scaffold, just remove
+        for (_, (left, right)) in tree.iter_mut() {
+            if *left == Some(val) {
+                *left = None;
+            }
+            if *right == Some(val) {
+                *right = None;
+            }
+        }
+    }
+}
+
+fn update_tree_display(
+    mut commands: Commands,
+    state: Res<State>,
+    node_query: Query<Entity, With<TreeNode>>,
+) {
+    // Despawn old nodes
+    for entity in node_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    // Spawn new nodes
+    let mut positions = HashMap::new();
+    assign_positions(
+        &state.tree,
+        *state.tree.keys().next().unwrap_or(&0),
+        0.0,
+        200.0,
+        &mut positions,
+    );
+
+    for &val in state.tree.keys() {
+        let pos = positions[&val];
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgb(0.5, 0.5, 0.5),
+                        custom_size: Some(Vec2::new(NODE_RADIUS * 2.0, NODE_RADIUS * 2.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pos.0, pos.1, 0.0),
+                    ..default()
+                },
+                TreeNode { value: val },
+            ))
+            .with_children(|parent| {
+                parent.spawn(SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        val.to_string(),
+                        SegmentStyle {
+                            font_size: 16.0,
+                            color: Color::BLACK,
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                    ..default()
+                });
+            });
+    }
+}
+
+fn assign_positions(
+    tree: &HashMap<i32, (Option<i32>, Option<i32>)>,
+    node: i32,
+    x: f32,
+    y: f32,
+    positions: &mut HashMap<i32, (f32, f32)>,
+) {
+    if !tree.contains_key(&node) {
+        return;
+    }
+    positions.insert(node, (x, y));
+    if let Some(left) = tree[&node].0 {
+        assign_positions(tree, left, x - 100.0, y - 80.0, positions);
+    }
+    if let Some(right) = tree[&node].1 {
+        assign_positions(tree, right, x + 100.0, y - 80.0, positions);
+    }
+}
@@ -0,0 +1,50 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "BST Validate";
+const BG_COLOR: Color = Color::srgb(0.03, 0.04, 0.07);
+
+#[derive(Component)]
+struct PlaceholderRange;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bst-validate";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.settled,
+                custom_size: Some(Vec2::new(280.0, 120.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-140.0, 0.0, 0.0),
+            ..default()
+        },
+        PlaceholderRange,
+        Highlight::Settled,
+    ));
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare,
+                custom_size: Some(Vec2::new(280.0, 120.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(140.0, 0.0, 0.0),
+            ..default()
+        },
+        PlaceholderRange,
+        Highlight::Compare,
+    ));
+
+    info!("BST Validate scaffold running. Swap placeholders with actual range-propagation cues.");
+}
@@ -0,0 +1,811 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::{HashMap, VecDeque};
+
+const NODE_R: f32 = 16.0;
+const H_GAP: f32 = 36.0; // horizontal gap multiplier by subtree width
+const V_GAP: f32 = 64.0; // vertical gap between levels
+const STEP_INTERVAL: f32 = 0.6;
+const EASE_RATE: f32 = 8.0; // per second; nodes close most of the gap to their new place in ~0.3 s
+const MAX_VALUE: i32 = 999; // three digits fit a node
+
+#[derive(Copy, Clone)]
+struct Node {
+    idx: usize,
+    value: i32,
+    left: Option<usize>,
+    right: Option<usize>,
+    parent: Option<usize>,
+    depth: usize,
+}
+
+/// Nodes are never removed from `nodes`; a deleted node is unlinked from the tree and its slot stays unused.
+#[derive(Resource, Default, Clone)]
+struct Bst {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+#[derive(Component)]
+struct Circle {
+    idx: usize,
+}
+#[derive(Component)]
+struct Edge {
+    child: usize,
+} // line segment from the child's parent to the child, as a thin sprite
+#[derive(Component)]
+struct Pointer; // moving indicator to current node
+#[derive(Component)]
+struct StepText;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Command {
+    Insert,
+    Delete,
+    Search,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Phase {
+    /// Walking down from the root toward `target`.
+    Descend,
+    /// Walking to the in-order successor of the node being deleted.
+    Successor {
+        of: usize,
+    },
+    Done,
+}
+
+/// The command being animated, one node per step, and the ones typed while it runs.
+#[derive(Resource)]
+struct Walk {
+    command: Command,
+    target: i32,
+    current: Option<usize>,
+    phase: Phase,
+    found: bool,
+    note: String,
+    queue: VecDeque<(Command, i32)>,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bst";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .console("bst.console")
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                (handle_input, read_console).chain().in_set(VizSet::Input),
+                walk_step.in_set(VizSet::Step),
+                (sync_entities, ease_nodes, place_edges, animate_pointer)
+                    .chain()
+                    .in_set(VizSet::Animate),
+                (color_update, narrate).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut rng: ResMut<VizRng>, locale: Res<Locale>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let bst = random_bst(&mut rng);
+    let target = rng.0.gen_range(1..=15);
+    commands.insert_resource(Walk::start(
+        Command::Search,
+        target,
+        &bst,
+        &locale,
+        VecDeque::new(),
+    ));
+    commands.insert_resource(bst);
+
+    // nodes and edges come from sync_entities; the pointer waits above the root
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(1.0, 0.8, 0.2),
+                custom_size: Some(Vec2::new(NODE_R * 1.2, NODE_R * 1.2)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, 200.0 + V_GAP / 3.0, 5.0),
+            ..default()
+        },
+        Pointer,
+    ));
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "",
+                SegmentStyle {
+                    font_size: 18.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 260.0, 1.0),
+            ..default()
+        },
+        StepText,
+        Narration,
+    ));
+}
+
+fn random_bst(rng: &mut VizRng) -> Bst {
+    let mut vals: Vec<i32> = (1..=15).collect();
+    vals.shuffle(&mut rng.0);
+    let mut bst = Bst {
+        nodes: Vec::new(),
+        root: None,
+    };
+    for v in vals {
+        insert_bst(&mut bst, v);
+    }
+    bst
+}
+
+fn insert_bst(bst: &mut Bst, value: i32) {
+    let (mut parent, mut cur) = (None, bst.root);
+    while let Some(c) = cur {
+        parent = Some(c);
+        cur = if value < bst.nodes[c].value {
+            bst.nodes[c].left
+        } else {
+            bst.nodes[c].right
+        };
+    }
+    bst.attach(parent, value);
+}
+
+impl Bst {
+    /// Hangs `new` where `old` hung below `parent` (or at the root).
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: Option<usize>) {
+        match parent {
+            Some(p) if self.nodes[p].left == Some(old) => self.nodes[p].left = new,
+            Some(p) => self.nodes[p].right = new,
+            None => self.root = new,
+        }
+        if let Some(n) = new {
+            self.nodes[n].parent = parent;
+        }
+    }
+
+    /// Deletion cases 1 and 2: a node with at most one child is replaced by that child.
+    fn splice_out(&mut self, d: usize) {
+        let child = self.nodes[d].left.or(self.nodes[d].right);
+        self.replace_child(self.nodes[d].parent, d, child);
+        self.detach(d);
+    }
+
+    /// Deletion case 3: successor `s` (which has no left child) leaves its place to its
+    /// right child and takes the place and both subtrees of `d`.
+    fn replace_with_successor(&mut self, d: usize, s: usize) {
+        self.replace_child(self.nodes[s].parent, s, self.nodes[s].right);
+        let (left, right) = (self.nodes[d].left, self.nodes[d].right);
+        self.nodes[s].left = left;
+        self.nodes[s].right = right;
+        for child in [left, right].into_iter().flatten() {
+            self.nodes[child].parent = Some(s);
+        }
+        self.replace_child(self.nodes[d].parent, d, Some(s));
+        self.detach(d);
+    }
+
+    fn detach(&mut self, d: usize) {
+        let n = &mut self.nodes[d];
+        (n.left, n.right, n.parent) = (None, None, None);
+    }
+
+    /// A new leaf below `parent` (on the side `value` belongs), or the root; returns its index.
+    fn attach(&mut self, parent: Option<usize>, value: i32) -> usize {
+        let idx = self.nodes.len();
+        let depth = parent.map_or(0, |p| self.nodes[p].depth + 1);
+        self.nodes.push(Node {
+            idx,
+            value,
+            left: None,
+            right: None,
+            parent,
+            depth,
+        });
+        match parent {
+            Some(p) if value < self.nodes[p].value => self.nodes[p].left = Some(idx),
+            Some(p) => self.nodes[p].right = Some(idx),
+            None => self.root = Some(idx),
+        }
+        idx
+    }
+
+    // depths from the root again, after a node was spliced out
+    fn update_depths(&mut self) {
+        let mut stack: Vec<(usize, usize)> = self.root.map(|r| (r, 0)).into_iter().collect();
+        while let Some((i, depth)) = stack.pop() {
+            self.nodes[i].depth = depth;
+            stack.extend(
+                [self.nodes[i].left, self.nodes[i].right]
+                    .into_iter()
+                    .flatten()
+                    .map(|c| (c, depth + 1)),
+            );
+        }
+    }
+}
+
+fn inorder_collect(root: Option<usize>, nodes: &Vec<Node>, order: &mut Vec<usize>) {
+    if let Some(i) = root {
+        inorder_collect(nodes[i].left, nodes, order);
+        order.push(i);
+        inorder_collect(nodes[i].right, nodes, order);
+    }
+}
+
+fn node_pos(n: &Node, x0: f32, xs: &[f32]) -> Vec2 {
+    let x = x0 + xs[n.idx];
+    let y = 200.0 - n.depth as f32 * V_GAP;
+    Vec2::new(x, y)
+}
+
+fn spawn_node(commands: &mut Commands, n: &Node, at: Vec2) {
+    let color = Color::srgb(0.25, 0.55, 0.95);
+    let id = commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(NODE_R * 2.0, NODE_R * 2.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(at.x, at.y, 1.0),
+                ..default()
+            },
+            Circle { idx: n.idx },
+        ))
+        .id();
+    // digits
+    commands
+        .entity(id)
+        .with_children(|c| spawn_digits(c, n.value, Vec2::ZERO, DigitStyle::SMALL, Color::WHITE));
+}
+
+impl Walk {
+    fn start(
+        command: Command,
+        target: i32,
+        bst: &Bst,
+        locale: &Locale,
+        queue: VecDeque<(Command, i32)>,
+    ) -> Self {
+        let key = match command {
+            Command::Insert => "bst.start.insert",
+            Command::Delete => "bst.start.delete",
+            Command::Search => "bst.start.search",
+        };
+        let note = tr!(locale, key, value = target);
+        Walk {
+            command,
+            target,
+            current: bst.root,
+            phase: Phase::Descend,
+            found: false,
+            note,
+            queue,
+        }
+    }
+}
+
+fn handle_input(
+    mut commands: Commands,
+    mut reset: EventReader<ResetRequested>,
+    mut bst: ResMut<Bst>,
+    mut walk: ResMut<Walk>,
+    drawn: Query<Entity, Or<(With<Circle>, With<Edge>)>>,
+    mut rng: ResMut<VizRng>,
+    locale: Res<Locale>,
+) {
+    if reset.read().count() > 0 {
+        // rebuild tree with new values and new target; sync_entities draws it from scratch
+        for entity in &drawn {
+            commands.entity(entity).despawn_recursive();
+        }
+        *bst = random_bst(&mut rng);
+        let target = rng.0.gen_range(1..=15);
+        *walk = Walk::start(Command::Search, target, &bst, &locale, VecDeque::new());
+    }
+}
+
+// `insert 42`, `delete 17`, `search 8` from the console; they run one after another
+fn read_console(
+    mut typed: EventReader<ConsoleCommand>,
+    mut walk: ResMut<Walk>,
+    locale: Res<Locale>,
+) {
+    for ConsoleCommand(line) in typed.read() {
+        let mut words = line.split_whitespace();
+        let command = match words.next().map(|w| w.to_lowercase()).as_deref() {
+            Some("insert" | "i") => Some(Command::Insert),
+            Some("delete" | "d") => Some(Command::Delete),
+            Some("search" | "s") => Some(Command::Search),
+            _ => None,
+        };
+        let value = words
+            .next()
+            .and_then(|w| w.parse::<i32>().ok())
+            .filter(|v| (0..=MAX_VALUE).contains(v));
+        match (command, value, words.next()) {
+            (Some(command), Some(value), None) => walk.queue.push_back((command, value)),
+            _ => walk.note = tr!(locale, "bst.invalid", line = line),
+        }
+    }
+}
+
+fn walk_step(
+    settings: Res<Settings>,
+    mut walk: ResMut<Walk>,
+    mut bst: ResMut<Bst>,
+    locale: Res<Locale>,
+) {
+    if !settings.step_ready() {
+        return;
+    }
+    if walk.phase == Phase::Done {
+        if let Some((command, target)) = walk.queue.pop_front() {
+            let queue = std::mem::take(&mut walk.queue);
+            *walk = Walk::start(command, target, &bst, &locale, queue);
+        }
+        return;
+    }
+    let target = walk.target;
+
+    let Some(cur) = walk.current else {
+        // walked off the tree (or it is empty)
+        match walk.command {
+            Command::Insert => {
+                walk.current = Some(bst.attach(None, target));
+                walk.note = tr!(locale, "bst.inserted.root", value = target);
+            }
+            _ => walk.note = tr!(locale, "bst.missing", value = target),
+        }
+        walk.phase = Phase::Done;
+        return;
+    };
+    let node = bst.nodes[cur];
+
+    if let Phase::Successor { of } = walk.phase {
+        if let Some(l) = node.left {
+            walk.current = Some(l);
+            walk.note = tr!(locale, "bst.successor.left", node = node.value);
+        } else {
+            bst.replace_with_successor(of, cur);
+            bst.update_depths();
+            walk.note = tr!(
+                locale,
+                "bst.successor.found",
+                successor = node.value,
+                value = target
+            );
+            walk.found = true;
+            walk.phase = Phase::Done;
+        }
+        return;
+    }
+
+    if target == node.value {
+        walk.found = true;
+        walk.phase = Phase::Done;
+        walk.note = match (walk.command, node.left, node.right) {
+            (Command::Search, _, _) => tr!(locale, "bst.found", value = target),
+            (Command::Insert, _, _) => tr!(locale, "bst.duplicate", value = target),
+            (Command::Delete, Some(_), Some(r)) => {
+                walk.phase = Phase::Successor { of: cur };
+                walk.current = Some(r);
+                walk.found = false;
+                tr!(locale, "bst.delete.two", value = target)
+            }
+            (Command::Delete, None, None) => {
+                bst.splice_out(cur);
+                bst.update_depths();
+                walk.current = None;
+                tr!(locale, "bst.delete.leaf", value = target)
+            }
+            (Command::Delete, l, r) => {
+                let child = l.or(r).map_or(0, |c| bst.nodes[c].value);
+                bst.splice_out(cur);
+                bst.update_depths();
+                walk.current = l.or(r);
+                tr!(locale, "bst.delete.one", value = target, child = child)
+            }
+        };
+        return;
+    }
+
+    let (next, key) = if target < node.value {
+        (node.left, "bst.left")
+    } else {
+        (node.right, "bst.right")
+    };
+    match (next, walk.command) {
+        (None, Command::Insert) => {
+            walk.current = Some(bst.attach(Some(cur), target));
+            walk.found = true;
+            walk.phase = Phase::Done;
+            let key = if target < node.value {
+                "bst.inserted.left"
+            } else {
+                "bst.inserted.right"
+            };
+            walk.note = tr!(locale, key, value = target, parent = node.value);
+        }
+        (None, _) => {
+            walk.phase = Phase::Done;
+            walk.note = tr!(locale, "bst.missing", value = target);
+        }
+        (Some(_), _) => {
+            walk.current = next;
+            walk.note = tr!(locale, key, value = target, node = node.value);
+        }
+    }
+}
+
+fn compute_xs(bst: &Bst) -> Vec<f32> {
+    let mut xs = vec![0.0; bst.nodes.len()];
+    let mut order = Vec::new();
+    inorder_collect(bst.root, &bst.nodes, &mut order);
+    for (i, idx) in order.iter().enumerate() {
+        xs[*idx] = i as f32 * (H_GAP + NODE_R * 2.0);
+    }
+    xs
+}
+fn compute_x0(bst: &Bst) -> f32 {
+    let mut order = Vec::new();
+    inorder_collect(bst.root, &bst.nodes, &mut order);
+    let n = order.len().max(1) as f32;
+    let width = (n - 1.0) * (H_GAP + NODE_R * 2.0);
+    -width / 2.0
+}
+
+// spawns the nodes the tree gained, despawns the ones it lost and redraws the edges
+fn sync_entities(
+    mut commands: Commands,
+    bst: Res<Bst>,
+    circles: Query<(Entity, &Circle)>,
+    edges: Query<Entity, With<Edge>>,
+) {
+    if !bst.is_changed() {
+        return;
+    }
+    let mut order = Vec::new();
+    inorder_collect(bst.root, &bst.nodes, &mut order);
+    let mut shown = vec![false; bst.nodes.len()];
+    for (entity, circle) in &circles {
+        if order.contains(&circle.idx) {
+            shown[circle.idx] = true;
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+    let (x0, xs) = (compute_x0(&bst), compute_xs(&bst));
+    for &i in order.iter().filter(|&&i| !shown[i]) {
+        // a new leaf drops out of its parent
+        let from = bst.nodes[i].parent.map_or(&bst.nodes[i], |p| &bst.nodes[p]);
+        spawn_node(&mut commands, &bst.nodes[i], node_pos(from, x0, &xs));
+    }
+    for entity in &edges {
+        commands.entity(entity).despawn();
+    }
+    for &i in order.iter().filter(|&&i| bst.nodes[i].parent.is_some()) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1.0, 1.0, 1.0, 0.2),
+                    ..default()
+                },
+                ..default()
+            },
+            Edge { child: i },
+        ));
+    }
+}
+
+// every node eases toward its place in the current layout
+fn ease_nodes(time: Res<Time>, bst: Res<Bst>, mut q: Query<(&Circle, &mut Transform)>) {
+    let (x0, xs) = (compute_x0(&bst), compute_xs(&bst));
+    let t = 1.0 - (-EASE_RATE * time.delta_seconds()).exp();
+    for (circle, mut tf) in q.iter_mut() {
+        let p = node_pos(&bst.nodes[circle.idx], x0, &xs);
+        tf.translation = tf.translation.lerp(p.extend(1.0), t);
+    }
+}
+
+fn place_edges(
+    bst: Res<Bst>,
+    circles: Query<(&Circle, &Transform), Without<Edge>>,
+    mut edges: Query<(&Edge, &mut Transform, &mut Sprite), Without<Circle>>,
+) {
+    let at: HashMap<usize, Vec2> = circles
+        .iter()
+        .map(|(c, tf)| (c.idx, tf.translation.truncate()))
+        .collect();
+    for (edge, mut tf, mut sprite) in edges.iter_mut() {
+        let Some(parent) = bst.nodes[edge.child].parent else {
+            continue;
+        };
+        let (Some(&pa), Some(&pb)) = (at.get(&parent), at.get(&edge.child)) else {
+            continue;
+        };
+        let mid = (pa + pb) / 2.0;
+        let dir = pb - pa;
+        sprite.custom_size = Some(Vec2::new(dir.length(), 2.0));
+        tf.translation = Vec3::new(mid.x, mid.y, 0.0);
+        tf.rotation = Quat::from_rotation_z(dir.y.atan2(dir.x));
+    }
+}
+
+fn animate_pointer(
+    time: Res<Time>,
+    walk: Res<Walk>,
+    bst: Res<Bst>,
+    mut q: Query<&mut Transform, With<Pointer>>,
+) {
+    let Ok(mut tf) = q.get_single_mut() else {
+        return;
+    };
+    let Some(cur) = walk
+        .current
+        .filter(|&c| bst.nodes[c].parent.is_some() || bst.root == Some(c))
+    else {
+        return;
+    };
+    let p = node_pos(&bst.nodes[cur], compute_x0(&bst), &compute_xs(&bst));
+    let t = 1.0 - (-EASE_RATE * time.delta_seconds()).exp();
+    tf.translation = tf
+        .translation
+        .lerp(Vec3::new(p.x, p.y + V_GAP / 3.0, 5.0), t);
+}
+
+fn color_update(walk: Res<Walk>, mut nodes: Query<(&Circle, &mut Sprite)>, palette: Res<Palette>) {
+    for (n, mut sprite) in nodes.iter_mut() {
+        let mut color = palette.visited;
+        if let Phase::Successor { of } = walk.phase {
+            if n.idx == of {
+                color = palette.violation;
+            }
+        }
+        if Some(n.idx) == walk.current {
+            color = palette.focus;
+        }
+        if walk.found && Some(n.idx) == walk.current {
+            color = palette.settled;
+        }
+        sprite.color = color;
+    }
+}
+
+fn narrate(walk: Res<Walk>, mut text: Query<&mut SegmentText, With<StepText>>) {
+    if !walk.is_changed() {
+        return;
+    }
+    for mut t in &mut text {
+        t.sections[0].value.clone_from(&walk.note);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algoviz_core::{run_until, test_app};
+
+    fn tree(values: &[i32]) -> Bst {
+        let mut bst = Bst::default();
+        for &v in values {
+            insert_bst(&mut bst, v);
+        }
+        bst
+    }
+
+    fn idx_of(bst: &Bst, value: i32) -> usize {
+        let mut order = Vec::new();
+        inorder_collect(bst.root, &bst.nodes, &mut order);
+        *order
+            .iter()
+            .find(|&&i| bst.nodes[i].value == value)
+            .unwrap()
+    }
+
+    /// In-order values, after checking order, parent links and depths of everything reachable.
+    fn values(bst: &Bst) -> Vec<i32> {
+        let mut order = Vec::new();
+        inorder_collect(bst.root, &bst.nodes, &mut order);
+        let values: Vec<i32> = order.iter().map(|&i| bst.nodes[i].value).collect();
+        assert!(
+            values.windows(2).all(|w| w[0] < w[1]),
+            "out of order: {values:?}"
+        );
+        for &i in &order {
+            let n = bst.nodes[i];
+            match n.parent {
+                Some(p) => {
+                    assert!(
+                        bst.nodes[p].left == Some(i) || bst.nodes[p].right == Some(i),
+                        "{} not below {}",
+                        n.value,
+                        bst.nodes[p].value
+                    );
+                    assert_eq!(n.depth, bst.nodes[p].depth + 1, "depth of {}", n.value);
+                }
+                None => {
+                    assert_eq!(bst.root, Some(i));
+                    assert_eq!(n.depth, 0);
+                }
+            }
+        }
+        values
+    }
+
+    //        50
+    //     30     70
+    //   20  40 60  80
+    //             65
+    const SAMPLE: [i32; 8] = [50, 30, 70, 20, 40, 60, 80, 65];
+
+    #[test]
+    fn delete_leaf() {
+        let mut bst = tree(&SAMPLE);
+        let d = idx_of(&bst, 20);
+        bst.splice_out(d);
+        bst.update_depths();
+        assert_eq!(values(&bst), [30, 40, 50, 60, 65, 70, 80]);
+        assert_eq!(bst.nodes[idx_of(&bst, 30)].left, None);
+    }
+
+    #[test]
+    fn delete_with_one_child() {
+        let mut bst = tree(&SAMPLE);
+        let d = idx_of(&bst, 60);
+        bst.splice_out(d);
+        bst.update_depths();
+        assert_eq!(values(&bst), [20, 30, 40, 50, 65, 70, 80]);
+        let child = idx_of(&bst, 65);
+        assert_eq!(bst.nodes[child].parent, Some(idx_of(&bst, 70)));
+        assert_eq!(bst.nodes[child].depth, 2);
+    }
+
+    #[test]
+    fn delete_with_two_children_deep_successor() {
+        let mut bst = tree(&SAMPLE);
+        let (d, s) = (idx_of(&bst, 50), idx_of(&bst, 60));
+        bst.replace_with_successor(d, s);
+        bst.update_depths();
+        assert_eq!(values(&bst), [20, 30, 40, 60, 65, 70, 80]);
+        assert_eq!(bst.root, Some(s));
+        // the successor's right child takes its old place
+        assert_eq!(bst.nodes[idx_of(&bst, 70)].left, Some(idx_of(&bst, 65)));
+    }
+
+    #[test]
+    fn delete_with_two_children_successor_is_right_child() {
+        let mut bst = tree(&SAMPLE);
+        let (d, s) = (idx_of(&bst, 70), idx_of(&bst, 80));
+        bst.replace_with_successor(d, s);
+        bst.update_depths();
+        assert_eq!(values(&bst), [20, 30, 40, 50, 60, 65, 80]);
+        assert_eq!(bst.nodes[s].left, Some(idx_of(&bst, 60)));
+        assert_eq!(bst.nodes[s].right, None);
+        assert_eq!(bst.nodes[bst.root.unwrap()].right, Some(s));
+    }
+
+    #[test]
+    fn delete_root_down_to_empty() {
+        let mut bst = tree(&[2, 1]);
+        let root = bst.root.unwrap();
+        bst.splice_out(root);
+        bst.update_depths();
+        assert_eq!(values(&bst), [1]);
+        bst.splice_out(bst.root.unwrap());
+        assert_eq!(bst.root, None);
+    }
+
+    fn console_app(bst: Bst) -> App {
+        let mut app = test_app(STEP_INTERVAL);
+        let walk = Walk {
+            command: Command::Search,
+            target: 0,
+            current: None,
+            phase: Phase::Done,
+            found: false,
+            note: String::new(),
+            queue: VecDeque::new(),
+        };
+        app.insert_resource(bst)
+            .insert_resource(walk)
+            .add_event::<ConsoleCommand>()
+            .add_systems(
+                Update,
+                (
+                    read_console.in_set(VizSet::Input),
+                    walk_step.in_set(VizSet::Step),
+                ),
+            );
+        app
+    }
+
+    fn run(app: &mut App, lines: &[&str]) -> String {
+        for line in lines {
+            app.world_mut().send_event(ConsoleCommand(line.to_string()));
+        }
+        app.update();
+        run_until(app, 200, |world| {
+            let walk = world.resource::<Walk>();
+            walk.phase == Phase::Done && walk.queue.is_empty()
+        });
+        app.world().resource::<Walk>().note.clone()
+    }
+
+    #[test]
+    fn console_delete_walks_to_the_successor() {
+        let mut app = console_app(tree(&[30, 20, 50, 42, 60, 45, 44]));
+        let note = run(&mut app, &["delete 30"]);
+        let bst = app.world().resource::<Bst>();
+        assert_eq!(values(bst), [20, 42, 44, 45, 50, 60]);
+        assert_eq!(bst.nodes[bst.root.unwrap()].value, 42);
+        assert!(note.contains("42") && note.contains("30"), "{note}");
+    }
+
+    #[test]
+    fn console_delete_42() {
+        let mut app = console_app(tree(&[10, 42, 5, 50, 41, 7]));
+        run(&mut app, &["delete 42"]);
+        assert_eq!(values(app.world().resource::<Bst>()), [5, 7, 10, 41, 50]);
+        let note = run(&mut app, &["search 42"]);
+        assert!(!app.world().resource::<Walk>().found, "{note}");
+    }
+
+    #[test]
+    fn console_runs_queued_commands_in_order() {
+        let mut app = console_app(Bst::default());
+        run(
+            &mut app,
+            &["insert 8", "i 3", "INSERT 12", "delete 8", "search 12"],
+        );
+        let bst = app.world().resource::<Bst>();
+        assert_eq!(values(bst), [3, 12]);
+        let walk = app.world().resource::<Walk>();
+        assert!(walk.found && walk.command == Command::Search);
+    }
+
+    #[test]
+    fn console_rejects_bad_lines() {
+        let mut app = console_app(tree(&SAMPLE));
+        for line in [
+            "insert",
+            "insert x",
+            "insert 1000",
+            "insert -1",
+            "insert 5 6",
+            "grow 5",
+        ] {
+            let note = run(&mut app, &[line]);
+            assert!(note.contains(line), "{line}: {note}");
+            assert!(app.world().resource::<Walk>().queue.is_empty());
+        }
+        assert_eq!(
+            values(app.world().resource::<Bst>()),
+            [20, 30, 40, 50, 60, 65, 70, 80]
+        );
+    }
+
+    #[test]
+    fn console_insert_duplicate_keeps_the_tree() {
+        let mut app = console_app(tree(&SAMPLE));
+        let nodes = app.world().resource::<Bst>().nodes.len();
+        run(&mut app, &["insert 65"]);
+        let bst = app.world().resource::<Bst>();
+        assert_eq!(bst.nodes.len(), nodes);
+        assert_eq!(values(bst), [20, 30, 40, 50, 60, 65, 70, 80]);
+        assert!(app.world().resource::<Walk>().found);
+    }
+}
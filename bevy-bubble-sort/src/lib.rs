@@ -0,0 +1,1394 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+pub const INPUT: ArraySpec = ArraySpec {
+    default_n: 10,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 60.0; // widest bar; more elements narrow the bars (and gaps) to fit the row
+const BAR_GAP: f32 = 10.0;
+const ROW_WIDTH: f32 = 760.0; // in the 900px window; wider windows widen the row
+const MAX_HEIGHT: f32 = 300.0;
+const ANIM_SPEED: f32 = 400.0; // pixels per second for swap animation
+const PRE_SWAP_DURATION: f32 = 0.3; // seconds highlighted before moving
+const STEP_INTERVAL: f32 = 1.0; // seconds per comparison in auto-play
+
+// the steps below name these lines: a compare is line 4, its swap line 5
+const PSEUDOCODE: Listing = Listing::new(
+    "code.pseudocode",
+    &[
+        ("", "procedure bubble_sort(a)"),
+        ("pass", "  for i = 0 to n - 2"),
+        ("", "    for j = 0 to n - 2 - i"),
+        ("compare", "      if a[j] > a[j+1]"),
+        ("swap", "        swap a[j], a[j+1]"),
+        ("done", "  return a"),
+    ],
+);
+const RUST: Listing = Listing::new(
+    "code.rust",
+    &[
+        ("", "fn bubble_sort(a: &mut [usize]) {"),
+        ("pass", "    for i in 0..a.len() - 1 {"),
+        ("", "        for j in 0..a.len() - 1 - i {"),
+        ("compare", "            if a[j] > a[j + 1] {"),
+        ("swap", "                a.swap(j, j + 1);"),
+        ("", "            }"),
+        ("", "        }"),
+        ("", "    }"),
+        ("done", "}"),
+    ],
+);
+// no font assets required for bar labels or UI
+const DIGIT_COLOR_TOP: Color = algoviz_core::DIGIT_COLOR; // bar-top label color
+
+#[derive(Component, Debug, Clone, Copy)]
+struct Bar {
+    index: usize, // logical position in the array
+    value: usize, // height proportional to value / Layout.top
+}
+
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.index
+    }
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+
+#[derive(Component, Deref, DerefMut)]
+struct TargetX(f32);
+
+#[derive(Resource, Default, Clone)]
+struct SortState {
+    i: usize,
+    j: usize,
+    array: Vec<usize>,
+    swapping: Option<(Entity, Entity)>, // entities currently swapping (moving)
+    pre_swap: Option<(Entity, Entity, f32)>, // entities highlighted before swapping, and remaining time
+    pending_swap_indices: Option<(usize, usize)>, // logical indices (a_idx, b_idx) awaiting swap
+    pre_swap_red: Option<Entity>,            // which entity is the moving (larger) bar
+    sorted: bool,
+    restoring: bool, // bars sliding back to a restored history snapshot
+    counts: OpCounts,
+}
+
+/// What stepping back restores: the array order, where each bar stands, the
+/// pass/scan cursors and the counts so far. Bars are found by entity, as
+/// values may repeat.
+#[derive(Clone, PartialEq)]
+struct Snapshot {
+    array: Vec<usize>,
+    slots: Vec<(Entity, usize)>, // (bar, index) for every bar
+    i: usize,
+    j: usize,
+    sorted: bool,
+    counts: OpCounts,
+}
+
+impl SortState {
+    fn snapshot(&self, slots: Vec<(Entity, usize)>) -> Snapshot {
+        Snapshot {
+            array: self.array.clone(),
+            slots,
+            i: self.i,
+            j: self.j,
+            sorted: self.sorted,
+            counts: self.counts,
+        }
+    }
+
+    fn idle(&self) -> bool {
+        self.swapping.is_none() && self.pre_swap.is_none() && !self.restoring
+    }
+
+    /// The pair the next `advance` compares, if it compares one.
+    fn next_pair(&self) -> Option<(usize, usize)> {
+        let n = self.array.len();
+        (self.i + 1 < n && self.j + 1 + self.i < n).then_some((self.j, self.j + 1))
+    }
+
+    /// Move the pass/scan cursors one step. A compare only reports whether the
+    /// pair is out of order; the caller does the swap (after the highlight, on screen).
+    fn advance(&mut self) -> Step {
+        let n = self.array.len();
+        if self.i + 1 >= n {
+            self.sorted = true;
+            return Step::Done;
+        }
+        if self.j + 1 + self.i >= n {
+            self.j = 0;
+            self.i += 1;
+            return Step::NextPass;
+        }
+        let (a, b) = (self.j, self.j + 1);
+        self.j += 1;
+        self.counts.compare();
+        Step::Compare {
+            a,
+            b,
+            swap: self.array[a] > self.array[b],
+        }
+    }
+}
+
+/// One logical step of the sort, shared by the window and `--headless`.
+enum Step {
+    Compare { a: usize, b: usize, swap: bool },
+    NextPass,
+    Done,
+}
+
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    top: usize,   // value drawn at MAX_HEIGHT
+    labels: bool, // bar-top digits fit without overlapping
+}
+
+impl Layout {
+    fn new(array: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(array.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = array.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            top,
+            labels: row.labels(top),
+        }
+    }
+
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, array: &[usize]) {
+        self.top = array.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+
+    fn x(&self, i: usize) -> f32 {
+        self.row.x(i)
+    }
+
+    fn height(&self, value: usize) -> f32 {
+        value as f32 / self.top as f32 * MAX_HEIGHT + 10.0 // min height
+    }
+
+    fn color(&self, value: usize) -> Color {
+        Color::hsl((value as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Component)]
+struct DecisionOverlay; // parent for a > b display
+
+#[derive(Component)]
+struct LeftDigits; // child group for left value
+
+#[derive(Component)]
+struct RightDigits; // child group for right value
+
+#[derive(Component)]
+struct ResultBox; // shows Yes/No (green/red)
+
+#[derive(Component)]
+struct OperatorGlyph; // the '>' chevron bars
+
+// Educational text components
+#[derive(Component)]
+struct ExplanationText;
+
+#[derive(Component)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct StepExplanation;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "bubble-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .history_scrubber::<Snapshot>()
+        .cost_chart(|sort: Res<SortState>| sort.counts)
+        .quiz()
+        .code_panel(&[PSEUDOCODE, RUST])
+        .bar_editor::<Bar>()
+        .resource(input)
+        .resource(SortState::default())
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                handle_input.in_set(VizSet::Input),
+                (restore_snapshot, step_bubble_sort)
+                    .chain()
+                    .in_set(VizSet::Step),
+                (pre_swap_anim, animate_swaps, animate_restore)
+                    .chain()
+                    .in_set(VizSet::Animate),
+                (
+                    update_highlights,
+                    update_decision_overlay,
+                    update_educational_text,
+                    record_history,
+                )
+                    .in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut sort: ResMut<SortState>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    // The --input array, or 1..=n shuffled
+    sort.array = input.permutation();
+
+    // layout origin centered, bars narrowed to fit the window
+    let layout = Layout::new(&sort.array, row_width(ROW_WIDTH, &windows));
+
+    // spawn bars and their number labels
+    for (idx, value) in sort.array.iter().copied().enumerate() {
+        let height = layout.height(value);
+        let x = layout.x(idx);
+        let color = layout.color(value);
+
+        let bar_entity = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.row.width, height)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, height / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar { index: idx, value },
+                TargetX(x),
+            ))
+            .id();
+
+        // bar-top block digits (no font needed), left out when the bars are too narrow
+        if layout.labels {
+            commands.entity(bar_entity).with_children(|parent| {
+                spawn_value_digits(parent, value, height / 2.0 + 12.0, DIGIT_COLOR_TOP);
+            });
+        }
+    }
+    commands.insert_resource(layout);
+
+    // Decision overlay centered near top (a > b: Yes/No)
+    let overlay_y = 250.0;
+    commands
+        .spawn((
+            SpatialBundle {
+                transform: Transform::from_xyz(0.0, overlay_y, 5.0),
+                ..default()
+            },
+            DecisionOverlay,
+        ))
+        .with_children(|parent| {
+            // left value group
+            parent.spawn((
+                SpatialBundle {
+                    transform: Transform::from_xyz(-120.0, 0.0, 0.0),
+                    ..default()
+                },
+                LeftDigits,
+            ));
+            // '>' chevron using two slanted bars positioned to the right of center
+            let arrow_color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+            let make_bar = |rot: f32, x: f32, y: f32| SpriteBundle {
+                sprite: Sprite {
+                    color: arrow_color,
+                    custom_size: Some(Vec2::new(28.0, 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(x, y, 0.0),
+                    rotation: Quat::from_rotation_z(rot),
+                    ..default()
+                },
+                ..default()
+            };
+            parent.spawn((make_bar(0.8, 8.0, 0.0), OperatorGlyph));
+            parent.spawn((make_bar(-0.8, 8.0, 0.0), OperatorGlyph));
+            // right value group
+            parent.spawn((
+                SpatialBundle {
+                    transform: Transform::from_xyz(120.0, 0.0, 0.0),
+                    ..default()
+                },
+                RightDigits,
+            ));
+            // result box
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgba(0.5, 0.5, 0.5, 0.6),
+                        custom_size: Some(Vec2::new(18.0, 18.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(180.0, 0.0, 0.0),
+                    ..default()
+                },
+                ResultBox,
+            ));
+        });
+
+    // Educational Text Overlays
+    // Algorithm title
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 32.0,
+                color: Color::srgb(1.0, 1.0, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        AlgorithmTitle,
+        Localized::new("bubble-sort.title"),
+    ));
+
+    // Progress information
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(50.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        ProgressText,
+    ));
+
+    // Step explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(1.0, 1.0, 0.8),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(80.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        StepExplanation,
+        Narration,
+    ));
+
+    // Algorithm explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(0.7, 0.9, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        ExplanationText,
+        Localized::new("bubble-sort.blurb"),
+    ));
+}
+
+fn handle_input(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut sort: ResMut<SortState>,
+    mut history: ResMut<History<Snapshot>>,
+    mut params: ParamSet<(
+        Query<(
+            Entity,
+            &mut Bar,
+            &mut Sprite,
+            &mut Transform,
+            &mut TargetX,
+            Option<&Children>,
+        )>,
+        Query<&ValueDigits>,
+    )>,
+    mut layout: ResMut<Layout>,
+    mut input: ResMut<ArrayInput>,
+    mut code: ResMut<CodeCursor>,
+    mut commands: Commands,
+) {
+    // Space on a finished run restarts it; claim the press so it doesn't also pause
+    let restart = sort.sorted && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        // restart: reshuffle (a fixed --input array comes back unchanged, an edited one once)
+        sort.array = input.permutation();
+        layout.rescale(&sort.array);
+        code.clear();
+        sort.i = 0;
+        sort.j = 0;
+        sort.swapping = None;
+        sort.pre_swap = None;
+        sort.pending_swap_indices = None;
+        sort.pre_swap_red = None;
+        sort.sorted = false;
+        sort.restoring = false;
+        sort.counts = OpCounts::default();
+        history.clear();
+        // Update entities to match new array order/values and replace digit children
+        let mut relabel: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        {
+            let mut q0 = params.p0();
+            for (bar_entity, mut bar, mut sprite, mut tf, mut tx, children) in q0.iter_mut() {
+                let idx = bar.index;
+                let value = sort.array[idx];
+                bar.value = value;
+                let height = layout.height(value);
+                sprite.custom_size = Some(Vec2::new(layout.row.width, height));
+                sprite.color = layout.color(value);
+                let x = layout.x(idx);
+                tx.0 = x;
+                tf.translation.x = x;
+                tf.translation.y = height / 2.0 - 200.0;
+                tf.scale = Vec3::ONE;
+
+                // collect children to check later
+                let children = children.map_or_else(Vec::new, |children| children.to_vec());
+                relabel.push((bar_entity, children, value, height / 2.0 + 12.0));
+            }
+        }
+        // old digits go; new ones only if they still fit over the rescaled bars
+        let q1 = params.p1();
+        for (bar_entity, children, value, y) in relabel {
+            for child in children {
+                if q1.get(child).is_ok() {
+                    commands.entity(child).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands.entity(bar_entity).with_children(|parent| {
+                    spawn_value_digits(parent, value, y, DIGIT_COLOR_TOP);
+                });
+            }
+        }
+    }
+}
+
+fn update_decision_overlay(
+    sort: Res<SortState>,
+    left_root: Query<Entity, With<LeftDigits>>,
+    right_root: Query<Entity, With<RightDigits>>,
+    mut params: ParamSet<(
+        Query<(&mut Sprite, &mut Visibility), With<ResultBox>>,
+        Query<&mut Visibility, With<OperatorGlyph>>,
+    )>,
+    children_q: Query<&Children>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    // Show the decision only during the pre-swap highlight window
+    let mut show = false;
+    let mut a = 0usize;
+    let mut b = 0usize;
+    if let Some((ai, bi)) = sort.pending_swap_indices {
+        // pre_swap is active between scheduling and animation; use these indices
+        if sort.pre_swap.is_some() {
+            show = true;
+            a = sort.array[ai];
+            b = sort.array[bi];
+        }
+    }
+
+    if let Ok(left) = left_root.get_single() {
+        if let Ok(children) = children_q.get(left) {
+            for &c in children.iter() {
+                commands.entity(c).despawn_recursive();
+            }
+        }
+        if show {
+            commands.entity(left).with_children(|p| {
+                spawn_value_digits(p, a, 0.0, DIGIT_COLOR_TOP);
+            });
+        }
+    }
+    if let Ok(right) = right_root.get_single() {
+        if let Ok(children) = children_q.get(right) {
+            for &c in children.iter() {
+                commands.entity(c).despawn_recursive();
+            }
+        }
+        if show {
+            commands.entity(right).with_children(|p| {
+                spawn_value_digits(p, b, 0.0, DIGIT_COLOR_TOP);
+            });
+        }
+    }
+    // Toggle operator chevron visibility (first borrow)
+    {
+        let mut op_q = params.p1();
+        for mut vis in &mut op_q {
+            *vis = if show {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+    // Toggle result indicator and set color if visible (second borrow)
+    let mut result_q = params.p0();
+    if let Ok((mut sprite, mut vis)) = result_q.get_single_mut() {
+        if show {
+            sprite.color = if a > b {
+                palette.settled
+            } else {
+                palette.violation
+            };
+            *vis = Visibility::Visible;
+        } else {
+            *vis = Visibility::Hidden;
+        }
+    }
+}
+
+fn update_educational_text(
+    sort: Res<SortState>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    mut text_params: ParamSet<(
+        Query<&mut Text, With<ProgressText>>,
+        Query<&mut Text, With<StepExplanation>>,
+    )>,
+) {
+    // Update progress text
+    if let Ok(mut progress_text) = text_params.p0().get_single_mut() {
+        let pass = sort.i + 1;
+        let status = if sort.sorted {
+            "status.sorted"
+        } else if sort.swapping.is_some() || sort.pre_swap.is_some() {
+            "status.swapping"
+        } else if settings.auto {
+            "status.running"
+        } else {
+            "status.paused"
+        };
+
+        progress_text.sections[0].value = tr!(
+            locale,
+            "bubble-sort.progress",
+            pass = pass,
+            comparisons = sort.counts.comparisons,
+            status = locale.text(status)
+        );
+    }
+
+    // Update step explanation
+    if let Ok(mut step_text) = text_params.p1().get_single_mut() {
+        step_text.sections[0].value =
+            step_explanation(&sort, sort.pre_swap.is_some(), settings.auto, &locale);
+    }
+}
+
+/// The step explanation panel's text; `highlighting` is the pre-swap window.
+fn step_explanation(sort: &SortState, highlighting: bool, auto: bool, locale: &Locale) -> String {
+    if sort.sorted {
+        locale.text("bubble-sort.step.done")
+    } else if let Some((a_idx, b_idx)) = sort.pending_swap_indices {
+        if highlighting {
+            tr!(
+                locale,
+                "bubble-sort.step.swap",
+                a = a_idx + 1,
+                b = b_idx + 1,
+                left = sort.array[a_idx],
+                right = sort.array[b_idx]
+            )
+        } else {
+            locale.text("bubble-sort.step.preparing")
+        }
+    } else if sort.j < sort.array.len() - 1 - sort.i {
+        tr!(
+            locale,
+            "bubble-sort.step.compare",
+            pass = sort.i + 1,
+            a = sort.j + 1,
+            b = sort.j + 2
+        )
+    } else if sort.i < sort.array.len() - 1 {
+        tr!(
+            locale,
+            "bubble-sort.step.pass-done",
+            pass = sort.i + 1,
+            next = sort.i + 2
+        )
+    } else if !auto && sort.i == 0 && sort.j == 0 {
+        locale.text("bubble-sort.step.intro")
+    } else {
+        locale.text("bubble-sort.step.running")
+    }
+}
+
+/// `--headless`: run the whole sort and write every compare, swap, finished
+/// pass and the end as a trace record, with the text the window would show
+/// in `locale`.
+pub fn run_headless(array: Vec<usize>, locale: &Locale, trace: &mut TraceWriter) {
+    let mut sort = SortState { array, ..default() };
+    loop {
+        let explanation = step_explanation(&sort, false, true, locale);
+        match sort.advance() {
+            Step::Compare { a, b, swap } => {
+                trace.write(&ArrayStep {
+                    op: "compare",
+                    indices: vec![a, b],
+                    array: sort.array.clone(),
+                    explanation,
+                });
+                if swap {
+                    sort.pending_swap_indices = Some((a, b));
+                    let explanation = step_explanation(&sort, true, true, locale);
+                    sort.pending_swap_indices = None;
+                    sort.array.swap(a, b);
+                    sort.counts.swap();
+                    trace.write(&ArrayStep {
+                        op: "swap",
+                        indices: vec![a, b],
+                        array: sort.array.clone(),
+                        explanation,
+                    });
+                }
+            }
+            Step::NextPass => {
+                // the pass just finished settled the element at this position
+                let settled = sort.array.len() - sort.i;
+                trace.write(&ArrayStep {
+                    op: "pass",
+                    indices: vec![settled],
+                    array: sort.array.clone(),
+                    explanation,
+                });
+            }
+            Step::Done => {
+                let explanation = step_explanation(&sort, false, true, locale);
+                trace.write(&ArrayStep {
+                    op: "done",
+                    indices: Vec::new(),
+                    array: sort.array.clone(),
+                    explanation,
+                });
+                return;
+            }
+        }
+    }
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    Box::new(Race {
+        sort: SortState { array, ..default() },
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    sort: SortState,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        self.focus = match self.sort.advance() {
+            Step::Compare { a, b, swap } => {
+                if swap {
+                    self.sort.array.swap(a, b);
+                    self.sort.counts.swap();
+                }
+                vec![a, b]
+            }
+            Step::NextPass | Step::Done => Vec::new(),
+        };
+    }
+
+    fn done(&self) -> bool {
+        self.sort.sorted
+    }
+
+    fn array(&self) -> &[usize] {
+        &self.sort.array
+    }
+
+    fn counts(&self) -> OpCounts {
+        self.sort.counts
+    }
+
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+fn step_bubble_sort(
+    mut sort: ResMut<SortState>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX)>,
+    mut settings: ResMut<Settings>,
+    mut quiz: ResMut<Quiz>,
+    mut code: ResMut<CodeCursor>,
+    locale: Res<Locale>,
+) {
+    if sort.sorted {
+        quiz.finish();
+        return;
+    }
+    // Only step when not in animation
+    if !sort.idle() {
+        return;
+    }
+
+    // One step per timer tick (auto) or Space press (manual)
+    if !settings.step_ready() {
+        return;
+    }
+    // in quiz mode the learner predicts the comparison before it happens
+    if let Some((a, b)) = sort.next_pair() {
+        if quiz.hold(|| swap_question(sort.array[a], sort.array[b], &locale)) {
+            return;
+        }
+    }
+    // restart the interval so the next comparison waits a full tick after this one,
+    // and consume the press so it doesn't also confirm the swap this step schedules
+    settings.timer.reset();
+    settings.manual_step = false;
+
+    let (a_idx, b_idx) = match sort.advance() {
+        Step::Compare { a, b, swap } => {
+            code.at("compare")
+                .var("i", sort.i)
+                .var("j", a)
+                .var("a[j]", sort.array[a])
+                .var("a[j+1]", sort.array[b]);
+            if !swap {
+                return;
+            }
+            (a, b)
+        }
+        Step::NextPass => {
+            code.at("pass").var("i", sort.i);
+            return;
+        }
+        Step::Done => {
+            code.at("done");
+            return;
+        }
+    };
+
+    // Find entities for a_idx and b_idx
+    // We'll use the Bar.index to match logical positions
+    let mut a_entity: Option<(Entity, Bar)> = None;
+    let mut b_entity: Option<(Entity, Bar)> = None;
+
+    for (e, bar, _tx) in bars.iter_mut() {
+        if bar.index == a_idx {
+            a_entity = Some((e, *bar));
+        } else if bar.index == b_idx {
+            b_entity = Some((e, *bar));
+        }
+    }
+
+    if let (Some((ea, _)), Some((eb, _))) = (a_entity, b_entity) {
+        // Start pre-swap highlight phase; actual swap will be triggered after timer
+        sort.pre_swap = Some((ea, eb, PRE_SWAP_DURATION));
+        sort.pending_swap_indices = Some((a_idx, b_idx));
+        sort.pre_swap_red = Some(ea); // moving (larger) bar goes to the right
+    }
+}
+
+/// Quiz mode's question before comparing `left` and `right`.
+fn swap_question(left: usize, right: usize, locale: &Locale) -> Question {
+    let choices = [
+        locale.text("bubble-sort.quiz.swap"),
+        locale.text("bubble-sort.quiz.keep"),
+    ];
+    Question::new(
+        tr!(
+            locale,
+            "bubble-sort.quiz.prompt",
+            left = left,
+            right = right
+        ),
+        choices,
+        if left > right { 0 } else { 1 },
+    )
+}
+
+fn pre_swap_anim(
+    time: Res<Time>,
+    mut sort: ResMut<SortState>,
+    mut q_tf: Query<&mut Transform>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX)>,
+    _layout: Res<Layout>,
+    settings: Res<Settings>,
+    mut code: ResMut<CodeCursor>,
+) {
+    if let Some((ea, eb, mut remaining)) = sort.pre_swap.take() {
+        // simple pulse: scale up slightly during pre-swap
+        for &e in [ea, eb].iter() {
+            if let Ok(mut tf) = q_tf.get_mut(e) {
+                tf.scale = Vec3::new(1.0, 1.15, 1.0);
+            }
+        }
+        // In auto mode, count down; in manual mode, wait for user trigger
+        let should_perform = if settings.auto {
+            remaining -= time.delta_seconds();
+            remaining <= 0.0
+        } else {
+            settings.manual_step
+        };
+
+        if should_perform {
+            // reset scales
+            for &e in [ea, eb].iter() {
+                if let Ok(mut tf) = q_tf.get_mut(e) {
+                    tf.scale = Vec3::ONE;
+                }
+            }
+            // Perform swap now
+            if let Some((a_idx, b_idx)) = sort.pending_swap_indices.take() {
+                // update array
+                sort.array.swap(a_idx, b_idx);
+                sort.counts.swap();
+                code.at("swap")
+                    .var("i", sort.i)
+                    .var("j", a_idx)
+                    .var("a[j]", sort.array[a_idx])
+                    .var("a[j+1]", sort.array[b_idx]);
+                // update indices and targets for these two entities
+                for (e, mut bar, mut tx) in bars.iter_mut() {
+                    if e == ea {
+                        bar.index = b_idx;
+                        tx.0 = _layout.x(b_idx);
+                    } else if e == eb {
+                        bar.index = a_idx;
+                        tx.0 = _layout.x(a_idx);
+                    }
+                }
+                sort.swapping = Some((ea, eb));
+            }
+        } else {
+            // continue pre-swap
+            sort.pre_swap = Some((ea, eb, remaining));
+        }
+    }
+}
+
+fn animate_swaps(
+    time: Res<Time>,
+    mut sort: ResMut<SortState>,
+    mut q: Query<(Entity, &TargetX, &mut Transform)>,
+) {
+    if let Some((ea, eb)) = sort.swapping {
+        let mut a_done = false;
+        let mut b_done = false;
+        for (e, tx, mut tf) in q.iter_mut() {
+            if e == ea || e == eb {
+                let dx = tx.0 - tf.translation.x;
+                let step = ANIM_SPEED * time.delta_seconds();
+                if dx.abs() <= step {
+                    tf.translation.x = tx.0;
+                    if e == ea {
+                        a_done = true;
+                    } else {
+                        b_done = true;
+                    }
+                } else {
+                    tf.translation.x += step * dx.signum();
+                }
+            }
+        }
+        if a_done && b_done {
+            sort.swapping = None;
+            sort.pre_swap_red = None;
+        }
+    }
+}
+
+// Left/Right or the timeline picked another snapshot: drop any swap in flight
+// and send every bar toward its slot in the restored order
+fn restore_snapshot(
+    mut events: EventReader<RestoreSnapshot>,
+    history: Res<History<Snapshot>>,
+    mut sort: ResMut<SortState>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX, &mut Transform)>,
+    layout: Res<Layout>,
+    mut code: ResMut<CodeCursor>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    // the snapshot has no line to point at until the next step
+    code.clear();
+    let Some(snap) = history.current() else {
+        return;
+    };
+    sort.array = snap.array.clone();
+    sort.i = snap.i;
+    sort.j = snap.j;
+    sort.sorted = snap.sorted;
+    sort.counts = snap.counts;
+    sort.swapping = None;
+    sort.pre_swap = None;
+    sort.pending_swap_indices = None;
+    sort.pre_swap_red = None;
+    for (entity, mut bar, mut tx, mut tf) in bars.iter_mut() {
+        if let Some(&(_, idx)) = snap.slots.iter().find(|&&(e, _)| e == entity) {
+            bar.index = idx;
+            tx.0 = layout.x(idx);
+        }
+        tf.scale = Vec3::ONE;
+    }
+    sort.restoring = true;
+}
+
+fn animate_restore(
+    time: Res<Time>,
+    mut sort: ResMut<SortState>,
+    mut q: Query<(&TargetX, &mut Transform), With<Bar>>,
+) {
+    if !sort.restoring {
+        return;
+    }
+    let step = ANIM_SPEED * time.delta_seconds();
+    let mut moving = false;
+    for (tx, mut tf) in q.iter_mut() {
+        let dx = tx.0 - tf.translation.x;
+        if dx.abs() <= step {
+            tf.translation.x = tx.0;
+        } else {
+            tf.translation.x += step * dx.signum();
+            moving = true;
+        }
+    }
+    if !moving {
+        sort.restoring = false;
+    }
+}
+
+fn record_history(
+    sort: Res<SortState>,
+    bars: Query<(Entity, &Bar)>,
+    mut history: ResMut<History<Snapshot>>,
+) {
+    if !sort.idle() {
+        return;
+    }
+    let mut slots: Vec<(Entity, usize)> = bars.iter().map(|(e, bar)| (e, bar.index)).collect();
+    slots.sort_unstable();
+    let snap = sort.snapshot(slots);
+    if history.differs(&snap) {
+        history.record(snap);
+    }
+}
+
+fn update_highlights(
+    sort: Res<SortState>,
+    layout: Res<Layout>,
+    mut q: Query<(Entity, &Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    let n = sort.array.len();
+    for (entity, bar, mut sprite) in q.iter_mut() {
+        // base color from value hue
+        let base = layout.color(bar.value);
+        if sort.sorted {
+            // when sorted, tint to a greenish color to indicate completion
+            sprite.color = palette.settled;
+        } else if bar.index >= n - sort.i {
+            // progressively mark the tail as sorted after each full pass
+            sprite.color = palette.settled;
+        } else if let Some((ea, eb)) = sort
+            .swapping
+            .or_else(|| sort.pre_swap.map(|(a, b, _)| (a, b)))
+        {
+            if let Some(red) = sort.pre_swap_red {
+                if entity == red {
+                    sprite.color = palette.violation; // moving larger bar
+                } else if entity == ea || entity == eb {
+                    sprite.color = palette.compare; // the other compared bar
+                } else {
+                    sprite.color = base;
+                }
+            } else if entity == ea || entity == eb {
+                sprite.color = palette.compare;
+            } else {
+                sprite.color = base;
+            }
+        } else if sort.j < n - 1 - sort.i && (bar.index == sort.j || bar.index == sort.j + 1) {
+            // highlight current comparison
+            sprite.color = palette.focus;
+        } else {
+            // default base color encodes value
+            sprite.color = base;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algoviz_core::{run_until, test_app};
+
+    fn sort_app(args: &[&str]) -> App {
+        let args = args.iter().map(|a| a.to_string());
+        let mut app = test_app(STEP_INTERVAL);
+        app.insert_resource(ArrayInput::parse(args, INPUT).unwrap())
+            .insert_resource(SortState::default())
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    step_bubble_sort.in_set(VizSet::Step),
+                    (pre_swap_anim, animate_swaps)
+                        .chain()
+                        .in_set(VizSet::Animate),
+                ),
+            );
+        app
+    }
+
+    fn run_to_end(app: &mut App) -> Vec<usize> {
+        app.update(); // Startup: spawn the bars
+        let n = app.world().resource::<SortState>().array.len();
+        // a comparison per tick plus a highlight and a slide per swap, with room to spare
+        run_until(app, 40 * n * n + 40, |world| {
+            world.resource::<SortState>().sorted
+        });
+        app.world().resource::<SortState>().array.clone()
+    }
+
+    /// Each bar ended where the array says its value is.
+    fn assert_bars_follow(app: &mut App, array: &[usize]) {
+        let layout = app.world().resource::<Layout>();
+        let x_of: Vec<f32> = (0..array.len()).map(|i| layout.x(i)).collect();
+        let mut bars = app.world_mut().query::<(&Bar, &TargetX, &Transform)>();
+        for (bar, target, tf) in bars.iter(app.world()) {
+            assert_eq!(array[bar.index], bar.value);
+            assert_eq!(target.0, x_of[bar.index]);
+            assert_eq!(tf.translation.x, x_of[bar.index]);
+        }
+    }
+
+    #[test]
+    fn sorts_given_array() {
+        let mut app = sort_app(&["--input", "5,1,4,2,8,3"]);
+        let array = run_to_end(&mut app);
+        assert_eq!(array, vec![1, 2, 3, 4, 5, 8]);
+        assert_bars_follow(&mut app, &array);
+    }
+
+    #[test]
+    fn sorts_random_permutations() {
+        for seed in 0..20 {
+            for n in [2, 3, 7, 16] {
+                let mut app = sort_app(&["--n", &n.to_string(), "--seed", &seed.to_string()]);
+                let array = run_to_end(&mut app);
+                assert_eq!(array, (1..=n).collect::<Vec<_>>(), "seed {seed}, n {n}");
+                assert_bars_follow(&mut app, &array);
+            }
+        }
+    }
+
+    #[test]
+    fn long_arrays_fit_the_row() {
+        for n in [10, 40, 199] {
+            let mut app = sort_app(&["--n", &n.to_string(), "--seed", "1"]);
+            app.update();
+            // no window in tests, so the row keeps its design width
+            let layout = app.world().resource::<Layout>();
+            let half = layout.row.width / 2.0;
+            assert!(layout.x(0) - half >= -ROW_WIDTH / 2.0 - 0.01, "n {n}");
+            assert!(layout.x(n - 1) + half <= ROW_WIDTH / 2.0 + 0.01, "n {n}");
+            let labels = app
+                .world_mut()
+                .query::<&ValueDigits>()
+                .iter(app.world())
+                .count();
+            assert_eq!(labels, if n == 10 { n } else { 0 }, "n {n}");
+        }
+        // from DENSE_N on the viz draws pixel columns instead of bars
+        let dense = |n: usize| {
+            ArrayInput::parse(["--n".to_string(), n.to_string()], INPUT).map(|input| input.dense())
+        };
+        assert_eq!(dense(DENSE_N - 1).ok(), Some(false));
+        assert_eq!(dense(DENSE_N).ok(), Some(true));
+        assert!(dense(algoviz_core::DENSE_MAX_N + 1).is_err());
+    }
+
+    #[test]
+    fn advance_matches_reference_swaps() {
+        for seed in 0..50u64 {
+            let mut input = ArrayInput::parse(
+                ["--n".into(), "12".into(), "--seed".into(), seed.to_string()],
+                INPUT,
+            )
+            .unwrap();
+            let start = input.permutation();
+            let mut sort = SortState {
+                array: start.clone(),
+                ..default()
+            };
+            let mut swaps = 0;
+            loop {
+                match sort.advance() {
+                    Step::Compare { a, b, swap: true } => {
+                        sort.array.swap(a, b);
+                        sort.counts.swap();
+                        swaps += 1;
+                    }
+                    Step::Done => break,
+                    _ => {}
+                }
+            }
+            // bubble sort swaps exactly once per inversion
+            let inversions = (0..start.len())
+                .flat_map(|i| (i + 1..start.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| start[i] > start[j])
+                .count();
+            assert_eq!(swaps, inversions, "seed {seed}");
+            // every pass runs to the end: n(n-1)/2 comparisons, whatever the input
+            let n = start.len();
+            let counts = sort.counts;
+            assert_eq!(counts.comparisons, n * (n - 1) / 2, "seed {seed}");
+            assert_eq!(counts.swaps, inversions, "seed {seed}");
+            assert_eq!(
+                (counts.reads, counts.writes),
+                (2 * (counts.comparisons + swaps), 2 * swaps),
+                "seed {seed}"
+            );
+            assert!(sort.array.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+    /// Bar positions, by value, after every frame of a whole run.
+    fn frames(args: &[&str]) -> Vec<Vec<(usize, f32, f32)>> {
+        let mut app = sort_app(args);
+        let mut frames = Vec::new();
+        while !app.world().resource::<SortState>().sorted {
+            app.update();
+            let mut bars = app.world_mut().query::<(&Bar, &Transform)>();
+            let mut frame: Vec<_> = bars
+                .iter(app.world())
+                .map(|(bar, tf)| (bar.value, tf.translation.x, tf.translation.y))
+                .collect();
+            frame.sort_by_key(|&(value, ..)| value);
+            frames.push(frame);
+        }
+        frames
+    }
+
+    #[test]
+    fn same_seed_replays_frame_for_frame() {
+        let args = ["--n", "10", "--seed", "42"];
+        let first = frames(&args);
+        assert!(first.len() > 10);
+        assert_eq!(first, frames(&args));
+    }
+
+    fn headless_trace(seed: u64, run: usize) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "bubble-sort-{}-{seed}-{run}.jsonl",
+            std::process::id()
+        ));
+        let mut input = ArrayInput::parse(
+            ["--n".into(), "12".into(), "--seed".into(), seed.to_string()],
+            INPUT,
+        )
+        .unwrap();
+        let mut trace = TraceWriter::new(Box::new(std::fs::File::create(&path).unwrap()));
+        run_headless(input.permutation(), &Locale::english(), &mut trace);
+        drop(trace);
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        text
+    }
+
+    #[test]
+    fn same_seed_gives_identical_traces() {
+        for seed in [0, 7, 1234] {
+            let first = headless_trace(seed, 0);
+            assert!(first.lines().count() > 12, "seed {seed}");
+            assert_eq!(first, headless_trace(seed, 1), "seed {seed}");
+        }
+        assert_ne!(headless_trace(1, 0), headless_trace(2, 0));
+    }
+
+    #[test]
+    fn quiz_holds_each_comparison_for_an_answer() {
+        // "keep" is the right answer exactly for the pairs already in order
+        for always_keep in [false, true] {
+            let mut app = sort_app(&["--input", "5,1,4,2,8,3"]);
+            app.world_mut().resource_mut::<Quiz>().enabled = true;
+            app.update();
+            let mut swap_answers = 0;
+            for _ in 0..20_000 {
+                if app.world().resource::<SortState>().sorted {
+                    break;
+                }
+                if let Some(question) = app.world().resource::<Quiz>().question().cloned() {
+                    // nothing moves while the question is open
+                    let before = app.world().resource::<SortState>().counts;
+                    app.update();
+                    assert_eq!(app.world().resource::<SortState>().counts, before);
+                    swap_answers += usize::from(question.answer == 0);
+                    app.world_mut()
+                        .resource_mut::<Quiz>()
+                        .answer(if always_keep { 1 } else { question.answer });
+                }
+                app.update();
+            }
+            let sort = app.world().resource::<SortState>();
+            let score = app.world().resource::<Quiz>().score();
+            assert_eq!(sort.array, vec![1, 2, 3, 4, 5, 8]);
+            assert_eq!(score.asked, sort.counts.comparisons);
+            assert_eq!(swap_answers, sort.counts.swaps);
+            if always_keep {
+                assert_eq!(score.correct, score.asked - sort.counts.swaps);
+            } else {
+                assert_eq!(
+                    (score.correct, score.best_streak),
+                    (score.asked, score.asked)
+                );
+            }
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Lines(Vec<&'static str>);
+
+    fn record_lines(code: Res<CodeCursor>, mut lines: ResMut<Lines>) {
+        if let Some(line) = code.line().filter(|_| code.is_changed()) {
+            lines.0.push(line);
+        }
+    }
+
+    #[test]
+    fn code_cursor_follows_each_step() {
+        let mut app = sort_app(&["--input", "5,1,4,2,8,3"]);
+        app.init_resource::<Lines>()
+            .add_systems(Update, record_lines.in_set(VizSet::Highlight));
+        run_to_end(&mut app);
+        app.update();
+        let lines = &app.world().resource::<Lines>().0;
+        let count = |id: &str| lines.iter().filter(|&&line| line == id).count();
+        let counts = app.world().resource::<SortState>().counts;
+        assert_eq!(count("compare"), counts.comparisons);
+        assert_eq!(count("swap"), counts.swaps);
+        assert_eq!(count("pass"), 5); // n - 1, the last one empty
+        assert_eq!(lines.last(), Some(&"done"));
+        // every stop is a line of both listings
+        for id in lines {
+            assert!(
+                PSEUDOCODE.lines.iter().any(|line| line.0 == *id)
+                    && RUST.lines.iter().any(|line| line.0 == *id),
+                "{id}"
+            );
+        }
+    }
+
+    #[test]
+    fn an_edit_restarts_on_the_edited_array() {
+        let mut app = sort_app(&["--input", "5,1,4,2,8,3"]);
+        app.init_resource::<History<Snapshot>>()
+            .add_systems(Update, handle_input.in_set(VizSet::Input));
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().resource::<SortState>().counts.comparisons > 0);
+        // paused, as the editor requires: 8 dragged to the front, 3 scrolled up past the old top
+        app.world_mut().resource_mut::<Settings>().auto = false;
+        let edited = vec![8, 5, 1, 4, 2, 40];
+        app.world_mut()
+            .resource_mut::<ArrayInput>()
+            .edit(edited.clone());
+        app.world_mut().send_event(ResetRequested);
+        app.update();
+        let sort = app.world().resource::<SortState>();
+        assert_eq!(
+            (sort.array.clone(), sort.i, sort.j, sort.counts),
+            (edited.clone(), 0, 0, OpCounts::default())
+        );
+        assert_eq!(app.world().resource::<Layout>().top, 40);
+        assert_bars_follow(&mut app, &edited);
+        let labels = app
+            .world_mut()
+            .query::<&ValueDigits>()
+            .iter(app.world())
+            .count();
+        assert_eq!(labels, edited.len());
+
+        app.world_mut().resource_mut::<Settings>().auto = true;
+        assert_eq!(run_to_end(&mut app), vec![1, 2, 4, 5, 8, 40]);
+        // the edit is used once: R brings back the --input array
+        app.world_mut().send_event(ResetRequested);
+        app.update();
+        assert_eq!(
+            app.world().resource::<SortState>().array,
+            vec![5, 1, 4, 2, 8, 3]
+        );
+    }
+
+    #[test]
+    fn every_language_fills_in_its_explanations() {
+        let mut sort = SortState {
+            array: vec![3, 1, 2],
+            ..default()
+        };
+        sort.pending_swap_indices = Some((0, 1));
+        for locale in Locale::all() {
+            let text = step_explanation(&sort, true, true, &locale);
+            assert!(
+                text.contains('3') && text.contains('1') && !text.contains('{'),
+                "{}: {text}",
+                locale.code()
+            );
+        }
+        let spanish = Locale::named("es").unwrap();
+        assert_ne!(
+            step_explanation(&sort, true, true, &spanish),
+            step_explanation(&sort, true, true, &Locale::english())
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub const TITLE: &str = "Connected Components";
+const BG_COLOR: Color = Color::srgb(0.04, 0.05, 0.09);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GridCell {
+    row: usize,
+    col: usize,
+    component: Option<usize>,
+}
+
+#[derive(Resource, Clone)]
+struct AppState {
+    grid: Vec<Vec<bool>>, // true for wall
+    components: Vec<HashSet<(usize, usize)>>,
+    colors: Vec<Color>,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "connected-components";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let grid = GridScenario::from_args().map_or_else(sample_grid, |scenario| scenario.walls());
+    let components = find_components(&grid);
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            grid,
+            components,
+            colors: vec![
+                Color::srgb(0.25, 0.55, 0.95),
+                Color::srgb(0.2, 0.8, 0.4),
+                Color::srgb(0.95, 0.65, 0.2),
+                Color::srgb(0.8, 0.2, 0.8),
+                Color::srgb(0.2, 0.8, 0.8),
+            ],
+        })
+        .setup(setup);
+}
+
+fn sample_grid() -> Vec<Vec<bool>> {
+    vec![
+        vec![false, false, true, false, false],
+        vec![false, true, false, true, false],
+        vec![false, false, false, false, false],
+        vec![true, true, false, true, false],
+        vec![false, false, false, false, false],
+    ]
+}
+
+fn find_components(grid: &Vec<Vec<bool>>) -> Vec<HashSet<(usize, usize)>> {
+    let mut visited = HashSet::new();
+    let mut components = Vec::new();
+    for row in 0..grid.len() {
+        for col in 0..grid[0].len() {
+            if !grid[row][col] && !visited.contains(&(row, col)) {
+                let mut component = HashSet::new();
+                dfs(grid, row, col, &mut visited, &mut component);
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+fn dfs(
+    grid: &Vec<Vec<bool>>,
+    row: usize,
+    col: usize,
+    visited: &mut HashSet<(usize, usize)>,
+    component: &mut HashSet<(usize, usize)>,
+) {
+    if grid[row][col] || visited.contains(&(row, col)) {
+        return;
+    }
+    visited.insert((row, col));
+    component.insert((row, col));
+    let dirs = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    for (dr, dc) in dirs {
+        let nr = row as i32 + dr;
+        let nc = col as i32 + dc;
+        if nr >= 0 && nr < grid.len() as i32 && nc >= 0 && nc < grid[0].len() as i32 {
+            let nr = nr as usize;
+            let nc = nc as usize;
+            dfs(grid, nr, nc, visited, component);
+        }
+    }
+}
+
+fn setup(mut commands: Commands, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let rows = state.grid.len();
+    let cols = state.grid[0].len();
+    let tile = (640.0 / rows.max(cols) as f32).min(80.0);
+    let origin = Vec2::new(
+        -(cols as f32 - 1.0) * tile / 2.0,
+        (rows as f32 - 1.0) * tile / 2.0,
+    );
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = origin.x + col as f32 * tile;
+            let y = origin.y - row as f32 * tile;
+            let is_wall = state.grid[row][col];
+            let component = state
+                .components
+                .iter()
+                .position(|c| c.contains(&(row, col)));
+            let color = if is_wall {
+                Color::srgb(0.3, 0.3, 0.3)
+            } else if let Some(comp) = component {
+                state.colors[comp % state.colors.len()]
+            } else {
+                Color::srgb(0.5, 0.5, 0.5)
+            };
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(tile - 6.0, tile - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                GridCell {
+                    row,
+                    col,
+                    component,
+                },
+                SegmentText::from_section(
+                    if let Some(comp) = component {
+                        format!("{}", comp + 1)
+                    } else if is_wall {
+                        "W".to_string()
+                    } else {
+                        "".to_string()
+                    },
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+        }
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Connected Components: DFS finds connected regions\nNumbers show component IDs",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
@@ -0,0 +1,275 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+const PILE_SPACING: f32 = 60.0;
+const CARD_WIDTH: f32 = 40.0;
+const CARD_HEIGHT: f32 = 30.0;
+const STEP_INTERVAL: f32 = 1.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct ArrayElement {
+    value: i32,
+    index: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct PileCard {
+    value: i32,
+    pile_index: usize,
+    card_index: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct PileIndicator {
+    pile_index: usize,
+}
+
+#[derive(Resource, Clone)]
+struct AppState {
+    array: Vec<i32>,
+    piles: Vec<Vec<i32>>,
+    current_index: usize,
+    done: bool,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "convex-hull";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL).manual())
+        .resource(ClearColor(Color::srgb(0.03, 0.04, 0.07)))
+        .resource(AppState {
+            array: sample_array(),
+            piles: Vec::new(),
+            current_index: 0,
+            done: false,
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_system.in_set(VizSet::Step),
+                update_visualization.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn sample_array() -> Vec<i32> {
+    vec![10, 9, 2, 5, 3, 7, 101, 18]
+}
+
+#[allow(dead_code)]
+fn patience_sorting_lis(arr: &[i32]) -> usize {
+    let mut piles: Vec<Vec<i32>> = Vec::new();
+
+    for &num in arr {
+        let mut placed = false;
+        for pile in piles.iter_mut() {
+            if *pile.last().unwrap() < num {
+                pile.push(num);
+                placed = true;
+                break;
+            }
+        }
+        if !placed {
+            piles.push(vec![num]);
+        }
+    }
+
+    piles.len()
+}
+
+fn setup(mut commands: Commands, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn array elements at the bottom
+    let start_x = -350.0;
+    let y = -300.0;
+    for (i, &value) in state.array.iter().enumerate() {
+        let x = start_x + i as f32 * 60.0;
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.2, 0.6, 1.0), // Blue for unprocessed
+                    custom_size: Some(Vec2::new(CARD_WIDTH, CARD_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, 0.0),
+                ..default()
+            },
+            ArrayElement { value, index: i },
+        ));
+
+        // Value label
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("{}", value),
+                SegmentStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(x, y, 1.0),
+            ..default()
+        });
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Longest Increasing Subsequence: Patience Sorting\nBlue cards = unprocessed, Green = processed, Yellow = current\nPress Space to start building piles, R to reset",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn input_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<AppState>,
+    mut commands: Commands,
+    query: Query<Entity, With<PileCard>>,
+) {
+    let restart = state.done && keyboard.clear_just_pressed(KeyCode::Space);
+    if restart || reset.read().count() > 0 {
+        *state = AppState {
+            array: sample_array(),
+            piles: Vec::new(),
+            current_index: 0,
+            done: false,
+        };
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn step_system(
+    settings: Res<Settings>,
+    mut state: ResMut<AppState>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    if state.done || !settings.step_ready() {
+        return;
+    }
+
+    if state.current_index < state.array.len() {
+        let num = state.array[state.current_index];
+        let mut placed = false;
+
+        // Find the leftmost pile where we can place this card
+        for (pile_idx, pile) in state.piles.iter_mut().enumerate() {
+            if *pile.last().unwrap() < num {
+                pile.push(num);
+
+                // Spawn card in this pile
+                let pile_x = -200.0 + pile_idx as f32 * PILE_SPACING;
+                let card_y = 100.0 + pile.len() as f32 * CARD_HEIGHT;
+
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: palette.settled, // Green for placed
+                            custom_size: Some(Vec2::new(CARD_WIDTH, CARD_HEIGHT)),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(pile_x, card_y, 0.0),
+                        ..default()
+                    },
+                    PileCard {
+                        value: num,
+                        pile_index: pile_idx,
+                        card_index: pile.len() - 1,
+                    },
+                ));
+
+                // Value label
+                commands.spawn(SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        format!("{}", num),
+                        SegmentStyle {
+                            font_size: 12.0,
+                            color: Color::srgb(0.9, 0.9, 0.9),
+                        },
+                    ),
+                    transform: Transform::from_xyz(pile_x, card_y, 1.0),
+                    ..default()
+                });
+
+                placed = true;
+                break;
+            }
+        }
+
+        if !placed {
+            // Create new pile
+            state.piles.push(vec![num]);
+            let pile_idx = state.piles.len() - 1;
+            let pile_x = -200.0 + pile_idx as f32 * PILE_SPACING;
+            let card_y = 100.0 + CARD_HEIGHT;
+
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: palette.settled, // Green for placed
+                        custom_size: Some(Vec2::new(CARD_WIDTH, CARD_HEIGHT)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pile_x, card_y, 0.0),
+                    ..default()
+                },
+                PileCard {
+                    value: num,
+                    pile_index: pile_idx,
+                    card_index: 0,
+                },
+            ));
+
+            // Value label
+            commands.spawn(SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("{}", num),
+                    SegmentStyle {
+                        font_size: 12.0,
+                        color: Color::srgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                transform: Transform::from_xyz(pile_x, card_y, 1.0),
+                ..default()
+            });
+        }
+
+        state.current_index += 1;
+    } else {
+        state.done = true;
+    }
+}
+
+fn update_visualization(
+    state: Res<AppState>,
+    mut query: Query<(&ArrayElement, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (element, mut sprite) in query.iter_mut() {
+        if element.index < state.current_index {
+            sprite.color = palette.settled; // Green for processed
+        } else if element.index == state.current_index {
+            sprite.color = palette.compare; // Yellow for current
+        } else {
+            sprite.color = palette.visited; // Blue for unprocessed
+        }
+    }
+}
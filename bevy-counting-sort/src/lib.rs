@@ -0,0 +1,507 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub const TITLE: &str = "Counting Sort";
+const BG_COLOR: Color = Color::srgb(0.03, 0.04, 0.09);
+const BAR_WIDTH: f32 = 36.0;
+const BAR_GAP: f32 = 12.0;
+const BASE_Y: f32 = -210.0;
+const BUCKET_WIDTH: f32 = 48.0;
+const BUCKET_GAP: f32 = 14.0;
+const BUCKET_HEIGHT: f32 = 120.0;
+const BUCKET_BASE_Y: f32 = -30.0;
+const STEP_INTERVAL: f32 = 0.6;
+// One count bucket per value 0..=9; 17 bars fill the fixed-size window.
+const MAX_VALUE: usize = 9;
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 15,
+    max_n: 17,
+    max_value: MAX_VALUE,
+    dense: false,
+};
+
+#[derive(Component)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct CountBucket {
+    value: usize,
+    count: usize,
+}
+
+#[derive(Component)]
+struct CumulativeBar;
+
+#[derive(Component)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct StepExplanation;
+
+#[derive(Component)]
+struct ExplanationText;
+
+#[derive(Resource)]
+#[allow(dead_code)]
+struct Layout {
+    origin_x: f32,
+    buckets_origin: f32,
+    max_value: usize,
+}
+
+#[derive(Resource)]
+struct SortState {
+    array: Vec<usize>,
+    count: Vec<usize>,
+    output: Vec<usize>,
+    current_index: usize,
+    phase: SortPhase,
+    counts: OpCounts,
+}
+
+#[derive(PartialEq)]
+enum SortPhase {
+    CountElements,
+    ComputeCumulative,
+    PlaceElements,
+    Complete,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "counting-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(input)
+        .setup(setup)
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (
+                    update_bars,
+                    update_buckets,
+                    update_cumulative_bars,
+                    update_educational_text,
+                )
+                    .in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut input: ResMut<ArrayInput>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Initialize array with the --input values or random values (0-9 for counting sort)
+    let array = new_array(&mut input);
+    let array_len = array.len();
+    let max_value = MAX_VALUE;
+
+    // Calculate layout positions
+    let origin_x = -(array_len as f32 * (BAR_WIDTH + BAR_GAP) - BAR_GAP) / 2.0 + BAR_WIDTH / 2.0;
+    let buckets_origin =
+        -(max_value as f32 * (BUCKET_WIDTH + BUCKET_GAP) - BUCKET_GAP) / 2.0 + BUCKET_WIDTH / 2.0;
+
+    commands.insert_resource(Layout {
+        origin_x,
+        buckets_origin,
+        max_value,
+    });
+
+    commands.insert_resource(SortState {
+        array: array.clone(),
+        count: vec![0; max_value + 1],
+        output: vec![0; array_len],
+        current_index: 0,
+        phase: SortPhase::CountElements,
+        counts: OpCounts::default(),
+    });
+
+    // Create array bars
+    for (i, &value) in array.iter().enumerate() {
+        let height = 40.0 + value as f32 * 25.0;
+        let x = origin_x + i as f32 * (BAR_WIDTH + BAR_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.5, 0.5, 0.5), // Gray initially
+                    custom_size: Some(Vec2::new(BAR_WIDTH, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, height / 2.0 + BASE_Y, 0.0),
+                ..default()
+            },
+            Bar { index: i, value },
+        ));
+    }
+
+    // Create count buckets
+    for i in 0..=max_value {
+        let x = buckets_origin + i as f32 * (BUCKET_WIDTH + BUCKET_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.25, 0.65, 0.85, 0.7),
+                    custom_size: Some(Vec2::new(BUCKET_WIDTH, BUCKET_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, BUCKET_BASE_Y, 0.0),
+                ..default()
+            },
+            CountBucket { value: i, count: 0 },
+        ));
+    }
+
+    // Create cumulative count bars (initially invisible)
+    for i in 0..=max_value {
+        let x = buckets_origin + i as f32 * (BUCKET_WIDTH + BUCKET_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.9, 0.8, 0.35, 0.0), // Initially transparent
+                    custom_size: Some(Vec2::new(BUCKET_WIDTH, 28.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -110.0, -1.0),
+                ..default()
+            },
+            CumulativeBar,
+        ));
+    }
+
+    // Educational text
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 32.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        AlgorithmTitle,
+        Localized::new("counting-sort.title"),
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        ProgressText,
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        StepExplanation,
+        Narration,
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.7, 0.7, 0.7),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(20.0),
+            right: Val::Px(20.0),
+            ..default()
+        }),
+        ExplanationText,
+        Localized::new("counting-sort.blurb"),
+    ));
+}
+
+// Every value 0..=MAX_VALUE once (while they fit), the rest repeats, shuffled
+fn random_array(n: usize, rng: &mut StdRng) -> Vec<usize> {
+    let mut array: Vec<usize> = (0..=MAX_VALUE).collect();
+    while array.len() < n {
+        array.push(rng.gen_range(0..=MAX_VALUE));
+    }
+    array.truncate(n);
+    array.shuffle(rng);
+    array
+}
+
+// Random keeps every value present; the other presets spread over 0..=MAX_VALUE
+fn new_array(input: &mut ArrayInput) -> Vec<usize> {
+    if input.distribution == Distribution::Random {
+        input.array_or(random_array)
+    } else {
+        input.preset(0, MAX_VALUE)
+    }
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut input: ResMut<ArrayInput>,
+) {
+    if reset.read().count() > 0 {
+        // Restart with a new array from the preset (a fixed --input array comes back unchanged)
+        state.array = new_array(&mut input);
+        let n = state.array.len();
+
+        state.count = vec![0; state.count.len()];
+        state.output = vec![0; n];
+        state.current_index = 0;
+        state.phase = SortPhase::CountElements;
+        state.counts = OpCounts::default();
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if !settings.step_ready() {
+        return;
+    }
+
+    match state.phase {
+        SortPhase::CountElements => {
+            if state.current_index < state.array.len() {
+                let value = state.array[state.current_index];
+                state.count[value] += 1;
+                // one counter per possible value: the k in O(n + k) space
+                let counters = state.count.len();
+                state.counts.read(1);
+                state.counts.hold(counters);
+                state.current_index += 1;
+            } else {
+                // Counting complete, move to cumulative phase
+                state.current_index = 1; // Start from index 1 for cumulative
+                state.phase = SortPhase::ComputeCumulative;
+            }
+        }
+        SortPhase::ComputeCumulative => {
+            if state.current_index < state.count.len() {
+                let current_idx = state.current_index;
+                let prev_count = state.count[current_idx - 1];
+                state.count[current_idx] += prev_count;
+                state.current_index = current_idx + 1;
+            } else {
+                // Cumulative complete, move to placement phase
+                state.current_index = state.array.len() - 1; // Start from end
+                state.phase = SortPhase::PlaceElements;
+            }
+        }
+        SortPhase::PlaceElements => {
+            let current_idx = state.current_index;
+            let value = state.array[current_idx];
+            let position = state.count[value] - 1;
+            state.output[position] = value;
+            state.count[value] -= 1;
+            // the output buffer fills alongside the counters
+            let held = state.count.len() + state.array.len();
+            state.counts.read(1);
+            state.counts.hold(held);
+            if current_idx > 0 {
+                state.current_index = current_idx - 1;
+            } else {
+                // Copy output back to array
+                let output = state.output.clone();
+                state.array.copy_from_slice(&output);
+                state.counts.write(output.len());
+                state.phase = SortPhase::Complete;
+            }
+        }
+        SortPhase::Complete => {}
+    }
+}
+
+fn update_bars(
+    mut bars: Query<(&mut Sprite, &mut Transform, &Bar)>,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, mut transform, bar) in bars.iter_mut() {
+        // Update position based on current array position
+        let current_value = if state.phase == SortPhase::Complete {
+            state.array[bar.index]
+        } else {
+            bar.value
+        };
+        let current_index = state
+            .array
+            .iter()
+            .position(|&v| v == current_value)
+            .unwrap_or(bar.index);
+
+        let x = layout.origin_x + current_index as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+
+        // Update height based on value
+        let height = 40.0 + current_value as f32 * 25.0;
+        transform.translation.y = height / 2.0 + BASE_Y;
+        sprite.custom_size = Some(Vec2::new(BAR_WIDTH, height));
+
+        // Color coding
+        if state.phase == SortPhase::CountElements && bar.index == state.current_index {
+            sprite.color = palette.compare; // Orange for current element being counted
+        } else if state.phase == SortPhase::PlaceElements && bar.index == state.current_index {
+            sprite.color = palette.settled; // Green for current element being placed
+        } else if state.phase == SortPhase::Complete {
+            sprite.color = palette.settled; // Green for sorted
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5); // Gray for unsorted
+        }
+    }
+}
+
+fn update_buckets(
+    mut buckets: Query<(&mut Sprite, &CountBucket)>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, bucket) in buckets.iter_mut() {
+        let current_count = state.count[bucket.value];
+
+        // Update height based on count
+        let height = 40.0 + current_count as f32 * 20.0;
+        sprite.custom_size = Some(Vec2::new(BUCKET_WIDTH, height.max(BUCKET_HEIGHT)));
+        sprite.color = if state.phase == SortPhase::CountElements
+            && bucket.value == state.array.get(state.current_index).copied().unwrap_or(0)
+        {
+            palette.compare // Orange for bucket being incremented
+        } else {
+            palette.visited.with_alpha(0.7) // Blue for normal buckets
+        };
+    }
+}
+
+fn update_cumulative_bars(
+    mut bars: Query<&mut Sprite, With<CumulativeBar>>,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    let mut bar_iter = bars.iter_mut();
+    for i in 0..=layout.max_value {
+        if let Some(mut sprite) = bar_iter.next() {
+            if state.phase == SortPhase::ComputeCumulative
+                || state.phase == SortPhase::PlaceElements
+                || state.phase == SortPhase::Complete
+            {
+                let cumulative_count = state.count[i];
+                let width = cumulative_count as f32 * 8.0; // Scale cumulative count
+                sprite.custom_size = Some(Vec2::new(width.max(10.0), 28.0));
+                sprite.color = palette.compare.with_alpha(0.8); // Yellow for cumulative
+            } else {
+                sprite.color = palette.compare.with_alpha(0.0); // Transparent when not in use
+            }
+        }
+    }
+}
+
+fn update_educational_text(
+    state: Res<SortState>,
+    locale: Res<Locale>,
+    mut text_params: ParamSet<(
+        Query<&mut Text, With<ProgressText>>,
+        Query<&mut Text, With<StepExplanation>>,
+    )>,
+) {
+    // Update progress text
+    if let Ok(mut text) = text_params.p0().get_single_mut() {
+        text.sections[0].value = tr!(
+            locale,
+            "counting-sort.progress",
+            reads = state.counts.reads,
+            writes = state.counts.writes
+        );
+    }
+
+    // Update step explanation
+    if let Ok(mut text) = text_params.p1().get_single_mut() {
+        let explanation = match state.phase {
+            SortPhase::CountElements => {
+                if state.current_index < state.array.len() {
+                    tr!(
+                        locale,
+                        "counting-sort.step.count",
+                        index = state.current_index,
+                        value = state.array[state.current_index]
+                    )
+                } else {
+                    locale.text("counting-sort.step.counted")
+                }
+            }
+            SortPhase::ComputeCumulative => {
+                if state.current_index < state.count.len() {
+                    tr!(
+                        locale,
+                        "counting-sort.step.cumulative",
+                        index = state.current_index,
+                        count = state.count[state.current_index],
+                        before = state.count[state.current_index - 1],
+                    )
+                } else {
+                    locale.text("counting-sort.step.accumulated")
+                }
+            }
+            SortPhase::PlaceElements => {
+                let value = state.array[state.current_index];
+                let position = state.count[value];
+                tr!(
+                    locale,
+                    "counting-sort.step.place",
+                    index = state.current_index,
+                    value = value,
+                    position = position
+                )
+            }
+            SortPhase::Complete => locale.text("counting-sort.step.done"),
+        };
+        text.sections[0].value = explanation;
+    }
+}
@@ -0,0 +1,292 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Daily Temperatures";
+const BG_COLOR: Color = Color::srgb(0.018, 0.045, 0.11);
+const TEMPS: [i32; 8] = [73, 74, 75, 71, 69, 72, 76, 73];
+const RESULTS: [i32; 8] = [1, 1, 4, 2, 1, 1, 0, 0];
+const TOKEN_SIZE: Vec2 = Vec2::new(96.0, 70.0);
+const TOKEN_GAP: f32 = 28.0;
+const STACK_VALUES: [usize; 4] = [2, 1, 0, usize::MAX];
+
+#[derive(Component)]
+struct TempBox;
+
+#[derive(Component)]
+struct StackSlot;
+
+#[derive(Component)]
+struct StepPanel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "daily-temps";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_temperature_row(&mut commands, &palette);
+    spawn_stack_column(&mut commands, &palette);
+    spawn_result_row(&mut commands, &palette);
+    spawn_step_panel(&mut commands);
+
+    info!("Daily Temperatures scaffold loaded. Animate monotonic stack operations next.");
+}
+
+fn spawn_temperature_row(commands: &mut Commands, palette: &Palette) {
+    let total_width = TEMPS.len() as f32 * (TOKEN_SIZE.x + TOKEN_GAP) - TOKEN_GAP;
+    let origin_x = -total_width / 2.0 + TOKEN_SIZE.x / 2.0;
+    let y = 210.0;
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Temperatures (°F)",
+            SegmentStyle {
+                font_size: 34.0,
+                color: Color::srgba(0.92, 0.96, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(origin_x - TOKEN_SIZE.x, y + TOKEN_SIZE.y / 2.0 + 36.0, 0.1),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    for (idx, temp) in TEMPS.iter().enumerate() {
+        let x = origin_x + idx as f32 * (TOKEN_SIZE.x + TOKEN_GAP);
+        let role = if idx == 6 {
+            Highlight::Compare
+        } else {
+            Highlight::Visited
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.role(role).with_alpha(0.85),
+                    custom_size: Some(TOKEN_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, 0.0),
+                ..default()
+            },
+            TempBox,
+            role,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("{}°", temp),
+                SegmentStyle {
+                    font_size: 30.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, y + 4.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("day {idx}"),
+                SegmentStyle {
+                    font_size: 18.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 0.75),
+                },
+            ),
+            transform: Transform::from_xyz(x, y - TOKEN_SIZE.y / 2.0 - 26.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_stack_column(commands: &mut Commands, palette: &Palette) {
+    let base = Vec3::new(-420.0, -20.0, 0.0);
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Stack of indices (monotonic decreasing temps)",
+            SegmentStyle {
+                font_size: 26.0,
+                color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_translation(base + Vec3::new(0.0, 240.0, 0.1)),
+        text_anchor: Anchor::TopCenter,
+        ..default()
+    });
+
+    for (level, day) in STACK_VALUES.iter().enumerate() {
+        let y = base.y - level as f32 * 90.0;
+        let mut slot = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: if *day == usize::MAX {
+                        Color::srgba(0.2, 0.28, 0.4, 0.5)
+                    } else {
+                        palette.compare.with_alpha(0.8)
+                    },
+                    custom_size: Some(Vec2::new(150.0, 80.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(Vec3::new(base.x, y, 0.0)),
+                ..default()
+            },
+            StackSlot,
+        ));
+        if *day != usize::MAX {
+            slot.insert(Highlight::Compare);
+        }
+
+        let label = if *day == usize::MAX {
+            "(empty)".to_string()
+        } else {
+            format!("day {day} ({}°)", TEMPS[*day])
+        };
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                label,
+                SegmentStyle {
+                    font_size: 22.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(base.x, y + 4.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+    }
+}
+
+fn spawn_result_row(commands: &mut Commands, palette: &Palette) {
+    let total_width = RESULTS.len() as f32 * (TOKEN_SIZE.x + TOKEN_GAP) - TOKEN_GAP;
+    let origin_x = -total_width / 2.0 + TOKEN_SIZE.x / 2.0;
+    let y = -160.0;
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Wait days until warmer",
+            SegmentStyle {
+                font_size: 32.0,
+                color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(origin_x - TOKEN_SIZE.x, y + TOKEN_SIZE.y / 2.0 + 32.0, 0.1),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    for (idx, wait) in RESULTS.iter().enumerate() {
+        let x = origin_x + idx as f32 * (TOKEN_SIZE.x + TOKEN_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.4),
+                    custom_size: Some(TOKEN_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, -0.1),
+                ..default()
+            },
+            Highlight::Visited,
+            TempBox,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                wait.to_string(),
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, y + 4.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+    }
+}
+
+fn spawn_step_panel(commands: &mut Commands) {
+    let panel_pos = Vec3::new(420.0, -20.0, -0.15);
+    let panel_size = Vec2::new(420.0, 420.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.16, 0.24, 0.34, 0.9),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Monotonic stack flow",
+                SegmentStyle {
+                    font_size: 30.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 28.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    let steps = [
+        "Iterate days left ➜ right",
+        "While stack top colder than today, pop and fill wait",
+        "Push today onto stack awaiting warmer day",
+        "Stack holds indices in decreasing temp order",
+        "Any index left in stack gets wait 0",
+    ];
+
+    for (i, step) in steps.iter().enumerate() {
+        let y = panel_pos.y + panel_size.y / 2.0 - 90.0 - i as f32 * 80.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.34, 0.6, 0.88, 0.32),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, 70.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, y, 0.0),
+                ..default()
+            },
+            StepPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    *step,
+                    SegmentStyle {
+                        font_size: 22.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(panel_pos.x, y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            StepPanel,
+        ));
+    }
+}
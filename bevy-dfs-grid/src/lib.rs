@@ -0,0 +1,169 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub const TITLE: &str = "DFS Grid";
+const BG_COLOR: Color = Color::srgb(0.02, 0.03, 0.07);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GridCell {
+    row: usize,
+    col: usize,
+    is_wall: bool,
+    is_visited: bool,
+    is_current: bool,
+}
+
+#[derive(Resource, Clone)]
+#[allow(dead_code)]
+struct AppState {
+    grid: Vec<Vec<bool>>, // true for wall
+    start: (usize, usize),
+    visited: HashSet<(usize, usize)>,
+    stack: Vec<(usize, usize)>,
+    step: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "dfs-grid";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let (grid, start) = match GridScenario::from_args_checked(GridScenario::open_start) {
+        Some(scenario) => (scenario.walls(), scenario.start()),
+        None => (sample_grid(), (0, 0)),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            grid,
+            start,
+            visited: HashSet::new(),
+            stack: vec![start],
+            step: 0,
+        })
+        .setup(setup)
+        .systems(Update, update_dfs);
+}
+
+fn sample_grid() -> Vec<Vec<bool>> {
+    vec![
+        vec![false, false, false, true, false],
+        vec![false, true, false, true, false],
+        vec![false, false, false, false, false],
+        vec![true, true, false, true, false],
+        vec![false, false, false, false, false],
+    ]
+}
+
+fn setup(mut commands: Commands, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let rows = state.grid.len();
+    let cols = state.grid[0].len();
+    // scenario grids can be larger than the sample, so shrink the tiles to fit
+    let tile = (600.0 / rows.max(cols) as f32).min(80.0);
+    let origin = Vec2::new(
+        -(cols as f32 - 1.0) * tile / 2.0,
+        (rows as f32 - 1.0) * tile / 2.0,
+    );
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = origin.x + col as f32 * tile;
+            let y = origin.y - row as f32 * tile;
+            let is_wall = state.grid[row][col];
+            let color = if is_wall {
+                Color::srgb(0.3, 0.3, 0.3)
+            } else if (row, col) == state.start {
+                Color::srgb(1.0, 1.0, 0.0)
+            } else {
+                Color::srgb(0.5, 0.5, 0.5)
+            };
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(tile - 6.0, tile - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                GridCell {
+                    row,
+                    col,
+                    is_wall,
+                    is_visited: false,
+                    is_current: (row, col) == state.start,
+                },
+                SegmentText::from_section(
+                    if is_wall { "W" } else { "" }.to_string(),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+        }
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "DFS Grid: Stack-based traversal\nYellow: Start, Green: Visited, Blue: Current",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn update_dfs(
+    mut cells: Query<(&mut Sprite, &GridCell)>,
+    time: Res<Time>,
+    mut state: ResMut<AppState>,
+    palette: Res<Palette>,
+) {
+    // Simulate DFS steps
+    if (time.elapsed_seconds() as usize).is_multiple_of(2) && !state.stack.is_empty() {
+        let (row, col) = state.stack.pop().unwrap();
+        if !state.visited.contains(&(row, col)) && !state.grid[row][col] {
+            state.visited.insert((row, col));
+            // Add neighbors
+            let dirs = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+            for (dr, dc) in dirs {
+                let nr = row as i32 + dr;
+                let nc = col as i32 + dc;
+                if nr >= 0
+                    && nr < state.grid.len() as i32
+                    && nc >= 0
+                    && nc < state.grid[0].len() as i32
+                {
+                    let nr = nr as usize;
+                    let nc = nc as usize;
+                    if !state.visited.contains(&(nr, nc)) && !state.grid[nr][nc] {
+                        state.stack.push((nr, nc));
+                    }
+                }
+            }
+        }
+    }
+
+    for (mut sprite, cell) in cells.iter_mut() {
+        if cell.is_wall {
+            sprite.color = Color::srgb(0.3, 0.3, 0.3);
+        } else if state.visited.contains(&(cell.row, cell.col)) {
+            sprite.color = palette.settled;
+        } else if (cell.row, cell.col) == state.start {
+            sprite.color = palette.compare;
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5);
+        }
+    }
+}
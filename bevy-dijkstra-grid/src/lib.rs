@@ -0,0 +1,170 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub const TITLE: &str = "Dijkstra Grid";
+const BG_COLOR: Color = Color::srgb(0.02, 0.03, 0.08);
+
+#[derive(Component)]
+struct GridCell {
+    row: usize,
+    col: usize,
+}
+
+impl GridTile for GridCell {
+    fn cell(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "dijkstra-grid";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let grid = match GridScenario::from_args_checked(GridScenario::open_endpoints) {
+        Some(scenario) => PathGrid::from_scenario(&scenario, true),
+        None => PathGrid::from_costs(&sample_grid(), (0, 0), (4, 4)),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(grid)
+        .grid_editor::<GridCell>(GridBrush::Weights)
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_grid() -> Vec<Vec<usize>> {
+    vec![
+        vec![1, 3, 1, 2, 1],
+        vec![1, 2, 1, 1, 1],
+        vec![1, 1, 1, 1, 1],
+        vec![2, 1, 1, 1, 1],
+        vec![1, 1, 1, 1, 1],
+    ]
+}
+
+// empty when walls cut the target off
+fn dijkstra_path(grid: &PathGrid) -> Vec<(usize, usize)> {
+    let (start, target) = (grid.start, grid.goal);
+    let mut distances = vec![vec![usize::MAX; grid.cols()]; grid.rows()];
+    distances[start.0][start.1] = 0;
+
+    let mut pq = BinaryHeap::new();
+    pq.push(Reverse((0, start))); // (distance, (row, col))
+
+    let mut prev = vec![vec![None; grid.cols()]; grid.rows()];
+
+    while let Some(Reverse((dist, (r, c)))) = pq.pop() {
+        if dist > distances[r][c] {
+            continue;
+        }
+
+        for (nr, nc) in grid.neighbors((r, c)) {
+            let Some(cost) = grid.cost((nr, nc)) else {
+                continue;
+            };
+            let new_dist = dist + cost;
+            if new_dist < distances[nr][nc] {
+                distances[nr][nc] = new_dist;
+                prev[nr][nc] = Some((r, c));
+                pq.push(Reverse((new_dist, (nr, nc))));
+            }
+        }
+    }
+
+    if distances[target.0][target.1] == usize::MAX {
+        return Vec::new();
+    }
+
+    // Reconstruct path to the target
+    let mut path = Vec::new();
+    let mut current = target;
+    while let Some(prev_pos) = prev[current.0][current.1] {
+        path.push(current);
+        current = prev_pos;
+    }
+    path.push(start);
+    path.reverse();
+    path
+}
+
+fn setup(mut commands: Commands, grid: Res<PathGrid>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let rows = grid.rows();
+    let cols = grid.cols();
+    let tile = (640.0 / rows.max(cols) as f32).min(70.0);
+    let origin = Vec2::new(
+        -(cols as f32 - 1.0) * tile / 2.0,
+        (rows as f32 - 1.0) * tile / 2.0,
+    );
+
+    // colors and weights come from redraw
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = origin.x + col as f32 * tile;
+            let y = origin.y - row as f32 * tile;
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::new(tile - 6.0, tile - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                GridCell { row, col },
+                SegmentText::from_section(
+                    "",
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+        }
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Dijkstra Grid: Priority queue shortest path\nYellow: Start, Red: End, Green: Path, Gray: Walls",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+// searches again whenever the grid was edited (or first shown) or the theme changed
+fn redraw(
+    grid: Res<PathGrid>,
+    palette: Res<Palette>,
+    mut cells: Query<(&GridCell, &mut Sprite, &mut SegmentText)>,
+) {
+    if !grid.is_changed() && !palette.is_changed() {
+        return;
+    }
+    let path = dijkstra_path(&grid);
+    for (cell, mut sprite, mut text) in cells.iter_mut() {
+        let at = (cell.row, cell.col);
+        let weight = grid.cost(at);
+        sprite.color = match weight {
+            None => Color::srgb(0.3, 0.3, 0.3),
+            Some(_) if path.contains(&at) => palette.settled,
+            Some(_) if at == grid.start => palette.compare,
+            Some(_) if at == grid.goal => palette.violation,
+            Some(weight) => Color::srgb(0.3 + weight as f32 * 0.1, 0.5, 0.9),
+        };
+        let label = weight.map_or(String::new(), |weight| weight.to_string());
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub const TITLE: &str = "Directed Cycle Detection";
+const BG_COLOR: Color = Color::srgb(0.03, 0.02, 0.07);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphNode {
+    id: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    in_cycle: bool,
+}
+
+/// Everything redraw spawns, cleared when the graph changes.
+#[derive(Component)]
+struct Drawn;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "directed-cycle";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let graph = match GraphScenario::from_args_checked(GraphScenario::directed_edges) {
+        Some(scenario) => Graph::from_scenario(&scenario, EdgeWeights::Unweighted),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(graph)
+        .graph_editor()
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_graph() -> Graph {
+    let positions = vec![
+        Vec2::new(-200.0, 100.0),
+        Vec2::new(0.0, 100.0),
+        Vec2::new(200.0, 100.0),
+        Vec2::new(-100.0, -100.0),
+        Vec2::new(100.0, -100.0),
+        Vec2::new(-200.0, -100.0),
+    ];
+    // cycle 0->1->2->0
+    let edges = [
+        (0, 1, 1),
+        (1, 2, 1),
+        (2, 0, 1),
+        (2, 3, 1),
+        (3, 4, 1),
+        (5, 2, 1),
+    ];
+    Graph::directed(positions, edges, EdgeWeights::Unweighted)
+}
+
+fn detect_cycle(graph: &[Vec<(usize, i32)>]) -> Vec<(usize, usize)> {
+    let mut visited = HashSet::new();
+    let mut rec_stack = HashSet::new();
+    let mut cycle = Vec::new();
+
+    for node in 0..graph.len() {
+        if !visited.contains(&node)
+            && dfs_cycle(graph, node, &mut visited, &mut rec_stack, &mut cycle)
+        {
+            break;
+        }
+    }
+    cycle
+}
+
+fn dfs_cycle(
+    graph: &[Vec<(usize, i32)>],
+    node: usize,
+    visited: &mut HashSet<usize>,
+    rec_stack: &mut HashSet<usize>,
+    cycle: &mut Vec<(usize, usize)>,
+) -> bool {
+    visited.insert(node);
+    rec_stack.insert(node);
+
+    for &(neighbor, _) in &graph[node] {
+        if !visited.contains(&neighbor) {
+            if dfs_cycle(graph, neighbor, visited, rec_stack, cycle) {
+                cycle.push((node, neighbor));
+                return true;
+            }
+        } else if rec_stack.contains(&neighbor) {
+            cycle.push((node, neighbor));
+            return true;
+        }
+    }
+
+    rec_stack.remove(&node);
+    false
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+// searches again and redraws whenever the graph was edited (or first shown) or the theme changed
+fn redraw(
+    mut commands: Commands,
+    graph: Res<Graph>,
+    palette: Res<Palette>,
+    drawn: Query<Entity, With<Drawn>>,
+) {
+    if !graph.is_changed() && !palette.is_changed() {
+        return;
+    }
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let cycle = detect_cycle(&graph.adjacency());
+    let positions = graph.positions();
+
+    // Nodes
+    for (id, &pos) in positions.iter().enumerate() {
+        let color = if cycle.iter().any(|&(f, _)| f == id) {
+            palette.violation
+        } else {
+            palette.visited
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            GraphNode { id },
+            SegmentText::from_section(
+                format!("{}", id),
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            Drawn,
+        ));
+    }
+
+    // Edges
+    for &(from, to, _) in graph.edges() {
+        let start = positions[from];
+        let end = positions[to];
+        let dir = (end - start).normalize_or_zero();
+        let length = (end - start).length();
+        let midpoint = start + dir * length / 2.0;
+        let angle = dir.y.atan2(dir.x);
+        let in_cycle = cycle.contains(&(from, to));
+        let color = if in_cycle {
+            palette.violation
+        } else {
+            Color::srgb(0.7, 0.7, 0.7)
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new((length - 60.0).max(0.0), 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            GraphEdge { from, to, in_cycle },
+            Drawn,
+        ));
+        // Arrow head
+        let arrow_pos = end - dir * 30.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(20.0, 20.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(arrow_pos.x, arrow_pos.y, -0.05),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            Drawn,
+        ));
+    }
+
+    // Instructions
+    let cycle_text = if cycle.is_empty() {
+        "No cycle detected".to_string()
+    } else {
+        format!("Cycle detected: {:?}", cycle)
+    };
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!(
+                    "Directed Cycle Detection: DFS with recursion stack\n{}",
+                    cycle_text
+                ),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, -350.0, 0.0),
+            ..default()
+        },
+        Drawn,
+    ));
+}
@@ -0,0 +1,280 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use rand::Rng;
+
+pub const TITLE: &str = "Dutch National Flag";
+const BG_COLOR: Color = Color::srgb(0.04, 0.05, 0.09);
+const STEP_INTERVAL: f32 = 0.6;
+const COUNT: usize = 18;
+const WIDTH: f32 = 36.0;
+const GAP: f32 = 10.0;
+const BASE_Y: f32 = -80.0;
+const ZONE_HEIGHT: f32 = 320.0;
+// low, mid and high values each keep their own hue
+const VALUE_COLORS: [Color; 3] = [
+    Color::srgba(0.87, 0.38, 0.42, 0.85),
+    Color::srgba(0.3, 0.7, 0.95, 0.85),
+    Color::srgba(0.95, 0.82, 0.35, 0.85),
+];
+const ZONE_COLORS: [Color; 3] = [
+    Color::srgba(0.5, 0.2, 0.3, 0.35),
+    Color::srgba(0.2, 0.45, 0.7, 0.35),
+    Color::srgba(0.75, 0.65, 0.2, 0.35),
+];
+
+#[derive(Component)]
+struct ArrayElement {
+    index: usize,
+}
+
+/// 0: `[0, low)`, 1: `[low, mid)`, 2: `[high, n)`.
+#[derive(Component)]
+struct PartitionZone(usize);
+
+/// 0: low, 1: mid, 2: high.
+#[derive(Component)]
+struct PointerLabel(usize);
+
+/// Values 0, 1 and 2 and the three-way partition's pointers: `[0, low)` holds
+/// 0s, `[low, mid)` 1s, `[high, n)` 2s, and `[mid, high)` is still unread.
+#[derive(Resource)]
+struct SortState {
+    array: Vec<usize>,
+    low: usize,
+    mid: usize,
+    high: usize,
+    counts: OpCounts,
+}
+
+impl SortState {
+    fn new(array: Vec<usize>) -> Self {
+        let high = array.len();
+        Self {
+            array,
+            low: 0,
+            mid: 0,
+            high,
+            counts: OpCounts::default(),
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.mid >= self.high
+    }
+
+    // places the element under mid
+    fn step(&mut self) {
+        if self.done() {
+            return;
+        }
+        self.counts.compare_held();
+        match self.array[self.mid] {
+            0 => {
+                self.array.swap(self.low, self.mid);
+                self.counts.swap();
+                self.low += 1;
+                self.mid += 1;
+            }
+            1 => self.mid += 1,
+            _ => {
+                self.high -= 1;
+                self.array.swap(self.mid, self.high);
+                self.counts.swap();
+            }
+        }
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "dnf";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .setup(setup)
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (update_array, update_zones, update_pointers).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut rng: ResMut<VizRng>) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.insert_resource(SortState::new(new_array(&mut rng)));
+    spawn_array(&mut commands);
+    spawn_partition_zones(&mut commands);
+    spawn_pointers(&mut commands);
+
+    info!("Dutch National Flag visualization ready!");
+}
+
+fn new_array(rng: &mut VizRng) -> Vec<usize> {
+    (0..COUNT).map(|_| rng.0.gen_range(0..3)).collect()
+}
+
+fn slot_x(index: usize) -> f32 {
+    let origin_x = -(COUNT as f32 * (WIDTH + GAP) - GAP) / 2.0 + WIDTH / 2.0;
+    origin_x + index as f32 * (WIDTH + GAP)
+}
+
+fn spawn_array(commands: &mut Commands) {
+    for index in 0..COUNT {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(WIDTH, 0.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(slot_x(index), BASE_Y, 0.0),
+                ..default()
+            },
+            ArrayElement { index },
+        ));
+    }
+}
+
+fn spawn_partition_zones(commands: &mut Commands) {
+    for (zone, &color) in ZONE_COLORS.iter().enumerate() {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::ZERO),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, BASE_Y + ZONE_HEIGHT / 2.0 - 40.0, -0.5),
+                ..default()
+            },
+            PartitionZone(zone),
+        ));
+    }
+
+    let zone_text = ["Low", "Mid", "High"]
+        .iter()
+        .map(|label| format!("{label} zone\n"))
+        .collect::<String>();
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            zone_text,
+            SegmentStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(0.0, 250.0, 0.5),
+        text_anchor: Anchor::TopCenter,
+        ..default()
+    });
+}
+
+fn spawn_pointers(commands: &mut Commands) {
+    let labels = ["low", "mid", "high"];
+
+    for (idx, label) in labels.into_iter().enumerate() {
+        // the labels stack so pointers on one slot stay readable
+        let label_y = -40.0 - idx as f32 * 30.0;
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::new(12.0, 80.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0.0, -140.0, 0.2),
+                    ..default()
+                },
+                Highlight::Focus,
+                PointerLabel(idx),
+            ))
+            .with_children(|parent| {
+                parent.spawn(SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        label,
+                        SegmentStyle {
+                            font_size: 24.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    text_anchor: Anchor::TopCenter,
+                    transform: Transform::from_xyz(0.0, label_y, 0.0),
+                    ..default()
+                });
+            });
+    }
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut rng: ResMut<VizRng>,
+) {
+    if reset.read().count() > 0 {
+        *state = SortState::new(new_array(&mut rng));
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if settings.step_ready() {
+        state.step();
+    }
+}
+
+fn update_array(
+    state: Res<SortState>,
+    palette: Res<Palette>,
+    mut elements: Query<(&ArrayElement, &mut Sprite, &mut Transform)>,
+) {
+    for (element, mut sprite, mut transform) in &mut elements {
+        let value = state.array[element.index];
+        let height = 80.0 + value as f32 * 60.0;
+        sprite.custom_size = Some(Vec2::new(WIDTH, height));
+        transform.translation.y = BASE_Y + height / 2.0;
+        sprite.color = if !state.done() && element.index == state.mid {
+            palette.compare.with_alpha(0.85)
+        } else {
+            VALUE_COLORS[value]
+        };
+    }
+}
+
+fn update_zones(
+    state: Res<SortState>,
+    mut zones: Query<(&PartitionZone, &mut Sprite, &mut Transform)>,
+) {
+    for (zone, mut sprite, mut transform) in &mut zones {
+        let (start, end) = match zone.0 {
+            0 => (0, state.low),
+            1 => (state.low, state.mid),
+            _ => (state.high, state.array.len()),
+        };
+        if start == end {
+            sprite.custom_size = Some(Vec2::ZERO);
+            continue;
+        }
+        let left = slot_x(start) - WIDTH / 2.0 - GAP / 2.0;
+        let right = slot_x(end - 1) + WIDTH / 2.0 + GAP / 2.0;
+        sprite.custom_size = Some(Vec2::new(right - left, ZONE_HEIGHT));
+        transform.translation.x = (left + right) / 2.0;
+    }
+}
+
+fn update_pointers(state: Res<SortState>, mut pointers: Query<(&PointerLabel, &mut Transform)>) {
+    for (pointer, mut transform) in &mut pointers {
+        // high marks the slot just past the unread part, so its pointer sits on the last one
+        let slot = match pointer.0 {
+            0 => state.low as f32,
+            1 => state.mid as f32,
+            _ => state.high as f32 - 1.0,
+        };
+        transform.translation.x = slot_x(0) + slot * (WIDTH + GAP);
+    }
+}
@@ -0,0 +1,150 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Floyd–Warshall";
+const BG_COLOR: Color = Color::srgb(0.02, 0.03, 0.07);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct MatrixCell {
+    i: usize,
+    j: usize,
+    distance: i32,
+}
+
+#[derive(Resource, Clone)]
+struct AppState {
+    dist: Vec<Vec<i32>>,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "floyd-warshall";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let graph = match GraphScenario::from_args_checked(GraphScenario::nonnegative_weights) {
+        Some(scenario) => weight_matrix(&scenario),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            dist: floyd_warshall(&graph),
+        })
+        .setup(setup);
+}
+
+// Direct edge weights, 0 on the diagonal and `i32::MAX / 2` where there is no edge;
+// of parallel edges the lightest counts.
+fn weight_matrix(scenario: &GraphScenario) -> Vec<Vec<i32>> {
+    let n = scenario.nodes.len();
+    let mut matrix = vec![vec![i32::MAX / 2; n]; n];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[i] = 0;
+    }
+    for (from, edges) in scenario.adjacency().into_iter().enumerate() {
+        for (to, weight) in edges {
+            matrix[from][to] = matrix[from][to].min(weight);
+        }
+    }
+    matrix
+}
+
+fn sample_graph() -> Vec<Vec<i32>> {
+    let inf = i32::MAX / 2;
+    vec![
+        vec![0, 3, inf, 7, inf, inf],
+        vec![8, 0, 2, inf, inf, inf],
+        vec![5, inf, 0, 1, 4, inf],
+        vec![2, inf, inf, 0, inf, 6],
+        vec![inf, inf, 9, inf, 0, inf],
+        vec![inf, inf, inf, inf, 2, 0],
+    ]
+}
+
+fn floyd_warshall(graph: &[Vec<i32>]) -> Vec<Vec<i32>> {
+    let mut dist = graph.to_vec();
+    let n = dist.len();
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if dist[i][k] != i32::MAX / 2 && dist[k][j] != i32::MAX / 2 {
+                    let new_dist = dist[i][k] + dist[k][j];
+                    if new_dist < dist[i][j] {
+                        dist[i][j] = new_dist;
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let n = state.dist.len();
+    // scenario graphs can have more nodes than the sample, so shrink the cells to fit
+    let cell = (600.0 / n as f32).min(60.0);
+    let origin = Vec2::new(
+        -(n as f32 - 1.0) * cell / 2.0,
+        (n as f32 - 1.0) * cell / 2.0,
+    );
+
+    for i in 0..n {
+        for j in 0..n {
+            let x = origin.x + j as f32 * cell;
+            let y = origin.y - i as f32 * cell;
+            let distance = state.dist[i][j];
+            let color = if distance == i32::MAX / 2 {
+                Color::srgb(0.2, 0.2, 0.2)
+            } else if distance == 0 {
+                palette.compare
+            } else {
+                let intensity = (10.0 / (distance as f32 + 1.0)).min(1.0);
+                Color::srgb(0.3 + intensity * 0.4, 0.5, 0.9 - intensity * 0.3)
+            };
+            let mut spawned = commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(cell - 6.0, cell - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                MatrixCell { i, j, distance },
+                SegmentText::from_section(
+                    if distance == i32::MAX / 2 {
+                        "∞".to_string()
+                    } else {
+                        format!("{}", distance)
+                    },
+                    SegmentStyle {
+                        font_size: 14.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+            if distance == 0 {
+                spawned.insert(Highlight::Compare);
+            }
+        }
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Floyd–Warshall: All-pairs shortest paths via dynamic programming\nYellow: 0, Dark: ∞, Lighter: shorter distances",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
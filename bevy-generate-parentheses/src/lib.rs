@@ -0,0 +1,376 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Generate Parentheses";
+const BG_COLOR: Color = Color::srgb(0.018, 0.045, 0.11);
+const NODE_CONFIGS: [(&str, f32, f32, u8); 10] = [
+    ("", -440.0, 240.0, 0),
+    ("(", -540.0, 120.0, 0),
+    ("((", -600.0, 0.0, 0),
+    ("(()", -600.0, -120.0, 0),
+    ("(())", -600.0, -240.0, 1),
+    ("(()(", -480.0, -120.0, 0),
+    ("(()()", -480.0, -240.0, 1),
+    ("(()))", -480.0, 0.0, 2),
+    ("()", -300.0, 120.0, 0),
+    ("()()", -300.0, 0.0, 1),
+];
+const EDGE_COORDS: [((f32, f32), (f32, f32)); 9] = [
+    ((-440.0, 240.0), (-540.0, 120.0)),
+    ((-440.0, 240.0), (-300.0, 120.0)),
+    ((-540.0, 120.0), (-600.0, 0.0)),
+    ((-540.0, 120.0), (-480.0, 0.0)),
+    ((-600.0, 0.0), (-600.0, -120.0)),
+    ((-600.0, -120.0), (-600.0, -240.0)),
+    ((-480.0, 0.0), (-480.0, -120.0)),
+    ((-480.0, -120.0), (-480.0, -240.0)),
+    ((-300.0, 120.0), (-300.0, 0.0)),
+];
+const SOLUTIONS: [&str; 5] = ["((()))", "(()())", "(())()", "()(())", "()()()"];
+const DECISION_NOTES: [&str; 5] = [
+    "Recurse until both open and close brackets reach n",
+    "Never place ')' if it would make closes exceed opens",
+    "Never place '(' once open count == n",
+    "Each recursion level records the partial string",
+    "Backtrack after exploring a branch to try alternatives",
+];
+
+#[derive(Component)]
+struct TreeNode;
+
+#[derive(Component)]
+struct TreeEdge;
+
+#[derive(Component)]
+struct SolutionCard;
+
+#[derive(Component)]
+struct StepPanel;
+
+#[derive(Component)]
+struct StatusPanel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "generate-parentheses";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_recursion_tree(&mut commands, &palette);
+    spawn_solution_gallery(&mut commands);
+    spawn_status_panel(&mut commands);
+    spawn_step_panel(&mut commands);
+
+    info!("Generate Parentheses scaffold ready. Animate backtracking choices next.");
+}
+
+fn spawn_recursion_tree(commands: &mut Commands, palette: &Palette) {
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Recursion tree",
+            SegmentStyle {
+                font_size: 34.0,
+                color: Color::srgba(0.92, 0.96, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(-520.0, 310.0, 0.1),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    for &(start, end) in EDGE_COORDS.iter() {
+        let (sx, sy) = start;
+        let (ex, ey) = end;
+        let mid = Vec3::new((sx + ex) / 2.0, (sy + ey) / 2.0, -0.05);
+        let delta = Vec2::new(ex - sx, ey - sy);
+        let length = delta.length();
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.34, 0.6, 0.88, 0.45),
+                    custom_size: Some(Vec2::new(length, 6.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(mid)
+                    .with_rotation(Quat::from_rotation_z(delta.y.atan2(delta.x))),
+                ..default()
+            },
+            TreeEdge,
+        ));
+    }
+
+    for &(label, x, y, state) in NODE_CONFIGS.iter() {
+        let (role, border) = match state {
+            1 => (Some(Highlight::Settled), Color::srgba(1.0, 0.9, 0.6, 1.0)),
+            2 => (None, Color::srgba(0.4, 0.45, 0.55, 1.0)),
+            _ => (Some(Highlight::Visited), Color::srgba(0.82, 0.94, 1.0, 1.0)),
+        };
+        let color = match role {
+            Some(role) => palette.role(role).with_alpha(0.95),
+            None => Color::srgba(0.26, 0.32, 0.42, 0.9),
+        };
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: border,
+                    custom_size: Some(Vec2::new(148.0, 78.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, -0.02),
+                ..default()
+            },
+            TreeNode,
+        ));
+
+        let mut node = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(140.0, 70.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, 0.0),
+                ..default()
+            },
+            TreeNode,
+        ));
+        if let Some(role) = role {
+            node.insert(role);
+        }
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("\"{label}\""),
+                SegmentStyle {
+                    font_size: 26.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, y + 6.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+
+        let status = match state {
+            1 => "complete",
+            2 => "pruned",
+            _ => "exploring",
+        };
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                status,
+                SegmentStyle {
+                    font_size: 18.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 0.75),
+                },
+            ),
+            transform: Transform::from_xyz(x, y - 40.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_solution_gallery(commands: &mut Commands) {
+    let base = Vec3::new(420.0, 200.0, -0.1);
+    let card_size = Vec2::new(220.0, 70.0);
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Solutions (n = 3)",
+            SegmentStyle {
+                font_size: 32.0,
+                color: Color::srgba(0.92, 0.96, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(
+            base.x - card_size.x,
+            base.y + card_size.y / 2.0 + 30.0,
+            0.1,
+        ),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    for (i, combo) in SOLUTIONS.iter().enumerate() {
+        let y = base.y - i as f32 * 90.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.4, 0.65, 0.9, 0.45),
+                    custom_size: Some(card_size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(base.x, y, 0.0),
+                ..default()
+            },
+            SolutionCard,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_sections([
+                SegmentSection::new(
+                    format!("{}.", i + 1),
+                    SegmentStyle {
+                        font_size: 24.0,
+                        color: Color::srgba(0.9, 0.95, 1.0, 0.9),
+                    },
+                ),
+                SegmentSection::new(
+                    format!("  {combo}"),
+                    SegmentStyle {
+                        font_size: 26.0,
+                        color: Color::WHITE,
+                    },
+                ),
+            ]),
+            transform: Transform::from_xyz(base.x, y, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+    }
+}
+
+fn spawn_status_panel(commands: &mut Commands) {
+    let panel_pos = Vec3::new(420.0, -140.0, -0.15);
+    let panel_size = Vec2::new(460.0, 180.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.16, 0.24, 0.34, 0.92),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        StatusPanel,
+    ));
+
+    let stats = [
+        ("n = 3", "target pairs"),
+        ("open_used = 3", "maxed open count"),
+        ("close_used = 3", "balanced closes"),
+        ("solutions = 5", "Catalan(3)"),
+    ];
+
+    for (i, (label, caption)) in stats.iter().enumerate() {
+        let y = panel_pos.y + panel_size.y / 2.0 - 40.0 - i as f32 * 70.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.34, 0.6, 0.88, 0.3),
+                    custom_size: Some(Vec2::new(panel_size.x - 36.0, 60.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, y, 0.0),
+                ..default()
+            },
+            StatusPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_sections([
+                    SegmentSection::new(
+                        *label,
+                        SegmentStyle {
+                            font_size: 24.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    SegmentSection::new(
+                        format!("  — {caption}"),
+                        SegmentStyle {
+                            font_size: 20.0,
+                            color: Color::srgba(0.9, 0.95, 1.0, 0.8),
+                        },
+                    ),
+                ]),
+                transform: Transform::from_xyz(panel_pos.x, y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            StatusPanel,
+        ));
+    }
+}
+
+fn spawn_step_panel(commands: &mut Commands) {
+    let panel_pos = Vec3::new(-20.0, -260.0, -0.15);
+    let panel_size = Vec2::new(620.0, 180.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.18, 0.28, 0.4, 0.9),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Backtracking checklist",
+                SegmentStyle {
+                    font_size: 30.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 28.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    for (i, note) in DECISION_NOTES.iter().enumerate() {
+        let y = panel_pos.y + panel_size.y / 2.0 - 80.0 - i as f32 * 60.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.34, 0.6, 0.88, 0.3),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, 52.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, y, 0.0),
+                ..default()
+            },
+            StepPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    *note,
+                    SegmentStyle {
+                        font_size: 22.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(panel_pos.x, y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            StepPanel,
+        ));
+    }
+}
@@ -0,0 +1,631 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Heap Sort";
+const BG_COLOR: Color = Color::srgb(0.02, 0.04, 0.07);
+const BAR_WIDTH: f32 = 48.0;
+const BAR_GAP: f32 = 12.0;
+const BASE_Y: f32 = -220.0;
+const STEP_INTERVAL: f32 = 0.8;
+// Heights are 40 + 25 per unit; 14 bars fill the fixed-size window and the
+// four-level tree holds up to 15 nodes.
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 12,
+    max_n: 14,
+    max_value: 14,
+    dense: true,
+};
+
+#[derive(Component)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+
+#[derive(Component)]
+struct HeapNode {
+    index: usize,
+    value: usize,
+}
+
+#[derive(Component)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct StepExplanation;
+
+#[derive(Component)]
+struct ExplanationText;
+
+#[derive(Resource)]
+struct Layout {
+    origin_x: f32,
+    heap_positions: Vec<Vec<Vec2>>,
+}
+
+#[derive(Resource)]
+struct SortState {
+    array: Vec<usize>,
+    heap_size: usize,
+    current_index: usize,
+    phase: SortPhase,
+    counts: OpCounts,
+}
+
+#[derive(PartialEq)]
+enum SortPhase {
+    BuildHeap,
+    ExtractMax,
+    Heapify,
+    Complete,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "heap-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(input)
+        .setup(setup)
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (update_bars, update_heap_nodes, update_educational_text).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut input: ResMut<ArrayInput>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Initialize array with the --input values or the preset over 0..n
+    let array = new_array(&mut input);
+    let n = array.len();
+
+    // Calculate layout positions
+    let origin_x = -(n as f32 * (BAR_WIDTH + BAR_GAP) - BAR_GAP) / 2.0 + BAR_WIDTH / 2.0;
+
+    // Calculate heap positions (complete binary tree layout)
+    let mut heap_positions = Vec::new();
+    let levels = n.ilog2() as usize + 1; // depth of a complete tree with n nodes
+    let node_size = 48.0;
+    let vertical_gap = 90.0;
+    let mut y = 140.0;
+
+    for level in 0..levels {
+        let start_index = (1 << level) - 1;
+        let end_index = ((1 << (level + 1)) - 1).min(n - 1);
+        let nodes_in_level = end_index - start_index + 1;
+        let total_width = nodes_in_level as f32 * (node_size + 24.0) - 24.0;
+        let start_x = -total_width / 2.0 + node_size / 2.0;
+
+        let mut level_positions = Vec::new();
+        for i in 0..nodes_in_level {
+            let x = start_x + i as f32 * (node_size + 24.0);
+            level_positions.push(Vec2::new(x, y));
+        }
+        heap_positions.push(level_positions);
+        y -= vertical_gap;
+    }
+
+    commands.insert_resource(Layout {
+        origin_x,
+        heap_positions: heap_positions.clone(),
+    });
+
+    commands.insert_resource(SortState::new(array.clone()));
+
+    // Create array bars
+    for (i, &value) in array.iter().enumerate() {
+        let height = 40.0 + value as f32 * 25.0;
+        let x = origin_x + i as f32 * (BAR_WIDTH + BAR_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.5, 0.5, 0.5), // Gray initially
+                    custom_size: Some(Vec2::new(BAR_WIDTH, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, height / 2.0 + BASE_Y, 0.0),
+                ..default()
+            },
+            Bar { index: i, value },
+        ));
+    }
+
+    // Create heap nodes
+    for (i, &value) in array.iter().enumerate().take(n) {
+        let level = (i + 1).ilog2() as usize;
+        let start_index = (1 << level) - 1;
+        let position_in_level = i - start_index;
+        let pos = heap_positions[level][position_in_level];
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.2, 0.8, 0.4, 0.5), // Green initially
+                    custom_size: Some(Vec2::new(node_size, node_size)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            HeapNode { index: i, value },
+        ));
+    }
+
+    // Educational text
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 32.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        AlgorithmTitle,
+        Localized::new("heap-sort.title"),
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        ProgressText,
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            left: Val::Px(20.0),
+            ..default()
+        }),
+        StepExplanation,
+        Narration,
+    ));
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.7, 0.7, 0.7),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(20.0),
+            right: Val::Px(20.0),
+            ..default()
+        }),
+        ExplanationText,
+        Localized::new("heap-sort.blurb"),
+    ));
+}
+
+// the heap's node labels count from 0
+fn new_array(input: &mut ArrayInput) -> Vec<usize> {
+    input.preset(0, input.n - 1)
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut input: ResMut<ArrayInput>,
+) {
+    if reset.read().count() > 0 {
+        // Restart with a new array from the preset (a fixed --input array comes back unchanged)
+        *state = SortState::new(new_array(&mut input));
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if settings.step_ready() {
+        state.advance();
+    }
+}
+
+impl SortState {
+    fn new(array: Vec<usize>) -> Self {
+        let n = array.len();
+        Self {
+            array,
+            heap_size: n,
+            current_index: (n / 2).saturating_sub(1), // Start building heap from bottom
+            phase: SortPhase::BuildHeap,
+            counts: OpCounts::default(),
+        }
+    }
+
+    /// One phase step: sift down the next subtree while building the heap,
+    /// swap the root behind the heap, or sift the new root down.
+    fn advance(&mut self) {
+        match self.phase {
+            SortPhase::BuildHeap => {
+                let heap_size = self.heap_size;
+                let current_index = self.current_index;
+                // Heapify current subtree, down to and including the root
+                let SortState { array, counts, .. } = &mut *self;
+                heapify(array, heap_size, current_index, counts);
+                if current_index > 0 {
+                    self.current_index = current_index - 1;
+                } else {
+                    // Heap building complete, start extraction
+                    self.current_index = self.heap_size - 1;
+                    self.phase = SortPhase::ExtractMax;
+                }
+            }
+            SortPhase::ExtractMax => {
+                if self.heap_size > 1 {
+                    let heap_size = self.heap_size;
+                    // Swap root with last element
+                    self.array.swap(0, heap_size - 1);
+                    self.counts.swap();
+                    self.heap_size = heap_size - 1;
+                    self.current_index = 0;
+                    self.phase = SortPhase::Heapify;
+                } else {
+                    self.phase = SortPhase::Complete;
+                }
+            }
+            SortPhase::Heapify => {
+                let heap_size = self.heap_size;
+                let current_index = self.current_index;
+                if current_index < heap_size {
+                    let SortState { array, counts, .. } = &mut *self;
+                    heapify(array, heap_size, current_index, counts);
+                    self.current_index = heap_size - 1;
+                    self.phase = SortPhase::ExtractMax;
+                }
+            }
+            SortPhase::Complete => {}
+        }
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(new_array(&mut input)), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    Box::new(SortState::new(array))
+}
+
+impl Racer for SortState {
+    fn step(&mut self) {
+        self.advance();
+    }
+
+    fn done(&self) -> bool {
+        self.phase == SortPhase::Complete
+    }
+
+    fn array(&self) -> &[usize] {
+        &self.array
+    }
+
+    fn counts(&self) -> OpCounts {
+        self.counts
+    }
+
+    fn focus(&self) -> Vec<usize> {
+        match self.phase {
+            SortPhase::BuildHeap => vec![self.current_index],
+            SortPhase::ExtractMax => vec![0],
+            // the root was just swapped with the slot past the shrunken heap
+            SortPhase::Heapify => vec![0, self.heap_size],
+            SortPhase::Complete => Vec::new(),
+        }
+    }
+}
+
+fn heapify(arr: &mut [usize], heap_size: usize, i: usize, counts: &mut OpCounts) {
+    let mut largest = i;
+    let left = 2 * i + 1;
+    let right = 2 * i + 2;
+
+    // Find largest among root, left child, right child
+    if left < heap_size && {
+        counts.compare();
+        arr[left] > arr[largest]
+    } {
+        largest = left;
+    }
+    if right < heap_size && {
+        counts.compare();
+        arr[right] > arr[largest]
+    } {
+        largest = right;
+    }
+
+    // If root is not largest, swap and continue heapifying
+    if largest != i {
+        arr.swap(i, largest);
+        counts.swap();
+        heapify(arr, heap_size, largest, counts);
+    }
+}
+
+fn update_bars(
+    mut bars: Query<(&mut Sprite, &mut Transform, &mut Bar)>,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, mut transform, mut bar) in bars.iter_mut() {
+        // Each bar stays on its slot and shows the value the array holds there,
+        // so repeated values need no telling apart
+        let Some(&value) = state.array.get(bar.index) else {
+            continue;
+        };
+        bar.value = value;
+        let x = layout.origin_x + bar.index as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+
+        // Update height based on value
+        let height = 40.0 + bar.value as f32 * 25.0;
+        transform.translation.y = height / 2.0 + BASE_Y;
+        sprite.custom_size = Some(Vec2::new(BAR_WIDTH, height));
+
+        // Color coding
+        if bar.index >= state.heap_size {
+            sprite.color = palette.settled; // Green for sorted
+        } else if bar.index == 0 && state.phase == SortPhase::ExtractMax {
+            sprite.color = palette.compare; // Orange for root being extracted
+        } else if bar.index < state.heap_size {
+            sprite.color = palette.visited; // Blue for heap elements
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5); // Gray for unsorted
+        }
+    }
+}
+
+fn update_heap_nodes(
+    mut nodes: Query<(&mut Sprite, &mut Transform, &mut HeapNode)>,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, mut transform, mut node) in nodes.iter_mut() {
+        // Update node value and position
+        if node.index < state.array.len() {
+            node.value = state.array[node.index];
+
+            if node.index < state.heap_size {
+                // Position in heap
+                let level = (node.index + 1).ilog2() as usize;
+                let start_index = (1 << level) - 1;
+                let position_in_level = node.index - start_index;
+                if level < layout.heap_positions.len()
+                    && position_in_level < layout.heap_positions[level].len()
+                {
+                    let pos = layout.heap_positions[level][position_in_level];
+                    transform.translation.x = pos.x;
+                    transform.translation.y = pos.y;
+                    sprite.color = palette.settled; // Green for active heap nodes
+                }
+            } else {
+                // Move to sorted position in array
+                let sorted_index = state.array.len() - 1 - (node.index - state.heap_size);
+                let x = layout.origin_x + sorted_index as f32 * (BAR_WIDTH + BAR_GAP);
+                let height = 40.0 + node.value as f32 * 25.0;
+                transform.translation.x = x;
+                transform.translation.y = height / 2.0 + BASE_Y + 100.0; // Above bars
+                sprite.color = palette.settled.with_alpha(0.3); // Faded for sorted
+            }
+        }
+    }
+}
+
+fn update_educational_text(
+    state: Res<SortState>,
+    locale: Res<Locale>,
+    mut text_params: ParamSet<(
+        Query<&mut Text, With<ProgressText>>,
+        Query<&mut Text, With<StepExplanation>>,
+    )>,
+) {
+    // Update progress text
+    if let Ok(mut text) = text_params.p0().get_single_mut() {
+        text.sections[0].value = tr!(
+            locale,
+            "heap-sort.progress",
+            comparisons = state.counts.comparisons,
+            swaps = state.counts.swaps
+        );
+    }
+
+    // Update step explanation
+    if let Ok(mut text) = text_params.p1().get_single_mut() {
+        let explanation = match state.phase {
+            SortPhase::BuildHeap => {
+                tr!(locale, "heap-sort.step.build", index = state.current_index)
+            }
+            SortPhase::ExtractMax => {
+                tr!(locale, "heap-sort.step.extract", root = state.array[0])
+            }
+            SortPhase::Heapify => locale.text("heap-sort.step.heapify"),
+            SortPhase::Complete => locale.text("heap-sort.step.done"),
+        };
+        text.sections[0].value = explanation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algoviz_core::{run_until, test_app};
+
+    fn sort_app(args: &[&str]) -> App {
+        let args = args.iter().map(|a| a.to_string());
+        let mut app = test_app(STEP_INTERVAL);
+        app.insert_resource(ArrayInput::parse(args, INPUT).unwrap())
+            .add_systems(Startup, setup)
+            .add_systems(Update, step_sort.in_set(VizSet::Step));
+        app
+    }
+
+    fn run_to_end(app: &mut App) -> Vec<usize> {
+        app.update(); // Startup: build the state
+        let n = app.world().resource::<SortState>().array.len();
+        run_until(app, 20 * n * n + 40, |world| {
+            world.resource::<SortState>().phase == SortPhase::Complete
+        });
+        app.world().resource::<SortState>().array.clone()
+    }
+
+    fn is_max_heap(arr: &[usize]) -> bool {
+        (1..arr.len()).all(|i| arr[(i - 1) / 2] >= arr[i])
+    }
+
+    #[test]
+    fn sorts_given_array() {
+        // the root is out of place after the bottom subtrees are heapified
+        let mut app = sort_app(&["--input", "1,3,2"]);
+        assert_eq!(run_to_end(&mut app), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sorts_random_permutations() {
+        for seed in 0..30 {
+            for n in [2, 5, 9, 14] {
+                let mut app = sort_app(&["--n", &n.to_string(), "--seed", &seed.to_string()]);
+                assert_eq!(
+                    run_to_end(&mut app),
+                    (0..n).collect::<Vec<_>>(),
+                    "seed {seed}, n {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_every_preset() {
+        for dist in Distribution::ALL {
+            for n in [2, 5, 9, 14] {
+                let args = ["--n", &n.to_string(), "--dist", dist.name(), "--seed", "3"]
+                    .map(str::to_string);
+                let mut sorted = new_array(&mut ArrayInput::parse(args.clone(), INPUT).unwrap());
+                sorted.sort_unstable();
+                let mut app = sort_app(&args.each_ref().map(String::as_str));
+                assert_eq!(run_to_end(&mut app), sorted, "{}, n {n}", dist.name());
+            }
+        }
+    }
+
+    #[test]
+    fn counts_stay_within_n_log_n() {
+        for seed in 0..20 {
+            let mut app = sort_app(&["--n", "12", "--seed", &seed.to_string()]);
+            run_to_end(&mut app);
+            let counts = app.world().resource::<SortState>().counts;
+            // building the heap compares under 2n times, each of the n - 1 sift-downs at most 2 log n
+            let (n, log) = (12, 12f32.log2().floor() as usize);
+            assert!(
+                counts.comparisons <= 2 * n + 2 * (n - 1) * log,
+                "seed {seed}: {counts:?}"
+            );
+            assert!(counts.swaps >= n - 1, "seed {seed}: {counts:?}");
+            assert_eq!(
+                counts.reads,
+                2 * (counts.comparisons + counts.swaps),
+                "seed {seed}"
+            );
+            assert_eq!(counts.writes, 2 * counts.swaps, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn heapify_builds_a_max_heap() {
+        let mut input = ArrayInput::parse(["--seed".to_string(), "7".to_string()], INPUT).unwrap();
+        for _ in 0..200 {
+            let mut arr = new_array(&mut input);
+            let n = arr.len();
+            for i in (0..n / 2).rev() {
+                heapify(&mut arr, n, i, &mut OpCounts::default());
+            }
+            assert!(is_max_heap(&arr), "{arr:?}");
+        }
+    }
+
+    #[test]
+    fn headless_writes_a_step_per_line_and_ends_sorted() {
+        let path = std::env::temp_dir().join(format!("heap-sort-{}.jsonl", std::process::id()));
+        let mut trace = TraceWriter::new(Box::new(std::fs::File::create(&path).unwrap()));
+        run_racer(racer(vec![3, 0, 4, 1, 2]), &mut trace);
+        drop(trace);
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut lane = racer(vec![3, 0, 4, 1, 2]);
+        let mut steps = 0;
+        while !lane.done() {
+            lane.step();
+            steps += 1;
+        }
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), steps + 1);
+        for (i, line) in lines.iter().enumerate() {
+            let op = if i == steps { "done" } else { "step" };
+            assert!(
+                line.starts_with(&format!("{{\"step\":{i},\"op\":\"{op}\",\"indices\":[")),
+                "{line}"
+            );
+        }
+        let counts = lane.counts();
+        assert_eq!(
+            lines[steps],
+            format!(
+                "{{\"step\":{steps},\"op\":\"done\",\"indices\":[],\"array\":[0,1,2,3,4],\"counts\":\
+                 {{\"comparisons\":{},\"swaps\":{},\"reads\":{},\"writes\":{},\"aux\":{}}}}}",
+                counts.comparisons, counts.swaps, counts.reads, counts.writes, counts.aux
+            )
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Insert Interval";
+const BG_COLOR: Color = Color::srgb(0.025, 0.045, 0.09);
+const EXISTING_INTERVALS: [(i32, i32); 4] = [(1, 2), (3, 5), (6, 7), (8, 10)];
+const NEW_INTERVAL: (i32, i32) = (4, 8);
+const SCALE: f32 = 42.0;
+const MAX_TIMELINE_WIDTH: f32 = 820.0; // wider scenarios shrink the scale to fit
+const TIMELINE_PADDING: f32 = 100.0;
+const BASELINE_Y: f32 = -160.0;
+
+/// The intervals on screen (the consts or `--scenario`) and the result of
+/// inserting `incoming`.
+#[derive(Resource, Clone)]
+struct Timeline {
+    existing: Vec<(i32, i32)>,
+    incoming: (i32, i32),
+    merged: Vec<(i32, i32)>,
+}
+
+#[derive(Component)]
+struct TimelineAxis;
+
+#[derive(Component)]
+struct ExistingInterval;
+
+#[derive(Component)]
+struct IncomingInterval;
+
+#[derive(Component)]
+struct MergeOverlay;
+
+#[derive(Component)]
+struct ResultPanel;
+
+#[derive(Component)]
+struct GuidanceText;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "insert-interval";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let check = |scenario: &IntervalScenario| {
+        scenario
+            .with_insert()
+            .and_then(|()| sorted_disjoint(scenario))
+    };
+    let (existing, incoming) = match IntervalScenario::from_args_checked(check) {
+        Some(scenario) => (scenario.intervals, scenario.insert.unwrap()),
+        None => (EXISTING_INTERVALS.to_vec(), NEW_INTERVAL),
+    };
+    let merged = insert_interval(&existing, incoming);
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(Timeline {
+            existing,
+            incoming,
+            merged,
+        })
+        .setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>, timeline: Res<Timeline>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_timeline_axis(&mut commands, &timeline);
+    spawn_existing_intervals(&mut commands, &palette, &timeline);
+    spawn_incoming_interval(&mut commands, &palette, &timeline);
+    spawn_merge_overlay(&mut commands, &palette, &timeline);
+    spawn_result_panel(&mut commands, &timeline);
+    spawn_guidance_text(&mut commands);
+
+    info!(
+        "Insert Interval scaffold running. Animate insertion position search and merge consolidation next."
+    );
+}
+
+fn spawn_timeline_axis(commands: &mut Commands, timeline: &Timeline) {
+    let width = timeline.total_width();
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.28, 0.4, 0.85),
+                custom_size: Some(Vec2::new(width, 8.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, BASELINE_Y, -0.3),
+            ..default()
+        },
+        TimelineAxis,
+    ));
+}
+
+fn spawn_existing_intervals(commands: &mut Commands, palette: &Palette, timeline: &Timeline) {
+    for (idx, &(start, end)) in timeline.existing.iter().enumerate() {
+        let mid_x = timeline.value_to_center(start, end);
+        let width = timeline.interval_width(start, end);
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.9),
+                    custom_size: Some(Vec2::new(width, 120.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(mid_x, BASELINE_Y + 80.0, -0.2),
+                ..default()
+            },
+            Highlight::Visited,
+            ExistingInterval,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("I{} = [{}, {}]", idx, start, end),
+                    SegmentStyle {
+                        font_size: 26.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(mid_x, BASELINE_Y + 150.0, -0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            ExistingInterval,
+        ));
+    }
+}
+
+fn spawn_incoming_interval(commands: &mut Commands, palette: &Palette, timeline: &Timeline) {
+    let (start, end) = timeline.incoming;
+    let mid_x = timeline.value_to_center(start, end);
+    let width = timeline.interval_width(start, end);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.4),
+                custom_size: Some(Vec2::new(width + 36.0, 140.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(mid_x, BASELINE_Y + 88.0, -0.15),
+            ..default()
+        },
+        Highlight::Compare,
+        IncomingInterval,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("New = [{}, {}]", start, end),
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::srgba(1.0, 0.95, 0.8, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(mid_x, BASELINE_Y + 188.0, -0.05),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        IncomingInterval,
+    ));
+}
+
+fn spawn_merge_overlay(commands: &mut Commands, palette: &Palette, timeline: &Timeline) {
+    let (new_start, new_end) = timeline.incoming;
+    let (start, end) = *timeline
+        .merged
+        .iter()
+        .find(|&&(start, end)| start <= new_start && new_end <= end)
+        .unwrap();
+    let mid_x = timeline.value_to_center(start, end);
+    let width = timeline.interval_width(start, end) + 36.0;
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.28),
+                custom_size: Some(Vec2::new(width, 150.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(mid_x, BASELINE_Y + 90.0, -0.1),
+            ..default()
+        },
+        Highlight::Compare,
+        MergeOverlay,
+    ));
+}
+
+fn spawn_result_panel(commands: &mut Commands, timeline: &Timeline) {
+    let panel_pos = Vec3::new(480.0, 40.0, -0.25);
+    let panel_size = Vec2::new(360.0, 360.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.18, 0.24, 0.34, 0.9),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        ResultPanel,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Merged Output",
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 26.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        ResultPanel,
+    ));
+
+    for (i, &(start, end)) in timeline.merged.iter().enumerate() {
+        let entry_y = panel_pos.y + panel_size.y / 2.0 - 80.0 - i as f32 * 120.0;
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.35, 0.6, 0.85, 0.35),
+                    custom_size: Some(Vec2::new(panel_size.x - 48.0, 100.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, entry_y, 0.0),
+                ..default()
+            },
+            ResultPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("[{}, {}]", start, end),
+                    SegmentStyle {
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(panel_pos.x, entry_y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            ResultPanel,
+        ));
+    }
+}
+
+fn spawn_guidance_text(commands: &mut Commands) {
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Timeline is sorted: compare new.start against existing intervals",
+                SegmentStyle {
+                    font_size: 24.0,
+                    color: Color::srgba(0.85, 0.9, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, BASELINE_Y - 80.0, 0.4),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        GuidanceText,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Step: append non-overlapping, merge overlaps into one interval",
+                SegmentStyle {
+                    font_size: 22.0,
+                    color: Color::srgba(0.95, 0.85, 0.65, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, BASELINE_Y - 120.0, 0.4),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        GuidanceText,
+    ));
+}
+
+/// A `--scenario` list has to be sorted and free of overlaps, like the
+/// problem's input.
+fn sorted_disjoint(scenario: &IntervalScenario) -> Result<(), Invalid> {
+    match scenario
+        .intervals
+        .windows(2)
+        .position(|pair| pair[1].0 <= pair[0].1)
+    {
+        Some(i) => Err(Invalid::at(
+            "intervals",
+            i + 1,
+            "intervals must be sorted and must not overlap",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn insert_interval(existing: &[(i32, i32)], incoming: (i32, i32)) -> Vec<(i32, i32)> {
+    let mut merged = Vec::new();
+    let (mut start, mut end) = incoming;
+    let mut placed = false;
+    for &(s, e) in existing {
+        if e < start {
+            merged.push((s, e));
+        } else if s > end {
+            if !placed {
+                merged.push((start, end));
+                placed = true;
+            }
+            merged.push((s, e));
+        } else {
+            start = start.min(s);
+            end = end.max(e);
+        }
+    }
+    if !placed {
+        merged.push((start, end));
+    }
+    merged
+}
+
+#[allow(dead_code)]
+impl Timeline {
+    fn bounds(&self) -> (i32, i32) {
+        let mut min_start = self.existing[0].0.min(self.incoming.0);
+        let mut max_end = self.existing[0].1.max(self.incoming.1);
+
+        for &(start, end) in &self.existing {
+            if start < min_start {
+                min_start = start;
+            }
+            if end > max_end {
+                max_end = end;
+            }
+        }
+
+        (min_start, max_end)
+    }
+
+    fn scale(&self) -> f32 {
+        let (min_start, max_end) = self.bounds();
+        let span = (max_end - min_start).max(1) as f32;
+        SCALE.min((MAX_TIMELINE_WIDTH - TIMELINE_PADDING * 2.0) / span)
+    }
+
+    fn total_width(&self) -> f32 {
+        let (min_start, max_end) = self.bounds();
+        (max_end - min_start) as f32 * self.scale() + TIMELINE_PADDING * 2.0
+    }
+
+    fn origin(&self) -> f32 {
+        let width = self.total_width();
+        -width / 2.0 + TIMELINE_PADDING
+    }
+
+    fn interval_width(&self, start: i32, end: i32) -> f32 {
+        (end - start) as f32 * self.scale()
+    }
+
+    fn value_to_center(&self, start: i32, end: i32) -> f32 {
+        let (min_start, _) = self.bounds();
+        self.origin()
+            + (start - min_start) as f32 * self.scale()
+            + self.interval_width(start, end) / 2.0
+    }
+
+    fn value_to_x(&self, value: i32) -> f32 {
+        let (min_start, _) = self.bounds();
+        self.origin() + (value - min_start) as f32 * self.scale()
+    }
+}
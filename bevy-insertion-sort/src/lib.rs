@@ -0,0 +1,552 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 10,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 60.0;
+const BAR_GAP: f32 = 10.0;
+const ROW_WIDTH: f32 = 800.0;
+const MAX_HEIGHT: f32 = 300.0;
+const SPEED: f32 = 420.0;
+const PRE_HOLD: f32 = 0.3;
+const STEP_INTERVAL: f32 = 0.8;
+
+// Educational text components
+#[derive(Component)]
+#[allow(dead_code)]
+struct ExplanationText;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct ProgressText;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct StepExplanation;
+
+#[derive(Component, Copy, Clone)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.index
+    }
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+#[derive(Component, Deref, DerefMut)]
+struct Target(Vec2);
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    w: f32,
+    top: usize,
+    labels: bool,
+}
+
+impl Layout {
+    // bars (and gaps) narrow to fit the row; heights and hues scale to the largest value;
+    // labels only where the digits fit over the bars
+    fn new(a: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(a.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = a.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            w: row.width,
+            top,
+            labels: row.labels(top),
+        }
+    }
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, a: &[usize]) {
+        self.top = a.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+    fn h(&self, v: usize) -> f32 {
+        v as f32 / self.top as f32 * MAX_HEIGHT + 10.0
+    }
+    fn color(&self, v: usize) -> Color {
+        Color::hsl((v as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+struct InsState {
+    i: usize,              // current key index being inserted
+    j: isize,              // scanning left among sorted prefix
+    key_e: Option<Entity>, // entity detached as the key (floating)
+    key_value: usize,
+    array: Vec<usize>,
+    moving: bool,
+    pre_hold: f32,
+    done: bool,
+    counts: OpCounts,
+}
+
+/// What stepping back restores. Mid-insertion the array holds a duplicate
+/// while the key floats, and values may repeat anyway, so bar slots are
+/// stored per bar entity rather than derived from the array.
+#[derive(Clone, PartialEq)]
+struct Snapshot {
+    array: Vec<usize>,
+    slots: Vec<(Entity, usize)>, // (bar, bar index) for every bar
+    i: usize,
+    j: isize,
+    key: Option<Entity>, // the lifted key bar
+    done: bool,
+    counts: OpCounts,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "insertion-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .history_scrubber::<Snapshot>()
+        .cost_chart(|st: Res<InsState>| st.counts)
+        .bar_editor::<Bar>()
+        .resource(input)
+        .resource(InsState::default())
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                (restore_snapshot, step_insertion)
+                    .chain()
+                    .in_set(VizSet::Step),
+                animate.in_set(VizSet::Animate),
+                (color_update, record_history).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut st: ResMut<InsState>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    st.array = input.permutation();
+    let layout = Layout::new(&st.array, row_width(ROW_WIDTH, &windows));
+
+    for (idx, v) in st.array.iter().copied().enumerate() {
+        let h = layout.h(v);
+        let x = x_at(idx, &layout);
+        let color = layout.color(v);
+        let id = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.w, h)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, h / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar {
+                    index: idx,
+                    value: v,
+                },
+                Target(Vec2::new(x, h / 2.0 - 200.0)),
+            ))
+            .id();
+        if layout.labels {
+            commands
+                .entity(id)
+                .with_children(|p| spawn_value_digits(p, v, h / 2.0 + 12.0, Color::WHITE));
+        }
+    }
+    commands.insert_resource(layout);
+    st.i = 1;
+    st.j = 0;
+    st.pre_hold = 0.0;
+}
+
+fn x_at(i: usize, layout: &Layout) -> f32 {
+    layout.row.x(i)
+}
+
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<InsState>,
+    mut input: ResMut<ArrayInput>,
+    mut history: ResMut<History<Snapshot>>,
+    mut layout: ResMut<Layout>,
+    mut bars: Query<(
+        Entity,
+        &mut Bar,
+        &mut Sprite,
+        &mut Transform,
+        &mut Target,
+        Option<&Children>,
+    )>,
+    digits_q: Query<&ValueDigits>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    let restart = st.done && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        // reshuffle (a fixed --input array comes back unchanged, an edited one once)
+        st.array = input.permutation();
+        layout.rescale(&st.array);
+        st.i = 1;
+        st.j = 0;
+        st.key_e = None;
+        st.key_value = 0;
+        st.moving = false;
+        st.pre_hold = 0.0;
+        st.done = false;
+        st.counts = OpCounts::default();
+        history.clear();
+        let mut raw: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        for (e, mut bar, mut sprite, mut tf, mut tgt, children) in bars.iter_mut() {
+            let idx = bar.index;
+            let value = st.array[idx];
+            bar.value = value;
+            let h = layout.h(value);
+            sprite.custom_size = Some(Vec2::new(layout.w, h));
+            sprite.color = layout.color(value);
+            let x = x_at(idx, &layout);
+            tgt.0 = Vec2::new(x, h / 2.0 - 200.0);
+            tf.translation = Vec3::new(x, h / 2.0 - 200.0, 0.0);
+            tf.scale = Vec3::ONE;
+            raw.push((
+                e,
+                children.map_or_else(Vec::new, |c| c.to_vec()),
+                value,
+                h / 2.0 + 12.0,
+            ));
+        }
+        // old digits go; new ones only if they still fit over the rescaled bars
+        for (parent, children, value, y) in raw {
+            for c in children {
+                if digits_q.get(c).is_ok() {
+                    commands.entity(c).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands
+                    .entity(parent)
+                    .with_children(|p| spawn_value_digits(p, value, y, palette.focus));
+            }
+        }
+    }
+}
+
+fn step_insertion(
+    mut st: ResMut<InsState>,
+    mut settings: ResMut<Settings>,
+    mut bars: Query<(Entity, &mut Bar, &mut Target, &mut Transform)>,
+    layout: Res<Layout>,
+) {
+    if st.done || st.moving {
+        return;
+    }
+    if !settings.step_ready() {
+        return;
+    }
+
+    // if key not picked, pick it and lift
+    if st.key_e.is_none() {
+        if !st.lift_key() {
+            return;
+        }
+        // grab entity at index i
+        let pick = bars
+            .iter()
+            .find(|(_, bar, _, _)| bar.index == st.i)
+            .map(|(e, _, _, tf)| (e, tf.translation.y));
+        if let Some((e, y)) = pick {
+            st.key_e = Some(e);
+            st.pre_hold = PRE_HOLD;
+            if let Ok((_, _, mut tgt, mut tf)) = bars.get_mut(e) {
+                tgt.0.y = y + 50.0;
+                st.moving = true;
+                tf.scale = Vec3::new(1.0, 1.1, 1.0);
+            }
+        }
+        if !settings.auto {
+            settings.manual_step = false;
+        }
+        return;
+    }
+
+    // if pre-hold, wait (auto) or step (manual)
+    if st.pre_hold > 0.0 {
+        st.pre_hold = 0.0;
+        if !settings.auto {
+            settings.manual_step = false;
+            return;
+        }
+    }
+
+    // while j>=0 and array[j] > key, shift right by one
+    if let Some(j) = st.shift() {
+        // shift entity at j one slot to the right (index+1)
+        if let Some((_, mut barj, mut tgtj, _)) =
+            bars.iter_mut().find(|(_, bar, _, _)| bar.index == j)
+        {
+            barj.index += 1;
+            tgtj.0.x = x_at(barj.index, &layout);
+            st.moving = true;
+        }
+        if !settings.auto {
+            settings.manual_step = false;
+        }
+        return;
+    }
+
+    // place key at j+1
+    let pos = st.place_key();
+    if let Some(e) = st.key_e.take() {
+        if let Ok((_, mut barkey, mut tgt, mut tf)) = bars.get_mut(e) {
+            barkey.index = pos;
+            tgt.0.x = x_at(pos, &layout);
+            tf.scale = Vec3::ONE;
+            st.moving = true;
+        }
+    }
+    if !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+impl InsState {
+    /// Read `array[i]` out as the key; false once every element is inserted.
+    fn lift_key(&mut self) -> bool {
+        if self.i >= self.array.len() {
+            self.done = true;
+            return false;
+        }
+        self.key_value = self.array[self.i];
+        self.j = self.i as isize - 1;
+        // the key is read out into a temporary
+        self.counts.read(1);
+        self.counts.hold(1);
+        true
+    }
+
+    /// Compare the key with `array[j]`; if that is larger, copy it one slot to
+    /// the right and return its old index. None: the key belongs at j + 1.
+    fn shift(&mut self) -> Option<usize> {
+        if self.j < 0 {
+            return None;
+        }
+        let j = self.j as usize;
+        let aj = self.array[j];
+        self.counts.compare_held();
+        if aj <= self.key_value {
+            return None;
+        }
+        self.array[j + 1] = aj;
+        self.counts.write(1);
+        self.j -= 1;
+        Some(j)
+    }
+
+    /// Write the key into the gap at j + 1 and move on to the next key; returns the gap.
+    fn place_key(&mut self) -> usize {
+        let pos = (self.j + 1) as usize;
+        self.array[pos] = self.key_value;
+        self.counts.write(1);
+        self.i += 1;
+        self.j = self.i as isize - 1;
+        if self.i >= self.array.len() {
+            self.done = true;
+        }
+        pos
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    Box::new(Race {
+        state: InsState {
+            i: 1,
+            array,
+            ..default()
+        },
+        lifted: false,
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    state: InsState,
+    lifted: bool,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        let st = &mut self.state;
+        self.focus = if !self.lifted {
+            self.lifted = st.lift_key();
+            if self.lifted {
+                vec![st.i]
+            } else {
+                Vec::new()
+            }
+        } else if let Some(j) = st.shift() {
+            vec![j, j + 1]
+        } else {
+            self.lifted = false;
+            vec![st.place_key()]
+        };
+    }
+
+    fn done(&self) -> bool {
+        self.state.done
+    }
+
+    fn array(&self) -> &[usize] {
+        &self.state.array
+    }
+
+    fn counts(&self) -> OpCounts {
+        self.state.counts
+    }
+
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+fn animate(time: Res<Time>, mut st: ResMut<InsState>, mut q: Query<(&Target, &mut Transform)>) {
+    let mut active = false;
+    for (t, mut tf) in q.iter_mut() {
+        let dest = Vec3::new(t.0.x, t.0.y, tf.translation.z);
+        let d = dest - tf.translation;
+        let step = SPEED * time.delta_seconds();
+        if d.length() <= step {
+            tf.translation = dest;
+        } else {
+            tf.translation += d.normalize() * step;
+            active = true;
+        }
+    }
+    if st.moving && !active {
+        st.moving = false;
+    }
+}
+
+fn base_y(value: usize, layout: &Layout) -> f32 {
+    layout.h(value) / 2.0 - 200.0
+}
+
+// Left/Right or the timeline picked another snapshot: put every bar (and the
+// lifted key, if any) back on its recorded slot; `animate` slides them there
+fn restore_snapshot(
+    mut events: EventReader<RestoreSnapshot>,
+    history: Res<History<Snapshot>>,
+    mut st: ResMut<InsState>,
+    mut bars: Query<(Entity, &mut Bar, &mut Target, &mut Transform)>,
+    layout: Res<Layout>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    let Some(snap) = history.current() else {
+        return;
+    };
+    st.array = snap.array.clone();
+    st.i = snap.i;
+    st.j = snap.j;
+    st.done = snap.done;
+    st.pre_hold = 0.0;
+    st.key_e = None;
+    st.key_value = 0;
+    st.counts = snap.counts;
+    for (e, mut bar, mut tgt, mut tf) in bars.iter_mut() {
+        if let Some(&(_, slot)) = snap.slots.iter().find(|&&(be, _)| be == e) {
+            bar.index = slot;
+        }
+        let lifted = snap.key == Some(e);
+        tgt.0 = Vec2::new(
+            x_at(bar.index, &layout),
+            base_y(bar.value, &layout) + if lifted { 50.0 } else { 0.0 },
+        );
+        tf.scale = if lifted {
+            Vec3::new(1.0, 1.1, 1.0)
+        } else {
+            Vec3::ONE
+        };
+        if lifted {
+            st.key_e = Some(e);
+            st.key_value = bar.value;
+        }
+    }
+    st.moving = true;
+}
+
+fn record_history(
+    st: Res<InsState>,
+    bars: Query<(Entity, &Bar)>,
+    mut history: ResMut<History<Snapshot>>,
+) {
+    if st.moving {
+        return;
+    }
+    let mut slots: Vec<(Entity, usize)> = bars.iter().map(|(e, bar)| (e, bar.index)).collect();
+    slots.sort_unstable();
+    let key = st.key_e;
+    let snap = Snapshot {
+        array: st.array.clone(),
+        slots,
+        i: st.i,
+        j: st.j,
+        key,
+        done: st.done,
+        counts: st.counts,
+    };
+    if history.differs(&snap) {
+        history.record(snap);
+    }
+}
+
+fn color_update(
+    st: Res<InsState>,
+    layout: Res<Layout>,
+    mut q: Query<(&Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (bar, mut sprite) in q.iter_mut() {
+        let base = layout.color(bar.value);
+        let c = if st.done || bar.index < st.i {
+            palette.settled
+        } else {
+            base
+        };
+        sprite.color = c;
+    }
+}
@@ -0,0 +1,345 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Interval Merge";
+const BG_COLOR: Color = Color::srgb(0.02, 0.04, 0.08);
+const INTERVALS: [(i32, i32); 5] = [(1, 3), (2, 6), (5, 9), (8, 10), (15, 18)];
+const SCALE: f32 = 36.0; // world units per value unit along the timeline
+const MAX_TIMELINE_WIDTH: f32 = 780.0; // wider scenarios shrink the scale to fit
+const TIMELINE_PADDING: f32 = 80.0;
+const BASELINE_Y: f32 = -140.0;
+
+/// The intervals on screen (`INTERVALS` or `--scenario`) and their merge.
+#[derive(Resource, Clone)]
+struct Timeline {
+    intervals: Vec<(i32, i32)>,
+    merged: Vec<(i32, i32)>,
+}
+
+#[derive(Component)]
+struct TimelineAxis;
+
+#[derive(Component)]
+struct IntervalBar;
+
+#[derive(Component)]
+struct MergeOverlay;
+
+#[derive(Component)]
+struct ResultPanel;
+
+#[derive(Component)]
+struct GuidanceText;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "interval-merge";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let intervals = IntervalScenario::from_args()
+        .map_or_else(|| INTERVALS.to_vec(), |scenario| scenario.intervals);
+    let merged = merge_intervals(&intervals);
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(Timeline { intervals, merged })
+        .setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>, timeline: Res<Timeline>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_timeline_axis(&mut commands, &timeline);
+    spawn_intervals(&mut commands, &palette, &timeline);
+    spawn_merge_overlay(&mut commands, &palette, &timeline);
+    spawn_results_panel(&mut commands, &timeline);
+    spawn_guidance_text(&mut commands);
+
+    info!(
+        "Interval Merge scaffold ready. Animate sorting, sweep comparisons, and merged output insertion next."
+    );
+}
+
+fn spawn_timeline_axis(commands: &mut Commands, timeline: &Timeline) {
+    let width = timeline.total_width();
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.25, 0.35, 0.8),
+                custom_size: Some(Vec2::new(width, 8.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, BASELINE_Y, -0.3),
+            ..default()
+        },
+        TimelineAxis,
+    ));
+}
+
+fn spawn_intervals(commands: &mut Commands, palette: &Palette, timeline: &Timeline) {
+    for (idx, &(start, end)) in timeline.intervals.iter().enumerate() {
+        let mid_x = timeline.value_to_center(start, end);
+        let width = timeline.interval_width(start, end);
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.85),
+                    custom_size: Some(Vec2::new(width, 120.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(mid_x, BASELINE_Y + 80.0, -0.2),
+                ..default()
+            },
+            Highlight::Visited,
+            IntervalBar,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("I{} = [{}, {}]", idx, start, end),
+                    SegmentStyle {
+                        font_size: 26.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(mid_x, BASELINE_Y + 150.0, -0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            IntervalBar,
+        ));
+    }
+}
+
+fn spawn_merge_overlay(commands: &mut Commands, palette: &Palette, timeline: &Timeline) {
+    let (start, end) = timeline.merged[0];
+    let mid_x = timeline.value_to_center(start, end);
+    let width = timeline.interval_width(start, end) + 24.0;
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.25),
+                custom_size: Some(Vec2::new(width, 150.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(mid_x, BASELINE_Y + 90.0, -0.1),
+            ..default()
+        },
+        Highlight::Compare,
+        MergeOverlay,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("Comparing next interval to merge -> [{}, {}]", start, end),
+                SegmentStyle {
+                    font_size: 24.0,
+                    color: Color::srgba(0.95, 0.85, 0.65, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(mid_x, BASELINE_Y + 170.0, 0.0),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        MergeOverlay,
+    ));
+}
+
+fn spawn_results_panel(commands: &mut Commands, timeline: &Timeline) {
+    let panel_pos = Vec3::new(460.0, 90.0, -0.25);
+    let panel_size = Vec2::new(340.0, 360.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.18, 0.24, 0.32, 0.9),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        ResultPanel,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Merged Output",
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 26.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        ResultPanel,
+    ));
+
+    for (i, &(start, end)) in timeline.merged.iter().enumerate() {
+        let entry_y = panel_pos.y + panel_size.y / 2.0 - 80.0 - i as f32 * 110.0;
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.35, 0.6, 0.85, 0.35),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, 90.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, entry_y, 0.0),
+                ..default()
+            },
+            ResultPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_sections([
+                    SegmentSection::new(
+                        format!("{}\n", i + 1),
+                        SegmentStyle {
+                            font_size: 24.0,
+                            color: Color::srgba(0.85, 0.9, 1.0, 1.0),
+                        },
+                    ),
+                    SegmentSection::new(
+                        format!("[{}, {}]", start, end),
+                        SegmentStyle {
+                            font_size: 28.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                ]),
+                transform: Transform::from_xyz(panel_pos.x, entry_y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            ResultPanel,
+        ));
+    }
+}
+
+fn spawn_guidance_text(commands: &mut Commands) {
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Timeline sorted from left to right",
+                SegmentStyle {
+                    font_size: 24.0,
+                    color: Color::srgba(0.8, 0.85, 0.95, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, BASELINE_Y - 70.0, 0.4),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        GuidanceText,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Step: if next.start <= current.end -> extend, else push current",
+                SegmentStyle {
+                    font_size: 22.0,
+                    color: Color::srgba(0.95, 0.85, 0.65, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, BASELINE_Y - 110.0, 0.4),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        GuidanceText,
+    ));
+}
+
+fn merge_intervals(intervals: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut sorted = intervals.to_vec();
+    sorted.sort();
+    let mut merged: Vec<(i32, i32)> = Vec::new();
+    for (start, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+impl Timeline {
+    fn bounds(&self) -> (i32, i32) {
+        let mut min_start = self.intervals[0].0;
+        let mut max_end = self.intervals[0].1;
+
+        for &(start, end) in &self.intervals {
+            if start < min_start {
+                min_start = start;
+            }
+            if end > max_end {
+                max_end = end;
+            }
+        }
+
+        (min_start, max_end)
+    }
+
+    fn scale(&self) -> f32 {
+        let (min_start, max_end) = self.bounds();
+        let span = (max_end - min_start).max(1) as f32;
+        SCALE.min((MAX_TIMELINE_WIDTH - TIMELINE_PADDING * 2.0) / span)
+    }
+
+    fn total_width(&self) -> f32 {
+        let (min_start, max_end) = self.bounds();
+        (max_end - min_start) as f32 * self.scale() + TIMELINE_PADDING * 2.0
+    }
+
+    fn origin(&self) -> f32 {
+        let width = self.total_width();
+        -width / 2.0 + TIMELINE_PADDING
+    }
+
+    fn value_to_center(&self, start: i32, end: i32) -> f32 {
+        let (min_start, _) = self.bounds();
+        self.origin()
+            + (start - min_start) as f32 * self.scale()
+            + self.interval_width(start, end) / 2.0
+    }
+
+    fn value_to_x(&self, value: i32) -> f32 {
+        let (min_start, _) = self.bounds();
+        self.origin() + (value - min_start) as f32 * self.scale()
+    }
+
+    fn interval_width(&self, start: i32, end: i32) -> f32 {
+        (end - start) as f32 * self.scale()
+    }
+}
+
+#[allow(dead_code)]
+fn debug_spawns(commands: &mut Commands, timeline: &Timeline) {
+    // Helper hook if you want to visualize tick marks along the timeline later.
+    let (min_start, max_end) = timeline.bounds();
+    for value in min_start..=max_end {
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.6, 0.7, 0.9, 0.5),
+                custom_size: Some(Vec2::new(2.0, 24.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(timeline.value_to_x(value), BASELINE_Y, -0.25),
+            ..default()
+        });
+    }
+}
@@ -0,0 +1,312 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+const BAR_HEIGHT: f32 = 30.0;
+const TIMELINE_WIDTH: f32 = 800.0;
+const ROOM_HEIGHT: f32 = 60.0;
+const STEP_INTERVAL: f32 = 1.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct Interval {
+    id: usize,
+    start: usize,
+    end: usize,
+    room: Option<usize>,
+}
+
+#[derive(Component)]
+struct IntervalBar;
+
+#[derive(Resource, Clone)]
+struct AppState {
+    intervals: Vec<(usize, usize)>, // (start, end)
+    /// From `--scenario`; R replays them instead of a new shuffle.
+    given: Option<Vec<(usize, usize)>>,
+    rooms: Vec<Vec<usize>>, // intervals per room
+    current: usize,
+    done: bool,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "interval-scheduling";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let given =
+        IntervalScenario::from_args_checked(IntervalScenario::nonnegative).map(|scenario| {
+            scenario
+                .intervals
+                .iter()
+                .map(|&(start, end)| (start as usize, end as usize))
+                .collect::<Vec<_>>()
+        });
+
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL).manual())
+        .resource(ClearColor(Color::srgb(0.03, 0.04, 0.07)))
+        .resource(AppState {
+            intervals: Vec::new(),
+            given,
+            rooms: Vec::new(),
+            current: 0,
+            done: false,
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_system.in_set(VizSet::Step),
+                update_visualization.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn sample_intervals(rng: &mut impl Rng) -> Vec<(usize, usize)> {
+    let mut intervals = vec![
+        (1, 3),
+        (2, 4),
+        (3, 5),
+        (4, 6),
+        (5, 7),
+        (6, 8),
+        (7, 9),
+        (8, 10),
+        (9, 11),
+        (10, 12),
+    ];
+    intervals.shuffle(rng);
+    intervals
+}
+
+fn min_rooms(intervals: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut sorted = intervals.iter().enumerate().collect::<Vec<_>>();
+    sorted.sort_by_key(|&(_, &(start, _))| start);
+
+    let mut rooms: Vec<BinaryHeap<Reverse<usize>>> = Vec::new(); // min-heap of end times
+    let mut assignment = vec![0; intervals.len()];
+
+    for (idx, &(start, end)) in sorted {
+        // Find the room that becomes free earliest
+        let mut assigned = false;
+        for (room_id, room) in rooms.iter_mut().enumerate() {
+            if let Some(&Reverse(earliest_end)) = room.peek() {
+                if start >= earliest_end {
+                    room.pop(); // remove the ended meeting
+                    room.push(Reverse(end));
+                    assignment[idx] = room_id;
+                    assigned = true;
+                    break;
+                }
+            }
+        }
+
+        if !assigned {
+            // Need a new room
+            let room_id = rooms.len();
+            let mut new_room = BinaryHeap::new();
+            new_room.push(Reverse(end));
+            rooms.push(new_room);
+            assignment[idx] = room_id;
+        }
+    }
+
+    // Convert assignment to room lists
+    let mut room_lists = vec![Vec::new(); rooms.len()];
+    for (interval_idx, &room_id) in assignment.iter().enumerate() {
+        room_lists[room_id].push(interval_idx);
+    }
+
+    room_lists
+}
+
+fn setup(
+    mut commands: Commands,
+    palette: Res<Palette>,
+    mut state: ResMut<AppState>,
+    mut rng: ResMut<VizRng>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    state.intervals = state
+        .given
+        .clone()
+        .unwrap_or_else(|| sample_intervals(&mut rng.0));
+
+    let max_time = state
+        .intervals
+        .iter()
+        .map(|&(_, end)| end)
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    // Draw timeline
+    commands.spawn(SpriteBundle {
+        sprite: Sprite {
+            color: Color::srgb(0.5, 0.5, 0.5),
+            custom_size: Some(Vec2::new(TIMELINE_WIDTH, 4.0)),
+            ..default()
+        },
+        transform: Transform::from_xyz(0.0, 0.0, -0.1),
+        ..default()
+    });
+
+    // Timeline labels
+    for i in (0..=max_time).step_by(max_time.div_ceil(13)) {
+        let x = -TIMELINE_WIDTH / 2.0 + (i as f32 / max_time as f32) * TIMELINE_WIDTH;
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("{}", i),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.8, 0.8, 0.8),
+                },
+            ),
+            transform: Transform::from_xyz(x, -30.0, 0.0),
+            ..default()
+        });
+    }
+
+    // Room labels
+    for room_id in 0..5 {
+        // Max 5 rooms for display
+        let y = 50.0 + room_id as f32 * ROOM_HEIGHT;
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("Room {}", room_id + 1),
+                SegmentStyle {
+                    font_size: 18.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(-TIMELINE_WIDTH / 2.0 - 80.0, y, 0.0),
+            ..default()
+        });
+    }
+
+    // Interval bars (initially all in room 0, will be updated)
+    for (i, &(start, end)) in state.intervals.iter().enumerate() {
+        let start_x = -TIMELINE_WIDTH / 2.0 + (start as f32 / max_time as f32) * TIMELINE_WIDTH;
+        let end_x = -TIMELINE_WIDTH / 2.0 + (end as f32 / max_time as f32) * TIMELINE_WIDTH;
+        let width = end_x - start_x;
+        let y = 50.0; // Start in first room
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited,
+                    custom_size: Some(Vec2::new(width, BAR_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(start_x + width / 2.0, y, 0.0),
+                ..default()
+            },
+            IntervalBar,
+            Interval {
+                id: i,
+                start,
+                end,
+                room: None,
+            },
+        ));
+
+        // Interval label
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!("I{}: {}-{}", i, start, end),
+                SegmentStyle {
+                    font_size: 14.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            transform: Transform::from_xyz(start_x + width / 2.0, y, 1.0),
+            ..default()
+        });
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Interval Scheduling: Minimum rooms needed using sweep line\nPress Space to start, R to reset",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn input_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<AppState>,
+    mut rng: ResMut<VizRng>,
+) {
+    let restart = state.done && keyboard.clear_just_pressed(KeyCode::Space);
+    if restart || reset.read().count() > 0 {
+        state.intervals = state
+            .given
+            .clone()
+            .unwrap_or_else(|| sample_intervals(&mut rng.0));
+        state.rooms.clear();
+        state.current = 0;
+        state.done = false;
+    }
+}
+
+fn step_system(settings: Res<Settings>, mut state: ResMut<AppState>) {
+    if state.done || !settings.step_ready() {
+        return;
+    }
+
+    if state.current == 0 {
+        // Compute room assignments
+        state.rooms = min_rooms(&state.intervals);
+        state.done = true;
+    }
+}
+
+fn update_visualization(
+    state: Res<AppState>,
+    palette: Res<Palette>,
+    mut query: Query<(&mut Interval, &mut Transform, &mut Sprite)>,
+) {
+    if !state.done {
+        // Unassigned bars only need repainting when the theme changed.
+        if palette.is_changed() {
+            for (_, _, mut sprite) in query.iter_mut() {
+                sprite.color = palette.visited;
+            }
+        }
+        return;
+    }
+    for (mut interval, mut transform, mut sprite) in query.iter_mut() {
+        if let Some(room_id) = interval.room {
+            let y = 50.0 + room_id as f32 * ROOM_HEIGHT;
+            transform.translation.y = y;
+            // Color by room
+            let hue = (room_id as f32 * 0.2) % 1.0;
+            sprite.color = Color::hsl(hue * 360.0, 0.7, 0.5);
+        } else {
+            // Assign room based on computed rooms
+            for (room_id, room_intervals) in state.rooms.iter().enumerate() {
+                if room_intervals.contains(&interval.id) {
+                    interval.room = Some(room_id);
+                    let y = 50.0 + room_id as f32 * ROOM_HEIGHT;
+                    transform.translation.y = y;
+                    let hue = (room_id as f32 * 0.2) % 1.0;
+                    sprite.color = Color::hsl(hue * 360.0, 0.7, 0.5);
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use std::f32::consts::FRAC_PI_4;
+
+pub const TITLE: &str = "Reverse k-Group";
+const BG_COLOR: Color = Color::srgb(0.018, 0.04, 0.1);
+const NODE_VALUES: [i32; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+const K: usize = 3;
+const NODE_SIZE: Vec2 = Vec2::new(110.0, 66.0);
+const NODE_GAP: f32 = 30.0;
+const ORIGINAL_Y: f32 = 200.0;
+const RESULT_Y: f32 = -160.0;
+
+#[derive(Component)]
+struct NodeBox;
+
+#[derive(Component)]
+struct PointerMarker;
+
+#[derive(Component)]
+struct StepPanel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "k-group-reverse";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_original_list(&mut commands, &palette);
+    spawn_result_lane(&mut commands);
+    spawn_step_panel(&mut commands);
+
+    info!(
+        "Reverse k-Group scaffold initialized. Animate group reversal and pointer rewiring next."
+    );
+}
+
+fn spawn_original_list(commands: &mut Commands, palette: &Palette) {
+    let total_width = NODE_VALUES.len() as f32 * (NODE_SIZE.x + NODE_GAP) - NODE_GAP;
+    let origin_x = -total_width / 2.0 + NODE_SIZE.x / 2.0;
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            format!("Original list (k = {K})"),
+            SegmentStyle {
+                font_size: 34.0,
+                color: Color::srgba(0.92, 0.96, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(
+            origin_x - NODE_SIZE.x,
+            ORIGINAL_Y + NODE_SIZE.y / 2.0 + 36.0,
+            0.1,
+        ),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    for (idx, value) in NODE_VALUES.iter().enumerate() {
+        let x = origin_x + idx as f32 * (NODE_SIZE.x + NODE_GAP);
+        let role = if idx / K == 1 {
+            Highlight::Compare
+        } else {
+            Highlight::Visited
+        };
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.role(role).with_alpha(0.85),
+                    custom_size: Some(NODE_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, ORIGINAL_Y, 0.0),
+                ..default()
+            },
+            NodeBox,
+            role,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 30.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, ORIGINAL_Y + 4.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+
+        if idx < NODE_VALUES.len() - 1 {
+            let next_x = origin_x + (idx + 1) as f32 * (NODE_SIZE.x + NODE_GAP);
+            spawn_arrow(
+                commands,
+                x + NODE_SIZE.x / 2.0 + 6.0,
+                next_x - NODE_SIZE.x / 2.0 - 6.0,
+                ORIGINAL_Y,
+                -0.05,
+            );
+        }
+    }
+
+    let group_start = K;
+    let group_end = 2 * K - 1;
+    let start_x =
+        origin_x + group_start as f32 * (NODE_SIZE.x + NODE_GAP) - NODE_SIZE.x / 2.0 - 12.0;
+    let width = (group_end - group_start + 1) as f32 * (NODE_SIZE.x + NODE_GAP) + 24.0 - NODE_GAP;
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.25),
+                custom_size: Some(Vec2::new(width, NODE_SIZE.y + 70.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(start_x + width / 2.0, ORIGINAL_Y, -0.1),
+            ..default()
+        },
+        Highlight::Compare,
+        PointerMarker,
+    ));
+
+    let labels = [
+        (group_start, "group head"),
+        (group_end, "group tail"),
+        (group_start - 1, "prev"),
+        (group_end + 1, "curr"),
+    ];
+    for (idx, label) in labels {
+        if idx >= NODE_VALUES.len() {
+            continue;
+        }
+        let x = origin_x + idx as f32 * (NODE_SIZE.x + NODE_GAP);
+        spawn_label(commands, palette, x, ORIGINAL_Y, label);
+    }
+}
+
+fn spawn_result_lane(commands: &mut Commands) {
+    let total_width = NODE_VALUES.len() as f32 * (NODE_SIZE.x + NODE_GAP) - NODE_GAP;
+    let origin_x = -total_width / 2.0 + NODE_SIZE.x / 2.0;
+
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Partially reversed output",
+            SegmentStyle {
+                font_size: 32.0,
+                color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+            },
+        ),
+        transform: Transform::from_xyz(
+            origin_x - NODE_SIZE.x,
+            RESULT_Y + NODE_SIZE.y / 2.0 + 30.0,
+            0.1,
+        ),
+        text_anchor: Anchor::TopLeft,
+        ..default()
+    });
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.28, 0.4, 0.75),
+                custom_size: Some(Vec2::new(total_width + NODE_SIZE.x, NODE_SIZE.y + 50.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(origin_x + NODE_SIZE.x / 2.0, RESULT_Y, -0.2),
+            ..default()
+        },
+        NodeBox,
+    ));
+
+    let mut tail_values = NODE_VALUES;
+    tail_values[K..2 * K].reverse();
+
+    for (idx, value) in tail_values.iter().enumerate() {
+        let x = origin_x + idx as f32 * (NODE_SIZE.x + NODE_GAP);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.4, 0.65, 0.9, 0.45),
+                    custom_size: Some(NODE_SIZE),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, RESULT_Y, -0.1),
+                ..default()
+            },
+            NodeBox,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, RESULT_Y + 4.0, 0.1),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+
+        if idx > 0 {
+            let prev_x = origin_x + (idx - 1) as f32 * (NODE_SIZE.x + NODE_GAP);
+            spawn_arrow(
+                commands,
+                prev_x + NODE_SIZE.x / 2.0 + 6.0,
+                x - NODE_SIZE.x / 2.0 - 6.0,
+                RESULT_Y,
+                -0.05,
+            );
+        }
+    }
+}
+
+fn spawn_step_panel(commands: &mut Commands) {
+    let panel_pos = Vec3::new(520.0, -40.0, -0.18);
+    let panel_size = Vec2::new(360.0, 360.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.16, 0.24, 0.34, 0.9),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Steps",
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 24.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        StepPanel,
+    ));
+
+    let steps = [
+        "Count ahead to verify a full group",
+        "Reverse nodes within group using head insertion",
+        "Connect prev_group_tail to new head",
+        "Move prev pointer to new tail",
+        "Repeat until fewer than k nodes remain",
+    ];
+
+    for (i, step) in steps.iter().enumerate() {
+        let y = panel_pos.y + panel_size.y / 2.0 - 80.0 - i as f32 * 70.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.34, 0.6, 0.88, 0.35),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, 60.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, y, 0.0),
+                ..default()
+            },
+            StepPanel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    *step,
+                    SegmentStyle {
+                        font_size: 22.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(panel_pos.x, y, 0.1),
+                text_anchor: Anchor::Center,
+                ..default()
+            },
+            StepPanel,
+        ));
+    }
+}
+
+fn spawn_label(commands: &mut Commands, palette: &Palette, x: f32, y: f32, label: &str) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.8),
+                custom_size: Some(Vec2::new(90.0, 38.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, y + NODE_SIZE.y / 2.0 + 46.0, -0.1),
+            ..default()
+        },
+        Highlight::Compare,
+        PointerMarker,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                label,
+                SegmentStyle {
+                    font_size: 22.0,
+                    color: Color::BLACK,
+                },
+            ),
+            transform: Transform::from_xyz(x, y + NODE_SIZE.y / 2.0 + 46.0, 0.0),
+            text_anchor: Anchor::Center,
+            ..default()
+        },
+        PointerMarker,
+    ));
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.8),
+                custom_size: Some(Vec2::new(14.0, 26.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, y + NODE_SIZE.y / 2.0 + 22.0, -0.15),
+            ..default()
+        },
+        Highlight::Compare,
+        PointerMarker,
+    ));
+}
+
+fn spawn_arrow(commands: &mut Commands, start_x: f32, end_x: f32, y: f32, z: f32) {
+    let length = end_x - start_x;
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.95, 0.85, 0.55, 0.85),
+                custom_size: Some(Vec2::new(length, 5.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(start_x + length / 2.0, y, z),
+            ..default()
+        },
+        PointerMarker,
+    ));
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.95, 0.85, 0.55, 0.85),
+                custom_size: Some(Vec2::new(16.0, 16.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(end_x, y, z + 0.01)
+                .with_rotation(Quat::from_rotation_z(-FRAC_PI_4)),
+            ..default()
+        },
+        PointerMarker,
+    ));
+}
@@ -0,0 +1,208 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Kruskal MST";
+const BG_COLOR: Color = Color::srgb(0.03, 0.03, 0.07);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphNode {
+    id: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    weight: i32,
+    in_mst: bool,
+}
+
+/// Everything redraw spawns, cleared when the graph changes.
+#[derive(Component)]
+struct Drawn;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "kruskal";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let check = |g: &GraphScenario| g.undirected().and_then(|()| g.nonnegative_weights());
+    let graph = match GraphScenario::from_args_checked(check) {
+        Some(scenario) => Graph::from_scenario(&scenario, EdgeWeights::NonNegative),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(graph)
+        .graph_editor()
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_graph() -> Graph {
+    let edges = [
+        (0, 1, 4),
+        (0, 3, 1),
+        (1, 2, 2),
+        (1, 3, 3),
+        (2, 4, 5),
+        (2, 5, 6),
+        (3, 4, 7),
+        (4, 5, 8),
+    ];
+    Graph::undirected(sample_positions(), edges, EdgeWeights::NonNegative)
+}
+
+fn sample_positions() -> Vec<Vec2> {
+    vec![
+        Vec2::new(-200.0, 100.0),
+        Vec2::new(0.0, 150.0),
+        Vec2::new(200.0, 100.0),
+        Vec2::new(-100.0, -50.0),
+        Vec2::new(100.0, -50.0),
+        Vec2::new(0.0, -150.0),
+    ]
+}
+
+fn kruskal_mst(graph: &Graph) -> Vec<(usize, usize)> {
+    let mut edges: Vec<(i32, usize, usize)> = graph
+        .edges()
+        .iter()
+        .map(|&(from, to, weight)| (weight, from, to))
+        .collect();
+    edges.sort();
+
+    let mut parent: Vec<usize> = (0..graph.len()).collect();
+    let mut mst = Vec::new();
+
+    fn find(parent: &mut Vec<usize>, i: usize) -> usize {
+        if parent[i] != i {
+            parent[i] = find(parent, parent[i]);
+        }
+        parent[i]
+    }
+
+    for (_weight, u, v) in edges {
+        let set_u = find(&mut parent, u);
+        let set_v = find(&mut parent, v);
+        if set_u != set_v {
+            mst.push((u, v));
+            parent[set_u] = set_v;
+        }
+    }
+    mst
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Kruskal MST: Sort edges by weight, add if no cycle (union-find)\nGreen edges form the minimum spanning tree",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+// runs the algorithm again and redraws whenever the graph was edited (or first shown) or the theme changed
+fn redraw(
+    mut commands: Commands,
+    graph: Res<Graph>,
+    palette: Res<Palette>,
+    drawn: Query<Entity, With<Drawn>>,
+) {
+    if !graph.is_changed() && !palette.is_changed() {
+        return;
+    }
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let mst_edges = kruskal_mst(&graph);
+    let positions = graph.positions();
+
+    // Nodes
+    for (id, &pos) in positions.iter().enumerate() {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited,
+                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            GraphNode { id },
+            SegmentText::from_section(
+                format!("{}", id),
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            Drawn,
+        ));
+    }
+
+    // Edges
+    for &(from, to, weight) in graph.edges() {
+        let start = positions[from];
+        let end = positions[to];
+        let dir = (end - start).normalize_or_zero();
+        let length = (end - start).length();
+        let midpoint = start + dir * length / 2.0;
+        let angle = dir.y.atan2(dir.x);
+        let in_mst = mst_edges.contains(&(from, to)) || mst_edges.contains(&(to, from));
+        let color = if in_mst {
+            palette.settled
+        } else {
+            Color::srgb(0.7, 0.7, 0.7)
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new((length - 60.0).max(0.0), 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            GraphEdge {
+                from,
+                to,
+                weight,
+                in_mst,
+            },
+            Drawn,
+        ));
+        // Weight label
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("{}", weight),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                transform: Transform::from_xyz(midpoint.x, midpoint.y + 20.0, 1.0),
+                ..default()
+            },
+            Drawn,
+        ));
+    }
+}
@@ -1,204 +1,19 @@
+use algoviz_core::Gallery;
 use bevy::prelude::*;
-use std::collections::HashMap;
-
-const TITLE: &str = "Kruskal MST";
-const BG_COLOR: Color = Color::srgb(0.03, 0.03, 0.07);
-
-#[derive(Component)]
-struct GraphNode {
-    id: usize,
-}
-
-#[derive(Component)]
-struct GraphEdge {
-    from: usize,
-    to: usize,
-    weight: usize,
-    in_mst: bool,
-}
-
-#[derive(Resource)]
-struct AppState {
-    graph: HashMap<usize, Vec<(usize, usize)>>, // to, weight
-    mst_edges: Vec<(usize, usize)>,
-}
+use bevy_kruskal::TITLE;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
-                title: format!("Bevy {TITLE}").into(),
+                title: format!("Bevy {TITLE}"),
                 resolution: (1000.0, 800.0).into(),
                 resizable: false,
                 ..default()
             }),
             ..default()
         }))
-        .insert_resource(ClearColor(BG_COLOR))
-        .insert_resource(AppState {
-            graph: sample_graph(),
-            mst_edges: kruskal_mst(&sample_graph()),
-        })
-        .add_systems(Startup, setup)
+        .add_plugins(bevy_kruskal::plugin)
+        .insert_state(Gallery::Viz(bevy_kruskal::ID))
         .run();
 }
-
-fn sample_graph() -> HashMap<usize, Vec<(usize, usize)>> {
-    let mut graph = HashMap::new();
-    graph.insert(0, vec![(1, 4), (3, 1)]);
-    graph.insert(1, vec![(2, 2), (3, 3)]);
-    graph.insert(2, vec![(4, 5), (5, 6)]);
-    graph.insert(3, vec![(4, 7)]);
-    graph.insert(4, vec![(5, 8)]);
-    graph.insert(5, vec![]);
-    graph
-}
-
-fn kruskal_mst(graph: &HashMap<usize, Vec<(usize, usize)>>) -> Vec<(usize, usize)> {
-    let mut edges: Vec<(usize, usize, usize)> = graph.iter().flat_map(|(&from, neighbors)| {
-        neighbors.iter().map(move |&(to, weight)| (weight, from, to))
-    }).collect();
-    edges.sort();
-
-    let mut parent: Vec<usize> = (0..6).collect();
-    let mut mst = Vec::new();
-
-    fn find(parent: &mut Vec<usize>, i: usize) -> usize {
-        if parent[i] != i {
-            parent[i] = find(parent, parent[i]);
-        }
-        parent[i]
-    }
-
-    for (weight, u, v) in edges {
-        let set_u = find(&mut parent, u);
-        let set_v = find(&mut parent, v);
-        if set_u != set_v {
-            mst.push((u, v));
-            parent[set_u] = set_v;
-        }
-    }
-    mst
-}
-
-fn setup(mut commands: Commands, asset_server: Res<AssetServer>, state: Res<AppState>) {
-    commands.spawn(Camera2dBundle::default());
-
-    let positions = [
-        Vec2::new(-200.0, 100.0),
-        Vec2::new(0.0, 150.0),
-        Vec2::new(200.0, 100.0),
-        Vec2::new(-100.0, -50.0),
-        Vec2::new(100.0, -50.0),
-        Vec2::new(0.0, -150.0),
-    ];
-
-    // Nodes
-    for (id, &pos) in positions.iter().enumerate() {
-        commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    color: Color::srgb(0.25, 0.55, 0.95),
-                    custom_size: Some(Vec2::new(50.0, 50.0)),
-                    ..default()
-                },
-                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
-                ..default()
-            },
-            GraphNode { id },
-            Text2dBundle {
-                text: Text::from_section(
-                    format!("{}", id),
-                    TextStyle {
-                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                        font_size: 20.0,
-                        color: Color::srgb(0.0, 0.0, 0.0),
-                    },
-                ),
-                transform: Transform::from_xyz(pos.x, pos.y, 1.0),
-                ..default()
-            },
-        ));
-    }
-
-    // Edges
-    for (&from, neighbors) in &state.graph {
-        for &(to, weight) in neighbors {
-            let start = positions[from];
-            let end = positions[to];
-            let dir = (end - start).normalize();
-            let length = (end - start).length();
-            let midpoint = start + dir * length / 2.0;
-            let angle = dir.y.atan2(dir.x);
-            let in_mst = state.mst_edges.contains(&(from, to)) || state.mst_edges.contains(&(to, from));
-            let color = if in_mst {
-                Color::srgb(0.0, 1.0, 0.0)
-            } else {
-                Color::srgb(0.7, 0.7, 0.7)
-            };
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        color,
-                        custom_size: Some(Vec2::new(length - 60.0, 4.0)),
-                        ..default()
-                    },
-                    transform: Transform {
-                        translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
-                        rotation: Quat::from_rotation_z(angle),
-                        ..default()
-                    },
-                    ..default()
-                },
-                GraphEdge {
-                    from,
-                    to,
-                    weight,
-                    in_mst,
-                },
-            ));
-            // Arrow head
-            let arrow_pos = end - dir * 30.0;
-            commands.spawn(SpriteBundle {
-                sprite: Sprite {
-                    color,
-                    custom_size: Some(Vec2::new(20.0, 20.0)),
-                    ..default()
-                },
-                transform: Transform {
-                    translation: Vec3::new(arrow_pos.x, arrow_pos.y, -0.05),
-                    rotation: Quat::from_rotation_z(angle),
-                    ..default()
-                },
-                ..default()
-            });
-            // Weight label
-            commands.spawn(Text2dBundle {
-                text: Text::from_section(
-                    format!("{}", weight),
-                    TextStyle {
-                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                        font_size: 16.0,
-                        color: Color::srgb(0.9, 0.9, 0.9),
-                    },
-                ),
-                transform: Transform::from_xyz(midpoint.x, midpoint.y + 20.0, 1.0),
-                ..default()
-            });
-        }
-    }
-
-    // Instructions
-    commands.spawn(Text2dBundle {
-        text: Text::from_section(
-            "Kruskal MST: Sort edges by weight, add if no cycle (union-find)\nGreen edges form the minimum spanning tree",
-            TextStyle {
-                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 16.0,
-                color: Color::srgb(0.9, 0.9, 0.9),
-            },
-        ),
-        transform: Transform::from_xyz(0.0, -350.0, 0.0),
-        ..default()
-    });
-}
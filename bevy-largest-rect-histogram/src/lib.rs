@@ -0,0 +1,205 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Largest Rectangle in Histogram";
+const BG_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const STEP_INTERVAL: f32 = 1.0;
+const BAR_WIDTH: f32 = 40.0;
+const BAR_BASE_Y: f32 = -200.0;
+const MAX_HEIGHT: f32 = 200.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct Bar {
+    index: usize,
+    height: i32,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct StackBox {
+    index: usize,
+}
+
+#[derive(Component)]
+struct CurrentMaxRect;
+
+#[derive(Resource, Clone)]
+struct State {
+    heights: Vec<i32>,
+    stack: Vec<usize>,
+    i: usize,
+    max_area: i32,
+    done: bool,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "largest-rect-histogram";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(State {
+            heights: vec![2, 1, 5, 6, 2, 3],
+            stack: Vec::new(),
+            i: 0,
+            max_area: 0,
+            done: false,
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                update_highlights.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, state: Res<State>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn bars
+    for (idx, &h) in state.heights.iter().enumerate() {
+        let height_px = (h as f32 / 6.0) * MAX_HEIGHT; // Scale to max 6
+        let x = (idx as f32 - state.heights.len() as f32 / 2.0) * (BAR_WIDTH + 10.0);
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgb(0.5, 0.5, 0.5),
+                        custom_size: Some(Vec2::new(BAR_WIDTH, height_px)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, BAR_BASE_Y + height_px / 2.0, 0.0),
+                    ..default()
+                },
+                Bar {
+                    index: idx,
+                    height: h,
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        h.to_string(),
+                        SegmentStyle {
+                            font_size: 16.0,
+                            color: Color::BLACK,
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                    ..default()
+                });
+            });
+    }
+
+    // Stack label
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Stack",
+            SegmentStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(-350.0, 100.0, 1.0),
+        ..default()
+    });
+
+    // Max area display
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Max Area: 0",
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 200.0, 1.0),
+            ..default()
+        },
+        CurrentMaxRect,
+    ));
+}
+
+fn input(mut reset: EventReader<ResetRequested>, mut state: ResMut<State>) {
+    if reset.read().count() > 0 {
+        // Reset
+        state.stack.clear();
+        state.i = 0;
+        state.max_area = 0;
+        state.done = false;
+    }
+}
+
+fn step(mut state: ResMut<State>, settings: Res<Settings>) {
+    if state.done || !settings.step_ready() {
+        return;
+    }
+
+    if state.i < state.heights.len() {
+        let current_height = state.heights[state.i];
+        let i = state.i;
+        while !state.stack.is_empty() {
+            let top_height = {
+                let top_idx = *state.stack.last().unwrap();
+                state.heights[top_idx]
+            };
+            if top_height < current_height {
+                break;
+            }
+            let top = state.stack.pop().unwrap();
+            let width = if state.stack.is_empty() {
+                i
+            } else {
+                i - state.stack.last().unwrap() - 1
+            };
+            let area = state.heights[top] * width as i32;
+            if area > state.max_area {
+                state.max_area = area;
+            }
+        }
+        state.stack.push(i);
+        state.i = i + 1;
+    } else {
+        // Finish popping
+        while let Some(top) = state.stack.pop() {
+            let width = if state.stack.is_empty() {
+                state.heights.len()
+            } else {
+                state.heights.len() - state.stack.last().unwrap() - 1
+            };
+            let area = state.heights[top] * width as i32;
+            if area > state.max_area {
+                state.max_area = area;
+            }
+        }
+        state.done = true;
+    }
+}
+
+fn update_highlights(
+    mut bar_query: Query<(&mut Sprite, &Bar)>,
+    mut text_query: Query<&mut SegmentText, With<CurrentMaxRect>>,
+    state: Res<State>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, bar) in bar_query.iter_mut() {
+        if bar.index == state.i && state.i < state.heights.len() {
+            sprite.color = palette.visited; // Blue for current
+        } else if state.stack.contains(&bar.index) {
+            sprite.color = palette.settled; // Green for in stack
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5);
+        }
+    }
+
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("Max Area: {}", state.max_area);
+    }
+}
@@ -0,0 +1,201 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub const TITLE: &str = "LCA Binary Tree";
+const BG_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const NODE_RADIUS: f32 = 25.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct TreeNode {
+    value: i32,
+    is_target: bool,
+    is_lca: bool,
+}
+
+#[derive(Resource, Clone)]
+struct State {
+    tree: HashMap<i32, (Option<i32>, Option<i32>)>,
+    root: i32,
+    node1: i32,
+    node2: i32,
+    lca: i32,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "lca-binary-tree";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let check = |t: &TreeScenario| t.queries(2);
+    let (tree, root, node1, node2) = match TreeScenario::from_args_checked(check) {
+        Some(scenario) => (
+            scenario.to_map(),
+            scenario.root,
+            scenario.queries[0],
+            scenario.queries[1],
+        ),
+        None => (sample_tree(), 3, 6, 2),
+    };
+    let lca = find_lca(&tree, root, node1, node2);
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(State {
+            tree,
+            root,
+            node1,
+            node2,
+            lca,
+        })
+        .setup(setup)
+        .systems(Update, ui);
+}
+
+fn sample_tree() -> HashMap<i32, (Option<i32>, Option<i32>)> {
+    let mut tree = HashMap::new();
+    tree.insert(3, (Some(5), Some(1)));
+    tree.insert(5, (Some(6), Some(2)));
+    tree.insert(1, (Some(0), Some(8)));
+    tree.insert(6, (None, None));
+    tree.insert(2, (None, None));
+    tree.insert(0, (None, None));
+    tree.insert(8, (None, None));
+    tree
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>, state: Res<State>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn nodes
+    let mut positions = HashMap::new();
+    assign_positions(&state.tree, state.root, 0.0, 200.0, 0, &mut positions);
+
+    for &val in state.tree.keys() {
+        let pos = positions[&val];
+        let is_target = val == state.node1 || val == state.node2;
+        let is_lca = val == state.lca;
+        let role = if is_lca {
+            Some(Highlight::Violation)
+        } else if is_target {
+            Some(Highlight::Compare)
+        } else {
+            None
+        };
+        let mut node = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: role.map_or(Color::srgb(0.5, 0.5, 0.5), |role| palette.role(role)),
+                    custom_size: Some(Vec2::new(NODE_RADIUS * 2.0, NODE_RADIUS * 2.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.0, pos.1, 0.0),
+                ..default()
+            },
+            TreeNode {
+                value: val,
+                is_target,
+                is_lca,
+            },
+        ));
+        if let Some(role) = role {
+            node.insert(role);
+        }
+        node.with_children(|parent| {
+            parent.spawn(SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    val.to_string(),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::BLACK,
+                    },
+                ),
+                transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                ..default()
+            });
+        });
+    }
+
+    // LCA text
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            format!(
+                "LCA of {} and {} is {}",
+                state.node1, state.node2, state.lca
+            ),
+            SegmentStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -200.0, 1.0),
+        ..default()
+    });
+}
+
+fn find_lca(tree: &HashMap<i32, (Option<i32>, Option<i32>)>, root: i32, p: i32, q: i32) -> i32 {
+    if root == p || root == q {
+        return root;
+    }
+    let (left, right) = tree[&root];
+    let left_lca = left.map(|l| find_lca(tree, l, p, q)).unwrap_or(-1);
+    let right_lca = right.map(|r| find_lca(tree, r, p, q)).unwrap_or(-1);
+    if left_lca != -1 && right_lca != -1 {
+        return root;
+    }
+    if left_lca != -1 {
+        left_lca
+    } else {
+        right_lca
+    }
+}
+
+fn assign_positions(
+    tree: &HashMap<i32, (Option<i32>, Option<i32>)>,
+    node: i32,
+    x: f32,
+    y: f32,
+    depth: usize,
+    positions: &mut HashMap<i32, (f32, f32)>,
+) {
+    if !tree.contains_key(&node) {
+        return;
+    }
+    positions.insert(node, (x, y));
+    let child_y = y - 80.0;
+    if let Some(left) = tree[&node].0 {
+        assign_positions(
+            tree,
+            left,
+            x - 100.0 / (depth as f32 + 1.0),
+            child_y,
+            depth + 1,
+            positions,
+        );
+    }
+    if let Some(right) = tree[&node].1 {
+        assign_positions(
+            tree,
+            right,
+            x + 100.0 / (depth as f32 + 1.0),
+            child_y,
+            depth + 1,
+            positions,
+        );
+    }
+}
+
+fn ui(mut commands: Commands) {
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "LCA Binary Tree",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(-350.0, 250.0, 1.0),
+        ..default()
+    });
+}
@@ -0,0 +1,39 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Level Order BFS";
+const BG_COLOR: Color = Color::srgb(0.02, 0.04, 0.07);
+
+#[derive(Component)]
+struct QueueSlot;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "level-order-bfs";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let base_x = -200.0;
+    for i in 0..5 {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.4),
+                    custom_size: Some(Vec2::new(80.0, 80.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(base_x + i as f32 * 100.0, -220.0, 0.0),
+                ..default()
+            },
+            Highlight::Visited,
+            QueueSlot,
+        ));
+    }
+
+    info!("Level Order BFS scaffold running. Replace queue slots with actual traversal state visuals.");
+}
@@ -0,0 +1,207 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Longest Substring Without Repeats";
+const BG_COLOR: Color = Color::srgb(0.03, 0.05, 0.09);
+
+#[derive(Component)]
+struct CharTile;
+
+#[derive(Component)]
+struct WindowOverlay;
+
+#[derive(Component)]
+struct SetEntry;
+
+#[derive(Component)]
+struct PointerLabel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "lsw";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_stream(&mut commands, &palette);
+    spawn_window_overlay(&mut commands, &palette);
+    spawn_set_panel(&mut commands);
+    spawn_pointers(&mut commands, &palette);
+
+    info!("Longest Substring scaffold ready. Animate window expansion, contraction, and best substring updates next.");
+}
+
+fn spawn_stream(commands: &mut Commands, palette: &Palette) {
+    let characters: Vec<char> = "abcabcbbxyz".chars().collect();
+    let width = 64.0;
+    let gap = 12.0;
+    let origin_x = -(characters.len() as f32 * (width + gap) - gap) / 2.0 + width / 2.0;
+
+    for (i, ch) in characters.iter().enumerate() {
+        let x = origin_x + i as f32 * (width + gap);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.8),
+                    custom_size: Some(Vec2::new(width, 120.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -40.0, 0.0),
+                ..default()
+            },
+            Highlight::Visited,
+            CharTile,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                ch.to_string(),
+                SegmentStyle {
+                    font_size: 36.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, 30.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_window_overlay(commands: &mut Commands, palette: &Palette) {
+    let window_size = 4;
+    let width = 64.0;
+    let gap = 12.0;
+    let overlay_width = window_size as f32 * (width + gap) - gap + 14.0;
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.25),
+                custom_size: Some(Vec2::new(overlay_width, 200.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-214.0, -20.0, -0.2),
+            ..default()
+        },
+        Highlight::Compare,
+        WindowOverlay,
+    ));
+}
+
+fn spawn_set_panel(commands: &mut Commands) {
+    let panel_pos = Vec3::new(360.0, 160.0, -0.3);
+    let panel_size = Vec2::new(300.0, 220.0);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.25, 0.35, 0.85),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(panel_pos),
+            ..default()
+        },
+        SetEntry,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Characters in window",
+                SegmentStyle {
+                    font_size: 26.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(
+                panel_pos.x,
+                panel_pos.y + panel_size.y / 2.0 + 24.0,
+                0.1,
+            ),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        SetEntry,
+    ));
+
+    let entries = ["a", "b", "c", "x"];
+    let start_y = panel_pos.y + panel_size.y / 2.0 - 60.0;
+    for (i, label) in entries.iter().enumerate() {
+        let y = start_y - i as f32 * 44.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.35, 0.6, 0.85, 0.45),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, 36.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(panel_pos.x, y, 0.0),
+                ..default()
+            },
+            SetEntry,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    *label,
+                    SegmentStyle {
+                        font_size: 22.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                text_anchor: Anchor::Center,
+                transform: Transform::from_xyz(panel_pos.x, y, 0.1),
+                ..default()
+            },
+            SetEntry,
+        ));
+    }
+}
+
+fn spawn_pointers(commands: &mut Commands, palette: &Palette) {
+    let pointer_data = [
+        ("left", -260.0, Highlight::Violation),
+        ("right", 40.0, Highlight::Compare),
+        ("best", -20.0, Highlight::Settled),
+    ];
+
+    for &(label, x, role) in &pointer_data {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.role(role).with_alpha(0.85),
+                    custom_size: Some(Vec2::new(16.0, 220.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, 60.0, 0.2),
+                ..default()
+            },
+            PointerLabel,
+            role,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    label,
+                    SegmentStyle {
+                        font_size: 28.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(x, 180.0, 0.3),
+                text_anchor: Anchor::TopCenter,
+                ..default()
+            },
+            PointerLabel,
+        ));
+    }
+}
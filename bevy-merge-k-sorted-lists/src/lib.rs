@@ -0,0 +1,139 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub const TITLE: &str = "Merge K Sorted Lists";
+const BG_COLOR: Color = Color::srgb(0.03, 0.04, 0.08);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct ListElement {
+    value: i32,
+    list_idx: usize,
+    pos: usize,
+}
+
+#[derive(Resource, Clone)]
+#[allow(dead_code)]
+struct AppState {
+    lists: Vec<Vec<i32>>,
+    heap: BinaryHeap<Reverse<(i32, usize, usize)>>, // (val, list_idx, pos)
+    merged: Vec<i32>,
+    step: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "merge-k-sorted-lists";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            lists: vec![vec![1, 4, 5], vec![1, 3, 4], vec![2, 6]],
+            heap: BinaryHeap::new(),
+            merged: Vec::new(),
+            step: 0,
+        })
+        .setup(setup)
+        .systems(Update, update_merge);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>, mut state: ResMut<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Initialize heap with first elements
+    let lists = state.lists.clone();
+    for (i, list) in lists.iter().enumerate() {
+        if !list.is_empty() {
+            state.heap.push(Reverse((list[0], i, 0)));
+        }
+    }
+
+    // Spawn lists
+    let base_y = 200.0;
+    for (i, list) in lists.iter().enumerate() {
+        let base_x = -300.0;
+        for (j, &val) in list.iter().enumerate() {
+            let color = if j == 0 {
+                palette.compare
+            } else {
+                Color::srgb(0.5, 0.5, 0.5)
+            };
+            let mut element = commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(40.0, 40.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(
+                        base_x + j as f32 * 50.0,
+                        base_y - i as f32 * 100.0,
+                        0.0,
+                    ),
+                    ..default()
+                },
+                ListElement {
+                    value: val,
+                    list_idx: i,
+                    pos: j,
+                },
+                SegmentText::from_section(
+                    val.to_string(),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+            if j == 0 {
+                element.insert(Highlight::Compare);
+            }
+        }
+    }
+
+    // Spawn merged list at bottom
+    let base_x = -300.0;
+    let base_y = -200.0;
+    for (i, &val) in state.merged.iter().enumerate() {
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                val.to_string(),
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.0, 1.0, 0.0),
+                },
+            ),
+            transform: Transform::from_xyz(base_x + i as f32 * 40.0, base_y, 0.0),
+            ..default()
+        });
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Merge K Sorted Lists: Use min-heap to merge\nYellow: Current heads, Green: Merged",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn update_merge(time: Res<Time>, mut state: ResMut<AppState>) {
+    // Simulate merge steps
+    if (time.elapsed_seconds() as usize).is_multiple_of(2) && !state.heap.is_empty() {
+        if let Some(Reverse((val, list_idx, pos))) = state.heap.pop() {
+            state.merged.push(val);
+            if pos + 1 < state.lists[list_idx].len() {
+                let next_val = state.lists[list_idx][pos + 1];
+                state.heap.push(Reverse((next_val, list_idx, pos + 1)));
+            }
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::ops::Range;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 16,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 40.0;
+const BAR_GAP: f32 = 8.0;
+const ROW_WIDTH: f32 = 880.0;
+const MAX_HEIGHT: f32 = 300.0;
+const STEP_INTERVAL: f32 = 0.75;
+
+#[derive(Component, Copy, Clone)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.index
+    }
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+#[derive(Component, Deref, DerefMut)]
+struct TargetX(f32);
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    w: f32,
+    top: usize,
+    labels: bool,
+}
+
+impl Layout {
+    // bars (and gaps) narrow to fit the row; heights and hues scale to the largest value;
+    // labels only where the digits fit over the bars
+    fn new(a: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(a.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = a.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            w: row.width,
+            top,
+            labels: row.labels(top),
+        }
+    }
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, a: &[usize]) {
+        self.top = a.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+    fn h(&self, v: usize) -> f32 {
+        v as f32 / self.top as f32 * MAX_HEIGHT + 10.0
+    }
+    fn color(&self, v: usize) -> Color {
+        Color::hsl((v as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+struct State {
+    width: usize,
+    left: usize,
+    mid: usize,
+    right: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    // Working buffer for the current merge
+    buf: Vec<usize>,
+    // Animation staging
+    pre: Option<(Entity, f32)>, // chosen entity, remaining pulse time
+    move_entity: Option<Entity>,
+    placing_idx: Option<usize>, // destination index k for chosen entity
+    done: bool,
+    array: Vec<usize>,
+    counts: OpCounts,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "merge-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(input)
+        .resource(State::default())
+        .cost_chart(|st: Res<State>| st.counts)
+        .bar_editor::<Bar>()
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                animate.in_set(VizSet::Animate),
+                colors.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    st.array = input.permutation();
+    st.buf = vec![0; st.array.len()];
+    let layout = Layout::new(&st.array, row_width(ROW_WIDTH, &windows));
+    for (idx, v) in st.array.iter().copied().enumerate() {
+        let h = layout.h(v);
+        let x = x_at(idx, &layout);
+        let color = layout.color(v);
+        let id = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.w, h)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, h / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar {
+                    index: idx,
+                    value: v,
+                },
+                TargetX(x),
+            ))
+            .id();
+        if layout.labels {
+            commands
+                .entity(id)
+                .with_children(|p| spawn_value_digits(p, v, h / 2.0 + 12.0, Color::WHITE));
+        }
+    }
+    commands.insert_resource(layout);
+    st.width = 1;
+    st.left = 0;
+    st.mid = 0;
+    st.right = 0;
+    st.i = 0;
+    st.j = 0;
+    st.k = 0;
+    st.done = false;
+}
+
+fn x_at(i: usize, layout: &Layout) -> f32 {
+    layout.row.x(i)
+}
+
+//
+
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    mut layout: ResMut<Layout>,
+    mut bars: Query<(
+        Entity,
+        &mut Bar,
+        &mut Sprite,
+        &mut Transform,
+        &mut TargetX,
+        Option<&Children>,
+    )>,
+    digits_q: Query<&ValueDigits>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    let restart = st.done && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        st.array = input.permutation();
+        layout.rescale(&st.array);
+        st.buf = vec![0; st.array.len()];
+        st.width = 1;
+        st.left = 0;
+        st.mid = 0;
+        st.right = 0;
+        st.i = 0;
+        st.j = 0;
+        st.k = 0;
+        st.pre = None;
+        st.move_entity = None;
+        st.placing_idx = None;
+        st.done = false;
+        st.counts = OpCounts::default();
+        let mut raw: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        for (e, mut bar, mut sp, mut tf, mut tx, children) in bars.iter_mut() {
+            let idx = bar.index;
+            let v = st.array[idx];
+            bar.value = v;
+            let h = layout.h(v);
+            sp.custom_size = Some(Vec2::new(layout.w, h));
+            sp.color = layout.color(v);
+            let x = x_at(idx, &layout);
+            tx.0 = x;
+            tf.translation = Vec3::new(x, h / 2.0 - 200.0, 0.0);
+            raw.push((
+                e,
+                children.map_or_else(Vec::new, |c| c.to_vec()),
+                v,
+                h / 2.0 + 12.0,
+            ));
+        }
+        for (p, children, v, y) in raw {
+            for c in children {
+                if digits_q.get(c).is_ok() {
+                    commands.entity(c).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands
+                    .entity(p)
+                    .with_children(|x| spawn_value_digits(x, v, y, palette.focus));
+            }
+        }
+    }
+}
+
+fn step(
+    mut st: ResMut<State>,
+    settings: Res<Settings>,
+    layout: Res<Layout>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX)>,
+) {
+    if st.done || !settings.step_ready() {
+        return;
+    }
+
+    let Some(run) = st.advance() else { return };
+    // Retarget bars in this segment to their new indices. The merge is stable, so
+    // ordering the run's bars by (value, old index) gives their merged order even
+    // when values repeat
+    let mut merged: Vec<(usize, usize, Entity)> = bars
+        .iter()
+        .filter(|(_, bar, _)| run.contains(&bar.index))
+        .map(|(e, bar, _)| (bar.value, bar.index, e))
+        .collect();
+    merged.sort_unstable();
+    for ((_, _, e), t) in merged.into_iter().zip(run) {
+        if let Ok((_, mut bar, mut tx)) = bars.get_mut(e) {
+            bar.index = t;
+            tx.0 = x_at(t, &layout);
+        }
+    }
+}
+
+impl State {
+    /// One pick of the bottom-up merge into the buffer, or the bookkeeping
+    /// between runs and widths. When a run is complete its merged range is
+    /// copied back to the array and returned.
+    fn advance(&mut self) -> Option<Range<usize>> {
+        let n = self.array.len();
+        if self.width >= n {
+            self.done = true;
+            return None;
+        }
+
+        if self.left >= n {
+            self.width *= 2;
+            self.left = 0;
+            self.mid = 0;
+            self.right = 0;
+            self.i = 0;
+            self.j = 0;
+            self.k = 0;
+            return None;
+        }
+
+        if self.mid == 0 || self.right == 0 || self.k == 0 {
+            // initialize a new run if needed
+            self.mid = (self.left + self.width).min(n);
+            self.right = (self.left + 2 * self.width).min(n);
+            self.i = self.left;
+            self.j = self.mid;
+            self.k = self.left;
+            if self.i >= self.mid {
+                // left run empty
+                self.left = self.right;
+                return None;
+            }
+        }
+
+        if self.i >= self.mid && self.j >= self.right {
+            // finished this run
+            // Copy buf back to array for [left..right)
+            let run = self.left..self.right;
+            self.array[run.clone()].copy_from_slice(&self.buf[run.clone()]);
+            self.counts.write(run.len());
+            self.left = self.right;
+            self.mid = 0;
+            self.right = 0;
+            self.i = 0;
+            self.j = 0;
+            self.k = 0;
+            return Some(run);
+        }
+
+        // choose next element
+        let choose_left = if self.i < self.mid && self.j < self.right {
+            self.counts.compare();
+            self.array[self.i] <= self.array[self.j]
+        } else {
+            self.j >= self.right
+        };
+
+        let idx = if choose_left { self.i } else { self.j };
+        self.buf[self.k] = self.array[idx];
+        // the buffer holds the merged part of this run
+        let held = self.k + 1 - self.left;
+        self.counts.read(1);
+        self.counts.hold(held);
+        if choose_left {
+            self.i += 1;
+        } else {
+            self.j += 1;
+        }
+        self.k += 1;
+        None
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    let buf = vec![0; array.len()];
+    Box::new(Race {
+        state: State {
+            width: 1,
+            buf,
+            array,
+            ..default()
+        },
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    state: State,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        let st = &mut self.state;
+        self.focus = match st.advance() {
+            Some(run) => run.collect(),
+            None if st.k == 0 => Vec::new(),
+            // the heads of the two runs being merged
+            None => [(st.i, st.mid), (st.j, st.right)]
+                .iter()
+                .filter(|(head, end)| head < end)
+                .map(|&(head, _)| head)
+                .collect(),
+        };
+    }
+    fn done(&self) -> bool {
+        self.state.done
+    }
+    fn array(&self) -> &[usize] {
+        &self.state.array
+    }
+    fn counts(&self) -> OpCounts {
+        self.state.counts
+    }
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+// Move all bars smoothly toward their target x positions
+fn animate(time: Res<Time>, mut q: Query<(&TargetX, &mut Transform)>) {
+    let speed = 600.0 * time.delta_seconds();
+    for (tx, mut tf) in q.iter_mut() {
+        let dx = tx.0 - tf.translation.x;
+        if dx.abs() <= speed {
+            tf.translation.x = tx.0;
+        } else {
+            tf.translation.x += speed * dx.signum();
+        }
+    }
+}
+
+// simplified animation: bars retarget after each run; transform interpolation handled elsewhere if needed
+
+fn colors(
+    st: Res<State>,
+    layout: Res<Layout>,
+    mut q: Query<(&Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (bar, mut sp) in q.iter_mut() {
+        let base = layout.color(bar.value);
+        let c = if st.done { palette.settled } else { base };
+        sp.color = c;
+    }
+}
@@ -0,0 +1,172 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Move Zeros";
+const BG_COLOR: Color = Color::srgb(0.03, 0.05, 0.08);
+
+#[derive(Component)]
+struct OriginalValue;
+
+#[derive(Component)]
+struct CompactedValue;
+
+#[derive(Component)]
+struct PointerLabel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "move-zeros";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_original_row(&mut commands, &palette);
+    spawn_compacted_row(&mut commands, &palette);
+    spawn_pointers(&mut commands, &palette);
+
+    info!("Move Zeros scaffold ready. Implement pointer traversal and stable compaction animations next.");
+}
+
+fn spawn_original_row(commands: &mut Commands, palette: &Palette) {
+    let values = [3, 0, 4, 0, 0, 7, 2, 0, 5, 6];
+    let width = 60.0;
+    let gap = 16.0;
+    let origin_x = -(values.len() as f32 * (width + gap) - gap) / 2.0 + width / 2.0;
+
+    for (i, &value) in values.iter().enumerate() {
+        let x = origin_x + i as f32 * (width + gap);
+        let height = if value == 0 {
+            40.0
+        } else {
+            100.0 + (value as f32 * 4.0)
+        };
+        let color = if value == 0 {
+            Color::srgba(0.3, 0.35, 0.4, 0.8)
+        } else {
+            palette.visited.with_alpha(0.85)
+        };
+
+        let mut bar = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(width, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, 80.0 + height / 2.0, 0.0),
+                ..default()
+            },
+            OriginalValue,
+        ));
+        if value != 0 {
+            bar.insert(Highlight::Visited);
+        }
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, 80.0 + height + 28.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_compacted_row(commands: &mut Commands, palette: &Palette) {
+    let compacted = [3, 4, 7, 2, 5, 6, 0, 0, 0, 0];
+    let width = 60.0;
+    let gap = 16.0;
+    let origin_x = -(compacted.len() as f32 * (width + gap) - gap) / 2.0 + width / 2.0;
+
+    for (i, &value) in compacted.iter().enumerate() {
+        let x = origin_x + i as f32 * (width + gap);
+        let height = if value == 0 {
+            40.0
+        } else {
+            90.0 + (value as f32 * 3.0)
+        };
+        let color = if value == 0 {
+            Color::srgba(0.45, 0.5, 0.55, 0.6)
+        } else {
+            palette.compare.with_alpha(0.8)
+        };
+
+        let mut bar = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(width, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -140.0 + height / 2.0, 0.0),
+                ..default()
+            },
+            CompactedValue,
+        ));
+        if value != 0 {
+            bar.insert(Highlight::Compare);
+        }
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, -140.0 + height + 28.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_pointers(commands: &mut Commands, palette: &Palette) {
+    let pointer_data = [
+        ("slow", -180.0, Highlight::Compare),
+        ("fast", 180.0, Highlight::Violation),
+    ];
+
+    for &(label, x, role) in &pointer_data {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.role(role).with_alpha(0.9),
+                    custom_size: Some(Vec2::new(14.0, 140.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, 40.0, 0.2),
+                ..default()
+            },
+            PointerLabel,
+            role,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    label,
+                    SegmentStyle {
+                        font_size: 28.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(x, 120.0, 0.3),
+                text_anchor: Anchor::TopCenter,
+                ..default()
+            },
+            PointerLabel,
+        ));
+    }
+}
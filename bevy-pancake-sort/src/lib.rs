@@ -0,0 +1,267 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use rand::seq::SliceRandom;
+
+pub const TITLE: &str = "Pancake Sort";
+const BG_COLOR: Color = Color::srgb(0.035, 0.05, 0.09);
+const STEP_INTERVAL: f32 = 0.7;
+const PANCAKES: usize = 9;
+const HEIGHT: f32 = 28.0;
+const GAP: f32 = 12.0;
+const MARKER_WIDTH: f32 = 320.0;
+
+#[derive(Component)]
+struct Pancake {
+    slot: usize,
+}
+
+#[derive(Component)]
+struct FlipMarker;
+
+#[derive(Component)]
+struct FlipLabel;
+
+/// The stack, top first, and where the sort is in it.
+#[derive(Resource)]
+struct SortState {
+    stack: Vec<usize>,
+    // the unsorted top of the stack; everything below is in place
+    size: usize,
+    // the pancake being compared with the largest found so far
+    scan: usize,
+    largest: usize,
+    phase: SortPhase,
+    last_flip: Option<usize>,
+    counts: OpCounts,
+}
+
+#[derive(PartialEq, Eq)]
+enum SortPhase {
+    FindLargest,
+    FlipToTop,
+    FlipDown,
+    Complete,
+}
+
+impl SortState {
+    fn new(stack: Vec<usize>) -> Self {
+        let size = stack.len();
+        Self {
+            stack,
+            size,
+            scan: 1,
+            largest: 0,
+            phase: SortPhase::FindLargest,
+            last_flip: None,
+            counts: OpCounts::default(),
+        }
+    }
+
+    // reverse the top `depth` pancakes
+    fn flip(&mut self, depth: usize) {
+        self.stack[..depth].reverse();
+        for _ in 0..depth / 2 {
+            self.counts.swap();
+        }
+        self.last_flip = Some(depth);
+    }
+
+    fn step(&mut self) {
+        match self.phase {
+            SortPhase::FindLargest => {
+                if self.size < 2 {
+                    self.phase = SortPhase::Complete;
+                } else if self.scan < self.size {
+                    self.counts.compare();
+                    if self.stack[self.scan] > self.stack[self.largest] {
+                        self.largest = self.scan;
+                    }
+                    self.scan += 1;
+                } else if self.largest == self.size - 1 {
+                    // already at the bottom of the unsorted part
+                    self.next_size();
+                } else if self.largest == 0 {
+                    self.phase = SortPhase::FlipDown;
+                } else {
+                    self.phase = SortPhase::FlipToTop;
+                }
+            }
+            SortPhase::FlipToTop => {
+                self.flip(self.largest + 1);
+                self.largest = 0;
+                self.phase = SortPhase::FlipDown;
+            }
+            SortPhase::FlipDown => {
+                self.flip(self.size);
+                self.next_size();
+            }
+            SortPhase::Complete => {}
+        }
+    }
+
+    fn next_size(&mut self) {
+        self.size -= 1;
+        self.scan = 1;
+        self.largest = 0;
+        self.phase = if self.size < 2 {
+            SortPhase::Complete
+        } else {
+            SortPhase::FindLargest
+        };
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "pancake-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .setup(setup)
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (update_pancakes, update_flip_marker).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut rng: ResMut<VizRng>) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.insert_resource(SortState::new(new_stack(&mut rng)));
+    spawn_pancakes(&mut commands);
+    spawn_flip_marker(&mut commands);
+
+    info!("Pancake Sort visualization ready!");
+}
+
+// The sizes 1..=PANCAKES in random order, top first
+fn new_stack(rng: &mut VizRng) -> Vec<usize> {
+    let mut stack: Vec<usize> = (1..=PANCAKES).collect();
+    stack.shuffle(&mut rng.0);
+    stack
+}
+
+fn slot_y(slot: usize) -> f32 {
+    let origin_y = (PANCAKES as f32 * (HEIGHT + GAP)) / 2.0 - HEIGHT / 2.0;
+    origin_y - slot as f32 * (HEIGHT + GAP)
+}
+
+fn spawn_pancakes(commands: &mut Commands) {
+    for slot in 0..PANCAKES {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::new(0.0, HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, slot_y(slot), 0.0),
+                ..default()
+            },
+            Pancake { slot },
+        ));
+    }
+}
+
+fn spawn_flip_marker(commands: &mut Commands) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(MARKER_WIDTH, 4.0)),
+                anchor: Anchor::Center,
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            transform: Transform::from_xyz(0.0, 0.0, 0.5),
+            ..default()
+        },
+        FlipMarker,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "",
+                SegmentStyle {
+                    font_size: 26.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            text_anchor: Anchor::CenterRight,
+            transform: Transform::from_xyz(-MARKER_WIDTH / 2.0 - 20.0, 0.0, 0.6),
+            ..default()
+        },
+        FlipLabel,
+    ));
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut rng: ResMut<VizRng>,
+) {
+    if reset.read().count() > 0 {
+        *state = SortState::new(new_stack(&mut rng));
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if settings.step_ready() {
+        state.step();
+    }
+}
+
+fn update_pancakes(
+    state: Res<SortState>,
+    palette: Res<Palette>,
+    mut pancakes: Query<(&Pancake, &mut Sprite)>,
+) {
+    for (pancake, mut sprite) in &mut pancakes {
+        let value = state.stack[pancake.slot];
+        sprite.custom_size = Some(Vec2::new(40.0 + value as f32 * 16.0, HEIGHT));
+        let searching = state.phase == SortPhase::FindLargest;
+        sprite.color = if pancake.slot >= state.size || state.phase == SortPhase::Complete {
+            palette.settled.with_alpha(0.9)
+        } else if pancake.slot == state.largest {
+            palette.compare.with_alpha(0.9)
+        } else if searching && pancake.slot == state.scan {
+            palette.focus.with_alpha(0.9)
+        } else {
+            Color::hsla((value as f32 * 32.0) % 360.0, 0.55, 0.6, 0.9)
+        };
+    }
+}
+
+fn update_flip_marker(
+    state: Res<SortState>,
+    palette: Res<Palette>,
+    mut marker: Query<(&mut Transform, &mut Visibility, &mut Sprite), With<FlipMarker>>,
+    mut label: Query<(&mut Transform, &mut SegmentText), (With<FlipLabel>, Without<FlipMarker>)>,
+) {
+    // the line sits under the last flipped pancake
+    let y = state
+        .last_flip
+        .map(|depth| slot_y(depth - 1) - (HEIGHT + GAP) / 2.0);
+    if let Ok((mut transform, mut visibility, mut sprite)) = marker.get_single_mut() {
+        *visibility = if y.is_some() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        transform.translation.y = y.unwrap_or(0.0);
+        sprite.color = palette.compare.with_alpha(0.8);
+    }
+    if let Ok((mut transform, mut text)) = label.get_single_mut() {
+        transform.translation.y = y.unwrap_or(0.0);
+        text.sections[0].value = state
+            .last_flip
+            .map_or(String::new(), |depth| format!("flip top {depth}"));
+    }
+}
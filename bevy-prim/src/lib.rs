@@ -0,0 +1,212 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+pub const TITLE: &str = "Prim MST";
+const BG_COLOR: Color = Color::srgb(0.03, 0.04, 0.07);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphNode {
+    id: usize,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    weight: i32,
+    in_mst: bool,
+}
+
+/// Everything redraw spawns, cleared when the graph changes.
+#[derive(Component)]
+struct Drawn;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "prim";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let check = |g: &GraphScenario| g.undirected().and_then(|()| g.nonnegative_weights());
+    let graph = match GraphScenario::from_args_checked(check) {
+        Some(scenario) => Graph::from_scenario(&scenario, EdgeWeights::NonNegative),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(graph)
+        .graph_editor()
+        .setup(setup)
+        .systems(Update, redraw);
+}
+
+fn sample_graph() -> Graph {
+    let edges = [
+        (0, 1, 4),
+        (0, 3, 1),
+        (1, 2, 2),
+        (1, 3, 3),
+        (2, 4, 5),
+        (2, 5, 6),
+        (3, 4, 7),
+        (4, 5, 8),
+    ];
+    Graph::undirected(sample_positions(), edges, EdgeWeights::NonNegative)
+}
+
+fn sample_positions() -> Vec<Vec2> {
+    vec![
+        Vec2::new(-200.0, 100.0),
+        Vec2::new(0.0, 150.0),
+        Vec2::new(200.0, 100.0),
+        Vec2::new(-100.0, -50.0),
+        Vec2::new(100.0, -50.0),
+        Vec2::new(0.0, -150.0),
+    ]
+}
+
+fn prim_mst(graph: &[Vec<(usize, i32)>], source: usize) -> Vec<(usize, usize)> {
+    let mut visited = HashSet::new();
+    let mut mst = Vec::new();
+    let mut pq = BinaryHeap::new();
+    if source >= graph.len() {
+        return mst;
+    }
+
+    // Start from the source node
+    visited.insert(source);
+    for &(to, weight) in &graph[source] {
+        pq.push(Reverse((weight, source, to)));
+    }
+
+    while let Some(Reverse((_weight, from, to))) = pq.pop() {
+        if visited.contains(&to) {
+            continue;
+        }
+        visited.insert(to);
+        mst.push((from, to));
+
+        for &(next_to, next_weight) in &graph[to] {
+            if !visited.contains(&next_to) {
+                pq.push(Reverse((next_weight, to, next_to)));
+            }
+        }
+    }
+
+    mst
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Prim MST: Priority queue grows tree from start node\nGreen edges form the minimum spanning tree",
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+// runs the algorithm again and redraws whenever the graph was edited (or first shown) or the theme changed
+fn redraw(
+    mut commands: Commands,
+    graph: Res<Graph>,
+    palette: Res<Palette>,
+    drawn: Query<Entity, With<Drawn>>,
+) {
+    if !graph.is_changed() && !palette.is_changed() {
+        return;
+    }
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let mst_edges = prim_mst(&graph.adjacency(), graph.source);
+    let positions = graph.positions();
+
+    // Nodes
+    for (id, &pos) in positions.iter().enumerate() {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited,
+                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            GraphNode { id },
+            SegmentText::from_section(
+                format!("{}", id),
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            Drawn,
+        ));
+    }
+
+    // Edges
+    for &(from, to, weight) in graph.edges() {
+        let start = positions[from];
+        let end = positions[to];
+        let dir = (end - start).normalize_or_zero();
+        let length = (end - start).length();
+        let midpoint = start + dir * length / 2.0;
+        let angle = dir.y.atan2(dir.x);
+        let in_mst = mst_edges.contains(&(from, to)) || mst_edges.contains(&(to, from));
+        let color = if in_mst {
+            palette.settled
+        } else {
+            Color::srgb(0.7, 0.7, 0.7)
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new((length - 60.0).max(0.0), 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            GraphEdge {
+                from,
+                to,
+                weight,
+                in_mst,
+            },
+            Drawn,
+        ));
+        // Weight label
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("{}", weight),
+                    SegmentStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                transform: Transform::from_xyz(midpoint.x, midpoint.y + 20.0, 1.0),
+                ..default()
+            },
+            Drawn,
+        ));
+    }
+}
@@ -0,0 +1,571 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Quick Sort (Hoare Partition)";
+const BG_COLOR: Color = Color::srgb(0.02, 0.04, 0.07);
+const BAR_WIDTH: f32 = 50.0;
+const BAR_GAP: f32 = 10.0;
+const BASE_Y: f32 = -220.0;
+const MAX_HEIGHT: f32 = 400.0;
+const STEP_INTERVAL: f32 = 0.8;
+// Heights are 40 + 25 per unit, so values above 14 would overflow MAX_HEIGHT;
+// 14 bars fill the fixed-size window.
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 12,
+    max_n: 14,
+    max_value: 14,
+    dense: true,
+};
+
+// Educational text components
+#[derive(Component)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct StepExplanation;
+
+#[derive(Component)]
+struct ExplanationText;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct Bar {
+    index: usize,
+    value: usize,
+    original_index: usize,
+}
+
+#[derive(Component)]
+struct PivotIndicator;
+
+#[derive(Component)]
+struct LeftPointer;
+
+#[derive(Component)]
+struct RightPointer;
+
+#[derive(Resource, Clone)]
+struct SortState {
+    array: Vec<usize>,
+    stack: Vec<(usize, usize)>, // (low, high) ranges to sort
+    current_low: usize,
+    current_high: usize,
+    left: usize,
+    right: usize,
+    pivot: usize,
+    pivot_index: usize,
+    phase: SortPhase,
+    counts: OpCounts,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum SortPhase {
+    Setup,
+    Partitioning,
+    Swapping,
+    Complete,
+}
+
+#[derive(Resource, Clone)]
+struct Layout {
+    origin_x: f32,
+    n: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "quicksort-hoare";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let mut input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    let array = input.permutation();
+    let n = array.len();
+
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(input)
+        .resource(Layout {
+            origin_x: -(n as f32 * (BAR_WIDTH + BAR_GAP) - BAR_GAP) / 2.0 + BAR_WIDTH / 2.0,
+            n,
+        })
+        .resource(SortState::new(array))
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (update_bars, update_pointers, update_educational_text).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, layout: Res<Layout>, state: Res<SortState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn bars
+    for i in 0..layout.n {
+        let value = state.array[i];
+        let height = 40.0 + value as f32 * 25.0;
+        let x = layout.origin_x + i as f32 * (BAR_WIDTH + BAR_GAP);
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.25, 0.55, 0.95, 0.8),
+                    custom_size: Some(Vec2::new(BAR_WIDTH, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, height / 2.0 + BASE_Y, 0.0),
+                ..default()
+            },
+            Bar {
+                index: i,
+                value,
+                original_index: i,
+            },
+        ));
+    }
+
+    // Pivot indicator
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(1.0, 0.8, 0.0),
+                custom_size: Some(Vec2::new(BAR_WIDTH + 10.0, 10.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, BASE_Y + MAX_HEIGHT + 30.0, 1.0),
+            ..default()
+        },
+        PivotIndicator,
+    ));
+
+    // Left pointer
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(0.0, 1.0, 0.0),
+                custom_size: Some(Vec2::new(15.0, 15.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, BASE_Y + MAX_HEIGHT + 60.0, 2.0),
+            ..default()
+        },
+        LeftPointer,
+    ));
+
+    // Right pointer
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(1.0, 0.0, 0.0),
+                custom_size: Some(Vec2::new(15.0, 15.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, BASE_Y + MAX_HEIGHT + 60.0, 2.0),
+            ..default()
+        },
+        RightPointer,
+    ));
+
+    // Educational Text Overlays
+    // Algorithm title
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 28.0,
+                color: Color::srgb(0.0, 1.0, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        AlgorithmTitle,
+        Localized::new("quicksort-hoare.title"),
+    ));
+
+    // Progress text
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(45.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        ProgressText,
+    ));
+
+    // Step explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(0.8, 0.8, 0.8),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        StepExplanation,
+        Narration,
+    ));
+
+    // Algorithm explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 12.0,
+                color: Color::srgb(0.7, 0.9, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        ExplanationText,
+        Localized::new("quicksort-hoare.blurb"),
+    ));
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut input: ResMut<ArrayInput>,
+) {
+    if reset.read().count() > 0 {
+        // Reset the sort (a fixed --input array comes back unchanged)
+        *state = SortState::new(input.permutation());
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if settings.step_ready() {
+        state.advance();
+    }
+}
+
+impl SortState {
+    fn new(array: Vec<usize>) -> Self {
+        let n = array.len();
+        Self {
+            stack: vec![(0, n - 1)],
+            current_low: 0,
+            current_high: n - 1,
+            left: 0,
+            right: n - 1,
+            pivot: array[n / 2],
+            pivot_index: n / 2,
+            phase: SortPhase::Setup,
+            counts: OpCounts::default(),
+            array,
+        }
+    }
+
+    /// One phase transition: pick the next range and its pivot, run both
+    /// pointer scans and swap, or split the range once the pointers meet.
+    fn advance(&mut self) {
+        match self.phase {
+            SortPhase::Setup => {
+                // Start partitioning the current range
+                if let Some((low, high)) = self.stack.last().cloned() {
+                    self.current_low = low;
+                    self.current_high = high;
+                    self.left = low;
+                    self.right = high;
+                    self.pivot_index = (low + high) / 2;
+                    self.pivot = self.array[self.pivot_index];
+                    self.phase = SortPhase::Partitioning;
+                    // the pivot is held while the pointers scan; the ranges wait on the stack
+                    let pending = self.stack.len();
+                    self.counts.read(1);
+                    self.counts.hold(pending);
+                } else {
+                    self.phase = SortPhase::Complete;
+                }
+            }
+            SortPhase::Partitioning => {
+                // Hoare partition: move left pointer right until >= pivot
+                while self.left < self.right && {
+                    self.counts.compare_held();
+                    self.array[self.left] < self.pivot
+                } {
+                    self.left += 1;
+                }
+
+                // Move right pointer left until <= pivot
+                while self.left < self.right && {
+                    self.counts.compare_held();
+                    self.array[self.right] > self.pivot
+                } {
+                    self.right -= 1;
+                }
+
+                // If pointers haven't crossed, swap and continue
+                if self.left < self.right {
+                    // Swap elements
+                    let left = self.left;
+                    let right = self.right;
+                    self.array.swap(left, right);
+                    self.counts.swap();
+                    self.phase = SortPhase::Swapping;
+                } else {
+                    // Partition complete, recurse on subarrays
+                    let right = self.right;
+                    if let Some((low, high)) = self.stack.pop() {
+                        // Push right partition if it exists
+                        if right + 1 < high {
+                            self.stack.push((right + 1, high));
+                        }
+                        // Push left partition if it exists
+                        if low < right {
+                            self.stack.push((low, right));
+                        }
+                    }
+                    self.phase = SortPhase::Setup;
+                }
+            }
+            SortPhase::Swapping => {
+                // Just transition back to partitioning after swap animation
+                self.phase = SortPhase::Partitioning;
+            }
+            SortPhase::Complete => {}
+        }
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    Box::new(SortState::new(array))
+}
+
+impl Racer for SortState {
+    fn step(&mut self) {
+        self.advance();
+    }
+
+    fn done(&self) -> bool {
+        self.phase == SortPhase::Complete
+    }
+
+    fn array(&self) -> &[usize] {
+        &self.array
+    }
+
+    fn counts(&self) -> OpCounts {
+        self.counts
+    }
+
+    fn focus(&self) -> Vec<usize> {
+        match self.phase {
+            SortPhase::Partitioning | SortPhase::Swapping => vec![self.left, self.right],
+            SortPhase::Setup | SortPhase::Complete => Vec::new(),
+        }
+    }
+}
+
+fn update_bars(
+    mut bars: Query<(&mut Sprite, &mut Transform, &mut Bar)>,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, mut transform, mut bar) in bars.iter_mut() {
+        // Each bar stays on its slot and shows the value the array holds there,
+        // so repeated values need no telling apart
+        let Some(&value) = state.array.get(bar.index) else {
+            continue;
+        };
+        bar.value = value;
+        let x = layout.origin_x + bar.index as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+
+        // Update height based on value
+        let height = 40.0 + bar.value as f32 * 25.0;
+        transform.translation.y = height / 2.0 + BASE_Y;
+        sprite.custom_size = Some(Vec2::new(BAR_WIDTH, height));
+
+        // Color coding
+        if bar.index == state.pivot_index {
+            sprite.color = palette.compare; // Orange for pivot
+        } else if bar.index == state.left {
+            sprite.color = palette.settled; // Green for left pointer
+        } else if bar.index == state.right {
+            sprite.color = palette.violation; // Red for right pointer
+        } else if bar.index >= state.current_low && bar.index <= state.current_high {
+            sprite.color = palette.visited; // Blue for current partition
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5); // Gray for sorted regions
+        }
+    }
+}
+
+fn update_pointers(
+    mut pivot: Query<
+        &mut Transform,
+        (
+            With<PivotIndicator>,
+            Without<LeftPointer>,
+            Without<RightPointer>,
+        ),
+    >,
+    mut left_ptr: Query<
+        &mut Transform,
+        (
+            With<LeftPointer>,
+            Without<PivotIndicator>,
+            Without<RightPointer>,
+        ),
+    >,
+    mut right_ptr: Query<
+        &mut Transform,
+        (
+            With<RightPointer>,
+            Without<PivotIndicator>,
+            Without<LeftPointer>,
+        ),
+    >,
+    layout: Res<Layout>,
+    state: Res<SortState>,
+) {
+    // Update pivot indicator
+    if let Ok(mut transform) = pivot.get_single_mut() {
+        let x = layout.origin_x + state.pivot_index as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+    }
+
+    // Update left pointer
+    if let Ok(mut transform) = left_ptr.get_single_mut() {
+        let x = layout.origin_x + state.left as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+    }
+
+    // Update right pointer
+    if let Ok(mut transform) = right_ptr.get_single_mut() {
+        let x = layout.origin_x + state.right as f32 * (BAR_WIDTH + BAR_GAP);
+        transform.translation.x = x;
+    }
+}
+
+fn update_educational_text(
+    state: Res<SortState>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    mut text_params: ParamSet<(
+        Query<&mut Text, With<ProgressText>>,
+        Query<&mut Text, With<StepExplanation>>,
+    )>,
+) {
+    // Update progress text
+    if let Ok(mut progress_text) = text_params.p0().get_single_mut() {
+        let phase = match state.phase {
+            SortPhase::Setup => "quicksort-hoare.phase.setup",
+            SortPhase::Partitioning => "quicksort-hoare.phase.partitioning",
+            SortPhase::Swapping => "quicksort-hoare.phase.swapping",
+            SortPhase::Complete => "quicksort-hoare.phase.complete",
+        };
+        let status = if matches!(state.phase, SortPhase::Complete) {
+            "status.finished"
+        } else if settings.auto {
+            "status.running"
+        } else {
+            "status.paused"
+        };
+
+        progress_text.sections[0].value = tr!(
+            locale,
+            "quicksort-hoare.progress",
+            phase = locale.text(phase),
+            comparisons = state.counts.comparisons,
+            swaps = state.counts.swaps,
+            status = locale.text(status)
+        );
+    }
+
+    // Update step explanation
+    if let Ok(mut step_text) = text_params.p1().get_single_mut() {
+        let explanation = match state.phase {
+            SortPhase::Setup => {
+                tr!(
+                    locale,
+                    "quicksort-hoare.step.setup",
+                    low = state.current_low,
+                    high = state.current_high,
+                    pivot = state.pivot,
+                    pivot_index = state.pivot_index
+                )
+            }
+            SortPhase::Partitioning => {
+                tr!(
+                    locale,
+                    "quicksort-hoare.step.partition",
+                    left = state.left,
+                    left_value = state.array.get(state.left).unwrap_or(&0),
+                    right = state.right,
+                    right_value = state.array.get(state.right).unwrap_or(&0),
+                    pivot = state.pivot
+                )
+            }
+            SortPhase::Swapping => {
+                tr!(
+                    locale,
+                    "quicksort-hoare.step.swap",
+                    left = state.left,
+                    right = state.right,
+                    right_value = state.array.get(state.right).unwrap_or(&0),
+                    left_value = state.array.get(state.left).unwrap_or(&0)
+                )
+            }
+            SortPhase::Complete => {
+                tr!(
+                    locale,
+                    "quicksort-hoare.step.done",
+                    array = format!("{:?}", state.array),
+                    comparisons = state.counts.comparisons,
+                    swaps = state.counts.swaps
+                )
+            }
+        };
+
+        step_text.sections[0].value = explanation;
+    }
+}
@@ -0,0 +1,504 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 16,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 40.0;
+const BAR_GAP: f32 = 8.0;
+const ROW_WIDTH: f32 = 880.0;
+const MAX_HEIGHT: f32 = 300.0;
+const STEP_INTERVAL: f32 = 0.75;
+// the stack of ranges stands in for the recursion
+const PSEUDOCODE: Listing = Listing::new(
+    "code.pseudocode",
+    &[
+        ("", "procedure quicksort(a, lo, hi)"),
+        ("", "  if lo < hi"),
+        ("", "    pivot = a[hi]; i = lo"),
+        ("", "    for j = lo to hi - 1"),
+        ("compare", "      if a[j] <= pivot"),
+        ("swap", "        swap a[i], a[j]; i = i + 1"),
+        ("place", "    swap a[i], a[hi]"),
+        ("", "    quicksort(a, lo, i - 1)"),
+        ("", "    quicksort(a, i + 1, hi)"),
+    ],
+);
+const RUST: Listing = Listing::new(
+    "code.rust",
+    &[
+        ("", "fn quicksort(a: &mut [usize], lo: usize, hi: usize) {"),
+        ("", "    if lo >= hi { return; }"),
+        ("", "    let (pivot, mut i) = (a[hi], lo);"),
+        ("", "    for j in lo..hi {"),
+        ("compare", "        if a[j] <= pivot {"),
+        ("swap", "            a.swap(i, j); i += 1;"),
+        ("", "        }"),
+        ("", "    }"),
+        ("place", "    a.swap(i, hi);"),
+        ("", "    if i > lo { quicksort(a, lo, i - 1); }"),
+        ("", "    quicksort(a, i + 1, hi);"),
+        ("", "}"),
+    ],
+);
+
+#[derive(Component, Copy, Clone)]
+struct Bar {
+    idx: usize,
+    val: usize,
+}
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.idx
+    }
+    fn value(&self) -> usize {
+        self.val
+    }
+}
+#[derive(Component, Deref, DerefMut)]
+struct TargetX(f32);
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    w: f32,
+    top: usize,
+    labels: bool,
+}
+
+impl Layout {
+    // bars (and gaps) narrow to fit the row; heights and hues scale to the largest value;
+    // labels only where the digits fit over the bars
+    fn new(a: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(a.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = a.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            w: row.width,
+            top,
+            labels: row.labels(top),
+        }
+    }
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, a: &[usize]) {
+        self.top = a.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+    fn h(&self, v: usize) -> f32 {
+        v as f32 / self.top as f32 * MAX_HEIGHT + 10.0
+    }
+    fn color(&self, v: usize) -> Color {
+        Color::hsl((v as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+struct State {
+    // stack of ranges to sort: (lo, hi) inclusive bounds
+    stack: Vec<(usize, usize)>,
+    // current pointers for partition
+    lo: usize,
+    hi: usize,
+    i: usize,
+    j: usize,
+    // data
+    a: Vec<usize>,
+    done: bool,
+    active: bool,
+    counts: OpCounts,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "quicksort-lomuto";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(input)
+        .resource(State::default())
+        .cost_chart(|st: Res<State>| st.counts)
+        .bar_editor::<Bar>()
+        .quiz()
+        .code_panel(&[PSEUDOCODE, RUST])
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                animate.in_set(VizSet::Animate),
+                colors.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    st.a = input.permutation();
+    let layout = Layout::new(&st.a, row_width(ROW_WIDTH, &windows));
+    for (idx, v) in st.a.iter().copied().enumerate() {
+        let h = layout.h(v);
+        let x = x_at(idx, &layout);
+        let color = layout.color(v);
+        let id = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.w, h)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, h / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar { idx, val: v },
+                TargetX(x),
+            ))
+            .id();
+        if layout.labels {
+            commands
+                .entity(id)
+                .with_children(|p| spawn_value_digits(p, v, h / 2.0 + 12.0, Color::WHITE));
+        }
+    }
+    commands.insert_resource(layout);
+    let n = st.a.len();
+    st.stack.clear();
+    st.stack.push((0, n - 1));
+    st.done = false;
+    st.active = false;
+    st.lo = 0;
+    st.hi = n - 1;
+    st.i = 0;
+    st.j = 0;
+}
+
+fn x_at(i: usize, layout: &Layout) -> f32 {
+    layout.row.x(i)
+}
+
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    mut layout: ResMut<Layout>,
+    mut bars: Query<(
+        Entity,
+        &mut Bar,
+        &mut Sprite,
+        &mut Transform,
+        &mut TargetX,
+        Option<&Children>,
+    )>,
+    digits_q: Query<&ValueDigits>,
+    mut code: ResMut<CodeCursor>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    let restart = st.done && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        code.clear();
+        st.a = input.permutation();
+        layout.rescale(&st.a);
+        let n = st.a.len();
+        st.stack = vec![(0, n - 1)];
+        st.lo = 0;
+        st.hi = n - 1;
+        st.i = 0;
+        st.j = 0;
+        st.done = false;
+        st.active = false;
+        st.counts = OpCounts::default();
+        let mut raw: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        for (e, mut bar, mut sp, mut tf, mut tx, children) in bars.iter_mut() {
+            let idx = bar.idx;
+            let v = st.a[idx];
+            bar.val = v;
+            let h = layout.h(v);
+            sp.custom_size = Some(Vec2::new(layout.w, h));
+            sp.color = layout.color(v);
+            let x = x_at(idx, &layout);
+            tx.0 = x;
+            tf.translation = Vec3::new(x, h / 2.0 - 200.0, 0.0);
+            raw.push((
+                e,
+                children.map_or_else(Vec::new, |c| c.to_vec()),
+                v,
+                h / 2.0 + 12.0,
+            ));
+        }
+        for (p, children, v, y) in raw {
+            for c in children {
+                if digits_q.get(c).is_ok() {
+                    commands.entity(c).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands
+                    .entity(p)
+                    .with_children(|x| spawn_value_digits(x, v, y, palette.focus));
+            }
+        }
+    }
+}
+
+fn step(
+    mut st: ResMut<State>,
+    mut settings: ResMut<Settings>,
+    layout: Res<Layout>,
+    mut q: Query<(&mut Bar, &mut TargetX)>,
+    mut quiz: ResMut<Quiz>,
+    mut code: ResMut<CodeCursor>,
+    locale: Res<Locale>,
+) {
+    if st.done {
+        quiz.finish();
+        return;
+    }
+    if !settings.step_ready() {
+        return;
+    }
+    // in quiz mode the learner predicts which side of the pivot the scanned value joins
+    if let Some((j, hi)) = st.next_compare() {
+        if quiz.hold(|| side_question(st.a[j], st.a[hi], &locale)) {
+            return;
+        }
+    }
+
+    // a step compares against the pivot unless it places the pivot or finds nothing left
+    let compare = st.next_compare();
+    let swapped = st.advance();
+    match compare {
+        Some((j, hi)) => {
+            code.at(if swapped.is_some() { "swap" } else { "compare" })
+                .var("lo", st.lo)
+                .var("hi", hi)
+                .var("i", st.i)
+                .var("j", j)
+                .var("pivot", st.a[hi]);
+        }
+        None if st.done => code.clear(),
+        None => {
+            let p = swapped.map_or(st.hi, |(p, _)| p);
+            code.at("place")
+                .var("lo", st.lo)
+                .var("hi", st.hi)
+                .var("i", p)
+                .var("pivot", st.a[p])
+                .var("stack", st.stack.len());
+        }
+    }
+
+    // retarget bars for a swapped pair (swap their indices and update values)
+    if let Some((i0, j0)) = swapped {
+        for (mut bar, mut tx) in q.iter_mut() {
+            if bar.idx == i0 {
+                bar.idx = j0;
+                bar.val = st.a[j0];
+                tx.0 = x_at(j0, &layout);
+            } else if bar.idx == j0 {
+                bar.idx = i0;
+                bar.val = st.a[i0];
+                tx.0 = x_at(i0, &layout);
+            }
+        }
+    }
+
+    if !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+/// Quiz mode's question before comparing `value` with `pivot`: values up to the pivot join the left part.
+fn side_question(value: usize, pivot: usize, locale: &Locale) -> Question {
+    let choices = [
+        locale.text("quicksort-lomuto.quiz.left"),
+        locale.text("quicksort-lomuto.quiz.right"),
+    ];
+    Question::new(
+        tr!(
+            locale,
+            "quicksort-lomuto.quiz.prompt",
+            pivot = pivot,
+            value = value
+        ),
+        choices,
+        usize::from(value > pivot),
+    )
+}
+
+impl State {
+    /// The position the next `advance` compares with the pivot, and the pivot's.
+    fn next_compare(&self) -> Option<(usize, usize)> {
+        if self.active {
+            return (self.j < self.hi).then_some((self.j, self.hi));
+        }
+        // the range `advance` will start: the first one off the stack that needs partitioning
+        self.stack.iter().rev().copied().find(|&(lo, hi)| lo < hi)
+    }
+
+    /// One comparison against the pivot, or placing the pivot once the scan
+    /// ends; starts the next range off the stack first if none is active.
+    /// Returns the pair of positions it swapped.
+    fn advance(&mut self) -> Option<(usize, usize)> {
+        // Initialize a partition if needed
+        if !self.active {
+            while let Some((lo, hi)) = self.stack.pop() {
+                if lo < hi {
+                    self.lo = lo;
+                    self.hi = hi;
+                    self.i = lo;
+                    self.j = lo;
+                    self.active = true;
+                    self.counts.read(1); // the pivot, held while scanning
+                    break;
+                }
+            }
+            if !self.active {
+                self.done = true;
+                return None;
+            }
+        }
+
+        let (lo, hi) = (self.lo, self.hi);
+        let pivot = self.a[hi];
+
+        // If finished scan, place pivot
+        if self.j >= hi {
+            // swap a[i] and a[hi]
+            let p = self.i;
+            let swapped = (p != hi).then(|| {
+                self.a.swap(p, hi);
+                self.counts.swap();
+                (p, hi)
+            });
+            // push subranges
+            if p > lo {
+                self.stack.push((lo, p - 1));
+            }
+            if p < hi {
+                self.stack.push((p + 1, hi));
+            }
+            let pending = self.stack.len();
+            self.counts.hold(pending);
+            self.i = 0;
+            self.j = 0;
+            self.active = false;
+            return swapped;
+        }
+
+        // Scan step
+        self.counts.compare_held();
+        let (i0, j0) = (self.i, self.j);
+        self.j += 1;
+        if self.a[j0] > pivot {
+            return None;
+        }
+        self.a.swap(i0, j0);
+        self.counts.swap();
+        self.i += 1;
+        Some((i0, j0))
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    let stack = vec![(0, array.len().saturating_sub(1))];
+    Box::new(Race {
+        state: State {
+            stack,
+            a: array,
+            ..default()
+        },
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    state: State,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        let st = &mut self.state;
+        self.focus = match st.advance() {
+            Some((a, b)) => vec![a, b],
+            // the element just compared and the pivot
+            None if st.active => vec![st.j - 1, st.hi],
+            None => Vec::new(),
+        };
+    }
+    fn done(&self) -> bool {
+        self.state.done
+    }
+    fn array(&self) -> &[usize] {
+        &self.state.a
+    }
+    fn counts(&self) -> OpCounts {
+        self.state.counts
+    }
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+// Move all bars smoothly toward their target x positions
+fn animate(time: Res<Time>, mut q: Query<(&TargetX, &mut Transform)>) {
+    let speed = 600.0 * time.delta_seconds();
+    for (tx, mut tf) in q.iter_mut() {
+        let dx = tx.0 - tf.translation.x;
+        if dx.abs() <= speed {
+            tf.translation.x = tx.0;
+        } else {
+            tf.translation.x += speed * dx.signum();
+        }
+    }
+}
+
+fn colors(
+    st: Res<State>,
+    layout: Res<Layout>,
+    mut q: Query<(&Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (bar, mut sp) in q.iter_mut() {
+        let base = layout.color(bar.val);
+        let mut color = base;
+        if st.done {
+            color = palette.settled;
+        } else if st.active {
+            if bar.idx == st.hi {
+                color = palette.compare; // pivot at hi
+            } else if bar.idx == st.i {
+                color = palette.violation; // partition boundary
+            } else if bar.idx == st.j && st.j < st.hi {
+                color = palette.visited; // current scan
+            } else if bar.idx < st.lo || bar.idx > st.hi {
+                color = Color::srgb(0.35, 0.35, 0.35);
+            }
+        }
+        sp.color = color;
+    }
+}
@@ -0,0 +1,386 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use rand::Rng;
+
+pub const TITLE: &str = "Radix Sort (LSD)";
+const BG_COLOR: Color = Color::srgb(0.02, 0.05, 0.08);
+const STEP_INTERVAL: f32 = 0.8;
+// Three digits give the three passes; 22 bars fill the fixed-size window.
+const MAX_VALUE: usize = 999;
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 18,
+    max_n: 22,
+    max_value: MAX_VALUE,
+    dense: false,
+};
+
+#[derive(Component)]
+struct ArrayBar {
+    index: usize,
+    value: usize,
+}
+
+#[derive(Component)]
+struct BucketLabel;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct BucketCell {
+    pass: usize,
+    digit: usize,
+}
+
+#[derive(Component)]
+struct EducationalText;
+
+#[derive(Resource)]
+struct SortState {
+    array: Vec<usize>,
+    buckets: Vec<Vec<Vec<usize>>>, // buckets[pass][digit] = list of values
+    current_pass: usize,
+    max_digits: usize,
+    phase: SortPhase,
+    current_index: usize,
+    counts: OpCounts,
+}
+
+#[derive(PartialEq, Eq)]
+enum SortPhase {
+    DistributeElements,
+    CollectElements,
+    Complete,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "radix-lsd";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(input)
+        .setup(setup)
+        .cost_chart(|state: Res<SortState>| state.counts)
+        .systems(
+            Update,
+            (
+                input_system.in_set(VizSet::Input),
+                step_sort.in_set(VizSet::Step),
+                (update_visuals, update_educational_text).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands, mut input: ResMut<ArrayInput>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let array = new_array(&mut input);
+    let max_digits = digits(&array);
+
+    // Initialize buckets for each pass and digit
+    let buckets = vec![vec![Vec::new(); 10]; max_digits];
+
+    commands.insert_resource(SortState {
+        array: array.clone(),
+        buckets,
+        current_pass: 0,
+        max_digits,
+        phase: SortPhase::DistributeElements,
+        current_index: 0,
+        counts: OpCounts::default(),
+    });
+
+    spawn_array(&mut commands, &array);
+    spawn_buckets(&mut commands, max_digits);
+    spawn_educational_text(&mut commands);
+
+    info!("Radix Sort (LSD) visualization ready!");
+}
+
+// Random draws 2-3 digit numbers; the other presets spread over 10..=MAX_VALUE
+fn new_array(input: &mut ArrayInput) -> Vec<usize> {
+    if input.distribution == Distribution::Random {
+        input.array_or(|n, rng| (0..n).map(|_| rng.gen_range(10..=MAX_VALUE)).collect())
+    } else {
+        input.preset(10, MAX_VALUE)
+    }
+}
+
+// One pass per digit of the largest value
+fn digits(array: &[usize]) -> usize {
+    array.iter().max().map_or(1, |max| max.to_string().len())
+}
+
+fn spawn_array(commands: &mut Commands, array: &[usize]) {
+    let array_len = array.len();
+    let bar_width = 32.0;
+    let bar_gap = 10.0;
+    let max_height = 200.0;
+    let origin_x = -(array_len as f32 * (bar_width + bar_gap) - bar_gap) / 2.0 + bar_width / 2.0;
+
+    for (i, &value) in array.iter().enumerate() {
+        let height = 30.0 + (value as f32 / 1000.0) * max_height; // Scale based on value
+        let x = origin_x + i as f32 * (bar_width + bar_gap);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.35, 0.7, 0.95, 0.65),
+                    custom_size: Some(Vec2::new(bar_width, height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, height / 2.0 - 240.0, 0.0),
+                ..default()
+            },
+            ArrayBar { index: i, value },
+        ));
+    }
+}
+
+fn spawn_buckets(commands: &mut Commands, max_digits: usize) {
+    let digits = 10;
+    let cell_size = Vec2::new(64.0, 64.0);
+    let x_gap = 16.0;
+    let y_gap = 90.0;
+    let start_y = 90.0;
+
+    let total_width = digits as f32 * (cell_size.x + x_gap) - x_gap;
+    let origin_x = -total_width / 2.0 + cell_size.x / 2.0;
+
+    for pass in 0..max_digits {
+        let y = start_y + pass as f32 * (cell_size.y + y_gap);
+
+        for digit in 0..digits {
+            let x = origin_x + digit as f32 * (cell_size.x + x_gap);
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgba(0.2 + pass as f32 * 0.1, 0.45, 0.85, 0.45),
+                        custom_size: Some(cell_size),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y, 0.0),
+                    ..default()
+                },
+                BucketCell { pass, digit },
+            ));
+
+            commands.spawn((
+                SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        format!("{digit}"),
+                        SegmentStyle {
+                            font_size: 24.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                    text_anchor: Anchor::TopCenter,
+                    transform: Transform::from_xyz(x, y - cell_size.y / 2.0 - 20.0, 0.1),
+                    ..default()
+                },
+                BucketLabel,
+            ));
+        }
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    "",
+                    SegmentStyle {
+                        font_size: 28.0,
+                        color: Color::srgba(0.95, 0.85, 0.4, 1.0),
+                    },
+                ),
+                text_anchor: Anchor::CenterLeft,
+                transform: Transform::from_xyz(origin_x - cell_size.x, y, 0.1),
+                ..default()
+            },
+            BucketLabel,
+            Localized::new("radix-lsd.pass")
+                .with("pass", pass + 1)
+                .with("power", pass),
+        ));
+    }
+}
+
+fn spawn_educational_text(commands: &mut Commands) {
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "",
+                SegmentStyle {
+                    font_size: 32.0,
+                    color: Color::srgba(0.95, 0.85, 0.4, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 320.0, 0.0),
+            ..default()
+        },
+        EducationalText,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "",
+                SegmentStyle {
+                    font_size: 20.0,
+                    color: Color::srgba(0.8, 0.8, 0.8, 0.8),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, 290.0, 0.0),
+            ..default()
+        },
+        EducationalText,
+    ));
+}
+
+fn input_system(
+    mut reset: EventReader<ResetRequested>,
+    mut state: ResMut<SortState>,
+    mut input: ResMut<ArrayInput>,
+) {
+    if reset.read().count() > 0 {
+        // Restart with a new array from the preset (a fixed --input array comes back unchanged)
+        state.array = new_array(&mut input);
+        let max_digits = digits(&state.array);
+        state.buckets = vec![vec![Vec::new(); 10]; max_digits];
+        state.current_pass = 0;
+        state.max_digits = max_digits;
+        state.phase = SortPhase::DistributeElements;
+        state.current_index = 0;
+        state.counts = OpCounts::default();
+    }
+}
+
+fn step_sort(mut state: ResMut<SortState>, settings: Res<Settings>) {
+    if !settings.step_ready() {
+        return;
+    }
+
+    match state.phase {
+        SortPhase::DistributeElements => {
+            if state.current_index < state.array.len() {
+                let value = state.array[state.current_index];
+                let current_pass = state.current_pass;
+                let digit = get_digit(value, current_pass);
+                state.buckets[current_pass][digit].push(value);
+                let held = state.current_index + 1;
+                state.counts.read(1);
+                state.counts.hold(held);
+                state.current_index += 1;
+            } else {
+                // Distribution complete, move to collection
+                state.current_index = 0;
+                state.phase = SortPhase::CollectElements;
+            }
+        }
+        SortPhase::CollectElements => {
+            let current_pass = state.current_pass;
+            let mut new_array = Vec::new();
+            for digit in 0..10 {
+                new_array.extend(&state.buckets[current_pass][digit]);
+            }
+            state.array = new_array;
+            let collected = state.array.len();
+            state.counts.write(collected);
+
+            // Clear buckets for next pass
+            for digit in 0..10 {
+                state.buckets[current_pass][digit].clear();
+            }
+
+            // Move to next pass or complete
+            state.current_pass += 1;
+            state.current_index = 0;
+            if state.current_pass >= state.max_digits {
+                state.phase = SortPhase::Complete;
+            } else {
+                state.phase = SortPhase::DistributeElements;
+            }
+        }
+        SortPhase::Complete => {}
+    }
+}
+
+fn get_digit(value: usize, pass: usize) -> usize {
+    (value / 10_usize.pow(pass as u32)) % 10
+}
+
+fn update_visuals(
+    state: Res<SortState>,
+    mut bars: Query<(&mut ArrayBar, &mut Sprite, &mut Transform)>,
+    palette: Res<Palette>,
+) {
+    let array_len = state.array.len();
+    let bar_width = 32.0;
+    let bar_gap = 10.0;
+    let max_height = 200.0;
+    let origin_x = -(array_len as f32 * (bar_width + bar_gap) - bar_gap) / 2.0 + bar_width / 2.0;
+
+    for (mut bar, mut sprite, mut transform) in bars.iter_mut() {
+        if bar.index < state.array.len() {
+            bar.value = state.array[bar.index];
+            let height = 30.0 + (bar.value as f32 / 1000.0) * max_height;
+            let x = origin_x + bar.index as f32 * (bar_width + bar_gap);
+
+            sprite.custom_size = Some(Vec2::new(bar_width, height));
+            transform.translation.x = x;
+            transform.translation.y = height / 2.0 - 240.0;
+
+            // Color based on current phase
+            let color = match state.phase {
+                SortPhase::DistributeElements => palette.visited.with_alpha(0.65),
+                SortPhase::CollectElements => palette.compare.with_alpha(0.65),
+                SortPhase::Complete => palette.settled.with_alpha(0.65),
+            };
+            sprite.color = color;
+        }
+    }
+}
+
+fn update_educational_text(
+    state: Res<SortState>,
+    locale: Res<Locale>,
+    mut texts: Query<&mut SegmentText, With<EducationalText>>,
+) {
+    let locale = locale.for_segment_text();
+    let mut text_iter = texts.iter_mut();
+    if let Some(mut title_text) = text_iter.next() {
+        // Update title with current status
+        let status = match state.phase {
+            SortPhase::DistributeElements => tr!(
+                locale,
+                "radix-lsd.status.distributing",
+                pass = state.current_pass + 1,
+                power = state.current_pass
+            ),
+            SortPhase::CollectElements => tr!(
+                locale,
+                "radix-lsd.status.collecting",
+                pass = state.current_pass,
+                power = state.current_pass - 1
+            ),
+            SortPhase::Complete => locale.text("status.complete"),
+        };
+        title_text.sections[0].value = tr!(locale, "radix-lsd.title", status = status);
+    }
+
+    if let Some(mut controls_text) = text_iter.next() {
+        let phase = match state.phase {
+            SortPhase::DistributeElements => "radix-lsd.phase.distribute",
+            SortPhase::CollectElements => "radix-lsd.phase.collect",
+            SortPhase::Complete => "radix-lsd.phase.complete",
+        };
+        controls_text.sections[0].value = tr!(
+            locale,
+            "radix-lsd.progress",
+            reads = state.counts.reads,
+            writes = state.counts.writes,
+            pass = state.current_pass + 1,
+            phase = locale.text(phase),
+        );
+    }
+}
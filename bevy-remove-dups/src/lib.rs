@@ -0,0 +1,130 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Remove Duplicates (Sorted Array)";
+const BG_COLOR: Color = Color::srgb(0.035, 0.05, 0.08);
+
+#[derive(Component)]
+struct InputTile;
+
+#[derive(Component)]
+struct UniqueHighlight;
+
+#[derive(Component)]
+struct PointerLabel;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "remove-dups";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_input_tiles(&mut commands, &palette);
+    spawn_unique_highlight(&mut commands, &palette);
+    spawn_pointers(&mut commands, &palette);
+
+    info!("Remove Duplicates scaffold ready. Add pointer advancement and overwriting animations next.");
+}
+
+fn spawn_input_tiles(commands: &mut Commands, palette: &Palette) {
+    let values = [1, 1, 1, 2, 2, 3, 4, 4, 5, 6];
+    let width = 72.0;
+    let gap = 12.0;
+    let origin_x = -(values.len() as f32 * (width + gap) - gap) / 2.0 + width / 2.0;
+
+    for (i, &value) in values.iter().enumerate() {
+        let x = origin_x + i as f32 * (width + gap);
+        let color = if i < 6 {
+            palette.visited.with_alpha(0.8)
+        } else {
+            Color::srgba(0.3, 0.35, 0.4, 0.6)
+        };
+
+        let mut tile = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(width, 120.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -20.0, 0.0),
+                ..default()
+            },
+            InputTile,
+        ));
+        if i < 6 {
+            tile.insert(Highlight::Visited);
+        }
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 36.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, 40.0, 0.1),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        });
+    }
+}
+
+fn spawn_unique_highlight(commands: &mut Commands, palette: &Palette) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: palette.compare.with_alpha(0.35),
+                custom_size: Some(Vec2::new(6.0 * 84.0, 150.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(-84.0, -20.0, -0.2),
+            ..default()
+        },
+        Highlight::Compare,
+        UniqueHighlight,
+    ));
+}
+
+fn spawn_pointers(commands: &mut Commands, palette: &Palette) {
+    let pointers = [("read", 216.0), ("write", -168.0)];
+
+    for &(label, x) in &pointers {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.8),
+                    custom_size: Some(Vec2::new(14.0, 180.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, 120.0, 0.2),
+                ..default()
+            },
+            Highlight::Visited,
+            PointerLabel,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    label,
+                    SegmentStyle {
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                transform: Transform::from_xyz(x, 200.0, 0.3),
+                text_anchor: Anchor::TopCenter,
+                ..default()
+            },
+            PointerLabel,
+        ));
+    }
+}
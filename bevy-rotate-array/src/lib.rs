@@ -0,0 +1,489 @@
+use algoviz_core::prelude::*;
+use bevy::math::primitives::{Cuboid, Plane3d};
+use bevy::prelude::*;
+use bevy::render::camera::ClearColorConfig;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 12,
+    max_n: 24,
+    max_value: 99,
+    dense: false,
+};
+const ROT_BY: usize = 4; // rotate right by ROT_BY positions (mod n)
+const BAR_WIDTH: f32 = 42.0;
+const BAR_DEPTH: f32 = 24.0;
+const BAR_GAP: f32 = 16.0;
+const MAX_HEIGHT: f32 = 280.0;
+const STEP_INTERVAL: f32 = 0.7;
+const ANIM_SPEED: f32 = 600.0;
+const SWAP_ARROW_HEIGHT: f32 = MAX_HEIGHT + 70.0;
+const ROTATION_ARROW_HEIGHT: f32 = MAX_HEIGHT + 140.0;
+
+#[derive(Component)]
+struct Bar {
+    index: usize,
+    value: usize,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    base_color: Color,
+}
+
+#[derive(Component, Deref, DerefMut)]
+struct TargetPos(Vec3);
+
+#[derive(Resource, Clone)]
+struct Layout {
+    center: Vec3,
+    radius: f32,
+    start_angle: f32,
+    angle_step: f32,
+    top: usize, // value drawn at MAX_HEIGHT
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Whole,
+    Prefix,
+    Suffix,
+}
+
+#[derive(Clone, Copy)]
+struct Op {
+    a: usize,
+    b: usize,
+    stage: Stage,
+}
+
+#[derive(Resource, Clone)]
+struct State {
+    array: Vec<usize>,
+    rot_by: usize,
+    ops: Vec<Op>,
+    cursor: usize,
+    done: bool,
+    stage: Stage,
+    active_pair: Option<(usize, usize)>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            array: Vec::new(),
+            rot_by: 0,
+            ops: Vec::new(),
+            cursor: 0,
+            done: false,
+            stage: Stage::Whole,
+            active_pair: None,
+        }
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "rotate-array";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(input)
+        .resource(State::default())
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                animate.in_set(VizSet::Animate),
+                (colors, draw_arrows).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+) {
+    st.array = input.permutation();
+    let n = st.array.len();
+
+    commands.insert_resource(AmbientLight {
+        color: Color::WHITE,
+        brightness: 450.0,
+    });
+
+    commands.spawn(DirectionalLightBundle {
+        directional_light: DirectionalLight {
+            shadows_enabled: true,
+            illuminance: 12000.0,
+            ..default()
+        },
+        transform: Transform::from_xyz(220.0, 460.0, 180.0)
+            .looking_at(Vec3::new(0.0, 140.0, 0.0), Vec3::Y),
+        ..default()
+    });
+
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 320.0, 720.0)
+            .looking_at(Vec3::new(0.0, 160.0, 0.0), Vec3::Y),
+        ..default()
+    });
+
+    commands.spawn(Camera2dBundle {
+        camera: Camera {
+            order: 1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        ..default()
+    });
+
+    let plane_mesh = meshes.add(
+        Plane3d::default()
+            .mesh()
+            .size((n as f32 + 6.0) * (BAR_WIDTH + BAR_GAP), 800.0),
+    );
+    let plane_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.08, 0.08, 0.11),
+        perceptual_roughness: 1.0,
+        ..default()
+    });
+    commands.spawn(PbrBundle {
+        mesh: plane_mesh,
+        material: plane_material,
+        transform: Transform::from_xyz(0.0, -0.01, 0.0),
+        ..default()
+    });
+
+    st.rot_by = ROT_BY % n;
+    st.ops = build_ops(n, st.rot_by);
+    st.cursor = 0;
+    st.done = false;
+    st.active_pair = None;
+    st.stage = st.ops.first().map(|op| op.stage).unwrap_or(Stage::Whole);
+
+    let angle_span = PI * 1.25;
+    let angle_step = angle_span / (n as f32 - 1.0);
+    let radius = (BAR_WIDTH + BAR_GAP) / angle_step;
+    let layout = Layout {
+        center: Vec3::ZERO,
+        radius,
+        start_angle: -angle_span / 2.0,
+        angle_step,
+        top: st.array.iter().copied().max().unwrap_or(1).max(1),
+    };
+    let layout_for_spawn = layout.clone();
+    commands.insert_resource(layout);
+
+    for (index, value) in st.array.iter().copied().enumerate() {
+        let height = bar_height(value, &layout_for_spawn);
+        let pos = target_position(index, height, &layout_for_spawn);
+        let rotation = target_rotation(index, &layout_for_spawn);
+        let mesh = meshes.add(Cuboid::new(BAR_WIDTH, height, BAR_DEPTH));
+        let base_color = bar_color(value, &layout_for_spawn);
+        let material = materials.add(StandardMaterial {
+            base_color,
+            perceptual_roughness: 0.6,
+            metallic: 0.03,
+            ..default()
+        });
+
+        commands.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: material.clone(),
+                transform: Transform {
+                    translation: pos,
+                    rotation,
+                    ..default()
+                },
+                ..default()
+            },
+            Bar {
+                index,
+                value,
+                mesh,
+                material,
+                base_color,
+            },
+            TargetPos(pos),
+        ));
+    }
+}
+
+fn build_ops(n: usize, rot_by: usize) -> Vec<Op> {
+    let mut ops = Vec::new();
+
+    for offset in 0..(n / 2) {
+        let a = offset;
+        let b = n - 1 - offset;
+        if a >= b {
+            break;
+        }
+        ops.push(Op {
+            a,
+            b,
+            stage: Stage::Whole,
+        });
+    }
+
+    for offset in 0..(rot_by / 2) {
+        let a = offset;
+        let b = rot_by - 1 - offset;
+        if a >= b {
+            break;
+        }
+        ops.push(Op {
+            a,
+            b,
+            stage: Stage::Prefix,
+        });
+    }
+
+    let suffix = n - rot_by;
+    for offset in 0..(suffix / 2) {
+        let a = rot_by + offset;
+        let b = n - 1 - offset;
+        if a >= b {
+            break;
+        }
+        ops.push(Op {
+            a,
+            b,
+            stage: Stage::Suffix,
+        });
+    }
+
+    ops
+}
+
+fn bar_height(value: usize, layout: &Layout) -> f32 {
+    value as f32 / layout.top as f32 * MAX_HEIGHT + 20.0
+}
+
+fn bar_color(value: usize, layout: &Layout) -> Color {
+    Color::hsl((value as f32 / layout.top as f32) * 300.0, 0.7, 0.5)
+}
+
+fn stage_arrow_color(stage: Stage) -> Color {
+    match stage {
+        Stage::Whole => Color::srgb(0.95, 0.75, 0.2),
+        Stage::Prefix => Color::srgb(0.3, 0.8, 1.0),
+        Stage::Suffix => Color::srgb(0.9, 0.4, 0.85),
+    }
+}
+
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    layout: Res<Layout>,
+    mut bars: Query<(&mut Bar, &mut Transform, &mut TargetPos)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let restart = st.done && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        st.array = input.permutation();
+        reshuffle(&mut st, &layout, &mut bars, &mut meshes, &mut materials);
+    }
+}
+
+fn reshuffle(
+    st: &mut State,
+    layout: &Layout,
+    bars: &mut Query<(&mut Bar, &mut Transform, &mut TargetPos)>,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+) {
+    st.ops = build_ops(st.array.len(), st.rot_by);
+    st.cursor = 0;
+    st.done = false;
+    st.stage = st.ops.first().map(|op| op.stage).unwrap_or(Stage::Whole);
+    st.active_pair = None;
+
+    for (mut bar, mut transform, mut target) in bars.iter_mut() {
+        let idx = bar.index;
+        let value = st.array[idx];
+        bar.value = value;
+        bar.base_color = bar_color(value, layout);
+
+        let height = bar_height(value, layout);
+        if let Some(mesh) = meshes.get_mut(&bar.mesh) {
+            *mesh = Mesh::from(Cuboid::new(BAR_WIDTH, height, BAR_DEPTH));
+        }
+        if let Some(material) = materials.get_mut(&bar.material) {
+            material.base_color = bar.base_color;
+        }
+
+        let pos = target_position(idx, height, layout);
+        target.0 = pos;
+        transform.translation = pos;
+        transform.rotation = target_rotation(idx, layout);
+    }
+}
+
+fn step(
+    mut st: ResMut<State>,
+    mut settings: ResMut<Settings>,
+    layout: Res<Layout>,
+    mut bars: Query<(&mut Bar, &mut TargetPos, &mut Transform)>,
+) {
+    if st.done {
+        if !settings.auto {
+            settings.manual_step = false;
+        }
+        return;
+    }
+
+    if !settings.step_ready() {
+        return;
+    }
+
+    if st.cursor >= st.ops.len() {
+        st.done = true;
+        st.active_pair = None;
+        if !settings.auto {
+            settings.manual_step = false;
+        }
+        return;
+    }
+
+    let op = st.ops[st.cursor];
+    st.cursor += 1;
+    st.active_pair = Some((op.a, op.b));
+
+    let left_val = st.array[op.a];
+    let right_val = st.array[op.b];
+    st.array.swap(op.a, op.b);
+
+    for (mut bar, mut target, mut transform) in bars.iter_mut() {
+        if bar.index == op.a {
+            bar.index = op.b;
+            bar.value = left_val;
+        } else if bar.index == op.b {
+            bar.index = op.a;
+            bar.value = right_val;
+        } else {
+            continue;
+        }
+
+        bar.base_color = bar_color(bar.value, &layout);
+        let pos = target_position(bar.index, bar_height(bar.value, &layout), &layout);
+        target.0 = pos;
+        transform.rotation = target_rotation(bar.index, &layout);
+    }
+
+    st.stage = st
+        .ops
+        .get(st.cursor)
+        .map(|next| next.stage)
+        .unwrap_or(op.stage);
+
+    if st.cursor >= st.ops.len() {
+        st.done = true;
+        st.active_pair = None;
+    }
+
+    if !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+fn animate(time: Res<Time>, mut q: Query<(&TargetPos, &mut Transform)>) {
+    let step = ANIM_SPEED * time.delta_seconds();
+    for (target, mut transform) in q.iter_mut() {
+        let delta = target.0 - transform.translation;
+        let distance = delta.length();
+        if distance <= step || distance <= f32::EPSILON {
+            transform.translation = target.0;
+        } else {
+            transform.translation += delta.normalize() * step;
+        }
+    }
+}
+
+fn colors(
+    st: Res<State>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q: Query<&Bar>,
+    palette: Res<Palette>,
+) {
+    for bar in q.iter() {
+        if let Some(material) = materials.get_mut(&bar.material) {
+            let mut color = bar.base_color;
+
+            if st.done {
+                color = palette.settled;
+            } else {
+                let in_active_segment = match st.stage {
+                    Stage::Whole => true,
+                    Stage::Prefix => bar.index < st.rot_by,
+                    Stage::Suffix => bar.index >= st.rot_by,
+                };
+
+                if !in_active_segment {
+                    color = Color::srgba(0.45, 0.45, 0.45, 1.0);
+                }
+
+                // the pair just swapped, by slot, as values may repeat
+                if let Some((a, b)) = st.active_pair {
+                    if bar.index == a || bar.index == b {
+                        color = palette.focus;
+                    }
+                }
+            }
+
+            material.base_color = color;
+        }
+    }
+}
+
+fn draw_arrows(
+    st: Res<State>,
+    layout: Res<Layout>,
+    mut gizmos: SceneGizmos,
+    palette: Res<Palette>,
+) {
+    let rotation_color = palette.visited;
+    let n = st.array.len();
+    for i in (n - st.rot_by)..n {
+        let start = slot_flat(i, &layout) + Vec3::Y * ROTATION_ARROW_HEIGHT;
+        let end_index = (i + st.rot_by) % n;
+        let end = slot_flat(end_index, &layout) + Vec3::Y * ROTATION_ARROW_HEIGHT;
+        gizmos.arrow(start, end, rotation_color);
+    }
+
+    if let Some((a, b)) = st.active_pair {
+        let color = stage_arrow_color(st.stage);
+        let from = slot_flat(a, &layout) + Vec3::Y * SWAP_ARROW_HEIGHT;
+        let to = slot_flat(b, &layout) + Vec3::Y * SWAP_ARROW_HEIGHT;
+        gizmos.arrow(from, to, color);
+        gizmos.arrow(to, from, color.with_alpha(0.6));
+    }
+}
+
+fn slot_angle(index: usize, layout: &Layout) -> f32 {
+    layout.start_angle + index as f32 * layout.angle_step
+}
+
+fn slot_flat(index: usize, layout: &Layout) -> Vec3 {
+    let angle = slot_angle(index, layout);
+    layout.center + Vec3::new(angle.sin(), 0.0, angle.cos()) * layout.radius
+}
+
+fn target_position(index: usize, height: f32, layout: &Layout) -> Vec3 {
+    let base = slot_flat(index, layout);
+    Vec3::new(base.x, height / 2.0, base.z)
+}
+
+fn target_rotation(index: usize, layout: &Layout) -> Quat {
+    let angle = slot_angle(index, layout);
+    Quat::from_rotation_y(angle + FRAC_PI_2)
+}
@@ -0,0 +1,734 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 10,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 60.0; // widest bar; more elements narrow the bars (and gaps) to fit the row
+const BAR_GAP: f32 = 10.0;
+const ROW_WIDTH: f32 = 760.0;
+const MAX_HEIGHT: f32 = 300.0;
+const ANIM_SPEED: f32 = 420.0;
+const PRE_SWAP_DURATION: f32 = 0.35;
+const STEP_INTERVAL: f32 = 0.9; // slower cadence than bubble for clarity
+const DIGIT_COLOR: Color = algoviz_core::DIGIT_COLOR;
+
+// Educational text components
+#[derive(Component)]
+struct ExplanationText;
+
+#[derive(Component)]
+struct AlgorithmTitle;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct StepExplanation;
+
+#[derive(Component, Debug, Clone, Copy)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.index
+    }
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+#[derive(Component, Deref, DerefMut)]
+struct TargetX(f32);
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    top: usize,
+    labels: bool,
+}
+
+impl Layout {
+    fn new(array: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(array.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = array.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            top,
+            labels: row.labels(top),
+        }
+    }
+
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, array: &[usize]) {
+        self.top = array.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+
+    fn x(&self, i: usize) -> f32 {
+        self.row.x(i)
+    }
+
+    fn height(&self, value: usize) -> f32 {
+        value as f32 / self.top as f32 * MAX_HEIGHT + 10.0
+    }
+
+    fn color(&self, value: usize) -> Color {
+        Color::hsl((value as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+struct SelState {
+    i: usize,       // current boundary of sorted prefix
+    j: usize,       // scanning pointer in the unsorted suffix
+    min_idx: usize, // index of min in the current pass
+    array: Vec<usize>,
+    pre_swap: Option<(Entity, Entity, f32)>, // (emin, ei, remaining)
+    swap_pair: Option<(Entity, Entity)>,
+    pending_indices: Option<(usize, usize)>, // (min_idx, i)
+    sorted: bool,
+    restoring: bool, // bars sliding back to a restored history snapshot
+    counts: OpCounts,
+}
+
+/// What stepping back restores: the array order, where each bar stands (by entity, as values may
+/// repeat), the scan cursors, the running minimum and the counts.
+#[derive(Clone, PartialEq)]
+struct Snapshot {
+    array: Vec<usize>,
+    slots: Vec<(Entity, usize)>,
+    i: usize,
+    j: usize,
+    min_idx: usize,
+    sorted: bool,
+    counts: OpCounts,
+}
+
+impl SelState {
+    fn snapshot(&self, slots: Vec<(Entity, usize)>) -> Snapshot {
+        Snapshot {
+            array: self.array.clone(),
+            slots,
+            i: self.i,
+            j: self.j,
+            min_idx: self.min_idx,
+            sorted: self.sorted,
+            counts: self.counts,
+        }
+    }
+
+    fn idle(&self) -> bool {
+        self.pre_swap.is_none() && self.swap_pair.is_none() && !self.restoring
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "selection-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .history_scrubber::<Snapshot>()
+        .cost_chart(|st: Res<SelState>| st.counts)
+        .bar_editor::<Bar>()
+        .resource(input)
+        .resource(SelState::default())
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                handle_input.in_set(VizSet::Input),
+                (restore_snapshot, step_selection)
+                    .chain()
+                    .in_set(VizSet::Step),
+                (pre_swap_anim, animate_swaps, animate_restore)
+                    .chain()
+                    .in_set(VizSet::Animate),
+                (update_colors, update_educational_text, record_history).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut st: ResMut<SelState>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    // the --input array, or 1..=n shuffled
+    st.array = input.permutation();
+
+    // layout, sized to the window
+    let layout = Layout::new(&st.array, row_width(ROW_WIDTH, &windows));
+
+    // spawn bars
+    for (idx, value) in st.array.iter().copied().enumerate() {
+        let h = layout.height(value);
+        let x = layout.x(idx);
+        let color = layout.color(value);
+        let id = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.row.width, h)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, h / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar { index: idx, value },
+                TargetX(x),
+            ))
+            .id();
+        if layout.labels {
+            commands
+                .entity(id)
+                .with_children(|p| spawn_value_digits(p, value, h / 2.0 + 12.0, DIGIT_COLOR));
+        }
+    }
+    commands.insert_resource(layout);
+
+    // Educational Text Overlays
+    // Algorithm title
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 32.0,
+                color: Color::srgb(1.0, 1.0, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        AlgorithmTitle,
+        Localized::new("selection-sort.title"),
+    ));
+
+    // Progress information
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(50.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        ProgressText,
+    ));
+
+    // Step explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(1.0, 1.0, 0.8),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(80.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        StepExplanation,
+        Narration,
+    ));
+
+    // Algorithm explanation
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(0.7, 0.9, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            max_width: Val::Px(350.0),
+            ..default()
+        }),
+        ExplanationText,
+        Localized::new("selection-sort.blurb"),
+    ));
+}
+
+fn handle_input(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<SelState>,
+    mut history: ResMut<History<Snapshot>>,
+    mut layout: ResMut<Layout>,
+    mut input: ResMut<ArrayInput>,
+    mut bars: Query<(
+        Entity,
+        &mut Bar,
+        &mut Sprite,
+        &mut Transform,
+        &mut TargetX,
+        Option<&Children>,
+    )>,
+    digits_q: Query<&ValueDigits>,
+    mut commands: Commands,
+) {
+    let restart = st.sorted && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        // reshuffle (a fixed --input array comes back unchanged, an edited one once)
+        st.array = input.permutation();
+        layout.rescale(&st.array);
+        st.i = 0;
+        st.j = 0;
+        st.min_idx = 0;
+        st.pre_swap = None;
+        st.swap_pair = None;
+        st.pending_indices = None;
+        st.sorted = false;
+        st.restoring = false;
+        st.counts = OpCounts::default();
+        history.clear();
+        let mut raw: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        for (bar_entity, mut bar, mut sprite, mut tf, mut tx, children) in bars.iter_mut() {
+            let idx = bar.index;
+            let value = st.array[idx];
+            bar.value = value;
+            let h = layout.height(value);
+            sprite.custom_size = Some(Vec2::new(layout.row.width, h));
+            sprite.color = layout.color(value);
+            let x = layout.x(idx);
+            tx.0 = x;
+            tf.translation.x = x;
+            tf.translation.y = h / 2.0 - 200.0;
+            tf.scale = Vec3::ONE;
+            raw.push((
+                bar_entity,
+                children.map_or_else(Vec::new, |c| c.to_vec()),
+                value,
+                h / 2.0 + 12.0,
+            ));
+        }
+        // old digits go; new ones only if they still fit over the rescaled bars
+        for (parent, children, value, y) in raw {
+            for c in children {
+                if digits_q.get(c).is_ok() {
+                    commands.entity(c).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands
+                    .entity(parent)
+                    .with_children(|p| spawn_value_digits(p, value, y, DIGIT_COLOR));
+            }
+        }
+    }
+}
+
+fn step_selection(
+    mut st: ResMut<SelState>,
+    bars: Query<(Entity, &Bar, &TargetX)>,
+    mut settings: ResMut<Settings>,
+) {
+    if st.sorted || !st.idle() {
+        return;
+    }
+    if !settings.step_ready() {
+        return;
+    }
+
+    if let Some((min_i, i)) = st.advance() {
+        if let (Some((emin, _)), Some((ei, _))) =
+            (find_by_index(min_i, &bars), find_by_index(i, &bars))
+        {
+            st.pre_swap = Some((emin, ei, PRE_SWAP_DURATION));
+            st.pending_indices = Some((min_i, i));
+        }
+    }
+    // consume one manual step if in manual mode
+    if !st.sorted && !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+impl SelState {
+    /// One comparison of the scan against the running minimum. At the end of
+    /// a pass returns the (minimum, boundary) pair to swap; the caller swaps
+    /// it and then calls `end_pass`.
+    fn advance(&mut self) -> Option<(usize, usize)> {
+        let n = self.array.len();
+        if self.i + 1 >= n {
+            self.sorted = true;
+            return None;
+        }
+
+        // initialize pass when starting or after advancing i
+        if self.j <= self.i {
+            self.min_idx = self.i;
+            self.j = self.i + 1;
+        }
+
+        // selection scan: track min
+        self.counts.compare();
+        if self.array[self.min_idx] > self.array[self.j] {
+            self.min_idx = self.j;
+        }
+        self.j += 1;
+
+        // end of pass triggers potential swap
+        if self.j < n {
+            return None;
+        }
+        if self.min_idx != self.i {
+            return Some((self.min_idx, self.i));
+        }
+        // no swap; advance boundary, next pass starts its scan at i
+        self.i += 1;
+        self.j = self.i;
+        None
+    }
+
+    /// The minimum is in place: move the sorted boundary past it.
+    fn end_pass(&mut self) {
+        self.i += 1;
+        self.j = self.i;
+        if self.i + 1 >= self.array.len() {
+            self.sorted = true;
+        }
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    Box::new(Race {
+        state: SelState { array, ..default() },
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    state: SelState,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        let st = &mut self.state;
+        let scanned = st.j.max(st.i + 1);
+        self.focus = match st.advance() {
+            Some((min_i, i)) => {
+                st.array.swap(min_i, i);
+                st.counts.swap();
+                st.end_pass();
+                vec![min_i, i]
+            }
+            None if st.sorted => Vec::new(),
+            None => vec![st.min_idx, scanned],
+        };
+    }
+
+    fn done(&self) -> bool {
+        self.state.sorted
+    }
+
+    fn array(&self) -> &[usize] {
+        &self.state.array
+    }
+
+    fn counts(&self) -> OpCounts {
+        self.state.counts
+    }
+
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+fn find_by_index(idx: usize, bars: &Query<(Entity, &Bar, &TargetX)>) -> Option<(Entity, Bar)> {
+    for (e, bar, _tx) in bars.iter() {
+        if bar.index == idx {
+            return Some((e, *bar));
+        }
+    }
+    None
+}
+
+fn pre_swap_anim(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut st: ResMut<SelState>,
+    mut q_tf: Query<&mut Transform>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX)>,
+    layout: Res<Layout>,
+) {
+    if let Some((emin, ei, mut remaining)) = st.pre_swap.take() {
+        for &e in [emin, ei].iter() {
+            if let Ok(mut tf) = q_tf.get_mut(e) {
+                tf.scale = Vec3::new(1.0, 1.15, 1.0);
+            }
+        }
+        let perform = if !settings.auto {
+            settings.manual_step
+        } else {
+            remaining -= time.delta_seconds();
+            remaining <= 0.0
+        };
+        if perform {
+            for &e in [emin, ei].iter() {
+                if let Ok(mut tf) = q_tf.get_mut(e) {
+                    tf.scale = Vec3::ONE;
+                }
+            }
+            if let Some((min_i, i)) = st.pending_indices.take() {
+                st.array.swap(min_i, i);
+                st.counts.swap();
+                for (e, mut bar, mut tx) in bars.iter_mut() {
+                    if e == emin {
+                        bar.index = i;
+                        tx.0 = layout.x(i);
+                    }
+                    if e == ei {
+                        bar.index = min_i;
+                        tx.0 = layout.x(min_i);
+                    }
+                }
+                st.swap_pair = Some((emin, ei));
+            }
+        } else {
+            st.pre_swap = Some((emin, ei, remaining));
+        }
+    }
+}
+
+fn animate_swaps(
+    time: Res<Time>,
+    mut st: ResMut<SelState>,
+    mut q: Query<(Entity, &TargetX, &mut Transform)>,
+) {
+    if let Some((ea, eb)) = st.swap_pair {
+        let mut a_done = false;
+        let mut b_done = false;
+        for (e, tx, mut tf) in q.iter_mut() {
+            if e == ea || e == eb {
+                let dx = tx.0 - tf.translation.x;
+                let step = ANIM_SPEED * time.delta_seconds();
+                if dx.abs() <= step {
+                    tf.translation.x = tx.0;
+                    if e == ea {
+                        a_done = true;
+                    } else {
+                        b_done = true;
+                    }
+                } else {
+                    tf.translation.x += step * dx.signum();
+                }
+            }
+        }
+        if a_done && b_done {
+            st.swap_pair = None;
+            st.end_pass();
+        }
+    }
+}
+
+// Left/Right or the timeline picked another snapshot: drop any swap in flight
+// and send every bar toward its slot in the restored order
+fn restore_snapshot(
+    mut events: EventReader<RestoreSnapshot>,
+    history: Res<History<Snapshot>>,
+    mut st: ResMut<SelState>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX, &mut Transform)>,
+    layout: Res<Layout>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    let Some(snap) = history.current() else {
+        return;
+    };
+    st.array = snap.array.clone();
+    st.i = snap.i;
+    st.j = snap.j;
+    st.min_idx = snap.min_idx;
+    st.sorted = snap.sorted;
+    st.counts = snap.counts;
+    st.pre_swap = None;
+    st.swap_pair = None;
+    st.pending_indices = None;
+    for (e, mut bar, mut tx, mut tf) in bars.iter_mut() {
+        if let Some(&(_, idx)) = snap.slots.iter().find(|&&(be, _)| be == e) {
+            bar.index = idx;
+            tx.0 = layout.x(idx);
+        }
+        tf.scale = Vec3::ONE;
+    }
+    st.restoring = true;
+}
+
+fn animate_restore(
+    time: Res<Time>,
+    mut st: ResMut<SelState>,
+    mut q: Query<(&TargetX, &mut Transform), With<Bar>>,
+) {
+    if !st.restoring {
+        return;
+    }
+    let step = ANIM_SPEED * time.delta_seconds();
+    let mut moving = false;
+    for (tx, mut tf) in q.iter_mut() {
+        let dx = tx.0 - tf.translation.x;
+        if dx.abs() <= step {
+            tf.translation.x = tx.0;
+        } else {
+            tf.translation.x += step * dx.signum();
+            moving = true;
+        }
+    }
+    if !moving {
+        st.restoring = false;
+    }
+}
+
+fn record_history(
+    st: Res<SelState>,
+    bars: Query<(Entity, &Bar)>,
+    mut history: ResMut<History<Snapshot>>,
+) {
+    if !st.idle() {
+        return;
+    }
+    let mut slots: Vec<(Entity, usize)> = bars.iter().map(|(e, bar)| (e, bar.index)).collect();
+    slots.sort_unstable();
+    let snap = st.snapshot(slots);
+    if history.differs(&snap) {
+        history.record(snap);
+    }
+}
+
+fn update_colors(
+    st: Res<SelState>,
+    layout: Res<Layout>,
+    mut q: Query<(&Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (bar, mut sprite) in q.iter_mut() {
+        let base = layout.color(bar.value);
+        let color = if st.sorted || bar.index < st.i {
+            palette.settled
+        } else if bar.index == st.min_idx && st.j > st.i {
+            palette.compare
+        } else if st.j > 0 && bar.index + 1 == st.j {
+            // highlight the last compared element (j-1) as focus
+            palette.focus
+        } else {
+            base
+        };
+        sprite.color = color;
+    }
+}
+
+fn update_educational_text(
+    st: Res<SelState>,
+    settings: Res<Settings>,
+    locale: Res<Locale>,
+    mut text_params: ParamSet<(
+        Query<&mut Text, With<ProgressText>>,
+        Query<&mut Text, With<StepExplanation>>,
+    )>,
+) {
+    // Update progress text
+    if let Ok(mut progress_text) = text_params.p0().get_single_mut() {
+        let pass = st.i + 1;
+        let status = if st.sorted {
+            "status.sorted"
+        } else if st.pre_swap.is_some() || st.swap_pair.is_some() {
+            "status.swapping"
+        } else if settings.auto {
+            "status.running"
+        } else {
+            "status.paused"
+        };
+
+        progress_text.sections[0].value = tr!(
+            locale,
+            "selection-sort.progress",
+            pass = pass,
+            comparisons = st.counts.comparisons,
+            status = locale.text(status)
+        );
+    }
+
+    // Update step explanation
+    if let Ok(mut step_text) = text_params.p1().get_single_mut() {
+        let explanation = if st.sorted {
+            locale.text("selection-sort.step.done")
+        } else if st.pre_swap.is_some() {
+            tr!(
+                locale,
+                "selection-sort.step.swap",
+                min_at = st.min_idx + 1,
+                min = st.array[st.min_idx],
+                at = st.i + 1,
+                value = st.array[st.i]
+            )
+        } else if st.j > st.i {
+            tr!(
+                locale,
+                "selection-sort.step.scan",
+                pass = st.i + 1,
+                at = st.j + 1,
+                value = st.array[st.j],
+                min_at = st.min_idx + 1,
+                min = st.array[st.min_idx],
+            )
+        } else if st.i < st.array.len() - 1 && settings.auto {
+            tr!(
+                locale,
+                "selection-sort.step.pass-done",
+                pass = st.i + 1,
+                next = st.i + 2
+            )
+        } else if !settings.auto && st.i == 0 && st.j == 0 {
+            locale.text("selection-sort.step.intro")
+        } else {
+            locale.text("selection-sort.step.running")
+        };
+
+        step_text.sections[0].value = explanation;
+    }
+}
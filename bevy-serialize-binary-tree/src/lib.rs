@@ -0,0 +1,257 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub const TITLE: &str = "Serialize/Deserialize Binary Tree";
+const BG_COLOR: Color = Color::srgb(0.03, 0.04, 0.08);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct TreeNode {
+    value: i32,
+    is_current: bool,
+}
+
+#[derive(Resource, Clone)]
+#[allow(dead_code)]
+struct AppState {
+    tree: HashMap<i32, (Option<i32>, Option<i32>)>,
+    root: i32,
+    serialized: Vec<String>,
+    step: usize,
+    mode: Mode,
+}
+
+#[derive(PartialEq, Clone)]
+#[allow(dead_code)]
+enum Mode {
+    Serializing,
+    Deserializing,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "serialize-binary-tree";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let (tree, root) = match TreeScenario::from_args() {
+        Some(scenario) => (scenario.to_map(), scenario.root),
+        None => (sample_tree(), 1),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            tree,
+            root,
+            serialized: Vec::new(),
+            step: 0,
+            mode: Mode::Serializing,
+        })
+        .setup(setup)
+        .systems(Update, (update_highlights, ui));
+}
+
+fn sample_tree() -> HashMap<i32, (Option<i32>, Option<i32>)> {
+    let mut tree = HashMap::new();
+    tree.insert(1, (Some(2), Some(3)));
+    tree.insert(2, (Some(4), Some(5)));
+    tree.insert(3, (None, Some(6)));
+    tree.insert(4, (None, None));
+    tree.insert(5, (None, None));
+    tree.insert(6, (None, None));
+    tree
+}
+
+fn setup(mut commands: Commands, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Serialize the tree
+    let mut serialized = Vec::new();
+    serialize(&state.tree, state.root, &mut serialized);
+    commands.insert_resource(AppState {
+        tree: state.tree.clone(),
+        root: state.root,
+        serialized: serialized.clone(),
+        step: 0,
+        mode: Mode::Serializing,
+    });
+
+    // Spawn tree nodes
+    spawn_tree(
+        &mut commands,
+        &state.tree,
+        state.root,
+        state.root,
+        0.0,
+        300.0,
+        0,
+    );
+
+    // Spawn serialized tokens on the right
+    let base_x = 300.0;
+    let base_y = 200.0;
+    for (i, token) in serialized.iter().enumerate() {
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                token.clone(),
+                SegmentStyle {
+                    font_size: 24.0,
+                    color: if i == 0 {
+                        Color::srgb(1.0, 1.0, 0.0)
+                    } else {
+                        Color::srgb(0.8, 0.8, 0.8)
+                    },
+                },
+            ),
+            transform: Transform::from_xyz(base_x + i as f32 * 50.0, base_y, 0.0),
+            ..default()
+        });
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Preorder Serialization: Root -> Left -> Right\nYellow: Current token",
+            SegmentStyle {
+                font_size: 20.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn serialize(tree: &HashMap<i32, (Option<i32>, Option<i32>)>, node: i32, result: &mut Vec<String>) {
+    if !tree.contains_key(&node) {
+        result.push("null".to_string());
+        return;
+    }
+    result.push(node.to_string());
+    let (left, right) = tree[&node];
+    if let Some(l) = left {
+        serialize(tree, l, result);
+    } else {
+        result.push("null".to_string());
+    }
+    if let Some(r) = right {
+        serialize(tree, r, result);
+    } else {
+        result.push("null".to_string());
+    }
+}
+
+fn spawn_tree(
+    commands: &mut Commands,
+    tree: &HashMap<i32, (Option<i32>, Option<i32>)>,
+    root: i32,
+    node: i32,
+    x: f32,
+    y: f32,
+    depth: i32,
+) {
+    if !tree.contains_key(&node) {
+        return;
+    }
+
+    let color = if node == root {
+        Color::srgb(1.0, 1.0, 0.0)
+    } else {
+        Color::srgb(0.5, 0.5, 0.5)
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::new(40.0, 40.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, y, 0.0),
+            ..default()
+        },
+        TreeNode {
+            value: node,
+            is_current: node == root,
+        },
+        SegmentText::from_section(
+            node.to_string(),
+            SegmentStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.0, 0.0, 0.0),
+            },
+        ),
+    ));
+
+    let (left, right) = tree[&node];
+    let offset = 150.0 / (depth as f32 + 1.0);
+    if let Some(l) = left {
+        spawn_tree(commands, tree, root, l, x - offset, y - 80.0, depth + 1);
+    }
+    if let Some(r) = right {
+        spawn_tree(commands, tree, root, r, x + offset, y - 80.0, depth + 1);
+    }
+}
+
+fn update_highlights(
+    mut nodes: Query<(&mut Sprite, &TreeNode)>,
+    mut texts: Query<&mut SegmentText>,
+    time: Res<Time>,
+    mut state: ResMut<AppState>,
+    palette: Res<Palette>,
+) {
+    // Simple animation: cycle through serialization steps
+    if (time.elapsed_seconds() as usize).is_multiple_of(2) && state.step < state.serialized.len() {
+        state.step += 1;
+    }
+
+    for (mut sprite, node) in nodes.iter_mut() {
+        sprite.color = if node.is_current && state.step > 0 {
+            palette.compare
+        } else {
+            Color::srgb(0.5, 0.5, 0.5)
+        };
+    }
+
+    // Update token colors
+    let text_iter = texts.iter_mut();
+    for (i, mut text) in text_iter.enumerate() {
+        if i < state.serialized.len() {
+            text.sections[0].style.color = if i < state.step {
+                palette.settled
+            } else if i == state.step {
+                palette.compare
+            } else {
+                Color::srgb(0.8, 0.8, 0.8)
+            };
+        }
+    }
+}
+
+fn ui(mut commands: Commands) {
+    // Toggle button for deserialization (placeholder)
+    commands
+        .spawn(ButtonBundle {
+            style: Style {
+                width: Val::Px(200.0),
+                height: Val::Px(50.0),
+                bottom: Val::Px(20.0),
+                right: Val::Px(20.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            background_color: Color::srgb(0.2, 0.2, 0.2).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Deserialize",
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                    ..default()
+                },
+            ));
+        });
+}
@@ -0,0 +1,553 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 20,
+    max_n: DENSE_N - 1,
+    max_value: 99,
+    dense: true,
+};
+const BAR_WIDTH: f32 = 32.0;
+const BAR_GAP: f32 = 6.0;
+const ROW_WIDTH: f32 = 880.0;
+const MAX_HEIGHT: f32 = 300.0;
+const SPEED: f32 = 460.0;
+const PRE: f32 = 0.28;
+const STEP_INTERVAL: f32 = 0.75;
+
+#[derive(Component, Copy, Clone)]
+struct Bar {
+    index: usize,
+    value: usize,
+}
+impl EditableBar for Bar {
+    fn slot(&self) -> usize {
+        self.index
+    }
+    fn value(&self) -> usize {
+        self.value
+    }
+}
+#[derive(Component, Deref, DerefMut)]
+struct TargetX(f32);
+#[derive(Resource)]
+struct Layout {
+    row: BarRow,
+    w: f32,
+    top: usize,
+    labels: bool,
+}
+
+impl Layout {
+    // bars (and gaps) narrow to fit the row; heights and hues scale to the largest value;
+    // labels only where the digits fit over the bars
+    fn new(a: &[usize], row_width: f32) -> Self {
+        let row = BarRow::fit(a.len(), row_width, BAR_WIDTH, BAR_GAP);
+        let top = a.iter().copied().max().unwrap_or(1).max(1);
+        Self {
+            row,
+            w: row.width,
+            top,
+            labels: row.labels(top),
+        }
+    }
+    // an edited array may hold a new largest value
+    fn rescale(&mut self, a: &[usize]) {
+        self.top = a.iter().copied().max().unwrap_or(1).max(1);
+        self.labels = self.row.labels(self.top);
+    }
+    fn h(&self, v: usize) -> f32 {
+        v as f32 / self.top as f32 * MAX_HEIGHT + 10.0
+    }
+    fn color(&self, v: usize) -> Color {
+        Color::hsl((v as f32 / self.top as f32) * 300.0, 0.7, 0.5)
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+struct State {
+    gap: usize,
+    i: usize,
+    j: isize,
+    array: Vec<usize>,
+    pre: Option<(Entity, Entity, f32)>,
+    swap: Option<(Entity, Entity)>,
+    pair_idx: Option<(usize, usize)>,
+    done: bool,
+    restoring: bool,
+    counts: OpCounts,
+}
+
+/// What stepping back restores: the array order, where each bar stands (by entity, as values may
+/// repeat), the gap, the insertion cursors and the counts.
+#[derive(Clone, PartialEq)]
+struct Snapshot {
+    array: Vec<usize>,
+    slots: Vec<(Entity, usize)>,
+    gap: usize,
+    i: usize,
+    j: isize,
+    done: bool,
+    counts: OpCounts,
+}
+
+impl State {
+    fn snapshot(&self, slots: Vec<(Entity, usize)>) -> Snapshot {
+        Snapshot {
+            array: self.array.clone(),
+            slots,
+            gap: self.gap,
+            i: self.i,
+            j: self.j,
+            done: self.done,
+            counts: self.counts,
+        }
+    }
+    fn idle(&self) -> bool {
+        self.pre.is_none() && self.swap.is_none() && !self.restoring
+    }
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "shell-sort";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    if input.dense() {
+        app.add_viz(ID).resource(input).dense_sort(racer);
+        return;
+    }
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .history_scrubber::<Snapshot>()
+        .cost_chart(|st: Res<State>| st.counts)
+        .bar_editor::<Bar>()
+        .resource(input)
+        .resource(State::default())
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                (restore_snapshot, step).chain().in_set(VizSet::Step),
+                (pre_anim, animate, animate_restore)
+                    .chain()
+                    .in_set(VizSet::Animate),
+                (colors, record_history).in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(
+    mut commands: Commands,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    st.array = input.permutation();
+    let layout = Layout::new(&st.array, row_width(ROW_WIDTH, &windows));
+    for (idx, v) in st.array.iter().copied().enumerate() {
+        let h = layout.h(v);
+        let x = x_at(idx, &layout);
+        let color = layout.color(v);
+        let id = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(layout.w, h)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, h / 2.0 - 200.0, 0.0),
+                    ..default()
+                },
+                Bar {
+                    index: idx,
+                    value: v,
+                },
+                TargetX(x),
+            ))
+            .id();
+        if layout.labels {
+            commands
+                .entity(id)
+                .with_children(|p| spawn_value_digits(p, v, h / 2.0 + 12.0, Color::WHITE));
+        }
+    }
+    commands.insert_resource(layout);
+    st.gap = st.array.len() / 2;
+    st.i = st.gap;
+    st.j = st.i as isize;
+    st.done = false;
+}
+
+fn x_at(i: usize, layout: &Layout) -> f32 {
+    layout.row.x(i)
+}
+
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut st: ResMut<State>,
+    mut input: ResMut<ArrayInput>,
+    mut history: ResMut<History<Snapshot>>,
+    mut layout: ResMut<Layout>,
+    mut bars: Query<(
+        Entity,
+        &mut Bar,
+        &mut Sprite,
+        &mut Transform,
+        &mut TargetX,
+        Option<&Children>,
+    )>,
+    digits_q: Query<&ValueDigits>,
+    mut commands: Commands,
+    palette: Res<Palette>,
+) {
+    let restart = st.done && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        st.array = input.permutation();
+        layout.rescale(&st.array);
+        st.gap = st.array.len() / 2;
+        st.i = st.gap;
+        st.j = st.i as isize;
+        st.pre = None;
+        st.swap = None;
+        st.pair_idx = None;
+        st.done = false;
+        st.restoring = false;
+        st.counts = OpCounts::default();
+        history.clear();
+        let mut raw: Vec<(Entity, Vec<Entity>, usize, f32)> = Vec::new();
+        for (e, mut bar, mut sp, mut tf, mut tx, children) in bars.iter_mut() {
+            let idx = bar.index;
+            let v = st.array[idx];
+            bar.value = v;
+            let h = layout.h(v);
+            sp.custom_size = Some(Vec2::new(layout.w, h));
+            sp.color = layout.color(v);
+            let x = x_at(idx, &layout);
+            tx.0 = x;
+            tf.translation = Vec3::new(x, h / 2.0 - 200.0, 0.0);
+            tf.scale = Vec3::ONE;
+            raw.push((
+                e,
+                children.map_or_else(Vec::new, |c| c.to_vec()),
+                v,
+                h / 2.0 + 12.0,
+            ));
+        }
+        for (p, children, v, y) in raw {
+            for c in children {
+                if digits_q.get(c).is_ok() {
+                    commands.entity(c).despawn_recursive();
+                }
+            }
+            if layout.labels {
+                commands
+                    .entity(p)
+                    .with_children(|x| spawn_value_digits(x, v, y, palette.focus));
+            }
+        }
+    }
+}
+
+fn step(
+    mut st: ResMut<State>,
+    bars: Query<(Entity, &Bar, &TargetX)>,
+    mut settings: ResMut<Settings>,
+) {
+    if st.done || !st.idle() {
+        return;
+    }
+    if !settings.step_ready() {
+        return;
+    }
+
+    if let Some((a, b, true)) = st.advance() {
+        if let (Some((ea, _)), Some((eb, _))) = (find_by_index(a, &bars), find_by_index(b, &bars)) {
+            st.pre = Some((ea, eb, PRE));
+            st.pair_idx = Some((a, b));
+        }
+    }
+    if !st.done && !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+impl State {
+    /// One comparison of the gapped insertion, or a move to the next element
+    /// or gap. Returns the pair compared and whether it is out of order; the
+    /// caller then calls `swap_back`.
+    fn advance(&mut self) -> Option<(usize, usize, bool)> {
+        if self.gap == 0 {
+            self.done = true;
+            return None;
+        }
+
+        if self.i >= self.array.len() {
+            if self.gap == 1 {
+                self.gap = 0;
+            } else {
+                self.gap /= 2;
+            }
+            self.i = self.gap;
+            self.j = self.i as isize;
+            return None;
+        }
+
+        // Reached start for this i; move to next i
+        if self.j < self.gap as isize {
+            self.i += 1;
+            self.j = self.i as isize;
+            return None;
+        }
+
+        let a = (self.j - self.gap as isize) as usize;
+        let b = self.j as usize;
+        self.counts.compare();
+        let out_of_order = self.array[a] > self.array[b];
+        // In order; end inner loop for this i
+        if !out_of_order {
+            self.i += 1;
+            self.j = self.i as isize;
+        }
+        Some((a, b, out_of_order))
+    }
+
+    /// Swap the pair `advance` found out of order and continue the inner loop a gap back.
+    fn swap_back(&mut self, a: usize, b: usize) {
+        self.array.swap(a, b);
+        self.counts.swap();
+        self.j -= self.gap as isize;
+    }
+}
+
+/// `--headless`: the sort on the array the viz would start from, written as JSON Lines.
+pub fn run_headless(trace: &mut TraceWriter) {
+    let mut input = ArrayInput::from_args(INPUT);
+    run_racer(racer(input.permutation()), trace);
+}
+
+/// The sort on `array` without its scene, for bevy-sort-race.
+pub fn racer(array: Vec<usize>) -> Box<dyn Racer> {
+    let gap = array.len() / 2;
+    Box::new(Race {
+        state: State {
+            gap,
+            i: gap,
+            j: gap as isize,
+            array,
+            ..default()
+        },
+        focus: Vec::new(),
+    })
+}
+
+struct Race {
+    state: State,
+    focus: Vec<usize>,
+}
+
+impl Racer for Race {
+    fn step(&mut self) {
+        let st = &mut self.state;
+        self.focus = match st.advance() {
+            Some((a, b, swap)) => {
+                if swap {
+                    st.swap_back(a, b);
+                }
+                vec![a, b]
+            }
+            None => Vec::new(),
+        };
+    }
+    fn done(&self) -> bool {
+        self.state.done
+    }
+    fn array(&self) -> &[usize] {
+        &self.state.array
+    }
+    fn counts(&self) -> OpCounts {
+        self.state.counts
+    }
+    fn focus(&self) -> Vec<usize> {
+        self.focus.clone()
+    }
+}
+
+fn find_by_index(idx: usize, bars: &Query<(Entity, &Bar, &TargetX)>) -> Option<(Entity, Bar)> {
+    for (e, bar, _) in bars.iter() {
+        if bar.index == idx {
+            return Some((e, *bar));
+        }
+    }
+    None
+}
+
+fn pre_anim(
+    time: Res<Time>,
+    mut st: ResMut<State>,
+    mut q_tf: Query<&mut Transform>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX)>,
+    layout: Res<Layout>,
+    mut settings: ResMut<Settings>,
+) {
+    if let Some((ea, eb, mut t)) = st.pre.take() {
+        for &e in [ea, eb].iter() {
+            if let Ok(mut tf) = q_tf.get_mut(e) {
+                tf.scale = Vec3::new(1.0, 1.15, 1.0);
+            }
+        }
+        let perform = if settings.auto {
+            t -= time.delta_seconds();
+            t <= 0.0
+        } else {
+            let do_it = settings.manual_step;
+            settings.manual_step = false;
+            do_it
+        };
+        if perform {
+            for &e in [ea, eb].iter() {
+                if let Ok(mut tf) = q_tf.get_mut(e) {
+                    tf.scale = Vec3::ONE;
+                }
+            }
+            if let Some((ai, bi)) = st.pair_idx.take() {
+                st.swap_back(ai, bi);
+                for (e, mut bar, mut tx) in bars.iter_mut() {
+                    if e == ea {
+                        bar.index = bi;
+                        tx.0 = x_at(bi, &layout);
+                    } else if e == eb {
+                        bar.index = ai;
+                        tx.0 = x_at(ai, &layout);
+                    }
+                }
+                st.swap = Some((ea, eb));
+            }
+        } else {
+            st.pre = Some((ea, eb, t));
+        }
+    }
+}
+
+fn animate(
+    time: Res<Time>,
+    mut st: ResMut<State>,
+    mut q: Query<(Entity, &TargetX, &mut Transform)>,
+) {
+    if let Some((ea, eb)) = st.swap {
+        let mut a = false;
+        let mut b = false;
+        for (e, tx, mut tf) in q.iter_mut() {
+            if e == ea || e == eb {
+                let dx = tx.0 - tf.translation.x;
+                let step = SPEED * time.delta_seconds();
+                if dx.abs() <= step {
+                    tf.translation.x = tx.0;
+                    if e == ea {
+                        a = true;
+                    } else {
+                        b = true;
+                    }
+                } else {
+                    tf.translation.x += step * dx.signum();
+                }
+            }
+        }
+        if a && b {
+            st.swap = None;
+        }
+    }
+}
+
+// Left/Right or the timeline picked another snapshot: drop any swap in flight
+// and send every bar toward its slot in the restored order
+fn restore_snapshot(
+    mut events: EventReader<RestoreSnapshot>,
+    history: Res<History<Snapshot>>,
+    mut st: ResMut<State>,
+    mut bars: Query<(Entity, &mut Bar, &mut TargetX, &mut Transform)>,
+    layout: Res<Layout>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    let Some(snap) = history.current() else {
+        return;
+    };
+    st.array = snap.array.clone();
+    st.gap = snap.gap;
+    st.i = snap.i;
+    st.j = snap.j;
+    st.done = snap.done;
+    st.counts = snap.counts;
+    st.pre = None;
+    st.swap = None;
+    st.pair_idx = None;
+    for (e, mut bar, mut tx, mut tf) in bars.iter_mut() {
+        if let Some(&(_, idx)) = snap.slots.iter().find(|&&(be, _)| be == e) {
+            bar.index = idx;
+            tx.0 = x_at(idx, &layout);
+        }
+        tf.scale = Vec3::ONE;
+    }
+    st.restoring = true;
+}
+
+fn animate_restore(
+    time: Res<Time>,
+    mut st: ResMut<State>,
+    mut q: Query<(&TargetX, &mut Transform), With<Bar>>,
+) {
+    if !st.restoring {
+        return;
+    }
+    let step = SPEED * time.delta_seconds();
+    let mut moving = false;
+    for (tx, mut tf) in q.iter_mut() {
+        let dx = tx.0 - tf.translation.x;
+        if dx.abs() <= step {
+            tf.translation.x = tx.0;
+        } else {
+            tf.translation.x += step * dx.signum();
+            moving = true;
+        }
+    }
+    if !moving {
+        st.restoring = false;
+    }
+}
+
+fn record_history(
+    st: Res<State>,
+    bars: Query<(Entity, &Bar)>,
+    mut history: ResMut<History<Snapshot>>,
+) {
+    if !st.idle() {
+        return;
+    }
+    let mut slots: Vec<(Entity, usize)> = bars.iter().map(|(e, bar)| (e, bar.index)).collect();
+    slots.sort_unstable();
+    let snap = st.snapshot(slots);
+    if history.differs(&snap) {
+        history.record(snap);
+    }
+}
+
+fn colors(
+    st: Res<State>,
+    layout: Res<Layout>,
+    mut q: Query<(&Bar, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (bar, mut sp) in q.iter_mut() {
+        let base = layout.color(bar.value);
+        let c = if st.done { palette.settled } else { base };
+        sp.color = c;
+    }
+}
@@ -0,0 +1,346 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+const INPUT: ArraySpec = ArraySpec {
+    default_n: 16,
+    max_n: 32,
+    max_value: 99,
+    dense: false,
+};
+// one unit of work per lane per tick: a comparison or an element written
+const STEP_INTERVAL: f32 = 0.05;
+const TITLE_Y: f32 = 250.0;
+const LANE_TOP: f32 = 215.0;
+const LANE_PITCH: f32 = 50.0;
+const BAR_MAX_HEIGHT: f32 = 38.0;
+const NAME_X: f32 = -440.0;
+const BARS_LEFT: f32 = -300.0;
+const BARS_WIDTH: f32 = 460.0;
+const COUNTS_X: f32 = 175.0;
+const PLACE_X: f32 = 400.0;
+
+/// The racers, top lane first.
+const LANES: [(&str, fn(Vec<usize>) -> Box<dyn Racer>); 10] = [
+    ("Bubble", bevy_bubble_sort::racer),
+    ("Selection", bevy_selection_sort::racer),
+    ("Insertion", bevy_insertion_sort::racer),
+    ("Cocktail", bevy_cocktail_sort::racer),
+    ("Gnome", bevy_gnome_sort::racer),
+    ("Shell", bevy_shell_sort::racer),
+    ("Merge", bevy_merge_sort::racer),
+    ("Quick Lomuto", bevy_quicksort_lomuto::racer),
+    ("Quick Hoare", bevy_quicksort_hoare::racer),
+    ("Heap", bevy_heap_sort::racer),
+];
+
+/// Every lane sorting its own copy of one array. A tick lets each unfinished
+/// lane step until its work (comparisons plus array writes) reaches the tick
+/// count, so lanes keep pace by cost rather than by how much one step does.
+#[derive(Resource)]
+struct Race {
+    lanes: Vec<Lane>,
+    work: usize,
+    finished: usize,
+    top: usize,
+}
+
+struct Lane {
+    name: &'static str,
+    racer: Box<dyn Racer>,
+    place: Option<usize>,
+}
+
+impl Race {
+    fn new(array: Vec<usize>) -> Self {
+        let top = array.iter().copied().max().unwrap_or(1).max(1);
+        let lanes = LANES
+            .iter()
+            .map(|&(name, racer)| Lane {
+                name,
+                racer: racer(array.clone()),
+                place: None,
+            })
+            .collect();
+        Self {
+            lanes,
+            work: 0,
+            finished: 0,
+            top,
+        }
+    }
+
+    /// Lanes that finish on the same tick did the same work and share a place.
+    fn tick(&mut self) {
+        self.work += 1;
+        let place = self.finished + 1;
+        for lane in self.lanes.iter_mut().filter(|lane| lane.place.is_none()) {
+            while !lane.racer.done() && work(&lane.racer.counts()) < self.work {
+                lane.racer.step();
+            }
+            if lane.racer.done() {
+                lane.place = Some(place);
+                self.finished += 1;
+            }
+        }
+    }
+
+    fn over(&self) -> bool {
+        self.finished == self.lanes.len()
+    }
+}
+
+fn work(counts: &OpCounts) -> usize {
+    counts.comparisons + counts.writes
+}
+
+#[derive(Component)]
+struct LaneBar {
+    lane: usize,
+    slot: usize,
+}
+
+#[derive(Component)]
+struct LaneCounts(usize);
+
+#[derive(Component)]
+struct LanePlace(usize);
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "sort-race";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let input = ArrayInput::from_args(INPUT);
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(input)
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input_sys.in_set(VizSet::Input),
+                step_race.in_set(VizSet::Step),
+                draw_lanes.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn lane_y(lane: usize) -> f32 {
+    LANE_TOP - lane as f32 * LANE_PITCH
+}
+
+fn slot_width(n: usize) -> f32 {
+    BARS_WIDTH / n.max(1) as f32
+}
+
+fn setup(mut commands: Commands, mut input: ResMut<ArrayInput>) {
+    commands.spawn(Camera2dBundle::default());
+    let race = Race::new(input.permutation());
+    let n = race.lanes[0].racer.array().len();
+    let style = |font_size| SegmentStyle {
+        font_size,
+        color: Color::srgb(0.9, 0.9, 0.9),
+    };
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section("", style(14.0)),
+            transform: Transform::from_xyz(0.0, TITLE_Y, 0.0),
+            ..default()
+        },
+        Localized::new("sort-race.title").with("n", n),
+    ));
+    for (lane, Lane { name, .. }) in race.lanes.iter().enumerate() {
+        let y = lane_y(lane);
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(*name, style(13.0)),
+            text_anchor: Anchor::CenterLeft,
+            transform: Transform::from_xyz(NAME_X, y, 0.0),
+            ..default()
+        });
+        for slot in 0..n {
+            let x = BARS_LEFT + (slot as f32 + 0.5) * slot_width(n);
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        anchor: Anchor::BottomCenter,
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(x, y - BAR_MAX_HEIGHT / 2.0, 0.0),
+                    ..default()
+                },
+                LaneBar { lane, slot },
+            ));
+        }
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section("", style(11.0)),
+                text_anchor: Anchor::CenterLeft,
+                transform: Transform::from_xyz(COUNTS_X, y, 0.0),
+                ..default()
+            },
+            LaneCounts(lane),
+        ));
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section("", style(16.0)),
+                transform: Transform::from_xyz(PLACE_X, y, 0.0),
+                ..default()
+            },
+            LanePlace(lane),
+        ));
+    }
+    commands.insert_resource(race);
+}
+
+// R reshuffles; Space once every lane is done starts a new race
+fn input_sys(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut reset: EventReader<ResetRequested>,
+    mut race: ResMut<Race>,
+    mut input: ResMut<ArrayInput>,
+) {
+    let restart = race.over() && keys.clear_just_pressed(KeyCode::Space);
+    if reset.read().count() > 0 || restart {
+        *race = Race::new(input.permutation());
+    }
+}
+
+fn step_race(mut race: ResMut<Race>, mut settings: ResMut<Settings>) {
+    if race.over() || !settings.step_ready() {
+        return;
+    }
+    race.tick();
+    if !settings.auto {
+        settings.manual_step = false;
+    }
+}
+
+fn draw_lanes(
+    race: Res<Race>,
+    palette: Res<Palette>,
+    locale: Res<Locale>,
+    mut bars: Query<(&LaneBar, &mut Sprite)>,
+    mut counts: Query<(&LaneCounts, &mut SegmentText), Without<LanePlace>>,
+    mut places: Query<(&LanePlace, &mut SegmentText), Without<LaneCounts>>,
+) {
+    if !race.is_changed() && !palette.is_changed() && !locale.is_changed() {
+        return;
+    }
+    let locale = locale.for_segment_text();
+    let focus: Vec<Vec<usize>> = race.lanes.iter().map(|lane| lane.racer.focus()).collect();
+    for (bar, mut sprite) in &mut bars {
+        let lane = &race.lanes[bar.lane];
+        let array = lane.racer.array();
+        let value = array[bar.slot];
+        let share = value as f32 / race.top as f32;
+        sprite.custom_size = Some(Vec2::new(
+            slot_width(array.len()) * 0.8,
+            share * BAR_MAX_HEIGHT + 2.0,
+        ));
+        sprite.color = if lane.place.is_some() {
+            palette.settled
+        } else if focus[bar.lane].contains(&bar.slot) {
+            palette.compare
+        } else {
+            palette.ink(Color::hsl(share * 300.0, 0.7, 0.5))
+        };
+    }
+    for (LaneCounts(lane), mut text) in &mut counts {
+        let counts = race.lanes[*lane].racer.counts();
+        text.sections[0].value = tr!(
+            locale,
+            "sort-race.counts",
+            comparisons = counts.comparisons,
+            swaps = counts.swaps,
+            writes = counts.writes,
+        );
+    }
+    for (LanePlace(lane), mut text) in &mut places {
+        let place = race.lanes[*lane].place;
+        text.sections[0].value = place.map_or_else(String::new, |place| {
+            tr!(locale, "sort-race.place", place = place)
+        });
+        text.sections[0].style.color = if place == Some(1) {
+            palette.focus
+        } else {
+            palette.settled
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(array: Vec<usize>) -> Race {
+        let mut race = Race::new(array);
+        for _ in 0..100_000 {
+            if race.over() {
+                break;
+            }
+            race.tick();
+        }
+        assert!(race.over(), "race did not finish");
+        race
+    }
+
+    #[test]
+    fn every_lane_sorts_the_shared_array() {
+        let mut input = ArrayInput::parse(["--seed".to_string(), "3".to_string()], INPUT).unwrap();
+        for _ in 0..20 {
+            let array = input.permutation();
+            let mut sorted = array.clone();
+            sorted.sort_unstable();
+            for lane in run(array).lanes {
+                assert_eq!(lane.racer.array(), sorted, "{}", lane.name);
+            }
+        }
+    }
+
+    #[test]
+    fn presets_change_what_each_sort_pays() {
+        let comparisons = |dist: &str, name| {
+            let args = ["--n", "24", "--dist", dist, "--seed", "5"].map(String::from);
+            let race = run(ArrayInput::parse(args, INPUT).unwrap().permutation());
+            race.lanes
+                .iter()
+                .find(|lane| lane.name == name)
+                .unwrap()
+                .racer
+                .counts()
+                .comparisons
+        };
+        assert!(
+            comparisons("nearly-sorted", "Insertion") < comparisons("reversed", "Insertion") / 4
+        );
+        // the last element is always the Lomuto pivot, so sorted runs split off one element at a time
+        assert!(
+            comparisons("reversed", "Quick Lomuto") > comparisons("random", "Quick Lomuto") * 2
+        );
+    }
+
+    #[test]
+    fn unfinished_lanes_keep_pace_with_the_ticks() {
+        let mut race = Race::new((1..=16).rev().collect());
+        while !race.over() {
+            race.tick();
+            for lane in race.lanes.iter().filter(|lane| lane.place.is_none()) {
+                assert!(
+                    work(&lane.racer.counts()) >= race.work,
+                    "{} fell behind",
+                    lane.name
+                );
+            }
+        }
+        // reversed input: selection sort swaps once per pass, bubble sort once per comparison
+        let place = |name| {
+            race.lanes
+                .iter()
+                .find(|lane| lane.name == name)
+                .and_then(|lane| lane.place)
+        };
+        assert!(place("Selection") < place("Bubble"));
+    }
+}
@@ -0,0 +1,18 @@
+use algoviz_core::Gallery;
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Bevy Sorting Race".into(),
+                resolution: (900.0, 600.0).into(),
+                resizable: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(bevy_sort_race::plugin)
+        .insert_state(Gallery::Viz(bevy_sort_race::ID))
+        .run();
+}
@@ -0,0 +1,266 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+pub const TITLE: &str = "Topological Sort (Kahn)";
+const BG_COLOR: Color = Color::srgb(0.04, 0.04, 0.08);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphNode {
+    id: usize,
+    indegree: usize,
+    processed: bool,
+}
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GraphEdge {
+    from: usize,
+    to: usize,
+}
+
+/// Everything redraw spawns, cleared when the graph changes.
+#[derive(Component)]
+struct Drawn;
+
+#[derive(Resource, Clone)]
+struct AppState {
+    order: Vec<usize>,
+    step: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "toposort-kahn";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let graph = match GraphScenario::from_args_checked(GraphScenario::directed_edges) {
+        Some(scenario) => Graph::from_scenario(&scenario, EdgeWeights::Unweighted),
+        None => sample_graph(),
+    };
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(graph)
+        .resource(AppState {
+            order: Vec::new(),
+            step: 0,
+        })
+        .graph_editor()
+        .setup(setup)
+        .systems(Update, (redraw, update_animation).chain());
+}
+
+fn sample_graph() -> Graph {
+    let positions = vec![
+        Vec2::new(-200.0, 150.0),
+        Vec2::new(-200.0, 50.0),
+        Vec2::new(-200.0, -50.0),
+        Vec2::new(0.0, 100.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(200.0, 50.0),
+    ];
+    let edges = [
+        (0, 3, 1),
+        (0, 4, 1),
+        (1, 3, 1),
+        (1, 4, 1),
+        (2, 5, 1),
+        (3, 5, 1),
+    ];
+    Graph::directed(positions, edges, EdgeWeights::Unweighted)
+}
+
+fn kahn_toposort(graph: &[Vec<(usize, i32)>]) -> Vec<usize> {
+    let mut indegree = vec![0; graph.len()];
+    for neighbors in graph {
+        for &(neighbor, _) in neighbors {
+            indegree[neighbor] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..graph.len())
+        .filter(|&node| indegree[node] == 0)
+        .collect();
+
+    let mut order = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for &(neighbor, _) in &graph[node] {
+            indegree[neighbor] -= 1;
+            if indegree[neighbor] == 0 {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    if order.len() == graph.len() {
+        order
+    } else {
+        vec![] // cycle detected
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+// sorts again from the first step and redraws whenever the graph was edited (or first shown)
+fn redraw(
+    mut commands: Commands,
+    graph: Res<Graph>,
+    mut state: ResMut<AppState>,
+    drawn: Query<Entity, With<Drawn>>,
+) {
+    if !graph.is_changed() {
+        return;
+    }
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let adjacency = graph.adjacency();
+    state.order = kahn_toposort(&adjacency);
+    state.step = 0;
+    let positions = graph.positions();
+
+    // Nodes
+    for (id, &pos) in positions.iter().enumerate() {
+        let indegree = adjacency
+            .iter()
+            .flatten()
+            .filter(|&&(to, _)| to == id)
+            .count();
+        let processed = state
+            .order
+            .iter()
+            .position(|&n| n == id)
+            .is_some_and(|pos| pos < state.step);
+        let color = if processed {
+            Color::srgb(0.0, 1.0, 0.0)
+        } else {
+            Color::srgb(0.25, 0.55, 0.95)
+        };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0),
+                ..default()
+            },
+            GraphNode {
+                id,
+                indegree,
+                processed,
+            },
+            SegmentText::from_section(
+                format!("{}\n{}", id, indegree),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.0, 0.0, 0.0),
+                },
+            ),
+            Drawn,
+        ));
+    }
+
+    // Edges
+    for &(from, to, _) in graph.edges() {
+        let start = positions[from];
+        let end = positions[to];
+        let dir = (end - start).normalize_or_zero();
+        let length = (end - start).length();
+        let midpoint = start + dir * length / 2.0;
+        let angle = dir.y.atan2(dir.x);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.7, 0.7, 0.7),
+                    custom_size: Some(Vec2::new((length - 60.0).max(0.0), 4.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(midpoint.x, midpoint.y, -0.1),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            GraphEdge { from, to },
+            Drawn,
+        ));
+        // Arrow head
+        let arrow_pos = end - dir * 30.0;
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgb(0.7, 0.7, 0.7),
+                    custom_size: Some(Vec2::new(20.0, 20.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(arrow_pos.x, arrow_pos.y, -0.05),
+                    rotation: Quat::from_rotation_z(angle),
+                    ..default()
+                },
+                ..default()
+            },
+            Drawn,
+        ));
+    }
+
+    // Order display
+    let order_text = if state.order.is_empty() {
+        "Cycle detected!".to_string()
+    } else {
+        format!(
+            "Topological Order: {:?}",
+            &state.order[..state.step.min(state.order.len())]
+        )
+    };
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                format!(
+                    "Topological Sort (Kahn): Indegree-based queue\n{}",
+                    order_text
+                ),
+                SegmentStyle {
+                    font_size: 16.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                },
+            ),
+            transform: Transform::from_xyz(0.0, -350.0, 0.0),
+            ..default()
+        },
+        Drawn,
+    ));
+}
+
+fn update_animation(
+    mut nodes: Query<(&mut Sprite, &GraphNode)>,
+    time: Res<Time>,
+    mut state: ResMut<AppState>,
+    palette: Res<Palette>,
+) {
+    // Animate processing
+    if (time.elapsed_seconds() as usize).is_multiple_of(3) && state.step < state.order.len() {
+        state.step += 1;
+    }
+
+    for (mut sprite, node) in nodes.iter_mut() {
+        let processed = state
+            .order
+            .iter()
+            .position(|&n| n == node.id)
+            .is_some_and(|pos| pos < state.step);
+        sprite.color = if processed {
+            palette.settled
+        } else {
+            palette.visited
+        };
+    }
+}
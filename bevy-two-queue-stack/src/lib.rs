@@ -0,0 +1,194 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+pub const TITLE: &str = "Two Queue Stack";
+const BG_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const STEP_INTERVAL: f32 = 1.0;
+const BOX_SIZE: f32 = 40.0;
+const SPACING: f32 = 50.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct QueueBox {
+    value: i32,
+    index: usize,
+}
+
+#[derive(Component)]
+struct ValueDigits;
+
+#[derive(Component)]
+struct MainQueue;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct TempQueue;
+
+#[derive(Resource, Clone)]
+struct State {
+    operations: Vec<String>,
+    current_idx: usize,
+    main: VecDeque<i32>,
+    temp: VecDeque<i32>,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "two-queue-stack";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(State {
+            operations: vec![
+                "push 1".to_string(),
+                "push 2".to_string(),
+                "push 3".to_string(),
+                "pop".to_string(),
+                "push 4".to_string(),
+                "pop".to_string(),
+                "pop".to_string(),
+            ],
+            current_idx: 0,
+            main: VecDeque::new(),
+            temp: VecDeque::new(),
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                animate_boxes.in_set(VizSet::Animate),
+                update_highlights.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Main Queue label
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Main Queue",
+            SegmentStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(-200.0, 200.0, 1.0),
+        ..default()
+    });
+
+    // Temp Queue label
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Temp Queue",
+            SegmentStyle {
+                font_size: 24.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(200.0, 200.0, 1.0),
+        ..default()
+    });
+
+    // Operations log
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Operations:\n(push/pop)",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -200.0, 1.0),
+        ..default()
+    });
+}
+
+fn input(mut reset: EventReader<ResetRequested>, mut state: ResMut<State>) {
+    if reset.read().count() > 0 {
+        // Reset
+        state.current_idx = 0;
+        state.main.clear();
+        state.temp.clear();
+        // Respawn boxes? For simplicity, just reset state
+    }
+}
+
+fn step(mut state: ResMut<State>, settings: Res<Settings>, mut commands: Commands) {
+    if state.current_idx >= state.operations.len() || !settings.step_ready() {
+        return;
+    }
+
+    let op = &state.operations[state.current_idx];
+    if op.starts_with("push ") {
+        if let Some(val_str) = op.strip_prefix("push ") {
+            if let Ok(val) = val_str.parse::<i32>() {
+                state.main.push_back(val);
+                // Spawn new box in main queue
+                let pos_y = 150.0 - (state.main.len() as f32 - 1.0) * SPACING;
+                commands
+                    .spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.5, 0.5, 0.5),
+                                custom_size: Some(Vec2::new(BOX_SIZE, BOX_SIZE)),
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(-200.0, pos_y, 0.0),
+                            ..default()
+                        },
+                        QueueBox {
+                            value: val,
+                            index: state.main.len() - 1,
+                        },
+                        MainQueue,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            SegmentText2dBundle {
+                                text: SegmentText::from_section(
+                                    val.to_string(),
+                                    SegmentStyle {
+                                        font_size: 20.0,
+                                        color: Color::BLACK,
+                                    },
+                                ),
+                                transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                                ..default()
+                            },
+                            ValueDigits,
+                        ));
+                    });
+            }
+        }
+    } else if op == "pop" {
+        if let Some(_val) = state.main.pop_back() {
+            // TODO: Remove the corresponding box entity
+        }
+    }
+    state.current_idx += 1;
+}
+
+fn animate_boxes() {
+    // Placeholder for animations, e.g., easing to target positions
+}
+
+fn update_highlights(
+    mut query: Query<(&mut Sprite, &QueueBox)>,
+    state: Res<State>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, queue_box) in query.iter_mut() {
+        if state.main.contains(&queue_box.value) {
+            sprite.color = palette.settled; // Green for in main
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+pub const TITLE: &str = "Two Sum";
+const BG_COLOR: Color = Color::srgb(0.03, 0.05, 0.09);
+
+#[derive(Component)]
+struct ArrayValue;
+
+#[derive(Component)]
+struct HashBucket;
+
+#[derive(Component)]
+struct Indicator;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "two-sum";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    spawn_array(&mut commands, &palette);
+    spawn_hash_map(&mut commands);
+    spawn_highlights(&mut commands, &palette);
+
+    info!("Two Sum scaffold ready. Implement iteration, lookups, and result highlights next.");
+}
+
+fn spawn_array(commands: &mut Commands, palette: &Palette) {
+    let len = 12;
+    let width = 56.0;
+    let gap = 16.0;
+    let origin_x = -(len as f32 * (width + gap) - gap) / 2.0 + width / 2.0;
+
+    for i in 0..len {
+        let value = (i * 3 + 5) % 21;
+        let x = origin_x + i as f32 * (width + gap);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.visited.with_alpha(0.8),
+                    custom_size: Some(Vec2::new(width, 120.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -160.0, 0.0),
+                ..default()
+            },
+            Highlight::Visited,
+            ArrayValue,
+        ));
+
+        commands.spawn(SegmentText2dBundle {
+            text: SegmentText::from_section(
+                value.to_string(),
+                SegmentStyle {
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(x, -160.0, 0.2),
+            text_anchor: Anchor::Center,
+            ..default()
+        });
+    }
+}
+
+fn spawn_hash_map(commands: &mut Commands) {
+    let panel_size = Vec2::new(520.0, 180.0);
+    let position = Vec3::new(0.0, 120.0, -0.2);
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.25, 0.35, 0.8),
+                custom_size: Some(panel_size),
+                ..default()
+            },
+            transform: Transform::from_translation(position),
+            ..default()
+        },
+        HashBucket,
+    ));
+
+    commands.spawn((
+        SegmentText2dBundle {
+            text: SegmentText::from_section(
+                "Hash Map (value → index)",
+                SegmentStyle {
+                    font_size: 28.0,
+                    color: Color::srgba(0.9, 0.95, 1.0, 1.0),
+                },
+            ),
+            transform: Transform::from_xyz(position.x, position.y + panel_size.y / 2.0 + 28.0, 0.3),
+            text_anchor: Anchor::TopCenter,
+            ..default()
+        },
+        HashBucket,
+    ));
+
+    let entries = 4;
+    let entry_height = 32.0;
+    let entry_gap = 12.0;
+    let start_y = position.y + panel_size.y / 2.0 - 48.0;
+
+    for i in 0..entries {
+        let y = start_y - i as f32 * (entry_height + entry_gap);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(0.35, 0.6, 0.85, 0.4),
+                    custom_size: Some(Vec2::new(panel_size.x - 40.0, entry_height)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(position.x, y, 0.1),
+                ..default()
+            },
+            HashBucket,
+        ));
+
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    format!("{} → {}", (i * 5) % 21, i),
+                    SegmentStyle {
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                text_anchor: Anchor::CenterLeft,
+                transform: Transform::from_xyz(position.x - panel_size.x / 2.0 + 30.0, y, 0.2),
+                ..default()
+            },
+            HashBucket,
+        ));
+    }
+}
+
+fn spawn_highlights(commands: &mut Commands, palette: &Palette) {
+    let indicators = [(-200.0, Highlight::Compare), (200.0, Highlight::Violation)];
+
+    for &(x, role) in &indicators {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.role(role).with_alpha(0.45),
+                    custom_size: Some(Vec2::new(70.0, 180.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, -160.0, -0.1),
+                ..default()
+            },
+            Indicator,
+            role,
+        ));
+    }
+}
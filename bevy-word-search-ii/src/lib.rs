@@ -0,0 +1,247 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+pub const TITLE: &str = "Word Search II";
+const BG_COLOR: Color = Color::srgb(0.02, 0.03, 0.06);
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct GridCell {
+    row: usize,
+    col: usize,
+    letter: char,
+    is_visited: bool,
+}
+
+#[derive(Component)]
+struct WordLabel(String);
+
+#[derive(Resource, Clone)]
+#[allow(dead_code)]
+struct AppState {
+    grid: Vec<Vec<char>>,
+    words: Vec<String>,
+    // each word the board holds, with the cells that spell it
+    matches: Vec<(String, Vec<(usize, usize)>)>,
+    found: HashSet<String>,
+    current_path: Vec<(usize, usize)>,
+    step: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "word-search-ii";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let (grid, words) = match WordGridScenario::from_args() {
+        Some(scenario) => (scenario.cells(), scenario.lowercase_words()),
+        None => (sample_grid(), sample_words()),
+    };
+    let matches = find_words(&grid, &words);
+
+    app.add_viz(ID)
+        .resource(ClearColor(BG_COLOR))
+        .resource(AppState {
+            grid,
+            words,
+            matches,
+            found: HashSet::new(),
+            current_path: Vec::new(),
+            step: 0,
+        })
+        .setup(setup)
+        .systems(Update, (update_search, ui));
+}
+
+fn sample_grid() -> Vec<Vec<char>> {
+    vec![
+        vec!['o', 'a', 'a', 'n'],
+        vec!['e', 't', 'a', 'e'],
+        vec!['i', 'h', 'k', 'r'],
+        vec!['i', 'f', 'l', 'v'],
+    ]
+}
+
+fn sample_words() -> Vec<String> {
+    ["oath", "pea", "eat", "rain"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+// Backtracking from every cell: the first path that spells each word, in list order
+fn find_words(grid: &[Vec<char>], words: &[String]) -> Vec<(String, Vec<(usize, usize)>)> {
+    words
+        .iter()
+        .filter_map(|word| {
+            let letters: Vec<char> = word.chars().collect();
+            let mut path = Vec::new();
+            let found = (0..grid.len()).any(|row| {
+                (0..grid[row].len()).any(|col| spell(grid, &letters, (row, col), &mut path))
+            });
+            found.then(|| (word.clone(), path))
+        })
+        .collect()
+}
+
+fn spell(
+    grid: &[Vec<char>],
+    letters: &[char],
+    (row, col): (usize, usize),
+    path: &mut Vec<(usize, usize)>,
+) -> bool {
+    if grid[row][col] != letters[path.len()] || path.contains(&(row, col)) {
+        return false;
+    }
+    path.push((row, col));
+    if path.len() == letters.len() {
+        return true;
+    }
+    let dirs = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    for (dr, dc) in dirs {
+        let (nr, nc) = (row as i32 + dr, col as i32 + dc);
+        if nr >= 0
+            && nr < grid.len() as i32
+            && nc >= 0
+            && nc < grid[0].len() as i32
+            && spell(grid, letters, (nr as usize, nc as usize), path)
+        {
+            return true;
+        }
+    }
+    path.pop();
+    false
+}
+
+fn setup(mut commands: Commands, state: Res<AppState>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn grid, shrunk to fit when a scenario board is larger than the sample
+    let (rows, cols) = (state.grid.len(), state.grid[0].len());
+    let cell_size = (560.0 / rows.max(cols) as f32).min(80.0);
+    let start_x = -cell_size * (cols as f32 - 1.0) / 2.0;
+    let start_y = cell_size * (rows as f32 - 1.0) / 2.0;
+    for (row, row_data) in state.grid.iter().enumerate() {
+        for (col, &letter) in row_data.iter().enumerate() {
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgb(0.3, 0.3, 0.3),
+                        custom_size: Some(Vec2::new(cell_size - 6.0, cell_size - 6.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(
+                        start_x + col as f32 * cell_size,
+                        start_y - row as f32 * cell_size,
+                        0.0,
+                    ),
+                    ..default()
+                },
+                GridCell {
+                    row,
+                    col,
+                    letter,
+                    is_visited: false,
+                },
+                SegmentText::from_section(
+                    letter.to_string(),
+                    SegmentStyle {
+                        font_size: 24.0,
+                        color: Color::srgb(0.0, 0.0, 0.0),
+                    },
+                ),
+            ));
+        }
+    }
+
+    // Spawn found words on the right
+    let base_x = 400.0;
+    let base_y = 200.0;
+    for (i, word) in state.words.iter().enumerate() {
+        commands.spawn((
+            SegmentText2dBundle {
+                text: SegmentText::from_section(
+                    word.clone(),
+                    SegmentStyle {
+                        font_size: 24.0,
+                        color: Color::srgb(0.8, 0.8, 0.8),
+                    },
+                ),
+                transform: Transform::from_xyz(base_x, base_y - i as f32 * 50.0, 0.0),
+                ..default()
+            },
+            WordLabel(word.clone()),
+        ));
+    }
+
+    // Instructions
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Word Search II: Find words in grid using backtracking\nYellow: Current path, Green: Found words",
+            SegmentStyle {
+                font_size: 18.0,
+                color: Color::srgb(0.9, 0.9, 0.9),
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -350.0, 0.0),
+        ..default()
+    });
+}
+
+fn update_search(
+    mut cells: Query<(&mut Sprite, &GridCell)>,
+    mut labels: Query<(&WordLabel, &mut SegmentText)>,
+    time: Res<Time>,
+    mut state: ResMut<AppState>,
+    palette: Res<Palette>,
+) {
+    // Reveal the next word the board holds every five seconds
+    let due = (time.elapsed_seconds() as usize / 5).min(state.matches.len());
+    if state.step < due {
+        let (word, path) = state.matches[state.step].clone();
+        state.found.insert(word);
+        state.current_path = path;
+        state.step += 1;
+    }
+
+    for (mut sprite, cell) in cells.iter_mut() {
+        if state.current_path.contains(&(cell.row, cell.col)) {
+            sprite.color = palette.compare;
+        } else {
+            sprite.color = Color::srgb(0.3, 0.3, 0.3);
+        }
+    }
+    for (label, mut text) in &mut labels {
+        if state.found.contains(&label.0) {
+            text.sections[0].style.color = Color::srgb(0.0, 1.0, 0.0);
+        }
+    }
+}
+
+fn ui(mut commands: Commands) {
+    // Placeholder button
+    commands
+        .spawn(ButtonBundle {
+            style: Style {
+                width: Val::Px(150.0),
+                height: Val::Px(50.0),
+                bottom: Val::Px(20.0),
+                right: Val::Px(20.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            background_color: Color::srgb(0.2, 0.2, 0.2).into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Search",
+                TextStyle {
+                    font_size: 20.0,
+                    color: Color::srgb(0.9, 0.9, 0.9),
+                    ..default()
+                },
+            ));
+        });
+}
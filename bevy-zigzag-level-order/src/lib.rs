@@ -0,0 +1,218 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub const TITLE: &str = "Zigzag Level Order";
+const BG_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const STEP_INTERVAL: f32 = 1.0;
+const NODE_RADIUS: f32 = 25.0;
+
+#[derive(Component)]
+#[allow(dead_code)]
+struct TreeNode {
+    value: i32,
+    level: usize,
+}
+
+#[derive(Resource, Clone)]
+struct State {
+    tree: HashMap<i32, (Option<i32>, Option<i32>)>,
+    root: i32,
+    levels: Vec<Vec<i32>>,
+    current_level_idx: usize,
+}
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "zigzag-level-order";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    let (tree, root) = match TreeScenario::from_args() {
+        Some(scenario) => (scenario.to_map(), scenario.root),
+        None => (sample_tree(), 1),
+    };
+
+    let levels = compute_levels(&tree, root);
+    // Zigzag: reverse odd levels
+    let mut zigzag_levels = levels.clone();
+    for (i, level) in zigzag_levels.iter_mut().enumerate() {
+        if i % 2 == 1 {
+            level.reverse();
+        }
+    }
+
+    app.add_viz(ID)
+        .controls(AlgoVizPlugin::new(STEP_INTERVAL))
+        .resource(ClearColor(BG_COLOR))
+        .resource(State {
+            tree,
+            root,
+            levels: zigzag_levels,
+            current_level_idx: 0,
+        })
+        .setup(setup)
+        .systems(
+            Update,
+            (
+                input.in_set(VizSet::Input),
+                step.in_set(VizSet::Step),
+                update_highlights.in_set(VizSet::Highlight),
+            ),
+        );
+}
+
+fn sample_tree() -> HashMap<i32, (Option<i32>, Option<i32>)> {
+    let mut tree = HashMap::new();
+    tree.insert(1, (Some(2), Some(3)));
+    tree.insert(2, (Some(4), Some(5)));
+    tree.insert(3, (Some(6), Some(7)));
+    tree.insert(4, (None, None));
+    tree.insert(5, (None, None));
+    tree.insert(6, (None, None));
+    tree.insert(7, (None, None));
+    tree
+}
+
+fn setup(mut commands: Commands, state: Res<State>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Spawn nodes
+    let mut positions = HashMap::new();
+    assign_positions(&state.tree, state.root, 0.0, 200.0, 0, &mut positions);
+
+    for &val in state.tree.keys() {
+        let pos = positions[&val];
+        let level = state
+            .levels
+            .iter()
+            .position(|l| l.contains(&val))
+            .unwrap_or(0);
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgb(0.5, 0.5, 0.5),
+                        custom_size: Some(Vec2::new(NODE_RADIUS * 2.0, NODE_RADIUS * 2.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pos.0, pos.1, 0.0),
+                    ..default()
+                },
+                TreeNode { value: val, level },
+            ))
+            .with_children(|parent| {
+                parent.spawn(SegmentText2dBundle {
+                    text: SegmentText::from_section(
+                        val.to_string(),
+                        SegmentStyle {
+                            font_size: 16.0,
+                            color: Color::BLACK,
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                    ..default()
+                });
+            });
+    }
+
+    // Current level text
+    commands.spawn(SegmentText2dBundle {
+        text: SegmentText::from_section(
+            "Zigzag Level 0: [1]",
+            SegmentStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        ),
+        transform: Transform::from_xyz(0.0, -200.0, 1.0),
+        ..default()
+    });
+}
+
+fn compute_levels(tree: &HashMap<i32, (Option<i32>, Option<i32>)>, root: i32) -> Vec<Vec<i32>> {
+    let mut levels = Vec::new();
+    let mut queue = std::collections::VecDeque::new();
+    queue.push_back(root);
+    while !queue.is_empty() {
+        let level_size = queue.len();
+        let mut level = Vec::new();
+        for _ in 0..level_size {
+            if let Some(node) = queue.pop_front() {
+                level.push(node);
+                if let Some(left) = tree[&node].0 {
+                    queue.push_back(left);
+                }
+                if let Some(right) = tree[&node].1 {
+                    queue.push_back(right);
+                }
+            }
+        }
+        levels.push(level);
+    }
+    levels
+}
+
+fn assign_positions(
+    tree: &HashMap<i32, (Option<i32>, Option<i32>)>,
+    node: i32,
+    x: f32,
+    y: f32,
+    depth: usize,
+    positions: &mut HashMap<i32, (f32, f32)>,
+) {
+    if !tree.contains_key(&node) {
+        return;
+    }
+    positions.insert(node, (x, y));
+    let child_y = y - 80.0;
+    if let Some(left) = tree[&node].0 {
+        assign_positions(
+            tree,
+            left,
+            x - 100.0 / (depth as f32 + 1.0),
+            child_y,
+            depth + 1,
+            positions,
+        );
+    }
+    if let Some(right) = tree[&node].1 {
+        assign_positions(
+            tree,
+            right,
+            x + 100.0 / (depth as f32 + 1.0),
+            child_y,
+            depth + 1,
+            positions,
+        );
+    }
+}
+
+fn input(mut reset: EventReader<ResetRequested>, mut state: ResMut<State>) {
+    if reset.read().count() > 0 {
+        state.current_level_idx = 0;
+    }
+}
+
+fn step(mut state: ResMut<State>, settings: Res<Settings>) {
+    if state.current_level_idx >= state.levels.len() || !settings.step_ready() {
+        return;
+    }
+
+    state.current_level_idx += 1;
+}
+
+fn update_highlights(
+    mut query: Query<(&mut Sprite, &TreeNode)>,
+    state: Res<State>,
+    palette: Res<Palette>,
+) {
+    for (mut sprite, node) in query.iter_mut() {
+        if node.level < state.current_level_idx {
+            sprite.color = palette.settled; // Green for visited
+        } else if node.level == state.current_level_idx {
+            sprite.color = palette.visited; // Blue for current
+        } else {
+            sprite.color = Color::srgb(0.5, 0.5, 0.5);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use algoviz_core::prelude::*;
+use bevy::prelude::*;
+
+pub const TITLE: &str = "Zigzag Level Order";
+const BG_COLOR: Color = Color::srgb(0.03, 0.03, 0.07);
+
+#[derive(Component)]
+struct LaneIndicator;
+
+/// Gallery id; the standalone binary starts in `Gallery::Viz(ID)`.
+pub const ID: &str = "zigzag-level";
+
+/// The viz without its window: state, setup and systems, scoped to `Gallery::Viz(ID)`.
+pub fn plugin(app: &mut App) {
+    app.add_viz(ID).resource(ClearColor(BG_COLOR)).setup(setup);
+}
+
+fn setup(mut commands: Commands, palette: Res<Palette>) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Draw alternating direction arrows as placeholders
+    let offsets = [(-180.0, 160.0), (-180.0, 40.0), (-180.0, -80.0)];
+    for (i, (x, y)) in offsets.into_iter().enumerate() {
+        let dir = if i % 2 == 0 { 1.0 } else { -1.0 };
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.focus.with_alpha(0.3),
+                    custom_size: Some(Vec2::new(240.0, 6.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(x, y, 0.0),
+                    rotation: Quat::from_rotation_z(0.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Highlight::Focus,
+            LaneIndicator,
+        ));
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette.focus.with_alpha(0.5),
+                    custom_size: Some(Vec2::new(60.0, 6.0)),
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(x + dir * 120.0, y, 0.0),
+                    rotation: Quat::from_rotation_z(dir * 0.6),
+                    ..default()
+                },
+                ..default()
+            },
+            Highlight::Focus,
+            LaneIndicator,
+        ));
+    }
+
+    info!(
+        "Zigzag Level Order scaffold initialized. Replace indicators with real traversal visuals."
+    );
+}